@@ -2,9 +2,27 @@
 
 use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Rem, RemAssign};
 use std::fmt;
+use std::cmp::Ordering;
+use std::str::FromStr;
+use std::convert::TryFrom;
 
-use num::{Zero, One};
+use num::{Zero, One, Num, Signed, FromPrimitive, ToPrimitive};
 
+/// An exact rational number `num / den` with `i64` parts, or the undefined value `UD`.
+///
+/// The arithmetic operators compute their intermediate products in `i128` and simplify before
+/// narrowing back down, so they work whenever the simplified result fits in an `i64` fraction.
+/// If it doesn't, they panic with a "Fraction overflow" message rather than wrapping. Use
+/// `try_add`, `try_sub`, `try_mul` and `try_div` to get `None` instead.
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::fractions::base::Fraction;
+/// let sum = Fraction::new(1, 3_000_000_000) + Fraction::new(1, 3_000_000_000);
+/// assert_eq!(sum.split(), (1, 1_500_000_000));
+/// let big = Fraction::new(1, 4_000_000_000);
+/// assert_eq!(big.try_mul(big), None);
+/// ```
 #[derive(Clone, Copy, Debug)]
 pub struct Fraction {
     pub num: i64,
@@ -32,22 +50,374 @@ impl One for Fraction {
     }
 }
 
+/// Parses `num`, `num/den` or `num / den` with both parts in the given radix. In radix 10 this
+/// is the same as `str::parse`, so mixed numbers and `UD` are accepted too.
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # extern crate num;
+/// # use fractions_and_matrices::fractions::base::Fraction;
+/// # use num::Num;
+/// # fn main() {
+/// assert_eq!(Fraction::from_str_radix("-a / 1f", 16), Ok(Fraction::new(-10, 31)));
+/// assert_eq!(Fraction::from_str_radix("-1 1/2", 10), Ok(Fraction::new(-3, 2)));
+/// assert!(Fraction::from_str_radix("1/0", 2).is_err());
+/// # }
+/// ```
+impl Num for Fraction {
+    type FromStrRadixErr = FractionError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Fraction, FractionError> {
+        if radix == 10 {
+            return s.parse();
+        }
+        let parse_int = |part: &str| i64::from_str_radix(part.trim(), radix)
+            .map_err(|e| FractionError::ParseError(format!("'{}' in '{}' (radix {}): {}",
+                part.trim(), s, radix, e)));
+        let (num, den) = match s.find('/') {
+            Some(ind) => (parse_int(&s[..ind])?, parse_int(&s[ind + 1..])?),
+            None => (parse_int(s)?, 1)
+        };
+        if den == 0 {
+            return Err(FractionError::ZeroDenominator);
+        }
+        let mut frac = Fraction::new(num, den);
+        frac.simplify();
+        Ok(frac)
+    }
+}
+
+/// Works on the simplified value. Undefined fractions are neither positive nor negative, and
+/// `abs`, `abs_sub` and `signum` return UD for them.
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # extern crate num;
+/// # use fractions_and_matrices::fractions::base::Fraction;
+/// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+/// # use num::Signed;
+/// # use std::iter::Sum;
+/// fn sum_of_abs<T: Signed + Sum + Clone>(m: &Matrix<T>) -> T {
+///     (0..m.num_rows())
+///         .flat_map(|r| (0..m.num_columns()).map(move |c| m[(r, c)].abs()))
+///         .sum()
+/// }
+///
+/// # fn main() {
+/// let foo = Matrix::new_from_vec((2, 2), vec![
+///     Fraction::new(1, 2), Fraction::new(-1, 3),
+///     Fraction::new(3, -4), Fraction::from(-2)
+/// ], RowAligned).unwrap();
+/// assert_eq!(sum_of_abs(&foo), Fraction::new(43, 12));
+/// let bar = Matrix::new_from_vec((2, 2), vec![1, -2, -3, 4], RowAligned).unwrap();
+/// assert_eq!(sum_of_abs(&bar), 10);
+/// assert_eq!(Fraction::new(-6, 4).signum(), Fraction::from(-1));
+/// assert!(!Fraction::from(0).is_positive() && !Fraction::from(0).is_negative());
+/// # }
+/// ```
+impl Signed for Fraction {
+    fn abs(&self) -> Fraction {
+        if self.ud {
+            return Fraction::undefined();
+        }
+        let mut abs = Fraction::new(self.num.abs(), self.den.abs());
+        abs.simplify();
+        abs
+    }
+
+    fn abs_sub(&self, other: &Fraction) -> Fraction {
+        if self.ud || other.ud {
+            Fraction::undefined()
+        } else if *self <= *other {
+            Fraction::zero()
+        } else {
+            *self - *other
+        }
+    }
+
+    fn signum(&self) -> Fraction {
+        if self.ud {
+            Fraction::undefined()
+        } else {
+            Fraction::from(self.num.signum() * self.den.signum())
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        !self.ud && self.num.signum() * self.den.signum() > 0
+    }
+
+    fn is_negative(&self) -> bool {
+        !self.ud && self.num.signum() * self.den.signum() < 0
+    }
+}
+
+/// `from_f64` (and `from_f32`) convert the float's exact binary value, so they return `None` for
+/// NaN, infinities, and values whose numerator or power-of-two denominator doesn't fit in an
+/// `i64`. `from_u64` returns `None` above `i64::MAX`.
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # extern crate num;
+/// # use fractions_and_matrices::fractions::base::Fraction;
+/// # use num::FromPrimitive;
+/// # fn main() {
+/// assert_eq!(Fraction::from_f64(-0.375), Some(Fraction::new(-3, 8)));
+/// assert_eq!(Fraction::from_f64(0.1).unwrap().split(), (3602879701896397, 36028797018963968));
+/// assert_eq!(Fraction::from_f64(f64::NAN), None);
+/// assert_eq!(Fraction::from_u64(u64::MAX), None);
+/// # }
+/// ```
+impl FromPrimitive for Fraction {
+    fn from_i64(n: i64) -> Option<Fraction> {
+        Some(Fraction::from(n))
+    }
+
+    fn from_u64(n: u64) -> Option<Fraction> {
+        Fraction::from_ratio(false, n, 1).ok()
+    }
+
+    fn from_f64(n: f64) -> Option<Fraction> {
+        if !n.is_finite() {
+            return None;
+        }
+        // n = (-1)^sign * mantissa * 2^exponent, with the mantissa made odd so that the result is
+        // already in lowest terms.
+        let bits = n.to_bits();
+        let negative = bits >> 63 == 1;
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i64;
+        let (mut mantissa, mut exponent) = if biased_exponent == 0 {
+            (bits & 0xf_ffff_ffff_ffff, -1074)
+        } else {
+            ((bits & 0xf_ffff_ffff_ffff) | 1 << 52, biased_exponent - 1075)
+        };
+        if mantissa == 0 {
+            return Some(Fraction::zero());
+        }
+        exponent += mantissa.trailing_zeros() as i64;
+        mantissa >>= mantissa.trailing_zeros();
+        let (num, den) = if exponent >= 0 {
+            if exponent >= 63 || mantissa >= 1 << (63 - exponent) {
+                return None;
+            }
+            ((mantissa << exponent) as i64, 1)
+        } else {
+            if exponent < -62 {
+                return None;
+            }
+            (mantissa as i64, 1i64 << -exponent)
+        };
+        Some(Fraction::new(if negative { -num } else { num }, den))
+    }
+}
+
+/// Every conversion returns `None` for undefined fractions. The integer conversions also return
+/// `None` unless the simplified value is a whole number that fits; `to_f64` works for any defined
+/// value.
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # extern crate num;
+/// # use fractions_and_matrices::fractions::base::Fraction;
+/// # use num::ToPrimitive;
+/// # fn main() {
+/// assert_eq!(Fraction::new(-6, 3).to_i64(), Some(-2));
+/// assert_eq!(Fraction::new(-6, 3).to_u64(), None);
+/// assert_eq!(Fraction::new(1, 2).to_i64(), None);
+/// assert_eq!(Fraction::new(1, 2).to_f64(), Some(0.5));
+/// assert_eq!((Fraction::new(1, 2) / 0).to_f64(), None);
+/// # }
+/// ```
+impl ToPrimitive for Fraction {
+    fn to_i64(&self) -> Option<i64> {
+        if self.ud {
+            return None;
+        }
+        let mut simplified = *self;
+        simplified.simplify();
+        if simplified.den == 1 {
+            Some(simplified.num)
+        } else {
+            None
+        }
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_i64().and_then(|n| n.to_u64())
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        if self.ud {
+            None
+        } else {
+            Some(self.num as f64 / self.den as f64)
+        }
+    }
+}
+
+/// Prints `num / den`, or just `num` for whole numbers. The alternate form (`{:#}`) prints the
+/// simplified value as a mixed number instead, e.g. `-3 1/2` for `-7 / 2`. Width, fill and
+/// alignment apply to the whole thing the same way they do for a `str`.
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::fractions::base::Fraction;
+/// assert_eq!(format!("{:>7}", Fraction::new(1, 2)), "  1 / 2");
+/// assert_eq!(format!("[{:*^10}]", Fraction::new(-7, 2)), "[**-7 / 2**]");
+/// assert_eq!(format!("{:<#8}|", Fraction::new(-7, 2)), "-3 1/2  |");
+/// ```
 impl fmt::Display for Fraction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.ud {
-            return write!(f, "UD");
+        let s = if self.ud {
+            "UD".to_string()
+        } else if f.alternate() {
+            let mut simplified = *self;
+            simplified.simplify();
+            let (num, den) = simplified.split();
+            if den == 1 {
+                format!("{}", num)
+            } else if num.abs() < den {
+                format!("{}/{}", num, den)
+            } else {
+                format!("{} {}/{}", num / den, (num % den).abs(), den)
+            }
         } else if self.den == 1 {
-            return write!(f, "{}", self.num);
+            format!("{}", self.num)
+        } else {
+            format!("{} / {}", self.num, self.den)
+        };
+        f.pad(&s)
+    }
+}
+
+/// Used to specify errors in constructing or parsing `Fraction`s.
+#[derive(Clone, PartialEq)]
+pub enum FractionError {
+    /// Returned when a fraction would have a zero denominator.
+    ZeroDenominator,
+    /// Returned when a string can't be parsed as a `Fraction`.
+    ParseError(String),
+    /// Returned when converting a fraction that isn't a whole number (or is undefined) into an
+    /// integer type.
+    NotAnInteger,
+    /// Returned when converting a whole number into an integer type that can't hold it.
+    OutOfRange
+}
+
+impl fmt::Debug for FractionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FractionError::ZeroDenominator => write!(f, "Fraction error: zero denominator"),
+            FractionError::ParseError(ref e) => write!(f, "Fraction parse error: {}", e),
+            FractionError::NotAnInteger => write!(f, "Fraction error: not an integer"),
+            FractionError::OutOfRange => write!(f, "Fraction error: out of range for the \
+            target type")
+        }
+    }
+}
+
+impl fmt::Display for FractionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FractionError::ZeroDenominator => write!(f, "Fraction error: zero denominator"),
+            FractionError::ParseError(ref e) => write!(f, "Fraction parse error: {}", e),
+            FractionError::NotAnInteger => write!(f, "Fraction error: not an integer"),
+            FractionError::OutOfRange => write!(f, "Fraction error: out of range for the \
+            target type")
+        }
+    }
+}
+
+/// Parses everything `Display` prints: whole numbers (`-3`), fractions (`3 / 4`, `3/-4`), mixed
+/// numbers (`-3 1/2`) and `UD`. Whitespace around the parts is ignored and the result is
+/// simplified.
+/// # Examples
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::fractions::base::{Fraction, FractionError};
+/// assert_eq!(" 6 /8 ".parse::<Fraction>().unwrap().split(), (3, 4));
+/// assert_eq!("-3 1/2".parse::<Fraction>().unwrap().split(), (-7, 2));
+/// assert_eq!("-0".parse::<Fraction>().unwrap().split(), (0, 1));
+/// assert!("UD".parse::<Fraction>().unwrap().is_ud());
+/// assert_eq!("1 / 0".parse::<Fraction>(), Err(FractionError::ZeroDenominator));
+/// assert!("1 / two".parse::<Fraction>().is_err());
+/// ```
+impl FromStr for Fraction {
+    type Err = FractionError;
+
+    fn from_str(s: &str) -> Result<Fraction, FractionError> {
+        let s = s.trim();
+        if s == "UD" {
+            return Ok(Fraction::undefined());
         }
-        write!(f, "{} / {}", self.num, self.den)
+        let parse_int = |part: &str| part.parse::<i64>()
+            .map_err(|e| FractionError::ParseError(format!("'{}' in '{}': {}", part, s, e)));
+        let (whole_part, den) = match s.find('/') {
+            Some(ind) => (&s[..ind], parse_int(s[ind + 1..].trim())?),
+            None => (s, 1)
+        };
+        let parts = whole_part.split_whitespace().collect::<Vec<&str>>();
+        let num = match parts.len() {
+            1 => parse_int(parts[0])?,
+            // Mixed number: the sign goes on the whole part, and the numerator carries none.
+            2 if s.contains('/') => {
+                let whole = parse_int(parts[0])?;
+                let num = parse_int(parts[1])?;
+                if num < 0 || parts[1].starts_with('+') {
+                    return Err(FractionError::ParseError(format!("The numerator of the mixed \
+                    number '{}' may not be signed.", s)));
+                }
+                let num = if parts[0].starts_with('-') { -num } else { num };
+                whole.checked_mul(den).and_then(|w| w.checked_add(num))
+                    .ok_or_else(|| FractionError::ParseError(format!("'{}' overflows.", s)))?
+            },
+            _ => return Err(FractionError::ParseError(format!("'{}' is not a fraction.", s)))
+        };
+        if den == 0 {
+            return Err(FractionError::ZeroDenominator);
+        }
+        let mut frac = Fraction::new(num, den);
+        frac.simplify();
+        Ok(frac)
     }
 }
 
-integer_into_frac!{u8 i8 u16 i16 u32 i32 u64 i64 usize isize}
+/// Makes a `Fraction` from a `(num, den)` tuple, returning an error instead of panicking if the
+/// fraction would be undefined. (There's deliberately no `From<(i64, i64)>` - it would clash with
+/// this impl, and `Fraction::new` already covers the panicking case.)
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # use std::convert::TryFrom;
+/// # use fractions_and_matrices::fractions::base::{Fraction, FractionError};
+/// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+/// let pairs = vec![(1, 2), (3, 4), (-5, 6), (7, 1)];
+/// let fracs = pairs.into_iter().map(Fraction::try_from).collect::<Result<Vec<_>, _>>().unwrap();
+/// let foo = Matrix::new_from_vec((2, 2), fracs, RowAligned).unwrap();
+/// assert_eq!(foo[(1, 0)], Fraction::new(-5, 6));
+/// let bad = vec![(1, 2), (3, 0)];
+/// let bad = bad.into_iter().map(Fraction::try_from).collect::<Result<Vec<_>, _>>();
+/// assert_eq!(bad.unwrap_err(), FractionError::ZeroDenominator);
+/// ```
+impl TryFrom<(i64, i64)> for Fraction {
+    type Error = FractionError;
+
+    fn try_from((num, den): (i64, i64)) -> Result<Self, FractionError> {
+        Fraction::try_new(num, den)
+    }
+}
+
+integer_into_frac!{u8 i8 u16 i16 u32 i32 i64 isize}
+
+unsigned_into_frac!{u64 usize}
 
 into_frac_float!{f32 f64}
 
-from_frac!{u8 i8 u16 i16 u32 i32 u64 i64 usize isize f32 f64}
+from_frac!{u8 i8 u16 i16 u32 i32 u64 i64 usize isize}
+
+try_from_frac!{u8 i8 u16 i16 u32 i32 u64 i64 usize isize}
+
+from_frac_float!{f32 f64}
 
 impl_arithmetic_with_frac!{u8 i8 u16 i16 u32 i32 u64 i64 usize isize f32 f64}
 
@@ -67,14 +437,65 @@ impl Fraction {
     /// let baz = Fraction::new(1, 0);
     /// ```
     pub fn new(num: i64, den: i64) -> Self {
+        match Fraction::try_new(num, den) {
+            Ok(frac) => frac,
+            Err(_) => panic!("Tried to create an undefined fraction (n / 0).")
+        }
+    }
+
+    /// Makes a new `Fraction`, returning `FractionError::ZeroDenominator` instead of panicking
+    /// for `n / 0` (with `n != 0`). Like `new`, the result isn't simplified.
+    /// # Examples
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::{Fraction, FractionError};
+    /// assert_eq!(Fraction::try_new(-3, 4), Ok(Fraction::new(-3, 4)));
+    /// assert_eq!(Fraction::try_new(1, 0), Err(FractionError::ZeroDenominator));
+    /// ```
+    pub fn try_new(num: i64, den: i64) -> Result<Self, FractionError> {
         if num != 0 && den == 0 {
-            panic!("Tried to create an undefined fraction (n / 0).");
+            return Err(FractionError::ZeroDenominator);
         }
-        Fraction {
+        Ok(Fraction {
             num: num,
             den: den,
             ud: false
+        })
+    }
+
+    /// Makes a simplified `Fraction` from a sign and an unsigned numerator and denominator, such
+    /// as the parts of a parsed ratio. The ratio is reduced in `u64` first, so parts bigger than
+    /// `i64::MAX` are fine as long as the reduced ones aren't. Returns
+    /// `FractionError::ZeroDenominator` for `n / 0` (like `try_new`, `0 / 0` is zero) and
+    /// `FractionError::OutOfRange` if the reduced numerator or denominator doesn't fit in an
+    /// `i64`.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::{Fraction, FractionError};
+    /// assert_eq!(Fraction::from_ratio(true, 6, 8), Ok(Fraction::new(-3, 4)));
+    /// assert_eq!(Fraction::from_ratio(false, 1 << 63, 1 << 62).unwrap().split(), (2, 1));
+    /// assert_eq!(Fraction::from_ratio(false, u64::MAX, 2), Err(FractionError::OutOfRange));
+    /// assert_eq!(Fraction::from_ratio(false, 1, 0), Err(FractionError::ZeroDenominator));
+    /// ```
+    pub fn from_ratio(negative: bool, num: u64, den: u64) -> Result<Fraction, FractionError> {
+        if den == 0 {
+            return if num == 0 {
+                Ok(Fraction::zero())
+            } else {
+                Err(FractionError::ZeroDenominator)
+            };
+        }
+        let gcd = get_gcd(num, den);
+        let (num, den) = (num / gcd, den / gcd);
+        if num > i64::MAX as u64 || den > i64::MAX as u64 {
+            return Err(FractionError::OutOfRange);
         }
+        Ok(Fraction {
+            num: if negative { -(num as i64) } else { num as i64 },
+            den: den as i64,
+            ud: false
+        })
     }
 
     /// Gives the inverse of a `Fraction`. Returns `self` if `self` is undefined.
@@ -91,7 +512,7 @@ impl Fraction {
     /// let mut foo = Fraction::new(2, 3);
     /// foo /= Fraction::from(0);
     /// assert!(foo.is_ud());
-    /// assert_eq!(foo, foo.inverse());
+    /// assert!(foo.inverse().is_ud());
     /// ```
     pub fn inverse(mut self) -> Fraction {
         if self.ud {
@@ -194,7 +615,12 @@ impl Fraction {
         }
     }
 
-    /// Checks to see whether or not a `Fraction` is undefined.
+    /// Checks whether a `Fraction` is undefined (the result of dividing by zero, or of any
+    /// arithmetic with an undefined operand). Undefined fractions propagate: `Fraction` operators
+    /// with an undefined operand on either side give an undefined `Fraction`, converting one to a
+    /// float gives NaN, and converting one to an integer (including through `i64 + Fraction` and
+    /// the like) panics. Undefined fractions never compare equal to anything, themselves
+    /// included.
     /// # Example
     /// ```rust
     /// # extern crate fractions_and_matrices;
@@ -202,11 +628,23 @@ impl Fraction {
     /// let mut foo = Fraction::new(1, 2);
     /// foo /= Fraction::from(0); // produces 1/0
     /// assert!(foo.is_ud());
+    /// assert!((Fraction::new(3, 4) + foo).is_ud());
+    /// assert!((foo * 2).is_ud());
+    /// assert!((1.5f64 - foo).is_nan());
+    /// assert!(!Fraction::new(1, 2).is_ud());
     /// ```
     pub fn is_ud(&self) -> bool {
         self.ud
     }
 
+    pub(crate) fn undefined() -> Fraction {
+        Fraction {
+            num: 0,
+            den: 0,
+            ud: true
+        }
+    }
+
     /// Converts the `Fraction` into an `(i64, i64)` tuple.
     /// # Examples
     /// ```rust
@@ -225,8 +663,10 @@ impl Fraction {
         (self.num, self.den)
     }
 
-    /// Returns an `Option<Fraction>` just in case either of the two `Fraction`s are undefined,
-    /// which is the only case that should return `None`, unless the result is somehow UD.
+    /// Returns `None` if either of the two `Fraction`s is undefined, if the result is undefined, or
+    /// if the simplified result doesn't fit in an `i64` fraction. The same goes for the other
+    /// `try_` arithmetic methods. Intermediate values are computed in `i128`, so only the final
+    /// result has to fit.
     /// # Examples
     /// ```rust
     /// # extern crate fractions_and_matrices;
@@ -246,120 +686,60 @@ impl Fraction {
         if self.ud || other.ud {
             return None;
         }
-        if self.den == other.den || (0 - self.den == other.den && other.num < 0) {
-            let mut s = Fraction::new(self.num + other.num, self.den);
-            s.simplify();
-            if s.ud {
-                return None;
-            }
-            Some(s)
-        } else {
-            let lcm = get_lcm(self.den, other.den) as i64;
-            let self_mult = lcm / self.den;
-            let other_mult = lcm / other.den;
-            let mut s = Fraction::new(self.num * self_mult + other.num * other_mult, lcm);
-            s.simplify();
-            if s.ud {
-                return None;
-            }
-            Some(s)
-        }
+        wide_sum(self.num, self.den, other.num as i128, other.den).filter(|s| !s.ud)
     }
 
     pub fn try_add_t<T: Into<i64>>(self, other: T) -> Option<Fraction> {
         if self.ud {
             return None;
         }
-        let mut s = Fraction::new(self.num + other.into() * self.den, self.den);
-        s.simplify();
-        if s.ud {
-            return None;
-        }
-        Some(s)
+        wide_sum(self.num, self.den, other.into() as i128, 1).filter(|s| !s.ud)
     }
 
     pub fn try_sub(self, other: Fraction) -> Option<Fraction> {
         if self.ud || other.ud {
             return None;
         }
-        if self.den == other.den || (0 - self.den == other.den && other.num < 0) {
-            let mut s = Fraction::new(self.num - other.num, self.den);
-            s.simplify();
-            if s.ud {
-                return None;
-            }
-            Some(s)
-        } else {
-            let lcm = get_lcm(self.den, other.den) as i64;
-            let self_mult = lcm / self.den;
-            let other_mult = lcm / other.den;
-            let mut s = Fraction::new(self.num * self_mult - other.num * other_mult, lcm);
-            s.simplify();
-            if s.ud {
-                return None;
-            }
-            Some(s)
-        }
+        wide_sum(self.num, self.den, -(other.num as i128), other.den).filter(|s| !s.ud)
     }
 
     pub fn try_sub_t<T: Into<i64>>(self, other: T) -> Option<Fraction> {
         if self.ud {
             return None;
         }
-        let mut s = Fraction::new(self.num - other.into() * self.den, self.den);
-        s.simplify();
-        if s.ud {
-            return None;
-        }
-        Some(s)
+        wide_sum(self.num, self.den, -(other.into() as i128), 1).filter(|s| !s.ud)
     }
 
     pub fn try_mul(self, other: Fraction) -> Option<Fraction> {
         if self.ud || other.ud {
             return None;
         }
-        let mut s = Fraction::new(self.num * other.num, self.den * other.den);
-        s.simplify();
-        if s.ud {
-            return None;
-        }
-        Some(s)
+        wide_product(self.num, self.den, other.num, other.den).filter(|s| !s.ud)
     }
 
     pub fn try_mul_t<T: Into<i64>>(self, other: T) -> Option<Fraction> {
         if self.ud {
             return None;
         }
-        let mut s = Fraction::new(self.num * other.into(), self.den);
-        s.simplify();
-        if s.ud {
-            return None;
-        }
-        Some(s)
+        wide_product(self.num, self.den, other.into(), 1).filter(|s| !s.ud)
     }
 
     pub fn try_div(self, other: Fraction) -> Option<Fraction> {
         if self.ud || other.ud {
             return None;
         }
-        let mut s = Fraction::new(self.num * other.den, self.den * other.num);
-        s.simplify();
-        if s.ud {
-            return None;
-        }
-        Some(s)
+        wide_product(self.num, self.den, other.den, other.num).filter(|s| !s.ud)
     }
 
     pub fn try_div_t<T: Into<i64>>(self, other: T) -> Option<Fraction> {
         if self.ud {
             return None;
         }
-        let mut s = Fraction::new(self.num, self.den * other.into());
-        s.simplify();
-        if s.ud {
+        let other = other.into();
+        if other == 0 {
             return None;
         }
-        Some(s)
+        wide_product(self.num, self.den, 1, other).filter(|s| !s.ud)
     }
 
     pub fn try_rem(self, other: Fraction) -> Option<Fraction> {
@@ -389,6 +769,586 @@ impl Fraction {
         }
         Some(s)
     }
+
+    /// Euclidean division: gives the whole number `q` and remainder `r` with
+    /// `self == other * q + r` and `0 <= r < |other|`. Unlike `%`, the remainder is never
+    /// negative, which is what's wanted for things like reducing a value into a period. Both
+    /// parts are undefined if either side is or if `other` is zero.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// let (q, r) = Fraction::new(-7, 2).div_rem(Fraction::from(2));
+    /// assert_eq!((q, r), (Fraction::from(-2), Fraction::new(1, 2)));
+    /// let (q, r) = Fraction::new(7, 3).div_rem(Fraction::new(-1, 2));
+    /// assert_eq!((q, r), (Fraction::from(-4), Fraction::new(1, 3)));
+    /// assert!(Fraction::from(1).div_rem(Fraction::from(0)).1.is_ud());
+    /// ```
+    pub fn div_rem(self, other: Fraction) -> (Fraction, Fraction) {
+        self.try_div_rem(other).unwrap_or((Fraction::undefined(), Fraction::undefined()))
+    }
+
+    /// The quotient from `div_rem`.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// assert_eq!(Fraction::new(-7, 2).div_floor(Fraction::from(2)), Fraction::from(-2));
+    /// ```
+    pub fn div_floor(self, other: Fraction) -> Fraction {
+        self.div_rem(other).0
+    }
+
+    /// The remainder from `div_rem`, which is always in `[0, |other|)`.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// assert_eq!(Fraction::new(-7, 2).mod_floor(Fraction::from(2)), Fraction::new(1, 2));
+    /// assert_eq!(Fraction::new(-7, 2) % 2i64, Fraction::new(-3, 2));
+    /// ```
+    pub fn mod_floor(self, other: Fraction) -> Fraction {
+        self.div_rem(other).1
+    }
+
+    /// `div_rem`, but `None` instead of undefined results.
+    pub fn try_div_rem(self, other: Fraction) -> Option<(Fraction, Fraction)> {
+        if self.ud || other.ud || other.num == 0 {
+            return None;
+        }
+        // Over a common (positive) denominator this is just Euclidean division of the numerators
+        let (mut s, mut o) = (self, other);
+        s.simplify();
+        o.simplify();
+        let lcm = get_lcm(s.den, o.den);
+        let (a, b) = (s.num * (lcm / s.den), o.num * (lcm / o.den));
+        let mut rem = Fraction::new(a.rem_euclid(b), lcm);
+        rem.simplify();
+        Some((Fraction::from(a.div_euclid(b)), rem))
+    }
+
+    /// `div_floor`, but `None` instead of an undefined result.
+    pub fn try_div_floor(self, other: Fraction) -> Option<Fraction> {
+        self.try_div_rem(other).map(|(q, _)| q)
+    }
+
+    /// `mod_floor`, but `None` instead of an undefined result.
+    pub fn try_mod_floor(self, other: Fraction) -> Option<Fraction> {
+        self.try_div_rem(other).map(|(_, r)| r)
+    }
+
+    /// Gives the mediant `(a + c) / (b + d)` of `a / b` and `c / d`. Negative denominators are
+    /// moved into the numerator first, but the result is *not* simplified, so the mediant of two
+    /// Stern-Brocot neighbours comes out exactly as the tree node (which is already in lowest
+    /// terms anyway).
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// let foo = Fraction::new(1, 3).mediant(Fraction::new(1, 2));
+    /// assert_eq!(foo.split(), (2, 5));
+    /// assert_eq!(Fraction::new(2, 4).mediant(Fraction::new(1, 1)).split(), (3, 5));
+    /// ```
+    /// # Panics
+    /// Panics if either fraction is undefined.
+    /// ```should_panic
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// let mut foo = Fraction::from(1);
+    /// foo /= Fraction::from(0);
+    /// foo.mediant(Fraction::new(1, 2));
+    /// ```
+    pub fn mediant(self, other: Fraction) -> Fraction {
+        assert!(!self.ud && !other.ud, "Tried to take the mediant of an undefined fraction.");
+        let (a, b) = if self.den < 0 { (-self.num, -self.den) } else { (self.num, self.den) };
+        let (c, d) = if other.den < 0 { (-other.num, -other.den) } else { (other.num, other.den) };
+        Fraction::new(a + c, b + d)
+    }
+
+    /// Gives the closest fractions strictly below and strictly above `self` whose denominators
+    /// are at most `max_den` - its neighbours in the Farey sequence of order `max_den`. Both
+    /// results are simplified.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// let (below, above) = Fraction::new(1, 2).farey_neighbors(5);
+    /// assert_eq!(below.split(), (2, 5));
+    /// assert_eq!(above.split(), (3, 5));
+    /// let (below, above) = Fraction::new(-7, 10).farey_neighbors(3);
+    /// assert_eq!(below.split(), (-1, 1));
+    /// assert_eq!(above.split(), (-2, 3));
+    /// ```
+    /// # Panics
+    /// Panics if `self` is undefined or `max_den` is less than 1, and with a "Fraction overflow"
+    /// message if a neighbour doesn't fit in an `i64` fraction.
+    /// ```should_panic
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// Fraction::new(1, 2).farey_neighbors(0);
+    /// ```
+    /// ```should_panic
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// Fraction::from(i64::MAX).farey_neighbors(2);
+    /// ```
+    pub fn farey_neighbors(&self, max_den: i64) -> (Fraction, Fraction) {
+        assert!(!self.ud, "Tried to find the Farey neighbours of an undefined fraction.");
+        assert!(max_den >= 1, "The maximum denominator must be at least 1.");
+        let mut x = *self;
+        x.simplify();
+        let (p, q, n) = (x.num as i128, x.den as i128, max_den as i128);
+        let to_frac = |num: i128, den: i128| narrow(num, den).unwrap_or_else(|| {
+            panic!("Fraction overflow: a Farey neighbour of {} doesn't fit in an i64 fraction.", x)
+        });
+        // `a + k * c`, for stepping one end of the interval towards the other.
+        let step = |a: i128, k: i128, c: i128| k.checked_mul(c)
+            .and_then(|kc| a.checked_add(kc))
+            .unwrap_or_else(|| panic!("Fraction overflow while finding the Farey neighbours of {}.",
+                x));
+        if q == 1 {
+            return (to_frac(p * n - 1, n), to_frac(p * n + 1, n));
+        }
+        // Walk down the Stern-Brocot tree, keeping a / b < x < c / d. Runs of steps in the same
+        // direction are taken all at once so this stays logarithmic in `max_den`.
+        let floor = p.div_euclid(q);
+        let (mut a, mut b, mut c, mut d) = (floor, 1i128, floor + 1, 1i128);
+        loop {
+            let left_gap = p * b - a * q;
+            let right_gap = c * q - p * d;
+            let left_steps = (left_gap - 1) / right_gap;
+            if left_steps > 0 {
+                let k = left_steps.min((n - b) / d);
+                a = step(a, k, c);
+                b = step(b, k, d);
+                if k < left_steps {
+                    break;
+                }
+                continue;
+            }
+            let right_steps = (right_gap - 1) / left_gap;
+            if right_steps > 0 {
+                let k = right_steps.min((n - d) / b);
+                c = step(c, k, a);
+                d = step(d, k, b);
+                if k < right_steps {
+                    break;
+                }
+                continue;
+            }
+            // The mediant is x itself, so x belongs to the sequence; its neighbours are reached
+            // by repeatedly taking mediants with x.
+            if q <= n {
+                let k = (n - b) / q;
+                a = step(a, k, p);
+                b = step(b, k, q);
+                let k = (n - d) / q;
+                c = step(c, k, p);
+                d = step(d, k, q);
+            }
+            break;
+        }
+        (to_frac(a, b), to_frac(c, d))
+    }
+
+    /// Finds the fraction closest to `value` whose denominator is at most `max_den`, using the
+    /// continued fraction expansion of `value`. Any fraction with a denominator of at most
+    /// `max_den` is recovered exactly from its `f64` value as long as `max_den` is small enough
+    /// (below about 2^26) that neighbouring candidates are further apart than rounding error.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// assert_eq!(Fraction::approximate(1.0 / 3.0, 1000).split(), (1, 3));
+    /// assert_eq!(Fraction::approximate(-3.141592653589793, 100).split(), (-311, 99));
+    /// assert_eq!(Fraction::approximate(0.001, 10).split(), (0, 1));
+    /// ```
+    /// # Panics
+    /// Panics if `value` isn't finite or doesn't fit in an `i64`, or if `max_den` is less than 1.
+    /// ```should_panic
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// Fraction::approximate(std::f64::NAN, 10);
+    /// ```
+    pub fn approximate(value: f64, max_den: i64) -> Fraction {
+        assert!(value.is_finite() && value.abs() < i64::MAX as f64, "Tried to approximate a value \
+        that doesn't fit in a fraction.");
+        assert!(max_den >= 1, "The maximum denominator must be at least 1.");
+        let target = value.abs();
+        // Convergents p0 / q0 and p1 / q1 of the continued fraction of `target`.
+        let (mut p0, mut q0, mut p1, mut q1) = (0i64, 1i64, 1i64, 0i64);
+        let mut x = target;
+        loop {
+            let a = x.floor();
+            let q2 = match (a as i64).checked_mul(q1).and_then(|aq| aq.checked_add(q0)) {
+                Some(q2) if q2 <= max_den => q2,
+                _ => break
+            };
+            let p2 = p0 + a as i64 * p1;
+            p0 = p1;
+            q0 = q1;
+            p1 = p2;
+            q1 = q2;
+            let rem = x - a;
+            if rem == 0.0 {
+                break;
+            }
+            x = 1.0 / rem;
+        }
+        // The best semiconvergent that still fits may be closer than the last convergent.
+        let k = (max_den - q0) / q1;
+        let (p_semi, q_semi) = (p0 + k * p1, q0 + k * q1);
+        let (num, den) = if (target - p_semi as f64 / q_semi as f64).abs()
+            < (target - p1 as f64 / q1 as f64).abs() {
+            (p_semi, q_semi)
+        } else {
+            (p1, q1)
+        };
+        let mut frac = Fraction::new(if value < 0.0 { -num } else { num }, den);
+        frac.simplify();
+        frac
+    }
+
+    /// Finds the fraction closest to `self` whose denominator is at most `max_den`, like
+    /// `limit_denominator` in Python's `fractions` module. Unlike `approximate` this works on the
+    /// exact value, so it's the way to tidy up the huge denominators that exact elimination can
+    /// leave behind. Fractions that already fit are returned simplified. When two candidates are
+    /// equally close, the one with the smaller denominator wins, and between equal denominators
+    /// the smaller value does (as in Python).
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// let pi = Fraction::new(3141592653589793, 1000000000000000);
+    /// assert_eq!(pi.limit_denominator(10).split(), (22, 7));
+    /// assert_eq!(pi.limit_denominator(100).split(), (311, 99));
+    /// assert_eq!(pi.limit_denominator(1000).split(), (355, 113));
+    /// assert_eq!(Fraction::new(355, 113).limit_denominator(100).split(), (311, 99));
+    /// assert_eq!(Fraction::new(-6, 14).limit_denominator(7).split(), (-3, 7));
+    /// ```
+    /// # Panics
+    /// Panics if `self` is undefined or `max_den` is less than 1.
+    /// ```should_panic
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// Fraction::new(1, 3).limit_denominator(0);
+    /// ```
+    pub fn limit_denominator(self, max_den: i64) -> Fraction {
+        assert!(!self.ud, "Tried to limit the denominator of an undefined fraction.");
+        assert!(max_den >= 1, "The maximum denominator must be at least 1.");
+        let mut x = self;
+        x.simplify();
+        if x.den <= max_den {
+            return x;
+        }
+        let (target_den, max_den) = (x.den as i128, max_den as i128);
+        // Convergents p0 / q0 and p1 / q1 of the continued fraction of x, with n / d the remainder
+        // still to be expanded. The expansion ends with x itself, whose denominator is too big,
+        // so the loop always stops before running out of terms.
+        let (mut p0, mut q0, mut p1, mut q1) = (0i128, 1i128, 1i128, 0i128);
+        let (mut n, mut d) = (x.num as i128, target_den);
+        loop {
+            let a = n.div_euclid(d);
+            let q2 = q0 + a * q1;
+            if q2 > max_den {
+                break;
+            }
+            let p2 = p0 + a * p1;
+            p0 = p1;
+            q0 = q1;
+            p1 = p2;
+            q1 = q2;
+            let rem = n - a * d;
+            n = d;
+            d = rem;
+        }
+        // The best semiconvergent that still fits may be closer than the last convergent. It's
+        // closer exactly when 2 * d * q_semi is more than x's denominator, and just as close
+        // when the two are equal.
+        let k = (max_den - q0) / q1;
+        let (p_semi, q_semi) = (p0 + k * p1, q0 + k * q1);
+        let (num, den) = match (2 * d * q_semi).cmp(&target_den) {
+            Ordering::Greater => (p_semi, q_semi),
+            Ordering::Equal if q_semi < q1 => (p_semi, q_semi),
+            _ => (p1, q1)
+        };
+        Fraction::new(num as i64, den as i64)
+    }
+
+    /// Expands `self` as a continued fraction `[a0; a1, a2, ...]`, i.e.
+    /// `a0 + 1 / (a1 + 1 / (a2 + ...))`. The expansion is the canonical one: `a0` is the floor of
+    /// the value (so negative fractions start with a negative term and every later term is
+    /// positive), and the last term is only 1 if it's the only term. Undefined fractions give an
+    /// empty expansion.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// assert_eq!(Fraction::new(355, 113).to_continued_fraction(), vec![3, 7, 16]);
+    /// assert_eq!(Fraction::new(-7, 3).to_continued_fraction(), vec![-3, 1, 2]);
+    /// assert_eq!(Fraction::from(5).to_continued_fraction(), vec![5]);
+    /// ```
+    pub fn to_continued_fraction(&self) -> Vec<i64> {
+        if self.ud {
+            return Vec::new();
+        }
+        let (mut n, mut d) = (self.num as i128, self.den as i128);
+        if d < 0 {
+            n = -n;
+            d = -d;
+        }
+        let mut terms = Vec::new();
+        while d != 0 {
+            terms.push(n.div_euclid(d) as i64);
+            let rem = n.rem_euclid(d);
+            n = d;
+            d = rem;
+        }
+        terms
+    }
+
+    /// Evaluates the continued fraction `[a0; a1, a2, ...]` (see `to_continued_fraction`), giving
+    /// a simplified fraction. Any expansion works, not just canonical ones: `[2; 1]` is 3, the
+    /// same as `[3]`. An empty expansion, one that ends up dividing by zero (a last term of 0) or
+    /// one whose value doesn't fit in an `i64` fraction gives an undefined fraction, rather than
+    /// panicking like the arithmetic operators would.
+    /// # Example
+    /// Truncating the expansion of the golden ratio, `[1; 1, 1, ...]`, gives ratios of
+    /// consecutive Fibonacci numbers:
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// assert_eq!(Fraction::from_continued_fraction(&[1; 8]).split(), (34, 21));
+    /// assert_eq!(Fraction::from_continued_fraction(&[1; 20]).split(), (10946, 6765));
+    /// assert_eq!(Fraction::from_continued_fraction(&[3, 7, 16]).split(), (355, 113));
+    /// assert!(Fraction::from_continued_fraction(&[]).is_ud());
+    /// ```
+    pub fn from_continued_fraction(terms: &[i64]) -> Fraction {
+        let (last, rest) = match terms.split_last() {
+            Some(split) => split,
+            None => return Fraction::undefined()
+        };
+        // Evaluated from the back as p / q, using a + 1 / (p / q) = (a * p + q) / p. The two stay
+        // coprime, so there's nothing to simplify along the way.
+        let (mut p, mut q) = (*last as i128, 1);
+        for &a in rest.iter().rev() {
+            if p == 0 {
+                return Fraction::undefined();
+            }
+            match (a as i128).checked_mul(p).and_then(|ap| ap.checked_add(q)) {
+                Some(next) => {
+                    q = p;
+                    p = next;
+                },
+                None => return Fraction::undefined()
+            }
+        }
+        narrow(p, q).unwrap_or_else(Fraction::undefined)
+    }
+
+    /// Parses a decimal whose repeating digits are in parentheses, like `0.(3)`, `1.2(45)` or
+    /// `-0.1(6)`, into the exact fraction it stands for (`1 / 3`, `137 / 110` and `-1 / 6`). The
+    /// whole part may be left out (`.(3)`), as may the repeating part (`1.25`). The result is
+    /// simplified. Returns a `ParseError` describing the problem for anything else - an empty
+    /// or second repeating group, a stray character, or more digits than fit in an `i64`.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// assert_eq!(Fraction::from_repeating_decimal("1.2(45)").unwrap().split(), (137, 110));
+    /// assert_eq!(Fraction::from_repeating_decimal("-0.1(6)").unwrap().split(), (-1, 6));
+    /// assert_eq!(Fraction::from_repeating_decimal("0.(9)").unwrap().split(), (1, 1));
+    /// assert!(Fraction::from_repeating_decimal("0.()").is_err());
+    /// assert!(Fraction::from_repeating_decimal("0.(1)(2)").is_err());
+    /// ```
+    pub fn from_repeating_decimal(s: &str) -> Result<Fraction, FractionError> {
+        let error = |problem: &str| Err(FractionError::ParseError(format!("'{}' is not a \
+        repeating decimal: {}.", s, problem)));
+        let trimmed = s.trim();
+        let (negative, unsigned) = match trimmed.chars().next() {
+            Some('-') => (true, &trimmed[1..]),
+            Some('+') => (false, &trimmed[1..]),
+            _ => (false, trimmed)
+        };
+        let (whole, decimals) = match unsigned.find('.') {
+            Some(ind) => (&unsigned[..ind], &unsigned[ind + 1..]),
+            None => (unsigned, "")
+        };
+        let (prefix, repetend) = match (decimals.find('('), decimals.find(')')) {
+            (None, None) => (decimals, ""),
+            (Some(open), Some(close)) if open < close && close == decimals.len() - 1 => {
+                (&decimals[..open], &decimals[open + 1..close])
+            },
+            _ => return error("the repeating digits have to be one group in parentheses at \
+            the end")
+        };
+        if decimals.contains('(') && repetend.is_empty() {
+            return error("the repeating group is empty");
+        }
+        if whole.is_empty() && decimals.is_empty() {
+            return error("there are no digits");
+        }
+        if let Some(c) = whole.chars().chain(prefix.chars()).chain(repetend.chars())
+            .find(|c| !c.is_ascii_digit()) {
+            return error(&format!("'{}' isn't a digit", c));
+        }
+        // With p digits before the repetend and r in it, the fractional part is
+        // (prefix + repetend - prefix) / (10^p * (10^r - 1)), e.g. 0.1(6) = (16 - 1) / 90.
+        let overflow = || FractionError::ParseError(format!("'{}' has too many digits to fit in \
+        a fraction.", s));
+        let digits = |d: &str| if d.is_empty() { Ok(0) } else { d.parse::<i64>() };
+        let pow10 = |n: usize| 10i64.checked_pow(n as u32);
+        let whole = digits(whole).map_err(|_| overflow())?;
+        let prefix_value = digits(prefix).map_err(|_| overflow())?;
+        let (num, den) = if repetend.is_empty() {
+            let den = pow10(prefix.len()).ok_or_else(overflow)?;
+            (prefix_value, den)
+        } else {
+            let both = digits(&format!("{}{}", prefix, repetend)).map_err(|_| overflow())?;
+            let den = pow10(repetend.len()).and_then(|r| r.checked_sub(1))
+                .and_then(|r| pow10(prefix.len()).and_then(|p| p.checked_mul(r)))
+                .ok_or_else(overflow)?;
+            (both - prefix_value, den)
+        };
+        let num = whole.checked_mul(den).and_then(|w| w.checked_add(num)).ok_or_else(overflow)?;
+        let mut frac = Fraction::new(if negative { -num } else { num }, den);
+        frac.simplify();
+        Ok(frac)
+    }
+
+    /// Creates `n` percent, i.e. `n / 100`. The result isn't simplified, so that this can be a
+    /// `const fn`; `fraction!(n %)` gives the simplified version.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// const VAT: Fraction = Fraction::percent(20);
+    /// assert_eq!(VAT, Fraction::new(1, 5));
+    /// assert_eq!(VAT.split(), (20, 100));
+    /// ```
+    pub const fn percent(n: i64) -> Fraction {
+        Fraction { num: n, den: 100, ud: false }
+    }
+
+    /// Creates `n` per mille, i.e. `n / 1000`. Not simplified, like `percent`.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// assert_eq!(Fraction::per_mille(25), Fraction::new(1, 40));
+    /// ```
+    pub const fn per_mille(n: i64) -> Fraction {
+        Fraction { num: n, den: 1000, ud: false }
+    }
+
+    /// Creates `n` basis points, i.e. `n / 10000`. Not simplified, like `percent`.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// assert_eq!(Fraction::basis_points(50), Fraction::percent(1) / 2);
+    /// ```
+    pub const fn basis_points(n: i64) -> Fraction {
+        Fraction { num: n, den: 10000, ud: false }
+    }
+
+    /// Treats `self` as a rate (e.g. from `percent`) and gives that share of `whole`. Undefined
+    /// if either side is.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// assert_eq!(Fraction::percent(15).percent_of(Fraction::from(40)), Fraction::from(6));
+    /// ```
+    pub fn percent_of(self, whole: Fraction) -> Fraction {
+        self * whole
+    }
+
+    /// Gives the relative change from `from` to `to` as a rate, i.e. `(to - from) / from`, or
+    /// `None` if `from` is zero. Undefined if either value is.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// let change = Fraction::percentage_change(Fraction::from(50), Fraction::from(75));
+    /// assert_eq!(change, Some(Fraction::percent(50)));
+    /// assert_eq!(Fraction::percentage_change(Fraction::from(0), Fraction::from(1)), None);
+    /// ```
+    pub fn percentage_change(from: Fraction, to: Fraction) -> Option<Fraction> {
+        if from.is_zero() {
+            return None;
+        }
+        Some((to - from) / from)
+    }
+
+    /// Increases (or, for a negative rate, decreases) `self` by the rate `pct`, giving
+    /// `self * (1 + pct)`. Undefined if either side is.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// let price = Fraction::from(80).apply_percentage(Fraction::percent(-25));
+    /// assert_eq!(price, Fraction::from(60));
+    /// ```
+    pub fn apply_percentage(self, pct: Fraction) -> Fraction {
+        self * (Fraction::one() + pct)
+    }
+}
+
+// Converts a decimal string as printed for a float (`-123.456`, `1.5e-4`, ...) into a fraction
+// over a power of ten. Expansions that don't fit are rounded (half up) to at most 18 significant
+// digits and 18 decimal places, and whole numbers too large for an `i64` are undefined.
+pub(crate) fn decimal_str_to_fraction(s: &str) -> Fraction {
+    const MAX_DIGITS: usize = 18;
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.trim_start_matches('+'))
+    };
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(ind) => (&s[..ind], s[ind + 1..].parse::<i64>()
+            .unwrap_or_else(|_| panic!("Invalid exponent in '{}'.", s))),
+        None => (s, 0)
+    };
+    let (whole, decimals) = match mantissa.find('.') {
+        Some(ind) => (&mantissa[..ind], &mantissa[ind + 1..]),
+        None => (mantissa, "")
+    };
+    // value = digits * 10^exp10
+    let mut digits = whole.bytes().chain(decimals.bytes())
+        .map(|b| {
+            assert!(b.is_ascii_digit(), "Invalid digit in '{}'.", s);
+            b - b'0'
+        })
+        .skip_while(|&d| d == 0)
+        .collect::<Vec<u8>>();
+    let mut exp10 = exponent - decimals.len() as i64;
+    while digits.last() == Some(&0) {
+        digits.pop();
+        exp10 += 1;
+    }
+    let drop = (digits.len().saturating_sub(MAX_DIGITS) as i64).max(-(MAX_DIGITS as i64) - exp10);
+    let mut round_up = false;
+    if drop > 0 {
+        // If every digit goes, the first dropped one is an implicit leading zero
+        let keep = digits.len().saturating_sub(drop as usize);
+        round_up = drop as usize <= digits.len() && digits[keep] >= 5;
+        digits.truncate(keep);
+        exp10 += drop;
+    }
+    let mut num = digits.iter().fold(0i64, |acc, &d| acc * 10 + d as i64);
+    if round_up {
+        num += 1;
+    }
+    let (num, den) = if exp10 >= 0 {
+        let scale = 10i64.checked_pow(exp10.min(u32::MAX as i64) as u32);
+        match scale.and_then(|scale| num.checked_mul(scale)) {
+            Some(num) => (num, 1),
+            None => return Fraction::undefined()
+        }
+    } else {
+        (num, 10i64.pow(-exp10 as u32))
+    };
+    Fraction::new(if negative { -num } else { num }, den)
 }
 
 // Not using Euclid's Algorithm anymore because it's really slow >:v
@@ -404,6 +1364,75 @@ pub fn get_gcd(mut a: u64, mut b: u64) -> u64 {
     }
 }
 
+// Multiplies (a / b) * (c / d). The products are taken in i128 (where they can't overflow), so
+// this only returns `None` when the simplified result doesn't fit in an i64. A zero denominator
+// gives an undefined fraction, the same as `Fraction::new(_, 0).simplify()` would.
+pub(crate) fn wide_product(a: i64, b: i64, c: i64, d: i64) -> Option<Fraction> {
+    narrow(a as i128 * c as i128, b as i128 * d as i128)
+}
+
+// Adds (a / b) + (c / d) over the lcm of the denominators in i128. `c` is wide so that
+// subtracting can negate it without overflowing. Returns `None` when the simplified result
+// doesn't fit in an i64.
+pub(crate) fn wide_sum(a: i64, b: i64, c: i128, d: i64) -> Option<Fraction> {
+    let (b, d) = (b as i128, d as i128);
+    if b == 0 || d == 0 {
+        return narrow(1, 0);
+    }
+    let gcd = get_gcd_u128(b.unsigned_abs(), d.unsigned_abs()) as i128;
+    let num = (a as i128 * (d / gcd)).checked_add(c * (b / gcd))?;
+    narrow(num, b / gcd * d)
+}
+
+// Simplifies num / den and narrows it back into a `Fraction` if it fits.
+fn narrow(num: i128, den: i128) -> Option<Fraction> {
+    if den == 0 {
+        let mut s = Fraction {
+            num: num.signum() as i64,
+            den: 0,
+            ud: false
+        };
+        s.simplify();
+        return Some(s);
+    }
+    let gcd = get_gcd_u128(num.unsigned_abs(), den.unsigned_abs()) as i128;
+    let (mut num, mut den) = (num / gcd, den / gcd);
+    if den < 0 {
+        num = -num;
+        den = -den;
+    }
+    match (i64::try_from(num), i64::try_from(den)) {
+        (Ok(num), Ok(den)) => Some(Fraction {
+            num,
+            den,
+            ud: false
+        }),
+        _ => None
+    }
+}
+
+fn get_gcd_u128(a: u128, b: u128) -> u128 {
+    if a <= u64::MAX as u128 && b <= u64::MAX as u128 {
+        return get_gcd(a as u64, b as u64) as u128;
+    }
+    let (mut a, mut b) = (a, b);
+    while b != 0 {
+        let c = b;
+        b = a % b;
+        a = c;
+    }
+    a
+}
+
+// Panics for operator results that don't fit in an i64 (see the `Fraction` docs).
+pub(crate) fn fit_or_panic(result: Option<Fraction>, lhs: Fraction, op: &str, rhs: Fraction)
+    -> Fraction {
+    match result {
+        Some(s) => s,
+        None => panic!("Fraction overflow: {} {} {} doesn't fit in an i64 fraction.", lhs, op, rhs)
+    }
+}
+
 // Neat trick here: lcm = a * b / gcd
 pub fn get_lcm(a: i64, b: i64) -> i64 {
     let ayy = match a < 0 {
@@ -1,38 +1,78 @@
 use std::cmp::{PartialEq, PartialOrd, Ordering};
 
-use fractions::base::{Fraction, get_lcm};
+use fractions::base::Fraction;
+
+impl Fraction {
+    /// Compares two fractions exactly, returning `None` if either of them is undefined - the same
+    /// as `partial_cmp`, which delegates to this. The cross products are taken in `i128`, so
+    /// unlike comparing over the lcm of the denominators this can't overflow, and the fractions
+    /// don't need to be simplified first.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use std::cmp::Ordering;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// let foo = Fraction::new(1, 3);
+    /// assert_eq!(foo.try_cmp(&Fraction::new(2, -5)), Some(Ordering::Greater));
+    /// assert_eq!(foo.try_cmp(&Fraction::new(2, 6)), Some(Ordering::Equal));
+    /// let big = Fraction::new(i64::MAX, 3_000_000_000);
+    /// assert_eq!(big.try_cmp(&Fraction::new(i64::MAX - 1, 3_000_000_000)),
+    ///     Some(Ordering::Greater));
+    /// assert_eq!(foo.try_cmp(&(foo / Fraction::from(0))), None);
+    /// ```
+    pub fn try_cmp(&self, other: &Fraction) -> Option<Ordering> {
+        let (a, b) = comparable_parts(self)?;
+        let (c, d) = comparable_parts(other)?;
+        Some((a * d).cmp(&(c * b)))
+    }
+
+    /// A total order for sorting, like `f64::total_cmp`: defined fractions are ordered by value
+    /// and undefined ones come after all of them (and are equal to each other). This is the only
+    /// comparison that orders undefined fractions at all - `partial_cmp` and `try_cmp` return
+    /// `None` for them, like floats do for NaN.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// let ud = Fraction::from(1) / Fraction::from(0);
+    /// let mut foo = vec![Fraction::new(1, 2), ud, Fraction::from(-3), Fraction::new(1, 3)];
+    /// foo.sort_by(Fraction::total_cmp);
+    /// assert_eq!(foo[..3], [Fraction::from(-3), Fraction::new(1, 3), Fraction::new(1, 2)]);
+    /// assert!(foo[3].is_ud());
+    /// ```
+    pub fn total_cmp(&self, other: &Fraction) -> Ordering {
+        match (comparable_parts(self).is_some(), comparable_parts(other).is_some()) {
+            (true, true) => self.try_cmp(other).unwrap(),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => Ordering::Equal
+        }
+    }
+}
+
+// The numerator and denominator with the sign moved into the numerator, or `None` for undefined
+// fractions (including `n / 0` that hasn't been simplified into UD yet). An unsimplified `0 / 0`
+// is zero, as `simplify` would make it.
+fn comparable_parts(frac: &Fraction) -> Option<(i128, i128)> {
+    match (frac.ud, frac.num, frac.den) {
+        (true, _, _) => None,
+        (false, 0, 0) => Some((0, 1)),
+        (false, _, 0) => None,
+        (false, num, den) if den < 0 => Some((-(num as i128), -(den as i128))),
+        (false, num, den) => Some((num as i128, den as i128))
+    }
+}
 
 impl Eq for Fraction {}
 
 impl PartialEq for Fraction {
     fn eq(&self, other: &Fraction) -> bool {
-        if self.ud || other.ud {
-            return false;
-        }
-        let lcm = get_lcm(self.den, other.den);
-        let self_mul = lcm / self.den;
-        let other_mul = lcm / other.den;
-        self.num * self_mul == other.num * other_mul
+        self.try_cmp(other) == Some(Ordering::Equal)
     }
 }
 
 impl PartialOrd for Fraction {
     fn partial_cmp(&self, other: &Fraction) -> Option<Ordering> {
-        if self.ud || other.ud {
-            return None;
-        }
-        if self == other {
-            return Some(Ordering::Equal);
-        }
-        let lcm = get_lcm(self.den, other.den);
-        let self_mul = lcm / self.den;
-        let other_mul = lcm / other.den;
-        if self.num * self_mul < other.num * other_mul {
-            Some(Ordering::Less)
-        } else if self.num * self_mul > other.num * other_mul {
-            Some(Ordering::Greater)
-        } else {
-            Some(Ordering::Equal)
-        }
+        self.try_cmp(other)
     }
-}
\ No newline at end of file
+}
@@ -1,3 +1,84 @@
+/// Creates a simplified `Fraction`. Accepts `fraction!(3 / 4)` (optionally with a leading `-`),
+/// `fraction!(-5)` for whole numbers, `fraction!(5 %)` for percentages and `fraction!(x, y)` for
+/// arbitrary expressions. Everything goes through `Fraction::new`, so an `n / 0` value panics at
+/// runtime, and a literal `0` denominator is rejected at compile time.
+/// # Examples
+/// ```rust
+/// # #[macro_use] extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::fractions::base::Fraction;
+/// assert_eq!(fraction!(6 / 8).split(), (3, 4));
+/// assert_eq!(fraction!(-6 / 8).split(), (-3, 4));
+/// assert_eq!(fraction!(-5).split(), (-5, 1));
+/// let (x, y) = (10, -4);
+/// assert_eq!(fraction!(x, y).split(), (-5, 2));
+/// assert_eq!(fraction!(x + 2, 3 * y).split(), (-1, 1));
+/// assert_eq!(fraction!(5 %).split(), (1, 20));
+/// assert_eq!(fraction!(-150 %).split(), (-3, 2));
+/// assert_eq!(fraction!(x %).split(), (1, 10));
+/// ```
+/// It can also be called by path, without `#[macro_use]`:
+/// ```rust
+/// extern crate fractions_and_matrices;
+/// assert_eq!(fractions_and_matrices::fraction!(-6 / 8).split(), (-3, 4));
+/// assert_eq!(fractions_and_matrices::fraction!(7).split(), (7, 1));
+/// assert_eq!(fractions_and_matrices::fraction!(-5 %).split(), (-1, 20));
+/// ```
+/// # Panics
+/// ```should_panic
+/// # #[macro_use] extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::fractions::base::Fraction;
+/// let y = 0;
+/// fraction!(1, y);
+/// ```
+/// Literal zero denominators don't compile:
+/// ```compile_fail
+/// # #[macro_use] extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::fractions::base::Fraction;
+/// fraction!(1 / 0);
+/// ```
+/// ```compile_fail
+/// # #[macro_use] extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::fractions::base::Fraction;
+/// fraction!(-1 / 0);
+/// ```
+/// ```compile_fail
+/// # #[macro_use] extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::fractions::base::Fraction;
+/// fraction!(1, 0);
+/// ```
+#[macro_export]
+macro_rules! fraction {
+    (- $num:tt / 0) => {
+        compile_error!("Tried to create a fraction with a denominator of 0.")
+    };
+    ($num:tt / 0) => {
+        compile_error!("Tried to create a fraction with a denominator of 0.")
+    };
+    (- $num:tt / $den:tt) => {
+        $crate::fraction!(-$num, $den)
+    };
+    ($num:tt / $den:tt) => {
+        $crate::fraction!($num, $den)
+    };
+    (- $num:tt %) => {
+        $crate::fraction!(-$num, 100)
+    };
+    ($num:tt %) => {
+        $crate::fraction!($num, 100)
+    };
+    ($num:expr, 0) => {
+        compile_error!("Tried to create a fraction with a denominator of 0.")
+    };
+    ($num:expr, $den:expr) => {{
+        let mut frac = $crate::fractions::base::Fraction::new($num, $den);
+        frac.simplify();
+        frac
+    }};
+    ($num:expr) => {
+        $crate::fraction!($num, 1)
+    };
+}
+
 macro_rules! integer_into_frac {
     ($($t:ty)*) => ($(
         impl From<$t> for Fraction {
@@ -14,43 +95,87 @@ macro_rules! integer_into_frac {
     )*)
 }
 
+macro_rules! unsigned_into_frac {
+    ($($t:ty)*) => ($(
+        /// Converts the integer exactly. Values over `i64::MAX` don't fit in the numerator, so
+        /// they give an undefined fraction (they used to wrap around to negative numbers). Use
+        /// `Fraction::from_ratio` or `FromPrimitive::from_u64` to get an error or `None` instead.
+        impl From<$t> for Fraction {
+            fn from(num: $t) -> Self {
+                Fraction::from_ratio(false, num as u64, 1).unwrap_or_else(|_| Fraction::undefined())
+            }
+        }
+    )*)
+}
+
 macro_rules! into_frac_float {
     ($($t:ty)*) => ($(
+        /// Converts the exact decimal expansion `Display` prints for the float, rounded to at most
+        /// 18 significant digits and 18 decimal places. NaN, the infinities and values too large
+        /// for an `i64` numerator (magnitudes of about `9.2e18` and up) give an undefined fraction.
         impl From<$t> for Fraction {
             fn from(num: $t) -> Self {
-                if num % 1.0 == 0.0 {
-                    return Fraction::new(num as i64, 1);
+                if !num.is_finite() {
+                    return Fraction::undefined();
                 }
-                let num_string = num.to_string();
-                let p10: i64 = num_string.len() as i64 - 2;
-                let mut decimal_ind: usize = 0;
-                for (i, c) in num_string.chars().enumerate() {
-                    if c == '.' {
-                        decimal_ind = i + 1;
-                        break;
-                    }
+                if num % 1.0 == 0.0 && num.abs() < i64::MAX as $t {
+                    return Fraction::new(num as i64, 1);
                 }
-                let string = {
-                    let mut num_string_tmp = num_string.into_bytes();
-                    let (mut start, mut end) = num_string_tmp.split_at_mut(decimal_ind);
-                    let mut start = start.to_vec();
-                    start.pop();
-                    start.extend_from_slice(end);
-                    start
-                };
-                let final_num = string.iter().enumerate()
-                    .map(|(i, &b)| ((b - '0' as u8) as i64) * 10i64.pow((p10 - i as i64) as u32))
-                    .sum::<i64>();
-                Fraction::new(final_num, 10i64.pow(p10 as u32))
+                decimal_str_to_fraction(&num.to_string())
             }
         }
     )*)
 }
 
+// Integers have no way to represent an undefined value, so converting one panics.
 macro_rules! from_frac {
     ($($t:ty)*) => ($(
+        /// Truncates towards zero, so `7 / 2` becomes `3`, and wraps like `as` if the result
+        /// doesn't fit. Panics if the fraction is undefined. Use `TryFrom<&Fraction>` to only
+        /// accept whole numbers that fit.
         impl From<Fraction> for $t {
             fn from(num: Fraction) -> Self {
+                if num.ud {
+                    panic!("Tried to convert an undefined fraction into an integer.");
+                }
+                num.num as $t / num.den as $t
+            }
+        }
+    )*)
+}
+
+// `TryFrom<Fraction>` would clash with the blanket impl over the truncating `From<Fraction>`, so
+// the checked conversions take the fraction by reference.
+macro_rules! try_from_frac {
+    ($($t:ty)*) => ($(
+        /// Converts a fraction that is a whole number, returning `FractionError::NotAnInteger` if
+        /// it isn't (or is undefined) and `FractionError::OutOfRange` if it doesn't fit.
+        impl<'a> TryFrom<&'a Fraction> for $t {
+            type Error = FractionError;
+
+            fn try_from(frac: &'a Fraction) -> Result<Self, FractionError> {
+                let mut frac = *frac;
+                if frac.ud {
+                    return Err(FractionError::NotAnInteger);
+                }
+                frac.simplify();
+                if frac.den != 1 {
+                    return Err(FractionError::NotAnInteger);
+                }
+                <$t>::try_from(frac.num).map_err(|_| FractionError::OutOfRange)
+            }
+        }
+    )*)
+}
+
+// Undefined fractions convert to NaN.
+macro_rules! from_frac_float {
+    ($($t:ty)*) => ($(
+        impl From<Fraction> for $t {
+            fn from(num: Fraction) -> Self {
+                if num.ud {
+                    return <$t>::NAN;
+                }
                 num.num as $t / num.den as $t
             }
         }
@@ -64,10 +189,6 @@ macro_rules! impl_add_with_frac {
             type Output = $target_type;
 
             fn add(self, rhs: Fraction) -> $target_type {
-                debug_assert!(rhs.ud);
-                if rhs.ud {
-                    return self;
-                }
                 Self::from(Fraction::from(self) + rhs)
             }
         }
@@ -79,10 +200,6 @@ macro_rules! impl_addassign_with_frac {
     ($($target_type:ty)* ) => ($(
         impl AddAssign<Fraction> for $target_type {
             fn add_assign(&mut self, rhs: Fraction) {
-                debug_assert!(rhs.ud);
-                if rhs.ud {
-                    return;
-                }
                 *self = Self::from(Fraction::from(*self) + rhs);
             }
         }
@@ -96,10 +213,6 @@ macro_rules! impl_sub_with_frac {
             type Output = $target_type;
 
             fn sub(self, rhs: Fraction) -> $target_type {
-                debug_assert!(rhs.ud);
-                if rhs.ud {
-                    return self;
-                }
                 Self::from(Fraction::from(self) - rhs)
             }
         }
@@ -111,10 +224,6 @@ macro_rules! impl_subassign_with_frac {
     ($($target_type:ty)* ) => ($(
         impl SubAssign<Fraction> for $target_type {
             fn sub_assign(&mut self, rhs: Fraction) {
-                debug_assert!(rhs.ud);
-                if rhs.ud {
-                    return;
-                }
                 *self = Self::from(Fraction::from(*self) - rhs);
             }
         }
@@ -128,10 +237,6 @@ macro_rules! impl_mul_with_frac {
             type Output = $target_type;
 
             fn mul(self, rhs: Fraction) -> $target_type {
-                debug_assert!(rhs.ud);
-                if rhs.ud {
-                    return self;
-                }
                 Self::from(Fraction::from(self) * rhs)
             }
         }
@@ -143,10 +248,6 @@ macro_rules! impl_mulassign_with_frac {
     ($($target_type:ty)* ) => ($(
         impl MulAssign<Fraction> for $target_type {
             fn mul_assign(&mut self, rhs: Fraction) {
-                debug_assert!(rhs.ud);
-                if rhs.ud {
-                    return;
-                }
                 *self = Self::from(Fraction::from(*self) * rhs);
             }
         }
@@ -160,10 +261,6 @@ macro_rules! impl_div_with_frac {
             type Output = $target_type;
 
             fn div(self, rhs: Fraction) -> $target_type {
-                debug_assert!(rhs.ud);
-                if rhs.ud {
-                    return self;
-                }
                 Self::from(Fraction::from(self) / rhs)
             }
         }
@@ -175,10 +272,6 @@ macro_rules! impl_divassign_with_frac {
     ($($target_type:ty)* ) => ($(
         impl DivAssign<Fraction> for $target_type {
             fn div_assign(&mut self, rhs: Fraction) {
-                debug_assert!(rhs.ud);
-                if rhs.ud {
-                    return;
-                }
                 *self = Self::from(Fraction::from(*self) / rhs);
             }
         }
@@ -192,10 +285,6 @@ macro_rules! impl_rem_with_frac {
             type Output = $target_type;
 
             fn rem(self, rhs: Fraction) -> $target_type {
-                debug_assert!(rhs.ud);
-                if rhs.ud {
-                    return self;
-                }
                 Self::from(Fraction::from(self) % rhs)
             }
         }
@@ -207,10 +296,6 @@ macro_rules! impl_remassign_with_frac {
     ($($target_type:ty)* ) => ($(
         impl RemAssign<Fraction> for $target_type {
             fn rem_assign(&mut self, rhs: Fraction) {
-                debug_assert!(rhs.ud);
-                if rhs.ud {
-                    return;
-                }
                 *self = Self::from(Fraction::from(*self) % rhs);
             }
         }
@@ -239,7 +324,9 @@ macro_rules! impl_assign_arithmetic_with_frac {
     )*);
 }
 
-// Implement all operator overloads from above
+// Implement all operator overloads from above. Each one converts to `Fraction`, does the
+// operation, and converts back, so an undefined operand (or dividing by zero) gives an undefined
+// result: NaN for floats and a panic for integers.
 macro_rules! impl_arithmetic_with_frac {
     ($($target_type:ty)* ) => ($(
         impl_nonassign_arithmetic_with_frac!{$target_type}
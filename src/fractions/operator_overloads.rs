@@ -1,6 +1,9 @@
 use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Rem, RemAssign, Neg};
+use std::iter::Sum;
 
-use fractions::base::{Fraction, get_lcm};
+use num::Zero;
+
+use fractions::base::{Fraction, get_lcm, wide_product, wide_sum, fit_or_panic};
 
 impl Neg for Fraction {
     type Output = Fraction;
@@ -19,51 +22,22 @@ impl<T: Into<Fraction> + From<Fraction>> Add<T> for Fraction {
     type Output = Fraction;
 
     fn add(self, rhs: T) -> Fraction {
-        debug_assert!(!self.ud);
         let r = rhs.into();
-        debug_assert!(!r.ud);
         if self.ud || r.ud {
-            return self;
-        }
-        if self.den == r.den || (0 - self.den == r.den && r.num < 0) {
-            let mut s = self.clone();
-            s.num += r.num;
-            s.simplify();
-            s
-        } else {
-            let mut s = self.clone();
-            let lcm = get_lcm(s.den, r.den) as i64;
-            let self_mult = lcm / s.den;
-            let r_mult = lcm / r.den;
-            s.num *= self_mult;
-            s.num += r.num * r_mult;
-            s.den = lcm;
-            s.simplify();
-            s
+            return Fraction::undefined();
         }
+        fit_or_panic(wide_sum(self.num, self.den, r.num as i128, r.den), self, "+", r)
     }
 }
 
 impl<T: Into<Fraction> + From<Fraction>> AddAssign<T> for Fraction {
     fn add_assign(&mut self, rhs: T) {
-        debug_assert!(!self.ud);
         let r = rhs.into();
-        debug_assert!(!r.ud);
         if self.ud || r.ud {
+            *self = Fraction::undefined();
             return;
         }
-        if self.den == r.den || (0 - self.den == r.den && r.num < 0) {
-            self.num += r.num;
-            self.simplify();
-        } else {
-            let lcm = get_lcm(self.den, r.den) as i64;
-            let self_mult = lcm / self.den;
-            let r_mult = lcm / r.den;
-            self.num *= self_mult;
-            self.num += r.num * r_mult;
-            self.den = lcm;
-            self.simplify();
-        }
+        *self = fit_or_panic(wide_sum(self.num, self.den, r.num as i128, r.den), *self, "+", r);
     }
 }
 
@@ -71,51 +45,22 @@ impl<T: Into<Fraction> + From<Fraction>> Sub<T> for Fraction {
     type Output = Fraction;
 
     fn sub(self, rhs: T) -> Fraction {
-        debug_assert!(!self.ud);
         let r = rhs.into();
-        debug_assert!(!r.ud);
         if self.ud || r.ud {
-            return self;
-        }
-        if self.den == r.den || (0 - self.den == r.den && r.num < 0) {
-            let mut s = self.clone();
-            s.num += r.num;
-            s.simplify();
-            s
-        } else {
-            let mut s = self.clone();
-            let lcm = get_lcm(s.den, r.den) as i64;
-            let self_mult = lcm / s.den;
-            let r_mult = lcm / r.den;
-            s.num *= self_mult;
-            s.num += r.num * r_mult;
-            s.den = lcm;
-            s.simplify();
-            s
+            return Fraction::undefined();
         }
+        fit_or_panic(wide_sum(self.num, self.den, -(r.num as i128), r.den), self, "-", r)
     }
 }
 
 impl<T: Into<Fraction> + From<Fraction>> SubAssign<T> for Fraction {
     fn sub_assign(&mut self, rhs: T) {
-        debug_assert!(!self.ud);
         let r = rhs.into();
-        debug_assert!(!r.ud);
         if self.ud || r.ud {
+            *self = Fraction::undefined();
             return;
         }
-        if self.den == r.den || (0 - self.den == r.den && r.num < 0) {
-            self.num -= r.num;
-            self.simplify();
-        } else {
-            let lcm = get_lcm(self.den, r.den) as i64;
-            let self_mult = lcm / self.den;
-            let r_mult = lcm / r.den;
-            self.num *= self_mult;
-            self.num -= r.num * r_mult;
-            self.den = lcm;
-            self.simplify();
-        }
+        *self = fit_or_panic(wide_sum(self.num, self.den, -(r.num as i128), r.den), *self, "-", r);
     }
 }
 
@@ -123,27 +68,22 @@ impl<T: Into<Fraction> + From<Fraction>> Mul<T> for Fraction {
     type Output = Fraction;
 
     fn mul(self, rhs: T) -> Fraction {
-        debug_assert!(!self.ud);
         let r = rhs.into();
-        debug_assert!(!r.ud);
         if self.ud || r.ud {
-            return self;
+            return Fraction::undefined();
         }
-        Fraction::new(self.num * r.num, self.den * r.den)
+        fit_or_panic(wide_product(self.num, self.den, r.num, r.den), self, "*", r)
     }
 }
 
 impl<T: Into<Fraction> + From<Fraction>> MulAssign<T> for Fraction {
     fn mul_assign(&mut self, rhs: T) {
-        debug_assert!(!self.ud);
         let r = rhs.into();
-        debug_assert!(!r.ud);
         if self.ud || r.ud {
+            *self = Fraction::undefined();
             return;
         }
-        self.num *= r.num;
-        self.den *= r.den;
-        self.simplify();
+        *self = fit_or_panic(wide_product(self.num, self.den, r.num, r.den), *self, "*", r);
     }
 }
 
@@ -151,27 +91,22 @@ impl<T: Into<Fraction> + From<Fraction>> Div<T> for Fraction {
     type Output = Fraction;
 
     fn div(self, rhs: T) -> Fraction {
-        debug_assert!(!self.ud);
         let r = rhs.into();
-        debug_assert!(!r.ud);
         if self.ud || r.ud {
-            return self;
+            return Fraction::undefined();
         }
-        Fraction::new(self.num * r.den, self.den * r.num)
+        fit_or_panic(wide_product(self.num, self.den, r.den, r.num), self, "/", r)
     }
 }
 
 impl<T: Into<Fraction> + From<Fraction>> DivAssign<T> for Fraction {
     fn div_assign(&mut self, rhs: T) {
-        debug_assert!(!self.ud);
         let r = rhs.into();
-        debug_assert!(!r.ud);
         if self.ud || r.ud {
+            *self = Fraction::undefined();
             return;
         }
-        self.num *= r.den;
-        self.den *= r.num;
-        self.simplify();
+        *self = fit_or_panic(wide_product(self.num, self.den, r.den, r.num), *self, "/", r);
     }
 }
 
@@ -179,42 +114,36 @@ impl<T: Into<Fraction> + From<Fraction>> Rem<T> for Fraction {
     type Output = Fraction;
 
     fn rem(self, rhs: T) -> Self::Output {
-        debug_assert!(!self.ud);
         let r = rhs.into();
-        debug_assert!(!r.ud);
-        if self.ud || r.ud {
-            return self;
-        }
-        if self.den == r.den || (0 - self.den == r.den && r.num < 0) {
-            Fraction::new(self.num % r.den, self.den)
-        } else {
-            let lcm = get_lcm(self.den, r.den) as i64;
-            let self_mult = lcm / self.den;
-            let r_mult = lcm / r.den;
-            Fraction::new((self.num * self_mult) % (r.num * r_mult), lcm)
-        }
+        if self.ud || r.ud || r.num == 0 {
+            return Fraction::undefined();
+        }
+        // Like the integer remainder, the result has the sign of `self`: with both sides over the
+        // same (positive) denominator, it's just the remainder of the numerators.
+        let (mut s, mut r) = (self, r);
+        s.simplify();
+        r.simplify();
+        let lcm = get_lcm(s.den, r.den);
+        let mut res = Fraction::new((s.num * (lcm / s.den)) % (r.num * (lcm / r.den)), lcm);
+        res.simplify();
+        res
     }
 }
 
 impl<T: Into<Fraction> + From<Fraction>> RemAssign<T> for Fraction {
     fn rem_assign(&mut self, rhs: T) {
-        debug_assert!(!self.ud);
-        let r = rhs.into();
-        debug_assert!(!r.ud);
-        if self.ud || r.ud {
-            return;
-        }
-        if self.den == r.den || (0 - self.den == r.den && r.num < 0) {
-            self.num %= r.num;
-            self.simplify();
-        } else {
-            let lcm = get_lcm(self.den, r.den) as i64;
-            let self_mult = lcm / self.den;
-            let r_mult = lcm / r.den;
-            self.num *= self_mult;
-            self.den = lcm;
-            self.num %= r * r_mult;
-            self.simplify();
-        }
+        *self = *self % rhs.into();
+    }
+}
+
+impl Sum for Fraction {
+    fn sum<I: Iterator<Item = Fraction>>(iter: I) -> Fraction {
+        iter.fold(Fraction::zero(), |acc, f| acc + f)
+    }
+}
+
+impl<'a> Sum<&'a Fraction> for Fraction {
+    fn sum<I: Iterator<Item = &'a Fraction>>(iter: I) -> Fraction {
+        iter.fold(Fraction::zero(), |acc, f| acc + *f)
     }
-}
\ No newline at end of file
+}
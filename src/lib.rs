@@ -1,16 +1,51 @@
 #![allow(unused_macros)]
 #![allow(unused_imports)]
 pub extern crate num;
+#[cfg(all(feature = "mmap", unix))] extern crate libc;
+#[cfg(test)] extern crate rand;
 
 #[macro_use] pub mod fractions;
 #[macro_use] pub mod matrices;
+pub mod numeric_utils;
 
 #[cfg(test)]
 mod tests {
-    use fractions::base::Fraction;
-    use matrices::base::{Matrix, AugmentedMatrix, Alignment::{ColumnAligned, RowAligned}};
+    use fractions::base::{Fraction, FractionError, decimal_str_to_fraction};
+    use matrices::base::{Matrix, AugmentedMatrix, MatrixError, MatrixErrorKind, RowIdx,
+        Alignment::{ColumnAligned, RowAligned}};
     use matrices::extras::*;
 
+    // A matrix as it is and column-aligned, so that the same checks can run on both storage
+    // orders.
+    trait BothAlignments: Sized {
+        fn both_alignments(self) -> Vec<Self>;
+    }
+
+    macro_rules! both_alignments_impls {
+        ($($target_type:ty),*) => ($(
+            impl<T: Clone> BothAlignments for $target_type {
+                fn both_alignments(self) -> Vec<Self> {
+                    let mut column_aligned = self.clone();
+                    column_aligned.column_align();
+                    vec![self, column_aligned]
+                }
+            }
+        )*)
+    }
+
+    both_alignments_impls!{Matrix<T>, AugmentedMatrix<T>}
+
+    // The elements of the 3x4 matrix that the summary and reduction tests share.
+    const SAMPLE: [i64; 12] = [3, -1, 4, 1, -5, 9, 2, 6, -5, 3, 5, 8];
+
+    // `SAMPLE` as a matrix and as an augmented matrix, whose solution column is (1, 6, 8), in
+    // both alignments.
+    fn sample_matrices() -> Vec<(Matrix<i64>, AugmentedMatrix<i64>)> {
+        let foo = Matrix::new_from_vec((3, 4), SAMPLE.to_vec(), RowAligned).unwrap();
+        let bar = AugmentedMatrix::new_from_vec((3, 4), SAMPLE.to_vec(), RowAligned).unwrap();
+        foo.both_alignments().into_iter().zip(bar.both_alignments()).collect()
+    }
+
     #[test]
     fn wewe() {
         let matrix: Matrix<u32> = matrix![
@@ -174,6 +209,4014 @@ mod tests {
         let bar = foo.gaussian_elim_display().unwrap();
         println!("foo:\n{}", foo);
         println!("Steps: {:?}", bar);
-        assert!(!foo.is_row_reduced());
+        assert!(foo.is_row_reduced());
+    }
+
+    #[test]
+    fn cross_reduced_mul_div_test() {
+        // Both naive products (a * b and b * a) overflow an i64, but everything cancels.
+        let a = 4_000_000_007i64;
+        let b = 3_000_000_019i64;
+        let foo = Fraction::new(a, b);
+        let bar = Fraction::new(b, a);
+        assert_eq!(foo * bar, Fraction::from(1));
+        assert_eq!(foo.try_mul(bar), Some(Fraction::from(1)));
+        assert_eq!(foo / foo, Fraction::from(1));
+        assert_eq!(foo.try_div(foo), Some(Fraction::from(1)));
+        let mut baz = foo;
+        baz *= bar;
+        assert_eq!(baz, Fraction::from(1));
+        // Partial cancellation still leaves a result that fits.
+        let qux = Fraction::new(a, 3) * Fraction::new(5, a);
+        assert_eq!(qux.split(), (5, 3));
+        let quux = Fraction::new(a, 7) / Fraction::new(a, b);
+        assert_eq!(quux.split(), (b, 7));
+    }
+
+    #[test]
+    fn spectral_condition_test() {
+        // Eigenvalues of tridiag(-1, 2, -1) are 2 - 2cos(k * pi / 6) for k = 1..5, so the
+        // condition number is (2 + sqrt(3)) / (2 - sqrt(3)) = 7 + 4 * sqrt(3).
+        let mut vec = vec![0.0f64; 25];
+        for i in 0..5 {
+            vec[i * 5 + i] = 2.0;
+            if i > 0 {
+                vec[i * 5 + i - 1] = -1.0;
+                vec[(i - 1) * 5 + i] = -1.0;
+            }
+        }
+        let foo = Matrix::new_from_vec((5, 5), vec, RowAligned).unwrap();
+        let expected = 7.0 + 4.0 * 3.0f64.sqrt();
+        let cond = foo.spectral_condition_sym().unwrap();
+        assert!((cond - expected).abs() / expected < 0.01);
+        let (smallest, _) = foo.smallest_eigen(0.0, 1000, 1e-12).unwrap();
+        assert!((smallest - (2.0 - 3.0f64.sqrt())).abs() < 1e-9);
+        let mut bar = foo.clone();
+        bar[(0, 4)] = 1.0;
+        assert!(bar.spectral_condition_sym().is_err());
+    }
+
+    #[test]
+    fn augmented_transpose_keeps_solution_column() {
+        let mut foo: AugmentedMatrix<i32> = augmented_matrix![
+            1 2 3 4 =>  5;
+            6 7 8 9 => 10
+        ];
+        let original = foo.clone();
+        for _ in 0..4 {
+            foo.in_place_transpose();
+            assert_eq!(foo.dimension(), (2, 4));
+            assert_eq!(foo.num_columns(), 4);
+            assert_eq!(foo.solution_column(), vec![5, 10]);
+            for r in 0..2 {
+                assert_eq!(foo[(r, foo.num_columns())], original[(r, 4)]);
+            }
+            assert_eq!(foo, original);
+        }
+        assert!(foo.exactly_equal_including_alignment(&original));
+        let bar = AugmentedMatrix::new_from_vec((2, 5), vec![1, 6, 2, 7, 3, 8, 4, 9, 5, 10],
+                                                ColumnAligned).unwrap();
+        assert_eq!(bar.solution_column(), vec![5, 10]);
+        assert_eq!(bar, original);
+    }
+
+    // Mirrors the `AugmentedMatrix::in_place_transpose()` doc example, which is built inside
+    // `matrix_base_impls!` and so isn't run as a doctest.
+    #[test]
+    fn augmented_in_place_transpose_example_test() {
+        let mut foo = augmented_matrix![
+            0 1 2 3 => 4;
+            5 6 7 8 => 9
+        ];
+        foo.in_place_transpose();
+        let bar = AugmentedMatrix::new_from_vec((2, 5), vec![0, 5, 1, 6, 2, 7, 3, 8, 4, 9],
+            ColumnAligned).unwrap();
+        assert!(foo.exactly_equal_including_alignment(&bar));
+        assert_eq!(foo.dimension(), (2, 4));
+        assert_eq!(foo.solution_column(), vec![4, 9]);
+    }
+
+    #[test]
+    fn farey_neighbors_brute_force_test() {
+        for max_den in 1..9i64 {
+            for den in 1..11i64 {
+                for num in -20..20i64 {
+                    let x = Fraction::new(num, den);
+                    let mut below: Option<Fraction> = None;
+                    let mut above: Option<Fraction> = None;
+                    for d in 1..max_den + 1 {
+                        for n in -22 * d..22 * d {
+                            let f = Fraction::new(n, d);
+                            if f < x && below.into_iter().all(|b| f > b) {
+                                below = Some(f);
+                            }
+                            if f > x && above.into_iter().all(|a| f < a) {
+                                above = Some(f);
+                            }
+                        }
+                    }
+                    let (b, a) = x.farey_neighbors(max_den);
+                    assert_eq!(b, below.unwrap(), "below {}/{} at order {}", num, den, max_den);
+                    assert_eq!(a, above.unwrap(), "above {}/{} at order {}", num, den, max_den);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fraction_string_round_trip_test() {
+        for den in 1..201i64 {
+            for num in -200..201i64 {
+                let f = Fraction::new(num, den);
+                assert_eq!(format!("{}", f).parse::<Fraction>(), Ok(f), "{}/{}", num, den);
+                assert_eq!(format!("{:#}", f).parse::<Fraction>(), Ok(f), "{}/{}", num, den);
+                assert_eq!(Fraction::approximate(f64::from(f), 200), f, "{}/{}", num, den);
+                // Only power-of-two denominators have an exact f64 representation
+                if den & (den - 1) == 0 {
+                    assert_eq!(Fraction::from(f64::from(f)), f, "{}/{}", num, den);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fraction_string_round_trip_large_values_test() {
+        use rand::{Rng, SeedableRng, StdRng};
+        let mut rng = StdRng::from_seed([7; 32]);
+        for _ in 0..10_000 {
+            // Kept below 2^31 so that comparing the results (via the lcm) can't overflow
+            let num = rng.gen_range(-(1i64 << 31), 1 << 31);
+            let den = rng.gen_range(1, 1i64 << 31);
+            let f = Fraction::new(num, den);
+            assert_eq!(format!("{}", f).parse::<Fraction>(), Ok(f), "{}/{}", num, den);
+            assert_eq!(format!("{:#}", f).parse::<Fraction>(), Ok(f), "{}/{}", num, den);
+        }
+    }
+
+    #[test]
+    fn fraction_string_snapshot_test() {
+        let cases = [
+            ((0, 1), "0", "0"),
+            ((0, -5), "0 / -5", "0"),
+            ((4, 1), "4", "4"),
+            ((-4, 2), "-4 / 2", "-2"),
+            ((1, 2), "1 / 2", "1/2"),
+            ((-1, 2), "-1 / 2", "-1/2"),
+            ((3, -4), "3 / -4", "-3/4"),
+            ((7, 2), "7 / 2", "3 1/2"),
+            ((-7, 2), "-7 / 2", "-3 1/2"),
+            ((200, 3), "200 / 3", "66 2/3")
+        ];
+        for &((num, den), plain, mixed) in cases.iter() {
+            let f = Fraction::new(num, den);
+            assert_eq!(format!("{}", f), plain);
+            assert_eq!(format!("{:#}", f), mixed);
+        }
+        let ud = Fraction::new(1, 2) / Fraction::from(0);
+        assert_eq!(format!("{}", ud), "UD");
+        assert_eq!(format!("{:#}", ud), "UD");
+        assert!("UD".parse::<Fraction>().unwrap().is_ud());
+        for &s in ["\t-3/4", "-3 /4 ", "  -3  /  4", "-0 3/4", "3/-4"].iter() {
+            assert_eq!(s.parse::<Fraction>(), Ok(Fraction::new(-3, 4)), "{:?}", s);
+        }
+        assert_eq!("+3/4".parse::<Fraction>(), Ok(Fraction::new(3, 4)));
+        assert_eq!("5 / 0".parse::<Fraction>(), Err(FractionError::ZeroDenominator));
+        assert_eq!("0 / 0".parse::<Fraction>(), Err(FractionError::ZeroDenominator));
+        for &s in ["", "/", "1 /", "1 2", "1 -1/2", "1 1/2/3", "x / 2", "1.5"].iter() {
+            assert!(s.parse::<Fraction>().is_err(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn augmented_merge_test() {
+        let f = |n, d| Fraction::new(n, d);
+        let mut system = AugmentedMatrix::new_from_vec((2, 4), vec![
+            f(1, 1), f(2, 1), f(0, 1), f(3, 1),
+            f(0, 1), f(1, 2), f(1, 1), f(1, 1)
+        ], RowAligned).unwrap();
+        let mut other = AugmentedMatrix::new_from_vec((5, 4), vec![
+            // exact duplicate of equation 0
+            f(1, 1), f(2, 1), f(0, 1), f(3, 1),
+            // equation 1 scaled by -2/3
+            f(0, 1), f(-1, 3), f(-2, 3), f(-2, 3),
+            // equation 0's left hand side scaled by 3, with a different right hand side
+            f(3, 1), f(6, 1), f(0, 1), f(10, 1),
+            // new
+            f(1, 1), f(0, 1), f(1, 1), f(0, 1),
+            // new, but shares its zero pattern with equation 0
+            f(1, 1), f(3, 1), f(0, 1), f(3, 1)
+        ], RowAligned).unwrap();
+        other.column_align();
+        let mut undeduped = system.clone();
+        let report = system.merge(&other, true).unwrap();
+        assert_eq!(report.appended, 2);
+        assert_eq!(report.duplicates, 2);
+        assert_eq!(report.conflicts, vec![(RowIdx(0), RowIdx(2))]);
+        assert_eq!(system.num_rows(), 4);
+        assert_eq!(system.solution_column(), vec![f(3, 1), f(1, 1), f(0, 1), f(3, 1)]);
+        let report = undeduped.merge(&other, false).unwrap();
+        assert_eq!(report.appended, 5);
+        assert_eq!(report.duplicates, 0);
+        assert!(report.conflicts.is_empty());
+        assert_eq!(undeduped.num_rows(), 7);
+        // Zero left hand sides only relate to each other
+        let mut zeros = augmented_matrix![0 0 => 0];
+        let report = zeros.merge(&augmented_matrix![0 0 => 0; 0 0 => 5; 1 0 => 0], true).unwrap();
+        assert_eq!((report.appended, report.duplicates), (1, 1));
+        assert_eq!(report.conflicts, vec![(RowIdx(0), RowIdx(1))]);
+        let wide = augmented_matrix![1 2 3 => 4];
+        assert!(zeros.merge(&wide, true).is_err());
+        assert_eq!(zeros.num_rows(), 2);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_arithmetic_test() {
+        // 5x3 = 15 elements: one full 8-wide chunk plus a remainder
+        let lhs = Matrix::new_from_vec((5, 3), (0..15).map(|n| n as f64).collect(), RowAligned)
+            .unwrap();
+        let rhs = Matrix::new_from_vec((5, 3), (0..15).map(|n| (n * n) as f64).collect(),
+            RowAligned).unwrap();
+        let mut column_aligned_rhs = rhs.clone();
+        column_aligned_rhs.column_align();
+        for rhs in [rhs, column_aligned_rhs].iter() {
+            let mut sum = lhs.clone();
+            sum.simd_add_assign(rhs).unwrap();
+            let mut difference = lhs.clone();
+            difference.simd_sub_assign(rhs).unwrap();
+            let mut product = lhs.clone();
+            product.simd_hadamard_assign(rhs).unwrap();
+            for r in 0..5 {
+                for c in 0..3 {
+                    assert_eq!(sum[(r, c)], lhs[(r, c)] + rhs[(r, c)]);
+                    assert_eq!(difference[(r, c)], lhs[(r, c)] - rhs[(r, c)]);
+                    assert_eq!(product[(r, c)], lhs[(r, c)] * rhs[(r, c)]);
+                }
+            }
+        }
+        let mut foo: Matrix<f32> = matrix![1.0 2.0 3.0];
+        assert!(foo.simd_add_assign(&matrix![1.0; 2.0; 3.0]).is_err());
+    }
+
+    // Runs `cargo check --features "simd mmap"` so that the feature-gated modules can't silently
+    // stop compiling while the default build stays green. It builds into its own target
+    // directory so it doesn't wait on the lock held by the `cargo test` running it.
+    #[test]
+    fn optional_features_build() {
+        use std::env;
+        use std::process::Command;
+        let cargo = match env::var("CARGO") {
+            Ok(cargo) => cargo,
+            Err(_) => return
+        };
+        let manifest_dir = env!("CARGO_MANIFEST_DIR");
+        let output = Command::new(cargo)
+            .args(["check", "--offline", "--all-targets", "--features", "simd mmap"])
+            .arg("--target-dir")
+            .arg(format!("{}/target/feature-check", manifest_dir))
+            .current_dir(manifest_dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    }
+
+    #[test]
+    fn fraction_scalar_operators_test() {
+        use matrices::transforms::{RowOpMul, RowOpDiv};
+        macro_rules! check_scalar_ops {
+            ($($t:ty)*) => ($(
+                for &(num, den) in [(3i64, 4i64), (-3, 4), (5, 1), (0, 1)].iter() {
+                    for &k in [1i64, 2, 7].iter() {
+                        let f = Fraction::new(num, den);
+                        let scalar = k as $t;
+                        assert_eq!(f + scalar, Fraction::new(num + k * den, den));
+                        assert_eq!(f - scalar, Fraction::new(num - k * den, den));
+                        assert_eq!(f * scalar, Fraction::new(num * k, den));
+                        assert_eq!(f / scalar, Fraction::new(num, den * k));
+                        let mut g = f;
+                        g += scalar;
+                        g -= scalar;
+                        g *= scalar;
+                        g /= scalar;
+                        assert_eq!(g, f);
+                        assert_eq!(f.try_add_t(scalar), Some(f + scalar));
+                        assert_eq!(f.try_sub_t(scalar), Some(f - scalar));
+                        assert_eq!(f.try_mul_t(scalar), Some(f * scalar));
+                        assert_eq!(f.try_div_t(scalar), Some(f / scalar));
+                    }
+                }
+            )*)
+        }
+        check_scalar_ops!{i64 i32 i16 i8 u32 u16 u8}
+        // Negative scalars on signed types, and usize (which has no `Into<i64>`)
+        assert_eq!(Fraction::new(3, 4) * -2i32, Fraction::new(-3, 2));
+        assert_eq!(Fraction::new(-3, 4) / -3i64, Fraction::new(1, 4));
+        assert_eq!(Fraction::new(1, 2) - -1i8, Fraction::new(3, 2));
+        assert_eq!(Fraction::new(-1, 2) + -1i16, Fraction::new(-3, 2));
+        assert_eq!(Fraction::new(5, 6) * 3usize, Fraction::new(5, 2));
+        assert_eq!(Fraction::new(5, 6) - 1usize, Fraction::new(-1, 6));
+        assert_eq!(Fraction::new(1, 2) * 3, Fraction::new(3, 2));
+        assert_eq!(Fraction::new(1, 2).try_div_t(0), None);
+        // Denominators that are negatives of each other
+        assert_eq!(Fraction::new(1, 2) + Fraction::new(-1, -2), Fraction::from(1));
+        assert_eq!(Fraction::new(1, 2) - Fraction::new(1, -2), Fraction::from(1));
+        let mut foo = Matrix::new_from_vec((2, 2), vec![Fraction::new(1, 2), Fraction::new(-1, 3),
+            Fraction::from(1), Fraction::from(2)], RowAligned).unwrap();
+        foo.row_op_mul(0, 6i64);
+        foo.row_op_div(1, 2);
+        let bar = Matrix::new_from_vec((2, 2), vec![Fraction::from(3), Fraction::from(-2),
+            Fraction::new(1, 2), Fraction::from(1)], RowAligned).unwrap();
+        assert_eq!(foo, bar);
+    }
+
+    #[test]
+    fn slice_normalization_test() {
+        use matrices::transforms::{Simplify, SimplifyGetStepsDisplay};
+        use numeric_utils::{slice_gcd, normalize_slice_in_place, normalize_fraction_slice};
+        assert_eq!(slice_gcd(&[0, -15, 0, 25, -35]), Some(5));
+        assert_eq!(slice_gcd(&[0i64, 0, 0]), None);
+        assert_eq!(slice_gcd::<i32>(&[]), None);
+        let mut ints = [0, -15, 0, 25, -35];
+        assert_eq!(normalize_slice_in_place(&mut ints), Some(5));
+        assert_eq!(ints, [0, -3, 0, 5, -7]);
+        assert_eq!(normalize_slice_in_place(&mut ints), Some(1));
+        assert_eq!(ints, [0, -3, 0, 5, -7]);
+        let mut zeros = [0u32; 4];
+        assert_eq!(normalize_slice_in_place(&mut zeros), None);
+        let f = |n, d| Fraction::new(n, d);
+        let mut fracs = [f(-2, 3), f(0, 1), f(4, 9), f(-8, 3)];
+        assert_eq!(normalize_fraction_slice(&mut fracs), Some(f(-2, 9)));
+        assert_eq!(fracs, [f(3, 1), f(0, 1), f(-2, 1), f(12, 1)]);
+        let mut fracs = [f(6, 1), f(-3, 2)];
+        assert_eq!(normalize_fraction_slice(&mut fracs), Some(f(3, 2)));
+        assert_eq!(fracs, [f(4, 1), f(-1, 1)]);
+        let mut fracs = [f(0, 1), f(0, 3)];
+        assert_eq!(normalize_fraction_slice(&mut fracs), None);
+        // simplify_row agrees with the free function on the extracted row, for either alignment
+        // and including an augmented matrix's solution column
+        let mut matrix = Matrix::new_from_vec((3, 3), vec![
+             4i64, -6,  8,
+             0,     0,  0,
+            -3,     9, 12
+        ], RowAligned).unwrap();
+        let mut augmented = AugmentedMatrix::new_from_vec((2, 3), vec![
+            6i64, -4, 10,
+            0,     3,  7
+        ], RowAligned).unwrap();
+        augmented.column_align();
+        for r in 0..3 {
+            let mut row = (0..3).map(|c| matrix[(r, c)]).collect::<Vec<i64>>();
+            normalize_slice_in_place(&mut row);
+            let mut column_aligned = matrix.clone();
+            column_aligned.column_align();
+            matrix.simplify_row(r);
+            column_aligned.simplify_row(r);
+            assert_eq!((0..3).map(|c| matrix[(r, c)]).collect::<Vec<i64>>(), row);
+            assert_eq!((0..3).map(|c| column_aligned[(r, c)]).collect::<Vec<i64>>(), row);
+        }
+        assert_eq!(augmented.simplify_row_get_steps_ds(0), Some("R0 / 2 -> R0".to_string()));
+        assert_eq!(augmented.simplify_row_get_steps_ds(1), None);
+        assert_eq!(augmented.solution_column(), vec![5, 7]);
+        assert_eq!((augmented[(0, 0)], augmented[(0, 1)]), (3, -2));
+    }
+
+    #[test]
+    fn preordered_elimination_test() {
+        use matrices::elimination::EliminationOptions;
+        let f = |n| Fraction::from(n);
+        // Sparse-ish system with the dense, large-valued equations listed first
+        let system = AugmentedMatrix::new_from_vec((5, 6), vec![
+            f(7), f(11), f(13), f(17), f(19), f(1),
+            f(23), f(29), f(0), f(31), f(37), f(2),
+            f(0), f(0), f(3), f(0), f(0), f(3),
+            f(0), f(5), f(0), f(0), f(2), f(4),
+            f(2), f(0), f(0), f(0), f(0), f(5)
+        ], RowAligned).unwrap();
+        let natural = system.solve_exact(EliminationOptions::default()).unwrap();
+        let preordered = system.solve_exact(EliminationOptions { preorder: true }).unwrap();
+        assert_eq!(natural.values, preordered.values);
+        assert!(preordered.max_denominator <= natural.max_denominator,
+            "preordered: {}, natural: {}", preordered.max_denominator, natural.max_denominator);
+        // Every equation is accounted for exactly once, and the solution satisfies them all
+        let mut order = preordered.row_order.iter().map(|r| r.to_zero_based())
+            .collect::<Vec<usize>>();
+        order.sort();
+        assert_eq!(order, vec![0, 1, 2, 3, 4]);
+        for r in 0..5 {
+            let lhs = (0..5).fold(Fraction::from(0), |acc, c| acc + system[(r, c)]
+                * preordered.values[c]);
+            assert_eq!(lhs, system[(r, 5)]);
+        }
+        let mut column_aligned = system.clone();
+        column_aligned.column_align();
+        assert_eq!(column_aligned.solve_exact(EliminationOptions { preorder: true }).unwrap(),
+            preordered);
+        let singular = AugmentedMatrix::new_from_vec((2, 3), vec![f(1), f(2), f(3), f(2), f(4),
+            f(6)], RowAligned).unwrap();
+        assert!(singular.solve_exact(EliminationOptions { preorder: true }).is_err());
+    }
+
+    #[test]
+    fn undefined_propagation_test() {
+        use std::panic;
+        let ud = Fraction::new(1, 2) / Fraction::from(0);
+        assert!(ud.is_ud());
+        let defined = Fraction::new(3, 4);
+        type NamedOp<A, B, R> = (&'static str, fn(A, B) -> R);
+        let frac_ops: [NamedOp<Fraction, Fraction, Fraction>; 10] = [
+            ("+", |a, b| a + b), ("-", |a, b| a - b), ("*", |a, b| a * b), ("/", |a, b| a / b),
+            ("%", |a, b| a % b),
+            ("+=", |mut a, b| { a += b; a }), ("-=", |mut a, b| { a -= b; a }),
+            ("*=", |mut a, b| { a *= b; a }), ("/=", |mut a, b| { a /= b; a }),
+            ("%=", |mut a, b| { a %= b; a })
+        ];
+        for &(name, op) in frac_ops.iter() {
+            assert!(op(ud, defined).is_ud(), "UD {} defined", name);
+            assert!(op(defined, ud).is_ud(), "defined {} UD", name);
+            assert!(op(ud, ud).is_ud(), "UD {} UD", name);
+            assert!(!op(defined, defined).is_ud(), "defined {} defined", name);
+        }
+        let scalar_ops: [NamedOp<Fraction, i64, Fraction>; 4] = [
+            ("+", |a, b| a + b), ("-", |a, b| a - b), ("*", |a, b| a * b), ("/", |a, b| a / b)
+        ];
+        for &(name, op) in scalar_ops.iter() {
+            assert!(op(ud, 5).is_ud(), "UD {} 5", name);
+        }
+        assert!((defined / 0).is_ud());
+        let float_ops: [NamedOp<f64, Fraction, f64>; 10] = [
+            ("+", |a, b| a + b), ("-", |a, b| a - b), ("*", |a, b| a * b), ("/", |a, b| a / b),
+            ("%", |a, b| a % b),
+            ("+=", |mut a, b| { a += b; a }), ("-=", |mut a, b| { a -= b; a }),
+            ("*=", |mut a, b| { a *= b; a }), ("/=", |mut a, b| { a /= b; a }),
+            ("%=", |mut a, b| { a %= b; a })
+        ];
+        for &(name, op) in float_ops.iter() {
+            assert!(op(2.5, ud).is_nan(), "2.5 {} UD", name);
+            assert!(!op(2.5, defined).is_nan(), "2.5 {} defined", name);
+        }
+        let int_ops: [NamedOp<i64, Fraction, i64>; 10] = [
+            ("+", |a, b| a + b), ("-", |a, b| a - b), ("*", |a, b| a * b), ("/", |a, b| a / b),
+            ("%", |a, b| a % b),
+            ("+=", |mut a, b| { a += b; a }), ("-=", |mut a, b| { a -= b; a }),
+            ("*=", |mut a, b| { a *= b; a }), ("/=", |mut a, b| { a /= b; a }),
+            ("%=", |mut a, b| { a %= b; a })
+        ];
+        for &(name, op) in int_ops.iter() {
+            assert!(panic::catch_unwind(|| op(5, ud)).is_err(), "5 {} UD", name);
+        }
+        assert!(f64::from(ud).is_nan());
+        assert!((-ud).is_ud());
+        assert!(ud.inverse().is_ud());
+        assert_eq!(ud.try_add(defined), None);
+        assert_eq!(defined.try_mul(ud), None);
+    }
+
+    #[test]
+    fn fraction_try_new_test() {
+        for &(num, den) in [(1, 2), (-3, 4), (0, 5), (6, -8), (i64::MAX, 1)].iter() {
+            let f = Fraction::try_new(num, den).unwrap();
+            assert_eq!(f.split(), (num, den));
+            assert_eq!(f, Fraction::new(num, den));
+        }
+        for &num in [1, -1, i64::MIN].iter() {
+            assert_eq!(Fraction::try_new(num, 0), Err(FractionError::ZeroDenominator));
+        }
+        let foo = Matrix::new_from_pairs((2, 3), vec![(1, 2), (0, 1), (-2, 3), (4, 1), (5, -6),
+            (7, 8)], ColumnAligned).unwrap();
+        let bar = Matrix::new_from_vec((2, 3), vec![Fraction::new(1, 2), Fraction::from(0),
+            Fraction::new(-2, 3), Fraction::from(4), Fraction::new(-5, 6), Fraction::new(7, 8)],
+            ColumnAligned).unwrap();
+        assert!(foo.exactly_equal_including_alignment(&bar));
+        match Matrix::new_from_pairs((1, 3), vec![(1, 2), (3, 0), (5, 6)], RowAligned) {
+            Err(e) => {
+                assert_eq!(e.kind(), MatrixErrorKind::Init);
+                assert!(e.to_string().contains("Element 1"), "{}", e);
+            },
+            Ok(m) => panic!("expected an error, got a {:?} matrix", m.dimension())
+        }
+        let short = Matrix::new_from_pairs((2, 2), vec![(1, 2)], RowAligned);
+        assert_eq!(short.map(|m| m.dimension()).unwrap_err().kind(), MatrixErrorKind::Init);
+    }
+
+    #[test]
+    fn row_idx_test() {
+        for r in 0..5 {
+            let idx = RowIdx(r);
+            assert_eq!(format!("R{}", idx), format!("R{}", r));
+            assert_eq!(format!("R{:#}", idx), format!("R{}", r + 1));
+            assert_eq!(RowIdx(idx.to_zero_based()), idx);
+            assert_eq!(RowIdx::from_one_based(idx.to_one_based()), idx);
+        }
+        assert!(::std::panic::catch_unwind(|| RowIdx::from_one_based(0)).is_err());
+        // Reported rows index straight back into the matrices they came from
+        let mut system = augmented_matrix![1 2 => 3; 0 1 => 1];
+        let other = augmented_matrix![0 1 => 1; 2 4 => 7];
+        let report = system.merge(&other, true).unwrap();
+        assert_eq!(report.conflicts, vec![(RowIdx(0), RowIdx(1))]);
+        let (mine, theirs) = report.conflicts[0];
+        assert_eq!(system[(mine.to_zero_based(), 2)], 3);
+        assert_eq!(other[(RowIdx::from_one_based(theirs.to_one_based()).0, 2)], 7);
+        assert_eq!(format!("R{:#} conflicts with R{:#}", mine, theirs),
+            "R1 conflicts with R2");
+    }
+
+    #[test]
+    fn signed_fraction_test() {
+        use num::{Num, Signed};
+        fn generic_abs_sub<T: Signed>(a: T, b: T) -> (T, bool, bool) {
+            let d = a.abs_sub(&b);
+            let (p, n) = (d.is_positive(), d.is_negative());
+            (d, p, n)
+        }
+        let f = |n, d| Fraction::new(n, d);
+        let ud = f(1, 2) / 0;
+        for &(num, den) in [(3, 4), (-3, 4), (3, -4), (-3, -4), (6, -8), (0, 5), (0, -5)].iter() {
+            let x = f(num, den);
+            let value = num as f64 / den as f64;
+            assert_eq!(x.abs(), Fraction::from(value.abs()), "|{:?}|", x);
+            assert!(x.abs().split().1 > 0);
+            assert_eq!(x.signum(), Fraction::from(if value == 0.0 { 0.0 } else {
+                value.signum() }), "signum {:?}", x);
+            assert_eq!(x.is_positive(), value > 0.0, "{:?} > 0", x);
+            assert_eq!(x.is_negative(), value < 0.0, "{:?} < 0", x);
+            assert_eq!(x.abs() * x.signum(), x);
+        }
+        assert!(ud.abs().is_ud());
+        assert!(ud.signum().is_ud());
+        assert!(!ud.is_positive() && !ud.is_negative());
+        assert!(ud.abs_sub(&f(1, 2)).is_ud());
+        assert_eq!(generic_abs_sub(f(5, 4), f(1, 2)), (f(3, 4), true, false));
+        assert_eq!(generic_abs_sub(f(1, 2), f(5, 4)), (Fraction::from(0), false, false));
+        assert_eq!(generic_abs_sub(5i64, 2), (3, true, false));
+        // Num needs a working Rem: the result has the sign of the left hand side
+        assert_eq!(f(7, 2) % Fraction::from(1), f(1, 2));
+        assert_eq!(f(-7, 2) % Fraction::from(1), f(-1, 2));
+        assert_eq!(f(5, 6) % f(1, 4), f(1, 12));
+        assert_eq!(f(5, -6) % f(-1, 4), f(-1, 12));
+        assert_eq!(f(3, 4) % f(3, 4), Fraction::from(0));
+        assert!((f(3, 4) % 0).is_ud());
+        let mut foo = f(11, 3);
+        foo %= f(2, 3);
+        assert_eq!(foo, f(1, 3));
+        assert_eq!(Fraction::from_str_radix("11/-101", 2), Ok(f(-3, 5)));
+        assert_eq!(Fraction::from_str_radix("ff", 16), Ok(Fraction::from(255)));
+        assert_eq!(Fraction::from_str_radix("1 / 0", 8), Err(FractionError::ZeroDenominator));
+        assert!(Fraction::from_str_radix("12", 2).is_err());
+        assert_eq!(vec![f(1, 2), f(1, 3), f(1, 6)].into_iter().sum::<Fraction>(),
+            Fraction::from(1));
+        assert_eq!([f(1, 2), f(-1, 2)].iter().sum::<Fraction>(), Fraction::from(0));
+    }
+
+    #[test]
+    fn matrix_ref_test() {
+        use matrices::matrix_ref::MatrixRef;
+        let foo = Matrix::new_from_vec((2, 3), vec![1.0, -2.0, 2.0, 0.0, 4.0, 0.0], RowAligned)
+            .unwrap();
+        let mut bar = foo.clone();
+        bar.column_align();
+        for m in [&foo, &bar].iter() {
+            assert_eq!(MatrixRef::dimension(*m), (2, 3));
+            assert_eq!(m.iter().cloned().collect::<Vec<f64>>(),
+                vec![1.0, -2.0, 2.0, 0.0, 4.0, 0.0]);
+            assert_eq!(m.iter().size_hint(), (6, Some(6)));
+            assert_eq!(m.get((1, 1)), Some(&4.0));
+            assert_eq!(m.get((2, 0)), None);
+            assert_eq!(m.get((0, 3)), None);
+            assert_eq!(m.mul_vector(&[1.0, 1.0, 2.0]).unwrap(), vec![3.0, 4.0]);
+            assert!(m.mul_vector(&[1.0, 1.0]).is_err());
+            assert_eq!(m.frobenius_norm(), 5.0);
+        }
+        assert_eq!(foo.row_slice(1), Some(&[0.0, 4.0, 0.0][..]));
+        assert_eq!(foo.row_slice(2), None);
+        assert_eq!(bar.row_slice(0), None);
+    }
+
+    #[cfg(all(feature = "mmap", unix))]
+    #[test]
+    fn mapped_matrix_test() {
+        use std::env;
+        use std::fs::{self, File};
+        use std::io::Write;
+        use matrices::mapped::MappedMatrix;
+        use matrices::matrix_ref::MatrixRef;
+        let data = (0..20).map(|n| n as f64 * 1.5 - 7.0).collect::<Vec<f64>>();
+        let path = env::temp_dir().join(format!("fractions_and_matrices_mapped_{}.bin",
+            ::std::process::id()));
+        {
+            let mut file = File::create(&path).unwrap();
+            for e in data.iter() {
+                file.write_all(&e.to_le_bytes()).unwrap();
+            }
+        }
+        for alignment in [RowAligned, ColumnAligned].iter() {
+            let mapped = MappedMatrix::<f64>::open(&path, (4, 5), alignment.clone()).unwrap();
+            let memory = Matrix::new_from_vec((4, 5), data.clone(), alignment.clone()).unwrap();
+            assert_eq!(MatrixRef::dimension(&mapped), (4, 5));
+            for r in 0..4 {
+                for c in 0..5 {
+                    assert_eq!(mapped[(r, c)], memory[(r, c)]);
+                }
+                assert_eq!(mapped.row_slice(r), memory.row_slice(r));
+            }
+            assert!(mapped.iter().eq(memory.iter()));
+            let v = [1.0, -1.0, 0.5, 2.0, 0.0];
+            assert_eq!(mapped.mul_vector(&v).unwrap(), memory.mul_vector(&v).unwrap());
+            assert_eq!(mapped.frobenius_norm(), memory.frobenius_norm());
+            let window = mapped.to_matrix(1..3);
+            assert_eq!(window.dimension(), (2, 5));
+            assert_eq!(window.get_alignment(), *alignment);
+            for r in 0..2 {
+                for c in 0..5 {
+                    assert_eq!(window[(r, c)], memory[(r + 1, c)]);
+                }
+            }
+            assert_eq!(mapped.to_matrix(4..4).dimension(), (0, 5));
+        }
+        // The file has to be exactly the right size
+        assert!(MappedMatrix::<f64>::open(&path, (4, 4), RowAligned).is_err());
+        assert!(MappedMatrix::<f32>::open(&path, (4, 5), RowAligned).is_err());
+        assert!(MappedMatrix::<f32>::open(&path, (8, 5), RowAligned).is_ok());
+        assert!(MappedMatrix::<f64>::open(&path, (0, 5), RowAligned).is_err());
+        assert!(MappedMatrix::<f64>::open(&path, (usize::MAX, 2), RowAligned).is_err());
+        fs::remove_file(&path).unwrap();
+        assert!(MappedMatrix::<f64>::open(&path, (4, 5), RowAligned).is_err());
+    }
+
+    #[test]
+    fn remove_row_bounds_test() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        let base = matrix![0 1 2; 3 4 5; 6 7 8; 9 10 11];
+        let augmented_base = augmented_matrix![0 1 => 2; 3 4 => 5; 6 7 => 8];
+        let mut column_aligned = base.clone();
+        column_aligned.column_align();
+        let mut augmented_column_aligned = augmented_base.clone();
+        augmented_column_aligned.column_align();
+        for m in [base, column_aligned].iter() {
+            let mut removed = m.clone();
+            removed.remove_row(3);
+            let mut popped = m.clone();
+            popped.pop_row();
+            assert_eq!(removed, matrix![0 1 2; 3 4 5; 6 7 8]);
+            assert_eq!(removed, popped);
+            let mut middle = m.clone();
+            middle.remove_row(1);
+            assert_eq!(middle, matrix![0 1 2; 6 7 8; 9 10 11]);
+            let mut past_end = m.clone();
+            assert!(catch_unwind(AssertUnwindSafe(|| past_end.remove_row(4))).is_err());
+            let mut past_end = m.clone();
+            assert!(past_end.try_remove_row(4).is_err());
+            assert_eq!(past_end, *m);
+            let mut columns = m.clone();
+            columns.remove_column(0);
+            columns.pop_column();
+            assert_eq!(columns, matrix![1; 4; 7; 10]);
+            assert!(catch_unwind(AssertUnwindSafe(|| columns.remove_column(1))).is_err());
+            let mut ranged = m.clone();
+            ranged.remove_rows(2..4);
+            assert_eq!(ranged, matrix![0 1 2; 3 4 5]);
+            let mut ranged = m.clone();
+            assert!(catch_unwind(AssertUnwindSafe(|| ranged.remove_rows(3..5))).is_err());
+            let mut empty = m.clone();
+            for _ in 0..4 {
+                empty.pop_row();
+            }
+            assert_eq!(empty.num_rows(), 0);
+            assert!(catch_unwind(AssertUnwindSafe(|| empty.pop_row())).is_err());
+        }
+        for m in [augmented_base, augmented_column_aligned].iter() {
+            let mut removed = m.clone();
+            removed.remove_row(2);
+            let mut popped = m.clone();
+            popped.pop_row();
+            assert_eq!(removed, augmented_matrix![0 1 => 2; 3 4 => 5]);
+            assert_eq!(removed, popped);
+            let mut past_end = m.clone();
+            assert!(catch_unwind(AssertUnwindSafe(|| past_end.remove_row(3))).is_err());
+            assert!(past_end.try_remove_row(3).is_err());
+            // The solution column can't be popped or removed
+            let mut columns = m.clone();
+            columns.pop_column();
+            assert_eq!(columns, augmented_matrix![0 => 2; 3 => 5; 6 => 8]);
+            assert!(catch_unwind(AssertUnwindSafe(|| columns.remove_column(1))).is_err());
+            let mut square = m.clone();
+            square.remove_row(0);
+            assert_eq!(square.dimension(), (2, 2));
+            assert_eq!(square.solution_column(), vec![5, 8]);
+        }
+    }
+
+    #[test]
+    fn fraction_primitive_conversions_test() {
+        use num::{Num, FromPrimitive, ToPrimitive};
+        // Goes through the traits only, the way third-party generic code would
+        fn three_quarters<T: Num + FromPrimitive + ToPrimitive>() -> (T, T, T, Option<f64>) {
+            let parsed = T::from_str_radix("3/4", 10).ok().unwrap_or_else(T::zero);
+            let from_float = T::from_f64(0.75).unwrap();
+            let from_ints = T::from_i64(3).unwrap() / T::from_u64(4).unwrap();
+            let as_float = from_ints.to_f64();
+            (parsed, from_float, from_ints, as_float)
+        }
+        let (parsed, from_float, from_ints, as_float) = three_quarters::<Fraction>();
+        assert_eq!(parsed, Fraction::new(3, 4));
+        assert_eq!(from_float, Fraction::new(3, 4));
+        assert_eq!(from_ints, Fraction::new(3, 4));
+        assert_eq!(as_float, Some(0.75));
+        assert_eq!(three_quarters::<f64>().1, 0.75);
+        let ud = Fraction::new(1, 2) / 0;
+        let values: [&dyn ToPrimitive; 5] = [&Fraction::new(-9, 3), &Fraction::new(1, 4), &ud,
+            &-3i32, &0.25f64];
+        assert_eq!(values.iter().map(|v| v.to_i64()).collect::<Vec<Option<i64>>>(),
+            vec![Some(-3), None, None, Some(-3), Some(0)]);
+        assert_eq!(values.iter().map(|v| v.to_u64()).collect::<Vec<Option<u64>>>(),
+            vec![None, None, None, None, Some(0)]);
+        assert_eq!(values.iter().map(|v| v.to_f64()).collect::<Vec<Option<f64>>>(),
+            vec![Some(-3.0), Some(0.25), None, Some(-3.0), Some(0.25)]);
+        assert_eq!(Fraction::from_f32(-1.5), Some(Fraction::new(-3, 2)));
+        assert_eq!(Fraction::from_f64(-0.0), Some(Fraction::from(0)));
+        assert_eq!(Fraction::from_f64(2f64.powi(62)).unwrap().split(), (1 << 62, 1));
+        assert_eq!(Fraction::from_f64(2f64.powi(-62)).unwrap().split(), (1, 1 << 62));
+        for &bad in [2f64.powi(63), 2f64.powi(-63), 1e300, 5e-324, f64::INFINITY,
+            f64::NEG_INFINITY, f64::NAN].iter() {
+            assert_eq!(Fraction::from_f64(bad), None, "{:e}", bad);
+        }
+        assert_eq!(Fraction::from_u64(i64::MAX as u64).unwrap().split(), (i64::MAX, 1));
+        // Dyadic values with small enough exponents survive a round trip exactly
+        for &num in [1i64, -3, 5, 1 << 40, -(1 << 40) + 7, 123_456_789_012].iter() {
+            for shift in 0..22 {
+                let value = num as f64 / 2f64.powi(shift);
+                let f = Fraction::from_f64(value).unwrap();
+                assert_eq!(f.to_f64(), Some(value));
+                assert!(f.split().1.count_ones() == 1);
+            }
+        }
+    }
+
+    #[test]
+    fn fraction_from_float_test() {
+        let f = |n, d| Fraction::new(n, d);
+        assert_eq!(Fraction::from(-0.5), f(-1, 2));
+        assert_eq!(Fraction::from(-123.456), f(-123456, 1000));
+        assert_eq!(Fraction::from(1e-5), f(1, 100000));
+        assert_eq!(Fraction::from(1.5e-4), f(3, 20000));
+        assert_eq!(Fraction::from(-0.25f32), f(-1, 4));
+        assert_eq!(Fraction::from(-7.0), Fraction::from(-7));
+        // 17 significant digits fit exactly
+        assert_eq!(Fraction::from(0.12345678901234568).split(), (12345678901234568,
+            100000000000000000));
+        assert_eq!(Fraction::from(-1234567.8901234567).split(), (-12345678901234567,
+            10000000000));
+        // Longer expansions are rounded to 18 decimal places instead of overflowing
+        assert_eq!(Fraction::from(1.2345678901234567e-10).split(), (123456789,
+            1000000000000000000));
+        assert_eq!(Fraction::from(5e-19).split(), (1, 1000000000000000000));
+        assert_eq!(Fraction::from(-4e-19).split().0, 0);
+        assert_eq!(Fraction::from(1e-300).split().0, 0);
+        // Too large for an i64, like the non-finite values
+        assert!(Fraction::from(1e300).is_ud());
+        assert!(Fraction::from(-1e19).is_ud());
+        assert!(Fraction::from(f32::MAX).is_ud());
+        assert_eq!(Fraction::from(9e18).split(), (9000000000000000000, 1));
+        assert!(Fraction::from(f64::NAN).is_ud());
+        assert!(Fraction::from(f32::INFINITY).is_ud());
+        // Exponent notation in the decimal string
+        assert_eq!(decimal_str_to_fraction("-1.5e-4"), f(-15, 100000));
+        assert_eq!(decimal_str_to_fraction("2.5E3"), Fraction::from(2500));
+        let mut rounded = decimal_str_to_fraction("0.0149999999999999999999");
+        rounded.simplify();
+        assert_eq!(rounded.split(), (3, 200));
+    }
+
+    #[test]
+    fn ldlt_test() {
+        use matrices::elimination::EliminationOptions;
+        // Float Cholesky for comparison: None if a pivot isn't positive
+        fn cholesky_is_pd(a: &[f64], n: usize) -> bool {
+            let mut l = vec![0.0; n * n];
+            for j in 0..n {
+                let dj = a[j * n + j] - (0..j).map(|k| l[j * n + k] * l[j * n + k]).sum::<f64>();
+                if dj <= 1e-12 {
+                    return false;
+                }
+                l[j * n + j] = dj.sqrt();
+                for i in j + 1..n {
+                    l[i * n + j] = (a[i * n + j] - (0..j).map(|k| l[i * n + k] * l[j * n + k])
+                        .sum::<f64>()) / l[j * n + j];
+                }
+            }
+            true
+        }
+        let symmetric = |entries: &[i64], n: usize| Matrix::new_from_vec((n, n),
+            entries.iter().map(|&e| Fraction::from(e)).collect(), RowAligned).unwrap();
+        let corpus: [(&[i64], usize); 4] = [
+            (&[4, 2, -2, 1, 2, 5, 0, -1, -2, 0, 6, 2, 1, -1, 2, 7], 4),
+            (&[2, -1, 0, 0, -1, 2, -1, 0, 0, -1, 2, -1, 0, 0, -1, 2], 4),
+            (&[1, 2, 3, 0, 2, 1, 0, 1, 3, 0, 1, 2, 0, 1, 2, -3], 4),
+            (&[9, 3, -3, 3, 5, 1, -3, 1, 3], 3)
+        ];
+        for &(entries, n) in corpus.iter() {
+            let a = symmetric(entries, n);
+            let (l, d) = a.ldlt().unwrap();
+            for r in 0..n {
+                assert_eq!(l[(r, r)], Fraction::from(1));
+                for c in 0..n {
+                    if c > r {
+                        assert_eq!(l[(r, c)], Fraction::from(0));
+                    }
+                    let ldlt = (0..n).fold(Fraction::from(0), |acc, k| acc + l[(r, k)] * d[k]
+                        * l[(c, k)]);
+                    assert_eq!(ldlt, a[(r, c)], "({}, {})", r, c);
+                }
+            }
+            let floats = entries.iter().map(|&e| e as f64).collect::<Vec<f64>>();
+            assert_eq!(a.is_positive_definite_exact().unwrap(), cholesky_is_pd(&floats, n));
+            // The solver agrees with exact Gauss-Jordan on [A | b]
+            let b = (0..n).map(|i| Fraction::new(i as i64 * 3 - 2, 5)).collect::<Vec<Fraction>>();
+            let system = AugmentedMatrix::new_from_vec((n, n + 1), (0..n)
+                .flat_map(|r| (0..n + 1).map(|c| if c < n { a[(r, c)] } else { b[r] })
+                    .collect::<Vec<Fraction>>())
+                .collect(), RowAligned).unwrap();
+            assert_eq!(a.solve_ldlt(&l, &d, &b),
+                system.solve_exact(EliminationOptions::default()).unwrap().values);
+        }
+        assert!(symmetric(corpus[0].0, 4).is_positive_definite_exact().unwrap());
+        assert!(!symmetric(corpus[2].0, 4).is_positive_definite_exact().unwrap());
+        // Zero pivots: fine on the semi-definite boundary, reported by index otherwise
+        let (_, d) = symmetric(&[1, 1, 1, 1], 2).ldlt().unwrap();
+        assert_eq!(d, vec![Fraction::from(1), Fraction::from(0)]);
+        assert!(!symmetric(&[1, 1, 1, 1], 2).is_positive_definite_exact().unwrap());
+        match symmetric(&[1, 1, 0, 1, 1, 2, 0, 2, 1], 3).ldlt() {
+            Err(e) => {
+                assert_eq!(e.kind(), MatrixErrorKind::Function);
+                assert!(e.to_string().contains("index 1"), "{}", e);
+            },
+            Ok((_, d)) => panic!("expected a zero pivot error, got D = {:?}", d)
+        }
+        assert!(!symmetric(&[1, 1, 0, 1, 1, 2, 0, 2, 1], 3).is_positive_definite_exact().unwrap());
+        assert!(symmetric(&[1, 2, 3, 1], 2).ldlt().is_err());
+        assert!(symmetric(&[1, 2, 3, 1], 2).is_positive_definite_exact().is_err());
+        assert!(Matrix::new_from_vec((1, 2), vec![Fraction::from(1); 2], RowAligned).unwrap()
+            .ldlt().is_err());
+    }
+
+    // Logical-order versions of the REF/RREF/unit predicates, for checking the storage-order ones
+    fn reference_predicates<F: Fn(usize, usize) -> i32>(rows: usize, columns: usize, get: F)
+        -> (bool, bool, bool) {
+        // The column of each row's leading element, or `columns` for zero rows
+        let leads = (0..rows).map(|r| (0..columns).find(|&c| get(r, c) != 0).unwrap_or(columns))
+            .collect::<Vec<usize>>();
+        let row_reduced = (0..rows).all(|r| leads[r] == columns || get(r, leads[r]) == 1)
+            && (1..rows).all(|r| leads[r] > leads[r - 1] || leads[r] == columns);
+        let gauss_jordan = row_reduced && (0..rows).filter(|&r| leads[r] < columns)
+            .all(|r| (0..r).all(|a| get(a, leads[r]) == 0));
+        let unit = rows == columns
+            && (0..rows).all(|r| (0..columns).all(|c| get(r, c) == if r == c { 1 } else { 0 }));
+        (row_reduced, gauss_jordan, unit)
+    }
+
+    // Reduced forms of every `rows` x `width` shape in `shapes`, plus every single-entry
+    // perturbation of them
+    fn predicate_corpus(shapes: &[(usize, usize)]) -> Vec<(usize, usize, Vec<i32>)> {
+        let mut corpus = Vec::new();
+        for &(rows, width) in shapes {
+            let rref = (0..rows * width).map(|i| {
+                let (r, c) = (i / width, i % width);
+                if c == r { 1 } else if c < rows { 0 } else { (r + c) as i32 }
+            }).collect::<Vec<i32>>();
+            let row_echelon = (0..rows * width).map(|i| {
+                let (r, c) = (i / width, i % width);
+                if c < r { 0 } else if c == r { 1 } else { (2 * r + c) as i32 }
+            }).collect::<Vec<i32>>();
+            for base in [rref, row_echelon].iter() {
+                corpus.push((rows, width, base.clone()));
+                for i in 0..base.len() {
+                    let mut perturbed = base.clone();
+                    perturbed[i] = if perturbed[i] == 0 { 2 } else { 0 };
+                    corpus.push((rows, width, perturbed));
+                }
+            }
+        }
+        corpus
+    }
+
+    #[test]
+    fn storage_order_predicates_test() {
+        use matrices::base::Unit;
+        use matrices::transforms::{REF, RREF};
+        let shapes = [(1, 1), (2, 2), (3, 3), (4, 4), (2, 4), (3, 5), (1, 3)];
+        for (rows, columns, vec) in predicate_corpus(&shapes) {
+            let mut foo = Matrix::new_from_vec((rows, columns), vec.clone(), RowAligned).unwrap();
+            let expected = reference_predicates(rows, columns, |r, c| foo[(r, c)]);
+            for _ in 0..2 {
+                assert_eq!((foo.is_row_reduced(), foo.is_gauss_jordan(), foo.is_unit()), expected,
+                    "{:?}\n{}", foo.get_alignment(), foo);
+                foo.column_align();
+            }
+        }
+        // Augmented matrices leave the solution column out of all three
+        let shapes = [(1, 2), (2, 3), (3, 4), (2, 4), (3, 3), (4, 4)];
+        for (rows, width, vec) in predicate_corpus(&shapes) {
+            let mut foo = AugmentedMatrix::new_from_vec((rows, width), vec.clone(), RowAligned)
+                .unwrap();
+            let expected = reference_predicates(rows, width - 1, |r, c| foo[(r, c)]);
+            for _ in 0..2 {
+                assert_eq!((foo.is_row_reduced(), foo.is_gauss_jordan(), foo.is_unit()), expected,
+                    "{:?}\n{}", foo.get_alignment(), foo);
+                foo.column_align();
+            }
+        }
+        // Tall matrices end in zero rows
+        let mut tall = Matrix::new_from_vec((3, 2), vec![1, 0, 0, 1, 0, 0], RowAligned).unwrap();
+        assert!(tall.is_row_reduced() && tall.is_gauss_jordan() && !tall.is_unit());
+        tall.column_align();
+        assert!(tall.is_row_reduced() && tall.is_gauss_jordan() && !tall.is_unit());
+        let tall = Matrix::new_from_vec((3, 2), vec![1, 0, 0, 0, 0, 1], RowAligned).unwrap();
+        assert!(!tall.is_row_reduced());
+    }
+
+    // Run with `cargo test --release -- --ignored --nocapture is_gauss_jordan_bench`
+    #[test]
+    #[ignore]
+    fn is_gauss_jordan_bench() {
+        use matrices::base::Unit;
+        use matrices::transforms::RREF;
+        use std::time::Instant;
+        let n = 2000;
+        let mut foo: Matrix<f64> = Matrix::unit(n);
+        foo.column_align();
+        let start = Instant::now();
+        let logical = reference_predicates(n, n, |r, c| foo[(r, c)] as i32).1;
+        let logical_time = start.elapsed();
+        let start = Instant::now();
+        let storage = foo.is_gauss_jordan();
+        let storage_time = start.elapsed();
+        assert!(logical && storage);
+        println!("is_gauss_jordan on a {0}x{0} column-aligned unit matrix: logical order {1:?}, \
+            storage order {2:?}", n, logical_time, storage_time);
+    }
+
+    #[test]
+    fn limit_denominator_test() {
+        // Expected values from Python's Fraction.limit_denominator
+        let pi = Fraction::new(3141592653589793, 1000000000000000);
+        let pi_cases = [(1, (3, 1)), (7, (22, 7)), (10, (22, 7)), (57, (179, 57)),
+            (100, (311, 99)), (113, (355, 113)), (1000, (355, 113)), (33102, (103993, 33102)),
+            (1000000, (3126535, 995207))];
+        for &(max_den, expected) in pi_cases.iter() {
+            assert_eq!(pi.limit_denominator(max_den).split(), expected, "max_den {}", max_den);
+        }
+        let big = Fraction::new(4611686018427387905, 4052555153018976267);
+        assert_eq!(big.limit_denominator(10).split(), (8, 7));
+        assert_eq!(big.limit_denominator(1000000).split(), (830701, 729985));
+        assert_eq!(big.limit_denominator(1000000000000).split(), (688399742503, 604936657151));
+        let tiny = Fraction::new(7, 1000000000000000000);
+        assert_eq!(tiny.limit_denominator(100000000000000000).split(), (1, 100000000000000000));
+        assert_eq!(tiny.limit_denominator(999999999999999999).split(), (6, 857142857142857143));
+        assert_eq!(Fraction::new(-987654321, 123456789).limit_denominator(1000).split(), (-8, 1));
+        // Ties go to the smaller denominator
+        assert_eq!(Fraction::new(5, 12).limit_denominator(3).split(), (1, 2));
+        assert_eq!(Fraction::new(-5, 12).limit_denominator(3).split(), (-1, 2));
+        assert_eq!(Fraction::new(1, 2).limit_denominator(1).split(), (0, 1));
+        assert_eq!(Fraction::new(-3, 2).limit_denominator(1).split(), (-2, 1));
+        // Fractions that already fit come back unchanged (but simplified)
+        for &(n, d) in [(0, 5), (3, 7), (-22, 7), (10, -4), (i64::MAX, 1)].iter() {
+            let mut expected = Fraction::new(n, d);
+            expected.simplify();
+            assert_eq!(Fraction::new(n, d).limit_denominator(7).split(), expected.split());
+        }
+        // Brute force: the closest p / q over every q <= max_den, ties to the smaller q
+        for den in 1..40i64 {
+            for num in -2 * den..2 * den + 1 {
+                for max_den in 1..12i64 {
+                    let (n, d) = (num as i128, den as i128);
+                    // (p, q, |p / q - n / d| * q * d)
+                    let mut best: Option<(i128, i128, i128)> = None;
+                    for q in 1..max_den as i128 + 1 {
+                        let floor = (n * q).div_euclid(d);
+                        for &p in [floor, floor + 1].iter() {
+                            let dist = (p * d - n * q).abs();
+                            let closer = match best {
+                                Some((_, best_q, best_dist)) => dist * best_q < best_dist * q,
+                                None => true
+                            };
+                            if closer {
+                                best = Some((p, q, dist));
+                            }
+                        }
+                    }
+                    let (p, q, _) = best.unwrap();
+                    let mut expected = Fraction::new(p as i64, q as i64);
+                    expected.simplify();
+                    assert_eq!(Fraction::new(num, den).limit_denominator(max_den).split(),
+                        expected.split(), "{} / {}, max_den {}", num, den, max_den);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn percentage_test() {
+        let five_percent = fraction!(5 %);
+        assert_eq!(five_percent.split(), (1, 20));
+        assert_eq!(five_percent, Fraction::percent(5));
+        // Compounding 5% twice on 100 gives exactly 110 1/4
+        let compounded = Fraction::from(100).apply_percentage(five_percent)
+            .apply_percentage(Fraction::percent(5));
+        assert_eq!(compounded.split(), (441, 4));
+        assert_eq!(Fraction::percentage_change(Fraction::from(50), Fraction::from(75)),
+            Some(Fraction::new(1, 2)));
+        assert_eq!(Fraction::percentage_change(Fraction::from(75), Fraction::from(50)),
+            Some(Fraction::new(-1, 3)));
+        assert_eq!(Fraction::percentage_change(Fraction::new(0, 7), Fraction::from(3)), None);
+        assert_eq!(Fraction::basis_points(125).percent_of(Fraction::from(2000)),
+            Fraction::from(25));
+        assert_eq!(Fraction::per_mille(-5), fraction!(-1 / 200));
+        assert_eq!(fraction!(-12 %).split(), (-3, 25));
+        const RATES: [Fraction; 3] = [Fraction::percent(1), Fraction::per_mille(10),
+            Fraction::basis_points(100)];
+        assert!(RATES.iter().all(|&r| r == fraction!(1 / 100)));
+        let ud = Fraction::from(1) / 0;
+        assert!(ud.percent_of(Fraction::from(3)).is_ud());
+        assert!(Fraction::from(3).apply_percentage(ud).is_ud());
+        assert!(Fraction::percentage_change(ud, Fraction::from(1)).unwrap().is_ud());
+        assert!(Fraction::percentage_change(Fraction::from(1), ud).unwrap().is_ud());
+    }
+
+    #[test]
+    fn fraction_display_padding_test() {
+        let half = Fraction::new(1, 2);
+        assert_eq!(format!("{:>7}", half), "  1 / 2");
+        assert_eq!(format!("{:7}|", half), "1 / 2  |");
+        assert_eq!(format!("{:<7}|", half), "1 / 2  |");
+        assert_eq!(format!("{:^9}|", half), "  1 / 2  |");
+        assert_eq!(format!("{:-^8}|", half), "-1 / 2--|");
+        assert_eq!(format!("{:3}", half), "1 / 2");
+        assert_eq!(format!("{:>4}", Fraction::from(-5)), "  -5");
+        assert_eq!(format!("{:>#9}", Fraction::new(7, 2)), "    3 1/2");
+        assert_eq!(format!("{:^6}", Fraction::from(1) / 0), "  UD  ");
+        // Unpadded output doesn't change
+        assert_eq!(format!("{}", Fraction::new(-7, 2)), "-7 / 2");
+        assert_eq!(format!("{:#}", Fraction::new(-7, 2)), "-3 1/2");
+    }
+
+    #[test]
+    fn matrix_error_kind_test() {
+        use matrices::transforms::Inverse;
+        use std::error::Error;
+        let init = Matrix::new_from_vec((2, 2), vec![1.0, 2.0, 3.0], RowAligned).unwrap_err();
+        assert_eq!(init.kind(), MatrixErrorKind::Init);
+        let mut singular = Matrix::new_from_vec((2, 2), vec![1.0, 2.0, 2.0, 4.0], RowAligned)
+            .unwrap();
+        let transform = singular.try_inverse().unwrap_err();
+        assert_eq!(transform.kind(), MatrixErrorKind::Transform);
+        let wide = Matrix::new_from_vec((1, 2), vec![Fraction::from(1); 2], RowAligned).unwrap();
+        let function = wide.ldlt().unwrap_err();
+        assert_eq!(function.kind(), MatrixErrorKind::Function);
+        // Display is prose, Debug shows the variant like a derive would
+        let message = match function {
+            MatrixError::FunctionError(ref e) => e.clone(),
+            ref other => panic!("expected a FunctionError, got {:?}", other)
+        };
+        assert_eq!(format!("{}", function), format!("Function error: {}", message));
+        assert_eq!(format!("{:?}", function), format!("FunctionError({:?})", message));
+        assert_eq!(format!("{:?}", init), format!("InitError({:?})", match init {
+            MatrixError::InitError(ref e) => e.clone(),
+            _ => unreachable!()
+        }));
+        // Usable as a boxed std error
+        let boxed: Box<dyn Error> = Box::new(transform);
+        assert!(boxed.source().is_none());
+        assert!(boxed.to_string().starts_with("Row/Matrix operation error: "));
+    }
+
+    #[test]
+    fn euclidean_division_test() {
+        use num::Signed;
+        let values = [-12i64, -7, -6, -5, -3, -2, -1, 0, 1, 2, 3, 5, 6, 7, 12];
+        let dens = [-6i64, -4, -3, -2, -1, 1, 2, 3, 4, 6];
+        for &n1 in values.iter() {
+            for &d1 in dens.iter() {
+                let a = Fraction::new(n1, d1);
+                for &n2 in values.iter().filter(|&&n| n != 0) {
+                    for &d2 in dens.iter() {
+                        let b = Fraction::new(n2, d2);
+                        let (q, r) = a.div_rem(b);
+                        assert_eq!(q.split().1, 1, "{} div {} isn't whole", a, b);
+                        assert!(r >= Fraction::from(0) && r < b.abs(), "{} mod {} = {}", a, b, r);
+                        assert_eq!(b * q + r, a, "{} = {} * {} + {}", a, b, q, r);
+                        assert_eq!(a.div_floor(b), q);
+                        assert_eq!(a.mod_floor(b), r);
+                        assert_eq!(a.try_div_rem(b), Some((q, r)));
+                    }
+                }
+            }
+        }
+        let ud = Fraction::from(1) / 0;
+        for &(a, b) in [(ud, Fraction::from(2)), (Fraction::from(2), ud),
+            (Fraction::from(2), Fraction::from(0))].iter() {
+            assert!(a.div_floor(b).is_ud() && a.mod_floor(b).is_ud());
+            assert!(a.try_div_floor(b).is_none() && a.try_mod_floor(b).is_none());
+            assert!(a.try_div_rem(b).is_none());
+        }
+    }
+
+    #[test]
+    fn zero_matrix_mul_test() {
+        let alignments = [RowAligned, ColumnAligned];
+        for &(n, m) in [(8, 8), (8, 12), (12, 8), (3, 3)].iter() {
+            let values = (0..n * m).map(|i| i as i64 % 7 - 3).collect::<Vec<i64>>();
+            for left_alignment in alignments.iter() {
+                for right_alignment in alignments.iter() {
+                    let a = Matrix::new_from_vec((n, m), values.clone(), left_alignment.clone())
+                        .unwrap();
+                    let b = Matrix::new_from_vec((m, n), values.clone(), right_alignment.clone())
+                        .unwrap();
+                    let general = a.clone() * b.clone();
+                    let zero_a = Matrix::new_from_vec((n, m), vec![0i64; n * m],
+                        left_alignment.clone()).unwrap();
+                    let zero_b = Matrix::new_from_vec((m, n), vec![0i64; n * m],
+                        right_alignment.clone()).unwrap();
+                    for product in [zero_a * b.clone(), a.clone() * zero_b].iter() {
+                        assert!(product.is_zero_matrix());
+                        assert_eq!(product.dimension(), general.dimension());
+                        assert_eq!(product.get_alignment(), general.get_alignment());
+                    }
+                }
+            }
+        }
+        assert!(!Matrix::new_from_vec((1, 2), vec![0, 1], RowAligned).unwrap().is_zero_matrix());
+        // 0 * NaN is NaN, so the NaN's column of the product is all NaNs, not zeros
+        let mut nan = Matrix::splat(&1.0f64, (8, 8), RowAligned);
+        nan[(2, 5)] = f64::NAN;
+        let zero = Matrix::splat(&0.0f64, (8, 8), ColumnAligned);
+        for product in [&zero * &nan, &nan.transpose() * &zero].iter() {
+            assert!(!product.is_zero_matrix());
+        }
+        assert!((0..8).all(|i| (&zero * &nan)[(i, 5)].is_nan()));
+    }
+
+    #[test]
+    fn unit_matrix_mul_test() {
+        use matrices::base::Unit;
+        let alignments = [RowAligned, ColumnAligned];
+        for &(n, m) in [(8, 8), (8, 12), (12, 8), (3, 3)].iter() {
+            let values = (0..n * m).map(|i| i as i64 % 7 - 3).collect::<Vec<i64>>();
+            for left_alignment in alignments.iter() {
+                for right_alignment in alignments.iter() {
+                    let a = Matrix::new_from_vec((n, m), values.clone(), left_alignment.clone())
+                        .unwrap();
+                    let mut unit_left: Matrix<i64> = Matrix::unit(n);
+                    let mut unit_right: Matrix<i64> = Matrix::unit(m);
+                    if *left_alignment == ColumnAligned {
+                        unit_left.column_align();
+                    }
+                    if *right_alignment == ColumnAligned {
+                        unit_right.column_align();
+                    }
+                    let product = &unit_left * &a;
+                    assert_eq!(product, a);
+                    assert_eq!(product.get_alignment(), unit_left.get_alignment());
+                    let product = &a * &unit_right;
+                    assert!(product.exactly_equal_including_alignment(&a));
+                }
+            }
+        }
+        // 0 * NaN is NaN, so the NaN's column of the product is all NaNs
+        let mut nan = Matrix::splat(&1.0f64, (8, 8), RowAligned);
+        nan[(2, 5)] = f64::NAN;
+        let product = &Matrix::<f64>::unit(8) * &nan;
+        assert!((0..8).all(|i| product[(i, 5)].is_nan()));
+        assert_eq!(product[(0, 0)], 1.0);
+        // The square of a swap is the unit matrix, and pow() stops there
+        let swap = Matrix::new_from_vec((2, 2), vec![0, 1, 1, 0], ColumnAligned).unwrap();
+        assert!(swap.pow(2).unwrap().is_unit());
+        assert!(swap.pow(1001).unwrap().exactly_equal_including_alignment(&swap));
+        assert_eq!(swap.pow(1000).unwrap().get_alignment(), ColumnAligned);
+    }
+
+    // Run with `cargo test --release -- --ignored --nocapture zero_matrix_mul_bench`
+    #[test]
+    #[ignore]
+    fn zero_matrix_mul_bench() {
+        use std::time::Instant;
+        let n = 500;
+        let ones = Matrix::splat(&1.0f64, (n, n), ColumnAligned);
+        let mut almost_zero = Matrix::splat(&0.0f64, (n, n), RowAligned);
+        almost_zero[(n - 1, n - 1)] = 1.0;
+        let zero = Matrix::splat(&0.0f64, (n, n), RowAligned);
+        let start = Instant::now();
+        let general = almost_zero * ones.clone();
+        let general_time = start.elapsed();
+        let start = Instant::now();
+        let short_circuit = zero * ones;
+        let short_circuit_time = start.elapsed();
+        assert_eq!(general.dimension(), short_circuit.dimension());
+        println!("{0}x{0} product: general {1:?}, zero operand {2:?}", n, general_time,
+            short_circuit_time);
+    }
+
+    // Run with `cargo test --release -- --ignored --nocapture unit_matrix_mul_bench`
+    #[test]
+    #[ignore]
+    fn unit_matrix_mul_bench() {
+        use matrices::base::Unit;
+        use std::time::Instant;
+        let n = 500;
+        let values = Matrix::from_fn((n, n), ColumnAligned, |i, j| (i * n + j) as f64);
+        let mut almost_unit: Matrix<f64> = Matrix::unit(n);
+        almost_unit[(n - 1, 0)] = 1.0;
+        let unit: Matrix<f64> = Matrix::unit(n);
+        let start = Instant::now();
+        let general = &almost_unit * &values;
+        let general_time = start.elapsed();
+        let start = Instant::now();
+        let short_circuit = &unit * &values;
+        let short_circuit_time = start.elapsed();
+        assert_eq!(general.dimension(), short_circuit.dimension());
+        assert_eq!(short_circuit, values);
+        println!("{0}x{0} product: general {1:?}, unit operand {2:?}", n, general_time,
+            short_circuit_time);
+    }
+
+    #[test]
+    fn matrix_equality_test() {
+        let foo = augmented_matrix![
+            1 2 3 => 4;
+            5 6 7 => 8
+        ];
+        let other_rhs = augmented_matrix![
+            1 2 3 => 4;
+            5 6 7 => 9
+        ];
+        assert!(!foo.logically_equal(&other_rhs));
+        assert!(!foo.exactly_equal_including_alignment(&other_rhs));
+        assert!(foo != other_rhs);
+        let mut column_aligned = foo.clone();
+        column_aligned.column_align();
+        assert!(foo.logically_equal(&column_aligned));
+        assert!(!foo.exactly_equal_including_alignment(&column_aligned));
+        assert!(foo == column_aligned);
+        let mut other_rhs = other_rhs;
+        other_rhs.column_align();
+        assert!(!column_aligned.logically_equal(&other_rhs));
+        assert!(!foo.logically_equal(&other_rhs));
+        let bar = matrix![
+            1 2 3;
+            4 5 6
+        ];
+        let mut bar_columns = bar.clone();
+        bar_columns.column_align();
+        assert!(bar.logically_equal(&bar_columns) && bar == bar_columns);
+        assert!(!bar.exactly_equal_including_alignment(&bar_columns));
+        let transposed = Matrix::new_from_vec((3, 2), vec![1, 4, 2, 5, 3, 6], RowAligned).unwrap();
+        assert!(!bar.logically_equal(&transposed));
+        #[allow(deprecated)]
+        let old_name = bar.exactly_equal_to(&bar_columns);
+        assert!(!old_name);
+    }
+
+    #[test]
+    fn repeating_decimal_test() {
+        let parse = |s: &str| Fraction::from_repeating_decimal(s).map(|f| f.split());
+        let cases = [("0.(3)", (1, 3)), ("1.2(45)", (137, 110)), ("-0.1(6)", (-1, 6)),
+            (".(3)", (1, 3)), ("0.(9)", (1, 1)), ("-2.(9)", (-3, 1)), ("0.4(9)", (1, 2)),
+            ("0.(142857)", (1, 7)), ("3.(0)", (3, 1)), ("+0.(05)", (5, 99)), ("12", (12, 1)),
+            ("1.25", (5, 4)), (" 0.(0588235294117647) ", (1, 17)), ("-0.00(3)", (-1, 300))];
+        for &(s, expected) in cases.iter() {
+            assert_eq!(parse(s), Ok(expected), "{}", s);
+        }
+        // Every repetend-only expansion of k / 7 comes back exactly
+        let sevenths = ["142857", "285714", "428571", "571428", "714285", "857142"];
+        for (k, repetend) in sevenths.iter().enumerate() {
+            assert_eq!(parse(&format!("0.({})", repetend)), Ok((k as i64 + 1, 7)));
+        }
+        let bad = ["", "-", ".", "0.()", "0.(1)(2)", "0.(12", "0.12)", "0.(1)2", "1(3)",
+            "0.(1a)", "x.(3)", "1.2.(3)", "--0.(3)", "0.((3))", "0.(12345678901234567890)"];
+        for &s in bad.iter() {
+            match parse(s) {
+                Err(FractionError::ParseError(e)) => assert!(e.contains(&format!("'{}'", s)), "{}",
+                    e),
+                other => panic!("'{}' parsed as {:?}", s, other)
+            }
+        }
+    }
+
+    #[test]
+    fn broadcast_test() {
+        // Centering the columns of a data matrix leaves every column summing to exactly zero
+        let data = vec![Fraction::new(1, 3), Fraction::from(2), Fraction::new(-5, 7),
+            Fraction::from(4), Fraction::new(7, 2), Fraction::from(0),
+            Fraction::new(2, 9), Fraction::from(-1), Fraction::new(11, 5),
+            Fraction::from(6), Fraction::new(1, 4), Fraction::from(3)];
+        for alignment in [RowAligned, ColumnAligned].iter() {
+            let mut foo = Matrix::new_from_vec((4, 3), data.clone(), RowAligned).unwrap();
+            if *alignment == ColumnAligned {
+                foo.column_align();
+            }
+            let means = (0..3).map(|c| (0..4).map(|r| foo[(r, c)]).sum::<Fraction>() / 4)
+                .collect::<Vec<Fraction>>();
+            foo.sub_row_vector(&means).unwrap();
+            for c in 0..3 {
+                let mut sum = (0..4).map(|r| foo[(r, c)]).sum::<Fraction>();
+                sum.simplify();
+                assert_eq!(sum.split(), (0, 1), "{:?}", alignment);
+            }
+        }
+        // Row and column offsets land on the logical rows and columns in both layouts, and the
+        // solution column of augmented matrices is never touched
+        for alignment in [RowAligned, ColumnAligned].iter() {
+            let mut foo = Matrix::new_from_vec((2, 3), vec![1, 2, 3, 4, 5, 6], RowAligned)
+                .unwrap();
+            let mut bar = AugmentedMatrix::new_from_vec((2, 3), vec![1, 2, 3, 4, 5, 6],
+                RowAligned).unwrap();
+            if *alignment == ColumnAligned {
+                foo.column_align();
+                bar.column_align();
+            }
+            foo.add_scalar_in_place(10);
+            foo.sub_scalar_in_place(1);
+            foo.add_to_row(1, 100).unwrap();
+            foo.add_to_column(2, -3).unwrap();
+            foo.sub_column_vector(&[1, 2]).unwrap();
+            assert_eq!(foo, Matrix::new_from_vec((2, 3), vec![9, 10, 8, 111, 112, 110],
+                RowAligned).unwrap(), "{:?}", alignment);
+            bar.add_scalar_in_place(10);
+            bar.add_to_row(0, 100).unwrap();
+            bar.add_to_column(1, 1000).unwrap();
+            bar.sub_row_vector(&[1, 2]).unwrap();
+            bar.sub_column_vector(&[0, 3]).unwrap();
+            let expected = [[110, 1110, 3], [10, 1010, 6]];
+            for r in 0..2 {
+                for c in 0..3 {
+                    assert_eq!(bar[(r, c)], expected[r][c], "{:?} ({}, {})", alignment, r, c);
+                }
+            }
+            assert!(bar.add_to_column(2, 1).is_err());
+            assert_eq!(bar.sub_row_vector(&[1, 2, 3]).unwrap_err().kind(),
+                MatrixErrorKind::Function);
+        }
+        let mut foo = Matrix::new_from_vec((2, 2), vec![1, 2, 3, 4], RowAligned).unwrap();
+        assert_eq!(foo.add_to_row(2, 1).unwrap_err().kind(), MatrixErrorKind::Function);
+        assert_eq!(foo.add_to_column(2, 1).unwrap_err().kind(), MatrixErrorKind::Function);
+        assert_eq!(foo.sub_row_vector(&[1]).unwrap_err().kind(), MatrixErrorKind::Function);
+        assert_eq!(foo.sub_column_vector(&[1, 2, 3]).unwrap_err().kind(),
+            MatrixErrorKind::Function);
+        assert_eq!(foo, Matrix::new_from_vec((2, 2), vec![1, 2, 3, 4], RowAligned).unwrap());
+    }
+
+    #[test]
+    fn fraction_try_from_test() {
+        use std::convert::TryFrom;
+        assert_eq!(i64::try_from(&Fraction::new(14, 2)), Ok(7));
+        assert_eq!(i32::try_from(&Fraction::new(-9, 3)), Ok(-3));
+        assert_eq!(usize::try_from(&Fraction::new(0, -5)), Ok(0));
+        assert_eq!(u8::try_from(&Fraction::new(-6, -2)), Ok(3));
+        assert_eq!(i64::try_from(&Fraction::new(7, 2)), Err(FractionError::NotAnInteger));
+        assert_eq!(i64::try_from(&Fraction::new(-1, 3)), Err(FractionError::NotAnInteger));
+        assert_eq!(i64::try_from(&(Fraction::from(1) / Fraction::from(0))),
+            Err(FractionError::NotAnInteger));
+        assert_eq!(u8::try_from(&Fraction::from(256)), Err(FractionError::OutOfRange));
+        assert_eq!(u64::try_from(&Fraction::from(-1)), Err(FractionError::OutOfRange));
+        assert_eq!(i8::try_from(&Fraction::from(-128)), Ok(-128));
+        assert_eq!(i32::try_from(&Fraction::from(i64::MAX)), Err(FractionError::OutOfRange));
+        // The lossy conversion still truncates
+        assert_eq!(i64::from(Fraction::new(7, 2)), 3);
+        // Solutions that look whole but aren't get reported with their position
+        for alignment in [RowAligned, ColumnAligned].iter() {
+            let mut foo = Matrix::new_from_vec((2, 3), vec![
+                Fraction::new(2, 1), Fraction::new(-9, 3), Fraction::from(5),
+                Fraction::from(0), Fraction::new(8, 4), Fraction::new(7, 2)
+            ], RowAligned).unwrap();
+            if *alignment == ColumnAligned {
+                foo.column_align();
+            }
+            let err = foo.try_into_integers().unwrap_err();
+            assert_eq!(err.kind(), MatrixErrorKind::Function);
+            assert!(err.to_string().contains("(1, 2)"), "{}", err);
+            foo[(1, 2)] = Fraction::new(12, 3);
+            foo[(0, 1)] = Fraction::new(1, 2);
+            assert!(foo.try_into_integers().unwrap_err().to_string().contains("(0, 1)"));
+            foo[(0, 1)] = Fraction::from(-3);
+            let ints = foo.try_into_integers().unwrap();
+            assert_eq!(ints.get_alignment(), *alignment);
+            assert_eq!(ints, Matrix::new_from_vec((2, 3), vec![2, -3, 5, 0, 2, 4], RowAligned)
+                .unwrap());
+        }
+    }
+
+    #[test]
+    fn tuple_index_bounds_test() {
+        use std::any::Any;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        let message = |payload: Box<dyn Any + Send>| payload.downcast::<String>()
+            .map(|s| *s).unwrap_or_default();
+        for alignment in [RowAligned, ColumnAligned].iter() {
+            let mut foo = Matrix::new_from_vec((3, 4), (0..12).collect(), RowAligned).unwrap();
+            let mut bar = AugmentedMatrix::new_from_vec((3, 4), (0..12).collect(), RowAligned)
+                .unwrap();
+            if *alignment == ColumnAligned {
+                foo.column_align();
+                bar.column_align();
+            }
+            // Every index that's inside the backing vec but outside the logical dimension
+            // panics, for reads and writes alike
+            for r in 0..12 {
+                for c in 0..12 {
+                    if r < 3 && c < 4 {
+                        assert_eq!(foo[(r, c)], (r * 4 + c) as i32);
+                        assert_eq!(bar[(r, c)], (r * 4 + c) as i32);
+                        continue;
+                    }
+                    let expected = format!("index ({}, {}) out of bounds for 3x4 matrix", r, c);
+                    let read = catch_unwind(|| foo[(r, c)]).unwrap_err();
+                    assert_eq!(message(read), expected, "{:?}", alignment);
+                    let read = catch_unwind(|| bar[(r, c)]).unwrap_err();
+                    assert_eq!(message(read), expected, "{:?}", alignment);
+                    let write = catch_unwind(AssertUnwindSafe(|| foo[(r, c)] = -1)).unwrap_err();
+                    assert_eq!(message(write), expected, "{:?}", alignment);
+                    let write = catch_unwind(AssertUnwindSafe(|| bar[(r, c)] = -1)).unwrap_err();
+                    assert_eq!(message(write), expected, "{:?}", alignment);
+                }
+            }
+            assert!(foo.matrix.iter().chain(bar.matrix.iter()).all(|&e| e >= 0));
+        }
+    }
+
+    #[test]
+    fn wide_intermediate_arithmetic_test() {
+        use std::panic::catch_unwind;
+        // Products of the ~3e9 denominators overflow an i64, but the reduced results fit
+        let (a, b) = (Fraction::new(1, 3_000_000_000), Fraction::new(1, 3_000_000_007));
+        assert_eq!((a + b).split(), (6_000_000_007, 9_000_000_021_000_000_000));
+        assert_eq!((a - b).split(), (7, 9_000_000_021_000_000_000));
+        assert_eq!(a.try_add(b).map(|f| f.split()),
+            Some((6_000_000_007, 9_000_000_021_000_000_000)));
+        assert_eq!(a.try_sub(b).map(|f| f.split()), Some((7, 9_000_000_021_000_000_000)));
+        let mut sum = a;
+        sum += b;
+        sum -= b;
+        assert_eq!(sum.split(), (1, 3_000_000_000));
+        let (x, y) = (Fraction::new(3_000_000_001, 2_999_999_999), Fraction::new(2_999_999_999,
+            6_000_000_002));
+        assert_eq!((x * y).split(), (1, 2));
+        assert_eq!((x / Fraction::new(6_000_000_002, 2_999_999_999)).split(), (1, 2));
+        assert_eq!(x.try_mul(y).map(|f| f.split()), Some((1, 2)));
+        let big = Fraction::new(i64::MAX, 3_000_000_000);
+        assert_eq!((big - big).split(), (0, 1));
+        assert_eq!((big * 3).split(), (i64::MAX, 1_000_000_000));
+        assert_eq!(big.try_add_t(0).map(|f| f.split()), Some(big.split()));
+        assert_eq!((Fraction::new(i64::MIN, 1) / Fraction::from(-2)).split(), (i64::MIN / -2, 1));
+        // Results that genuinely don't fit are reported, never wrapped
+        let c = Fraction::new(1, 4_000_000_000);
+        assert_eq!(a.try_mul(c), None);
+        assert_eq!(a.try_div(Fraction::from(4_000_000_000i64)), None);
+        assert_eq!(Fraction::from(i64::MAX).try_add(Fraction::from(1)), None);
+        assert_eq!(Fraction::from(i64::MAX).try_add_t(1), None);
+        assert_eq!(Fraction::new(i64::MIN, 1).try_sub_t(1), None);
+        assert_eq!(Fraction::from(i64::MAX).try_mul_t(2), None);
+        assert_eq!(Fraction::new(1, i64::MAX).try_div_t(2), None);
+        assert_eq!(Fraction::new(1, 3).try_div(Fraction::from(0)), None);
+        let overflow = catch_unwind(|| a * c).unwrap_err();
+        let message = overflow.downcast::<String>().unwrap();
+        assert!(message.starts_with("Fraction overflow:"), "{}", message);
+        assert!(catch_unwind(|| Fraction::from(i64::MAX) + 1).is_err());
+        assert!(catch_unwind(|| {
+            let mut d = Fraction::new(i64::MIN, 1);
+            d -= 1;
+            d
+        }).is_err());
+        // Dividing by zero still gives UD rather than an overflow
+        assert!((Fraction::from(1) / Fraction::from(0)).is_ud());
+    }
+
+    #[test]
+    fn augment_in_place_test() {
+        let (rows, columns) = (5, 3);
+        let coefficients = (0..(rows * columns) as i32).collect::<Vec<i32>>();
+        let solutions = (100..100 + rows as i32).collect::<Vec<i32>>();
+        for alignment in [RowAligned, ColumnAligned].iter() {
+            let mut foo = Matrix::new_from_vec((rows, columns), coefficients.clone(), RowAligned)
+                .unwrap();
+            if *alignment == ColumnAligned {
+                foo.column_align();
+            }
+            let expected = foo.clone();
+            let bar = foo.augment_in_place(&solutions).unwrap();
+            assert_eq!(bar.dimension(), (rows, columns), "{:?}", alignment);
+            assert_eq!(bar.get_alignment(), *alignment);
+            for r in 0..rows {
+                for c in 0..columns {
+                    assert_eq!(bar[(r, c)], expected[(r, c)], "{:?} ({}, {})", alignment, r, c);
+                }
+                assert_eq!(bar[(r, columns)], solutions[r], "{:?} {}", alignment, r);
+            }
+            let mut row_aligned = bar.clone();
+            row_aligned.row_align();
+            assert_eq!(row_aligned.matrix, (0..rows).flat_map(|r| coefficients[r * columns..]
+                .iter().take(columns).cloned().chain(Some(solutions[r])))
+                .collect::<Vec<i32>>(), "{:?}", alignment);
+            let (baz, stripped) = bar.strip_augment_in_place();
+            assert!(baz.exactly_equal_including_alignment(&expected), "{:?}", alignment);
+            assert_eq!(stripped, solutions);
+            // Stripping keeps the capacity, so augmenting again doesn't have to reallocate
+            assert!(baz.matrix.capacity() >= rows * (columns + 1));
+            let ptr = baz.matrix.as_ptr();
+            let again = baz.augment_in_place(&solutions).unwrap();
+            assert_eq!(again.matrix.as_ptr(), ptr, "{:?}", alignment);
+            assert_eq!(again.solution_column(), solutions);
+        }
+        // Without spare room the vec grows by exactly one element per row in a single reserve
+        let mut vec = Vec::with_capacity(rows * columns);
+        vec.extend(coefficients.iter().cloned());
+        let foo = Matrix::new_from_vec((rows, columns), vec, RowAligned).unwrap();
+        assert_eq!(foo.matrix.capacity(), rows * columns);
+        let bar = foo.augment_in_place(&solutions).unwrap();
+        assert_eq!(bar.matrix.capacity(), rows * (columns + 1));
+        // With room to spare nothing is reallocated
+        let mut vec = Vec::with_capacity(rows * (columns + 1));
+        vec.extend(coefficients.iter().cloned());
+        let ptr = vec.as_ptr();
+        let foo = Matrix::new_from_vec((rows, columns), vec, RowAligned).unwrap();
+        assert_eq!(foo.augment_in_place(&solutions).unwrap().matrix.as_ptr(), ptr);
+        let foo = Matrix::new_from_vec((2, 2), vec![1, 2, 3, 4], RowAligned).unwrap();
+        let err = foo.augment_in_place(&[1, 2, 3]).unwrap_err();
+        assert_eq!(err.kind(), MatrixErrorKind::Function);
+    }
+
+    #[test]
+    fn fraction_try_cmp_test() {
+        use std::cmp::Ordering;
+        let ud = Fraction::from(1) / Fraction::from(0);
+        let values = [Fraction::from(-3), Fraction::new(2, -5), Fraction::from(0),
+            Fraction::new(1, 3), Fraction::new(-4, -8), Fraction::from(i64::MAX)];
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                assert_eq!(a.try_cmp(b), Some(i.cmp(&j)), "{} vs {}", a, b);
+                assert_eq!(a.partial_cmp(b), Some(i.cmp(&j)));
+                assert_eq!(a.total_cmp(b), i.cmp(&j));
+            }
+            assert_eq!(a.partial_cmp(&ud), None);
+            assert_eq!(ud.partial_cmp(a), None);
+            assert_eq!(a.try_cmp(&ud), None);
+            assert_eq!((*a == ud, *a < ud, *a > ud), (false, false, false));
+            assert_eq!(a.total_cmp(&ud), Ordering::Less);
+            assert_eq!(ud.total_cmp(a), Ordering::Greater);
+        }
+        assert_eq!(ud.partial_cmp(&ud), None);
+        assert_eq!(ud.total_cmp(&ud), Ordering::Equal);
+        // Denominators whose lcm overflows an i64 still compare exactly
+        let (a, b) = (Fraction::new(1, 3_000_000_000), Fraction::new(1, 3_000_000_007));
+        assert_eq!(a.try_cmp(&b), Some(Ordering::Greater));
+        assert!(a > b && a != b);
+        assert_eq!(Fraction::new(i64::MIN, 1).try_cmp(&Fraction::new(i64::MAX, -1)),
+            Some(Ordering::Less));
+        // Sorting with UD mixed in doesn't panic and puts every UD at the end
+        let mut foo = [Fraction::new(1, 2), ud, Fraction::from(-3), ud, Fraction::new(1, 3),
+            Fraction::from(0)];
+        foo.sort_by(Fraction::total_cmp);
+        assert_eq!(foo[..4], [Fraction::from(-3), Fraction::from(0), Fraction::new(1, 3),
+            Fraction::new(1, 2)]);
+        assert!(foo[4..].iter().all(|f| f.is_ud()));
+    }
+
+    #[test]
+    fn display_without_realigning_test() {
+        use std::fmt;
+        use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+        static CLONES: AtomicUsize = AtomicUsize::new(0);
+        static FORMATS: AtomicUsize = AtomicUsize::new(0);
+        // Counts its clones and how often it's formatted
+        struct Counted(i64);
+        impl Clone for Counted {
+            fn clone(&self) -> Counted {
+                CLONES.fetch_add(1, SeqCst);
+                Counted(self.0)
+            }
+        }
+        impl fmt::Display for Counted {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                FORMATS.fetch_add(1, SeqCst);
+                write!(f, "{}", self.0)
+            }
+        }
+        impl fmt::Debug for Counted {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                FORMATS.fetch_add(1, SeqCst);
+                write!(f, "{:?}", self.0)
+            }
+        }
+        let reset = || {
+            CLONES.store(0, SeqCst);
+            FORMATS.store(0, SeqCst);
+        };
+        let (rows, columns) = (60, 40);
+        let value = |r: usize, c: usize| (r as i64 - 30) * (c as i64 % 7) * 13;
+        let row_order = |rows: usize, columns: usize| (0..rows)
+            .flat_map(|r| (0..columns).map(move |c| Counted(value(r, c))))
+            .collect::<Vec<Counted>>();
+        let column_order = |rows: usize, columns: usize| (0..columns)
+            .flat_map(|c| (0..rows).map(move |r| Counted(value(r, c))))
+            .collect::<Vec<Counted>>();
+        let by_rows = Matrix::new_from_vec((rows, columns), row_order(rows, columns), RowAligned)
+            .unwrap();
+        let by_columns = Matrix::new_from_vec((rows, columns), column_order(rows, columns),
+            ColumnAligned).unwrap();
+        let aug_by_rows = AugmentedMatrix::new_from_vec((rows, columns), row_order(rows, columns),
+            RowAligned).unwrap();
+        let aug_by_columns = AugmentedMatrix::new_from_vec((rows, columns),
+            column_order(rows, columns), ColumnAligned).unwrap();
+        reset();
+        // Each element is formatted twice: once to measure its column and once to write it
+        let display = by_columns.to_string();
+        assert_eq!(FORMATS.load(SeqCst), 2 * rows * columns);
+        let aug_display = aug_by_columns.to_string();
+        assert_eq!(FORMATS.load(SeqCst), 4 * rows * columns);
+        assert_eq!(CLONES.load(SeqCst), 0);
+        assert_eq!(display, by_rows.to_string());
+        assert_eq!(aug_display, aug_by_rows.to_string());
+        assert_eq!(display.lines().count(), rows);
+        let debug = format!("{:?}", by_columns);
+        let aug_debug = format!("{:?}", aug_by_columns);
+        assert_eq!(CLONES.load(SeqCst), 0);
+        assert_eq!(debug.replace("ColumnAligned", "RowAligned"), format!("{:?}", by_rows));
+        assert_eq!(aug_debug.replace("ColumnAligned", "RowAligned"),
+            format!("{:?}", aug_by_rows));
+        // Debug only shows (and only formats) the top left corner of a big matrix, formatting
+        // each shown element once
+        assert!(debug.ends_with(", ...], ...] }") && debug.contains("dim: (60, 40)"), "{}", debug);
+        reset();
+        let _ = format!("{:?}", by_columns);
+        assert_eq!(FORMATS.load(SeqCst), 10 * 10);
+        reset();
+        let _ = format!("{:?}", aug_by_columns);
+        assert_eq!(FORMATS.load(SeqCst), 10 * (10 + 1));
+        assert!(aug_debug.contains(", ...], ...], solution: ["), "{}", aug_debug);
+        // Small matrices are shown in full
+        let small = Matrix::new_from_vec((2, 2), vec![1, -20, 300, 4], ColumnAligned).unwrap();
+        assert_eq!(format!("{:?}", small), "Matrix { dim: (2, 2), alignment: ColumnAligned, \
+            data: [[1, 300], [-20, 4]] }");
+        assert_eq!(small.to_string(), "\u{23a1}   1  300  \u{23a4}\n\u{23a3} -20    4  \u{23a6}");
+        // Columns are as wide as their widest element in chars, not bytes
+        let wide = Matrix::new_from_vec((2, 1), vec!["\u{bd}", "ab"], ColumnAligned).unwrap();
+        assert_eq!(wide.to_string(), "\u{23a1}  \u{bd}  \u{23a4}\n\u{23a3} ab  \u{23a6}");
+    }
+
+    #[test]
+    fn display_allocation_test() {
+        use std::alloc::{GlobalAlloc, Layout, System};
+        use std::cell::Cell;
+        use std::fmt::{Display, Write};
+        // Passes everything on to `System`, and adds up the bytes allocated on this thread (and
+        // the largest single allocation) while `measure` is running.
+        struct Counting;
+        thread_local! {
+            static ALLOCATED: Cell<Option<(usize, usize)>> = const { Cell::new(None) };
+        }
+        fn record(size: usize) {
+            let _ = ALLOCATED.try_with(|a| if let Some((total, largest)) = a.get() {
+                a.set(Some((total + size, largest.max(size))));
+            });
+        }
+        unsafe impl GlobalAlloc for Counting {
+            unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+                record(layout.size());
+                System.alloc(layout)
+            }
+            unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+                System.dealloc(ptr, layout)
+            }
+            unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+                record(new_size);
+                System.realloc(ptr, layout, new_size)
+            }
+        }
+        #[global_allocator]
+        static COUNTING: Counting = Counting;
+        fn measure<F: FnOnce()>(f: F) -> (usize, usize) {
+            ALLOCATED.with(|a| a.set(Some((0, 0))));
+            f();
+            ALLOCATED.with(|a| a.replace(None)).unwrap()
+        }
+        // Formats into a string that's already big enough, so only the formatting allocates
+        let allocated = |m: &dyn Display| {
+            let mut out = String::with_capacity(1 << 16);
+            measure(|| write!(out, "{}", m).unwrap())
+        };
+        let columns = 30;
+        let mut seen = Vec::new();
+        for &rows in [10, 200].iter() {
+            let elements = (0..rows * columns).map(|n| n as i64 % 10).collect::<Vec<i64>>();
+            for column_aligned in [false, true] {
+                let mut foo = Matrix::new_from_vec((rows, columns), elements.clone(), RowAligned)
+                    .unwrap();
+                let mut bar = AugmentedMatrix::new_from_vec((rows, columns), elements.clone(),
+                    RowAligned).unwrap();
+                if column_aligned {
+                    foo.column_align();
+                    bar.column_align();
+                }
+                let used = (allocated(&foo), allocated(&bar));
+                // Nothing is sized by the number of elements, only by the number of columns
+                for &(total, largest) in [used.0, used.1].iter() {
+                    assert!(largest < rows * columns, "{} {}", total, largest);
+                }
+                seen.push(used);
+            }
+        }
+        assert!(seen.iter().all(|&used| used == seen[0]), "{:?}", seen);
+    }
+
+    #[test]
+    fn incremental_rref_test() {
+        use matrices::elimination::{EliminationOptions, IncrementalOutcome, Rref};
+        let f = |v: &[i64]| v.iter().map(|&x| Fraction::from(x)).collect::<Vec<Fraction>>();
+        let equations = [
+            [0, 2, -1, 3, 1, 7],
+            [1, 0, 4, -2, 0, -3],
+            [3, 1, 0, 0, 5, 12],
+            [2, -3, 1, 1, 1, 0],
+            [1, 1, 1, 1, 2, 9]
+        ];
+        let all = AugmentedMatrix::new_from_vec((5, 6), equations.iter()
+            .flat_map(|e| f(e)).collect(), RowAligned).unwrap();
+        let expected = all.solve_exact(EliminationOptions::default()).unwrap().values;
+        let mut rref = Rref::new(5);
+        for (i, e) in equations.iter().enumerate() {
+            match rref.push_equation_and_reduce(&f(e)).unwrap() {
+                IncrementalOutcome::NewPivot { solution, .. } => {
+                    assert_eq!(solution.is_some(), i == 4, "{}", i);
+                },
+                other => panic!("equation {} gave {:?}", i, other)
+            }
+        }
+        assert_eq!(rref.solution(), Some(expected.clone()));
+        assert_eq!(rref.pivot_columns(), &[0, 1, 2, 3, 4]);
+        assert_eq!(Rref::from_system(&all).unwrap(), rref);
+        // A combination of existing equations is redundant and reports the known solution
+        let combination = (0..6).map(|c| equations[0][c] * 2 - equations[3][c])
+            .collect::<Vec<i64>>();
+        assert_eq!(rref.push_equation_and_reduce(&f(&combination)).unwrap(),
+            IncrementalOutcome::Redundant { solution: Some(expected.clone()) });
+        // A conflicting one is flagged and leaves the system alone
+        let mut conflicting = combination.clone();
+        conflicting[5] += 1;
+        let before = rref.clone();
+        assert_eq!(rref.push_equation_and_reduce(&f(&conflicting)).unwrap(),
+            IncrementalOutcome::Inconsistent);
+        assert_eq!(rref, before);
+        assert_eq!(rref.solution(), Some(expected));
+        assert_eq!(rref.push_equation_and_reduce(&f(&[1, 2, 3])).unwrap_err().kind(),
+            MatrixErrorKind::Function);
+        // Pivots can arrive out of order and the rows stay sorted and reduced
+        let mut partial = Rref::new(3);
+        assert_eq!(partial.push_equation_and_reduce(&f(&[0, 0, 2, 4])).unwrap(),
+            IncrementalOutcome::NewPivot { column: 2, solution: None });
+        assert_eq!(partial.push_equation_and_reduce(&f(&[1, 1, 1, 3])).unwrap(),
+            IncrementalOutcome::NewPivot { column: 0, solution: None });
+        assert_eq!(partial.pivot_columns(), &[0, 2]);
+        let reduced = partial.to_augmented_matrix().unwrap();
+        assert_eq!(reduced, AugmentedMatrix::new_from_vec((2, 4), f(&[1, 1, 0, 1, 0, 0, 1, 2]),
+            RowAligned).unwrap());
+        let inconsistent = AugmentedMatrix::new_from_vec((2, 3), f(&[1, 1, 1, 2, 2, 3]),
+            RowAligned).unwrap();
+        assert!(Rref::from_system(&inconsistent).is_err());
+    }
+
+    #[test]
+    fn from_ratio_test() {
+        use num::FromPrimitive;
+        let ratio = |negative, num, den| Fraction::from_ratio(negative, num, den)
+            .map(|f| f.split());
+        assert_eq!(ratio(false, 6, 8), Ok((3, 4)));
+        assert_eq!(ratio(true, 6, 8), Ok((-3, 4)));
+        assert_eq!(ratio(true, 0, 5), Ok((0, 1)));
+        assert_eq!(ratio(false, 0, 0), Ok((0, 1)));
+        assert_eq!(ratio(true, 7, 0), Err(FractionError::ZeroDenominator));
+        // Over-range parts that reduce into range are fine
+        assert_eq!(ratio(false, 1 << 63, 1 << 62), Ok((2, 1)));
+        assert_eq!(ratio(true, u64::MAX, 3 << 60), Ok((-6_148_914_691_236_517_205, 1 << 60)));
+        assert_eq!(ratio(false, 10_000_000_000_000_000_000, 5_000_000_000_000_000_000),
+            Ok((2, 1)));
+        assert_eq!(ratio(true, i64::MAX as u64, 1), Ok((-i64::MAX, 1)));
+        // Irreducible over-range ratios are errors, in either part
+        assert_eq!(ratio(false, (1 << 63) + 1, 2), Err(FractionError::OutOfRange));
+        assert_eq!(ratio(true, 3, 1 << 63), Err(FractionError::OutOfRange));
+        assert_eq!(ratio(false, u64::MAX, u64::MAX - 1), Err(FractionError::OutOfRange));
+        assert_eq!(ratio(false, i64::MAX as u64, i64::MAX as u64 + 1),
+            Err(FractionError::OutOfRange));
+        // The u64 conversions agree with it instead of wrapping
+        assert_eq!(Fraction::from(i64::MAX as u64).split(), (i64::MAX, 1));
+        assert_eq!(Fraction::from_u64(1 << 63), None);
+        assert_eq!(Fraction::from_u64(12).map(|f| f.split()), Some((12, 1)));
+        assert!(Fraction::from(1u64 << 63).is_ud());
+        assert!(Fraction::from(usize::MAX).is_ud());
+    }
+
+    #[test]
+    fn continued_fraction_test() {
+        // Every small fraction round-trips through its canonical expansion
+        for num in -60i64..60 {
+            for den in 1i64..60 {
+                let mut frac = Fraction::new(num, den);
+                frac.simplify();
+                let terms = frac.to_continued_fraction();
+                assert!(terms[1..].iter().all(|&a| a > 0), "{} {:?}", frac, terms);
+                assert!(terms.len() == 1 || *terms.last().unwrap() > 1, "{} {:?}", frac, terms);
+                assert_eq!(terms[0], (num as f64 / den as f64).floor() as i64);
+                assert_eq!(Fraction::from_continued_fraction(&terms).split(), frac.split());
+                // Negative denominators expand the same way
+                assert_eq!(Fraction::new(-num, -den).to_continued_fraction(), terms);
+            }
+        }
+        assert_eq!(Fraction::new(-1, 2).to_continued_fraction(), vec![-1, 2]);
+        assert_eq!(Fraction::from(0).to_continued_fraction(), vec![0]);
+        let big = Fraction::new(i64::MAX, i64::MAX - 1);
+        assert_eq!(big.to_continued_fraction(), vec![1, i64::MAX - 1]);
+        assert_eq!(Fraction::from_continued_fraction(&[1, i64::MAX - 1]).split(), big.split());
+        // Non-canonical expansions still evaluate
+        assert_eq!(Fraction::from_continued_fraction(&[2, 1]).split(), (3, 1));
+        assert_eq!(Fraction::from_continued_fraction(&[0, 0, 5]).split(), (5, 1));
+        assert!(Fraction::from_continued_fraction(&[]).is_ud());
+        assert!(Fraction::from_continued_fraction(&[1, 0]).is_ud());
+        // Overflow gives an undefined fraction instead of panicking
+        assert!(Fraction::from_continued_fraction(&[i64::MAX, 2, i64::MAX]).is_ud());
+        assert!(Fraction::from_continued_fraction(&[1; 100]).is_ud());
+        assert!(Fraction::from_continued_fraction(&[-3, i64::MIN]).is_ud());
+        assert!((Fraction::from(1) / Fraction::from(0)).to_continued_fraction().is_empty());
+    }
+
+    #[test]
+    fn fraction_matrix_constructors_test() {
+        let foo = Matrix::from_integer_slice((2, 2), &[1, -2, 3, 4], ColumnAligned).unwrap();
+        assert_eq!(foo[(1, 0)], Fraction::from(-2));
+        assert_eq!(foo[(0, 1)], Fraction::from(3));
+        let bar = AugmentedMatrix::from_pairs((2, 3), &[(1, 2), (-1, 3), (5, 1), (0, 1), (2, 4),
+            (7, 8)], RowAligned).unwrap();
+        assert_eq!(bar[(0, 2)], Fraction::from(5));
+        assert_eq!(bar[(1, 1)], Fraction::new(1, 2));
+        match Matrix::from_pairs((2, 2), &[(1, 2), (3, 4), (5, 6), (7, 0)], RowAligned) {
+            Err(MatrixError::InitError(msg)) => assert!(msg.starts_with("Element 3 (7 / 0)")),
+            other => panic!("Expected an InitError, got {:?}", other)
+        }
+        let pi = Matrix::from_f64_slice((1, 3), &[::std::f64::consts::PI, 0.333, -2.5], Some(100),
+            RowAligned).unwrap();
+        assert_eq!(pi[(0, 0)], Fraction::new(311, 99));
+        assert_eq!(pi[(0, 1)], Fraction::new(1, 3));
+        assert_eq!(pi[(0, 2)], Fraction::new(-5, 2));
+        let exact = Matrix::from_f64_slice((1, 2), &[0.1, -0.75], None, RowAligned).unwrap();
+        assert_eq!(exact[(0, 0)], Fraction::new(1, 10));
+        match Matrix::from_f64_slice((1, 2), &[1.0, f64::NAN], None, RowAligned) {
+            Err(MatrixError::InitError(msg)) => assert!(msg.starts_with("Element 1 (NaN)")),
+            other => panic!("Expected an InitError, got {:?}", other)
+        }
+        assert!(Matrix::from_f64_slice((1, 1), &[1.0], Some(0), RowAligned).is_err());
+        let values = [0.5, -1.25, 3.0, 0.125, 6.0, -0.375];
+        let baz = AugmentedMatrix::from_f64_slice((2, 3), &values, None, ColumnAligned).unwrap();
+        let floats = baz.to_f64_matrix();
+        assert_eq!(floats.dimension(), baz.dimension());
+        for r in 0..2 {
+            for c in 0..3 {
+                assert_eq!(floats[(r, c)], values[c * 2 + r]);
+            }
+        }
+    }
+
+    #[test]
+    fn element_iterators_test() {
+        let row = Matrix::new_from_vec((3, 4), (0..12).collect(), RowAligned).unwrap();
+        let mut column = row.clone();
+        column.column_align();
+        assert!(column.is_column_aligned());
+        let expected = (0..12).collect::<Vec<i32>>();
+        assert_eq!(row.iter().cloned().collect::<Vec<i32>>(), expected);
+        assert_eq!(column.iter().cloned().collect::<Vec<i32>>(), expected);
+        assert_eq!((&column).into_iter().rev().cloned().collect::<Vec<i32>>(),
+            expected.iter().rev().cloned().collect::<Vec<i32>>());
+        let mut iter = column.iter();
+        assert_eq!(iter.len(), 12);
+        assert_eq!((iter.next(), iter.next_back()), (Some(&0), Some(&11)));
+        assert_eq!(iter.size_hint(), (10, Some(10)));
+        assert_eq!(iter.cloned().collect::<Vec<i32>>(), (1..11).collect::<Vec<i32>>());
+        let mut shifted = column.clone();
+        for (i, e) in shifted.iter_mut().enumerate() {
+            *e += i as i32;
+        }
+        assert_eq!(shifted[(2, 1)], 18);
+        assert!(shifted.is_column_aligned());
+        assert_eq!(column.clone().into_iter().collect::<Vec<i32>>(), expected);
+        assert_eq!(row.into_iter().rev().collect::<Vec<i32>>(),
+            expected.iter().rev().cloned().collect::<Vec<i32>>());
+        let mut aug = AugmentedMatrix::new_from_vec((2, 3), vec![1, 2, 3, 4, 5, 6], RowAligned)
+            .unwrap();
+        aug.column_align();
+        assert_eq!((&aug).into_iter().len(), 6);
+        assert_eq!(aug.into_iter().collect::<Vec<i32>>(), vec![1, 2, 3, 4, 5, 6]);
+        let empty = Matrix::<i32>::new_from_vec((0, 3), Vec::new(), ColumnAligned).unwrap();
+        assert_eq!(empty.iter().len(), 0);
+        assert_eq!(empty.into_iter().next(), None);
+    }
+
+    #[test]
+    fn row_views_test() {
+        let row = Matrix::new_from_vec((3, 4), (0..12).collect(), RowAligned).unwrap();
+        let mut column = row.clone();
+        column.column_align();
+        let sums = |m: &Matrix<i32>| m.rows().map(|r| r.iter().sum::<i32>()).collect::<Vec<i32>>();
+        assert_eq!(sums(&column), sums(&row));
+        assert_eq!(sums(&row), vec![6, 22, 38]);
+        assert_eq!(column.rows().len(), 3);
+        for (a, b) in row.rows().zip(column.rows()) {
+            assert_eq!(a.len(), 4);
+            assert_eq!(a.as_slice(), Some(&row[a[0] as usize / 4][..]));
+            assert_eq!(b.as_slice(), None);
+            assert_eq!((0..4).map(|c| b[c]).collect::<Vec<i32>>(),
+                b.into_iter().cloned().collect::<Vec<i32>>());
+            assert!(a.iter().eq(b.iter()));
+            assert_eq!(b.get(4), None);
+        }
+        assert_eq!(column.rows().next_back().unwrap()[0], 8);
+        let mut shifted = column.clone();
+        for (r, mut view) in shifted.rows_mut().enumerate() {
+            assert!(view.as_mut_slice().is_none());
+            view[0] = -1;
+            for e in view.iter_mut() {
+                *e += r as i32;
+            }
+        }
+        let mut expected = row.clone();
+        for (r, mut view) in expected.rows_mut().rev().enumerate() {
+            let r = 2 - r as i32;
+            view.as_mut_slice().unwrap()[0] = -1;
+            for e in view.iter_mut() {
+                *e += r;
+            }
+        }
+        assert!(shifted.iter().eq(expected.iter()));
+        assert_eq!(shifted[(2, 0)], 1);
+        let aug = AugmentedMatrix::new_from_vec((2, 3), vec![1, 2, 3, 4, 5, 6], ColumnAligned)
+            .unwrap();
+        let solutions = aug.rows().map(|r| r[r.len() - 1]).collect::<Vec<i32>>();
+        assert_eq!(solutions, vec![5, 6]);
+        let empty = Matrix::<i32>::new_from_vec((3, 0), Vec::new(), RowAligned).unwrap();
+        assert!(empty.rows().all(|r| r.is_empty()));
+        assert_eq!(empty.rows().count(), 3);
+    }
+
+    #[test]
+    fn column_views_test() {
+        let row = Matrix::new_from_vec((3, 5), (0..15).collect(), RowAligned).unwrap();
+        let mut column = row.clone();
+        column.column_align();
+        for m in &[&row, &column] {
+            assert_eq!(m.columns().len(), 5);
+            let maxima = m.columns().map(|c| *c.iter().max().unwrap()).collect::<Vec<i32>>();
+            assert_eq!(maxima, vec![10, 11, 12, 13, 14]);
+            for (c, view) in m.columns().enumerate() {
+                assert_eq!(view.len(), 3);
+                assert_eq!((0..3).map(|r| view[r]).collect::<Vec<i32>>(),
+                    (0..3).map(|r| m[(r, c)]).collect::<Vec<i32>>());
+                assert_eq!(view.get(3), None);
+            }
+            assert_eq!(m.columns().next_back().unwrap().iter().cloned().collect::<Vec<i32>>(),
+                vec![4, 9, 14]);
+        }
+        assert!(row.columns().all(|c| c.as_slice().is_none()));
+        assert_eq!(column.columns().nth(1).unwrap().as_slice(), Some(&[1, 6, 11][..]));
+        let (mut a, mut b) = (row.clone(), column.clone());
+        for m in [&mut a, &mut b] {
+            for (c, mut view) in m.columns_mut().enumerate() {
+                view[2] = 0;
+                for e in view.iter_mut().rev() {
+                    *e *= c as i32;
+                }
+            }
+        }
+        assert!(a.iter().eq(b.iter()));
+        assert_eq!(a[(1, 3)], 24);
+        assert_eq!(a[(2, 4)], 0);
+        assert!(b.columns_mut().next_back().unwrap().as_mut_slice().is_some());
+        let mut aug = AugmentedMatrix::new_from_vec((3, 5), (0..15).collect(), RowAligned)
+            .unwrap();
+        assert_eq!(aug.columns().len(), 4);
+        assert_eq!(aug.columns().next_back().unwrap()[0], 3);
+        assert_eq!(aug.solution_view().iter().cloned().collect::<Vec<i32>>(), vec![4, 9, 14]);
+        for mut view in aug.columns_mut().rev() {
+            view[0] = -1;
+        }
+        aug.solution_view_mut()[1] = 100;
+        assert_eq!(aug.rows().next().unwrap().iter().cloned().collect::<Vec<i32>>(),
+            vec![-1, -1, -1, -1, 4]);
+        aug.column_align();
+        assert_eq!(aug.solution_view().as_slice(), Some(&[4, 100, 14][..]));
+        for mut view in aug.columns_mut() {
+            view.as_mut_slice().unwrap()[2] = 0;
+        }
+        assert_eq!(aug.solution_column(), vec![4, 100, 14]);
+        assert_eq!(aug[(2, 3)], 0);
+    }
+
+    #[test]
+    fn indexed_iter_test() {
+        use matrices::base::Unit;
+        let mut foo: Matrix<i32> = Matrix::unit(4);
+        foo.column_align();
+        let nonzero = foo.indexed_iter()
+            .filter(|&(_, &e)| e != 0)
+            .map(|(index, _)| index)
+            .collect::<Vec<(usize, usize)>>();
+        assert_eq!(nonzero, vec![(0, 0), (1, 1), (2, 2), (3, 3)]);
+        let bar = Matrix::new_from_vec((2, 3), vec![1, 4, 2, 5, 3, 6], ColumnAligned).unwrap();
+        assert!(bar.indexed_iter().all(|(index, &e)| bar[index] == e));
+        assert_eq!(bar.indexed_iter().len(), 6);
+        assert_eq!(bar.indexed_iter().next_back(), Some(((1, 2), &6)));
+        let mut iter = bar.indexed_iter();
+        iter.next();
+        assert_eq!(iter.next_back().map(|(index, _)| index), Some((1, 2)));
+        assert_eq!(iter.next_back().map(|(index, _)| index), Some((1, 1)));
+        assert_eq!(iter.next().map(|(index, _)| index), Some((0, 1)));
+        let mut aug = AugmentedMatrix::new_from_vec((2, 3), vec![1, 2, 3, 4, 5, 6], RowAligned)
+            .unwrap();
+        aug.column_align();
+        for ((r, c), e) in aug.indexed_iter_mut() {
+            *e = 10 * r as i32 + c as i32;
+        }
+        assert_eq!(aug.solution_column(), vec![2, 12]);
+        assert_eq!(aug[(1, 0)], 10);
+    }
+
+    #[test]
+    fn from_fn_test() {
+        let row = Matrix::from_fn((3, 4), RowAligned, |r, c| 10 * r + c);
+        let column = Matrix::from_fn((3, 4), ColumnAligned, |r, c| 10 * r + c);
+        assert!(row.is_row_aligned() && column.is_column_aligned());
+        assert_eq!(column.dimension(), (3, 4));
+        assert!(row.iter().eq(column.iter()));
+        assert_eq!(column[(2, 3)], 23);
+        let mut calls = Vec::new();
+        let foo = Matrix::from_fn((2, 3), ColumnAligned, |r, c| {
+            calls.push((r, c));
+            r + c
+        });
+        assert_eq!(calls, vec![(0, 0), (1, 0), (0, 1), (1, 1), (0, 2), (1, 2)]);
+        assert_eq!(foo.matrix, vec![0, 1, 1, 2, 2, 3]);
+        let aug = AugmentedMatrix::from_fn((2, 3), RowAligned, |r, c| if c == 2 { 9 } else { r });
+        assert_eq!(aug.dimension(), (2, 2));
+        assert_eq!(aug.solution_column(), vec![9, 9]);
+        assert_eq!(aug[(1, 1)], 1);
+        let empty = Matrix::from_fn((0, 5), ColumnAligned, |_, _| -> i32 { unreachable!() });
+        assert_eq!(empty.dimension(), (0, 5));
+    }
+
+    #[test]
+    fn map_test() {
+        let row = Matrix::new_from_vec((2, 3), vec![1, -2, 3, 4, 5, -6], RowAligned).unwrap();
+        let mut column = row.clone();
+        column.column_align();
+        let fracs: Matrix<Fraction> = column.map(|&e| Fraction::new(e as i64, 3));
+        assert!(fracs.is_column_aligned());
+        assert_eq!(fracs.dimension(), (2, 3));
+        assert_eq!(fracs[(1, 2)], Fraction::new(-2, 1));
+        assert!(row.map(|&e| Fraction::from(e)).is_row_aligned());
+        let mut doubled = column.clone();
+        doubled.map_in_place(|e| *e *= 2);
+        assert!(doubled.iter().eq(row.map(|e| e * 2).iter()));
+        for m in &[&row, &column] {
+            let indexed = m.map_indexed(|(r, c), &e| (r, c, e));
+            assert!(indexed.indexed_iter()
+                .all(|(index, &(r, c, e))| index == (r, c) && e == m[index]));
+        }
+        let mut lower = column.clone();
+        lower.map_indexed_in_place(|(r, c), e| if c > r { *e = 0 });
+        assert_eq!(lower.iter().cloned().collect::<Vec<i32>>(), vec![1, 0, 0, 4, 5, 0]);
+        let mut aug = AugmentedMatrix::new_from_vec((2, 3), vec![1, 2, 3, 4, 5, 6], ColumnAligned)
+            .unwrap();
+        aug.map_in_place(|e| *e += 1);
+        assert_eq!(aug.solution_column(), vec![6, 7]);
+        let aug_fracs = aug.map_indexed(|(_, c), &e| Fraction::new(e as i64, c as i64 + 1));
+        assert!(aug_fracs.is_column_aligned());
+        assert_eq!(aug_fracs.solution_column(), vec![Fraction::from(2), Fraction::new(7, 3)]);
+    }
+
+    #[test]
+    fn zip_map_test() {
+        let a = Matrix::from_fn((3, 4), RowAligned, |r, c| (r * 4 + c) as i32);
+        let b = Matrix::from_fn((3, 4), ColumnAligned, |r, c| (r as i32 - c as i32) * 10);
+        let (rows, columns) = a.dimension();
+        let mut naive = Vec::new();
+        for r in 0..rows {
+            for c in 0..columns {
+                naive.push(a[(r, c)] * 100 + b[(r, c)]);
+            }
+        }
+        let ab = a.zip_map(&b, |x, y| x * 100 + y).unwrap();
+        let ba = b.zip_map(&a, |y, x| x * 100 + y).unwrap();
+        assert!(ab.is_row_aligned() && ba.is_column_aligned());
+        assert_eq!(ab.iter().cloned().collect::<Vec<i32>>(), naive);
+        assert_eq!(ba.iter().cloned().collect::<Vec<i32>>(), naive);
+        let mut c = b.clone();
+        c.zip_apply(&a, |y, x| *y += x * 100).unwrap();
+        assert!(c.is_column_aligned());
+        assert_eq!(c.iter().cloned().collect::<Vec<i32>>(), naive);
+        let mut d = a.clone();
+        d.zip_apply(&a, |x, y| *x -= y).unwrap();
+        assert!(d.is_zero_matrix());
+        let wrong = Matrix::splat(&0, (4, 3), RowAligned);
+        assert!(a.zip_map(&wrong, |x, y| x + y).is_err());
+        assert!(d.zip_apply(&wrong, |x, y| *x += y).is_err());
+        let aug_a = AugmentedMatrix::from_fn((2, 3), RowAligned, |r, c| r * 3 + c);
+        let aug_b = AugmentedMatrix::from_fn((2, 3), ColumnAligned, |r, c| r * 3 + c);
+        let sums = aug_a.zip_map(&aug_b, |x, y| x + y).unwrap();
+        assert_eq!(sums.solution_column(), vec![4, 10]);
+    }
+
+    #[test]
+    fn transpose_test() {
+        let row = Matrix::from_fn((2, 5), RowAligned, |r, c| r * 5 + c);
+        let column = Matrix::from_fn((2, 5), ColumnAligned, |r, c| r * 5 + c);
+        for m in &[&row, &column] {
+            let t = m.transpose();
+            assert_eq!(t.dimension(), (5, 2));
+            assert_ne!(t.is_row_aligned(), m.is_row_aligned());
+            for (i, j) in m.indexed_iter().map(|(index, _)| index) {
+                assert_eq!(t[(j, i)], m[(i, j)]);
+            }
+            assert_eq!(t.transpose(), **m);
+            assert!(t.transpose().exactly_equal_including_alignment(m));
+        }
+        let mut foo = row.clone();
+        foo.in_place_transpose();
+        assert_eq!(foo.dimension(), (2, 5));
+        assert_eq!(foo, row);
+        let bar = row.clone().transposed();
+        assert_eq!(bar.matrix, row.matrix);
+        assert_eq!(bar, row.transpose());
+        let empty = Matrix::<i32>::new_from_vec((0, 3), Vec::new(), RowAligned).unwrap();
+        assert_eq!(empty.transposed().dimension(), (3, 0));
+    }
+
+    #[test]
+    fn swap_rows_columns_test() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        let row = Matrix::from_fn((3, 4), RowAligned, |r, c| r * 4 + c);
+        let column = Matrix::from_fn((3, 4), ColumnAligned, |r, c| r * 4 + c);
+        for m in &[row, column] {
+            let mut foo = m.clone();
+            foo.swap_rows(0, 2);
+            assert_eq!(foo, Matrix::from_fn((3, 4), RowAligned, |r, c| (2 - r) * 4 + c));
+            foo.swap_rows(2, 0);
+            foo.swap_rows(1, 1);
+            assert_eq!(foo, *m);
+            foo.swap_columns(3, 1);
+            assert_eq!(foo, Matrix::from_fn((3, 4), RowAligned, |r, c| {
+                r * 4 + [0, 3, 2, 1][c]
+            }));
+            assert_eq!(foo.is_row_aligned(), m.is_row_aligned());
+            assert!(catch_unwind(AssertUnwindSafe(|| foo.swap_rows(0, 3))).is_err());
+            assert!(catch_unwind(AssertUnwindSafe(|| foo.swap_columns(4, 0))).is_err());
+        }
+        let mut aug = augmented_matrix![1 2 => 3; 4 5 => 6];
+        let mut aug_column = aug.clone();
+        aug_column.column_align();
+        for a in [&mut aug, &mut aug_column] {
+            a.swap_rows(0, 1);
+            assert_eq!(*a, augmented_matrix![4 5 => 6; 1 2 => 3]);
+            a.swap_columns(0, 1);
+            assert_eq!(a.solution_column(), vec![6, 3]);
+            assert_eq!(*a, augmented_matrix![5 4 => 6; 2 1 => 3]);
+            assert!(catch_unwind(AssertUnwindSafe(|| a.swap_columns(1, 2))).is_err());
+        }
+    }
+
+    #[test]
+    fn diagonal_test() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use matrices::base::Unit;
+        let foo = Matrix::from_diagonal(&[Fraction::new(1, 2), Fraction::from(3)]);
+        assert_eq!(foo.dimension(), (2, 2));
+        assert_eq!(foo[(0, 1)], Fraction::from(0));
+        assert_eq!(foo.diagonal(), vec![Fraction::new(1, 2), Fraction::from(3)]);
+        let unit: Matrix<i32> = Matrix::unit(4);
+        assert_eq!(Matrix::from_diagonal(&unit.diagonal()), unit);
+        for alignment in [RowAligned, ColumnAligned] {
+            let mut wide = Matrix::from_fn((2, 4), alignment, |r, c| r * 4 + c);
+            assert_eq!(wide.diagonal(), vec![0, 5]);
+            let mut tall = wide.transpose();
+            assert_eq!(tall.diagonal(), vec![0, 5]);
+            tall.set_diagonal(&[9, 9]);
+            assert_eq!((tall[(0, 0)], tall[(1, 1)], tall[(1, 0)]), (9, 9, 1));
+            wide.set_diagonal(&[7, 8]);
+            assert_eq!(wide.diagonal(), vec![7, 8]);
+            assert_eq!(wide[(0, 1)], 1);
+            assert!(catch_unwind(AssertUnwindSafe(|| wide.set_diagonal(&[1, 2, 3]))).is_err());
+        }
+        let empty = Matrix::<i32>::from_diagonal(&[]);
+        assert_eq!(empty.dimension(), (0, 0));
+        assert!(empty.diagonal().is_empty());
+    }
+
+    #[test]
+    fn zeros_ones_test() {
+        let zeros: Matrix<Fraction> = Matrix::zeros((2, 3));
+        assert_eq!(zeros, Matrix::splat(&Fraction::from(0), (2, 3), RowAligned));
+        assert!(zeros.is_row_aligned() && zeros.is_zero_matrix());
+        let ones: Matrix<i32> = Matrix::ones_aligned((2, 3), ColumnAligned);
+        assert!(ones.is_column_aligned());
+        assert_eq!(ones.dimension(), (2, 3));
+        assert_eq!(ones, Matrix::splat(&1, (2, 3), RowAligned));
+        let mut column_zeros = Matrix::<f64>::zeros_aligned((3, 1), ColumnAligned);
+        assert_eq!(column_zeros.dimension(), (3, 1));
+        column_zeros.row_align();
+        assert!(column_zeros.exactly_equal_including_alignment(&Matrix::zeros((3, 1))));
+        let aug: AugmentedMatrix<i32> = AugmentedMatrix::ones((2, 3));
+        assert_eq!(aug, AugmentedMatrix::splat(&1, (2, 3), RowAligned));
+        assert_eq!(aug.solution_column(), vec![1, 1]);
+        let aug_zeros: AugmentedMatrix<i32> = AugmentedMatrix::zeros_aligned((2, 3), ColumnAligned);
+        assert_eq!(aug_zeros.dimension(), (2, 2));
+        assert!(aug_zeros.iter().all(|&e| e == 0));
+    }
+
+    #[test]
+    fn stack_test() {
+        let left = Matrix::from_fn((2, 3), RowAligned, |r, c| r * 10 + c);
+        let right = Matrix::from_fn((2, 2), RowAligned, |r, c| 100 + r * 10 + c);
+        let wide = Matrix::from_fn((2, 5), RowAligned, |r, c| {
+            if c < 3 { r * 10 + c } else { 100 + r * 10 + c - 3 }
+        });
+        let below = Matrix::from_fn((1, 3), RowAligned, |_, c| 50 + c);
+        let tall = Matrix::from_fn((3, 3), RowAligned, |r, c| {
+            if r < 2 { r * 10 + c } else { 50 + c }
+        });
+        for (a, b) in [(RowAligned, RowAligned), (RowAligned, ColumnAligned),
+            (ColumnAligned, RowAligned), (ColumnAligned, ColumnAligned)] {
+            let mut l = left.clone();
+            let (mut r, mut d) = (right.clone(), below.clone());
+            if a == ColumnAligned {
+                l.column_align();
+            }
+            if b == ColumnAligned {
+                r.column_align();
+                d.column_align();
+            }
+            let h = l.hstack(&r).unwrap();
+            assert_eq!(h, wide);
+            assert_eq!(h.is_row_aligned(), l.is_row_aligned());
+            assert!(l.clone().hstack_into(&r).unwrap().exactly_equal_including_alignment(&h));
+            let v = l.vstack(&d).unwrap();
+            assert_eq!(v, tall);
+            assert_eq!(v.dimension(), (3, 3));
+            assert!(l.clone().vstack_into(&d).unwrap().exactly_equal_including_alignment(&v));
+            match l.hstack(&d) {
+                Err(MatrixError::FunctionError(msg)) => {
+                    assert!(msg.contains("(2, 3)") && msg.contains("(1, 3)"));
+                },
+                other => panic!("Expected a FunctionError, got {:?}", other)
+            }
+            assert!(l.vstack(&r).is_err());
+            assert!(l.clone().vstack_into(&r).is_err());
+            assert!(l.clone().hstack_into(&d).is_err());
+        }
+        let empty = Matrix::<usize>::new_from_vec((2, 0), Vec::new(), ColumnAligned).unwrap();
+        assert_eq!(empty.hstack(&left).unwrap(), left);
+        assert_eq!(left.clone().hstack_into(&empty).unwrap(), left);
+    }
+
+    #[test]
+    fn submatrix_test() {
+        for column_aligned in [false, true] {
+            let mut foo = Matrix::new_from_vec((3, 4), (0..12).collect::<Vec<i32>>(), RowAligned)
+                .unwrap();
+            if column_aligned {
+                foo.column_align();
+            }
+            let corner = foo.submatrix(1..3, 2..4).unwrap();
+            assert_eq!(corner.is_column_aligned(), column_aligned);
+            assert_eq!(corner, Matrix::new_from_vec((2, 2), vec![6, 7, 10, 11], RowAligned)
+                .unwrap());
+            assert_eq!(foo.submatrix(0..3, 0..4).unwrap(), foo);
+            assert_eq!(foo.submatrix(2..3, 0..4).unwrap(), Matrix::new_from_vec((1, 4),
+                vec![8, 9, 10, 11], RowAligned).unwrap());
+            assert_eq!(foo.submatrix(0..3, 3..4).unwrap(), Matrix::new_from_vec((3, 1),
+                vec![3, 7, 11], RowAligned).unwrap());
+            assert_eq!(window!(foo, rows: 0..2, cols: 1..3), Matrix::new_from_vec((2, 2),
+                vec![1, 2, 5, 6], RowAligned).unwrap());
+            assert!(foo.submatrix(2..2, 0..4).is_err());
+            assert!(foo.submatrix(0..3, 3..3).is_err());
+            assert!(foo.submatrix(0..4, 0..4).is_err());
+            assert!(foo.submatrix(0..3, 2..5).is_err());
+        }
+    }
+
+    #[test]
+    fn matrix_slice_test() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use matrices::matrix_ref::MatrixRef;
+        use matrices::slice::MatrixSlice;
+        // The slice borrows `m`, not anything local to this function
+        fn middle(m: &Matrix<i32>) -> MatrixSlice<'_, i32> {
+            let all = m.slice(0..3, 0..4);
+            all.slice(1..2, 1..3)
+        }
+        for column_aligned in [false, true] {
+            let mut foo = Matrix::new_from_vec((3, 4), (0..12).collect::<Vec<i32>>(), RowAligned)
+                .unwrap();
+            if column_aligned {
+                foo.column_align();
+            }
+            let (top, right) = (foo.slice(0..1, 0..4), foo.slice(0..3, 3..4));
+            assert_eq!(top.iter().cloned().collect::<Vec<i32>>(), vec![0, 1, 2, 3]);
+            assert_eq!(right.iter().rev().cloned().collect::<Vec<i32>>(), vec![11, 7, 3]);
+            assert_eq!(right.iter().len(), 3);
+            let block = foo.slice(1..3, 2..4);
+            assert_eq!(block.dimension(), (2, 2));
+            assert_eq!(block[(1, 1)], 11);
+            assert_eq!(block.get((2, 0)), None);
+            assert_eq!(block.to_matrix(), foo.submatrix(1..3, 2..4).unwrap());
+            assert_eq!(block.to_matrix().is_column_aligned(), column_aligned);
+            assert_eq!(block.mul_vector(&[1, -1]).unwrap(), vec![-1, -1]);
+            assert_eq!(block.row_slice(0).is_some(), !column_aligned);
+            assert_eq!(middle(&foo).to_matrix(), Matrix::new_from_vec((1, 2), vec![5, 6],
+                RowAligned).unwrap());
+            assert_eq!(foo.slice_row(2).to_matrix(), window!(foo, row: 2));
+            assert_eq!(foo.slice_column(0).to_matrix(), window!(foo, col: 0));
+            {
+                let mut block = foo.slice_mut(1..3, 2..4);
+                block[(0, 0)] = 60;
+                for e in block.iter_mut() {
+                    *e += 100;
+                }
+                assert_eq!(block.iter().cloned().collect::<Vec<i32>>(), vec![160, 107, 110, 111]);
+                assert_eq!(block.get_mut((0, 2)), None);
+            }
+            assert_eq!(foo[(1, 2)], 160);
+            assert_eq!(foo[(2, 3)], 111);
+            assert_eq!(foo[(1, 1)], 5);
+            assert_eq!(foo.iter().filter(|&&e| e > 100).count(), 4);
+            for (rows, cols) in [(0..0, 0..1), (0..4, 0..1), (0..1, 3..5)] {
+                assert!(catch_unwind(AssertUnwindSafe(|| {
+                    foo.slice(rows.clone(), cols.clone());
+                })).is_err());
+            }
+            assert!(catch_unwind(AssertUnwindSafe(|| foo.slice(0..2, 0..2)[(2, 0)])).is_err());
+        }
+    }
+
+    #[test]
+    fn reshape_test() {
+        // A column-aligned 4x3 matrix: its storage is column-major
+        let foo = Matrix::new_from_vec((4, 3), vec![1, 4, 7, 10, 2, 5, 8, 11, 3, 6, 9, 12],
+            ColumnAligned).unwrap();
+        assert_eq!(foo[(1, 0)], 4);
+        let bar = foo.clone().reshape((2, 6)).unwrap();
+        assert!(bar.is_row_aligned());
+        assert_eq!(bar, Matrix::new_from_vec((2, 6), (1..13).collect::<Vec<i32>>(), RowAligned)
+            .unwrap());
+        assert_eq!(bar.clone().reshape((4, 3)).unwrap(), foo);
+        assert_eq!(foo.clone().into_row_vector().dimension(), (1, 12));
+        assert_eq!(foo.clone().into_column_vector()[(4, 0)], 5);
+        assert_eq!(bar.into_column_vector(), foo.into_column_vector());
+        match Matrix::new_from_vec((2, 2), vec![1, 2, 3, 4], RowAligned).unwrap().reshape((3, 1)) {
+            Err(MatrixError::FunctionError(_)) => {},
+            other => panic!("Expected a FunctionError, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn resize_test() {
+        // Grow only, shrink only, and mixed grow/shrink in both orders
+        let dims = [(4, 5), (3, 4), (1, 2), (2, 5), (4, 2), (3, 3)];
+        for column_aligned in [false, true] {
+            for &new_dim in dims.iter() {
+                let mut foo = Matrix::new_from_vec((3, 3), (1..10).collect::<Vec<i32>>(),
+                    RowAligned).unwrap();
+                let mut bar = AugmentedMatrix::new_from_vec((3, 4), (1..13).collect::<Vec<i32>>(),
+                    RowAligned).unwrap();
+                if column_aligned {
+                    foo.column_align();
+                    bar.column_align();
+                }
+                let (old_foo, old_bar) = (foo.clone(), bar.clone());
+                foo.resize(new_dim, 0);
+                bar.resize(new_dim, 0);
+                assert_eq!(foo.dimension(), new_dim);
+                assert_eq!(bar.dimension(), new_dim);
+                assert_eq!(foo.is_column_aligned(), column_aligned);
+                assert_eq!(bar.is_column_aligned(), column_aligned);
+                for r in 0..new_dim.0 {
+                    for c in 0..new_dim.1 {
+                        let (expected_foo, expected_bar) = if r < 3 && c < 3 {
+                            (old_foo[(r, c)], old_bar[(r, c)])
+                        } else {
+                            (0, 0)
+                        };
+                        assert_eq!(foo[(r, c)], expected_foo);
+                        assert_eq!(bar[(r, c)], expected_bar);
+                    }
+                    let solution = if r < 3 { old_bar[(r, 3)] } else { 0 };
+                    assert_eq!(bar[(r, new_dim.1)], solution);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn nested_vec_test() {
+        use std::convert::TryFrom;
+        let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        let mut foo = Matrix::try_from(rows.clone()).unwrap();
+        assert!(foo.is_row_aligned());
+        assert_eq!(foo[(1, 0)], 4);
+        foo.column_align();
+        assert_eq!(foo.to_nested_vec(), rows);
+        assert_eq!(Matrix::try_from(foo.to_nested_vec()).unwrap(), foo);
+        match Matrix::try_from(vec![vec![1, 2], vec![3, 4], vec![5]]) {
+            Err(MatrixError::InitError(e)) => assert!(e.contains("Row 2")),
+            other => panic!("Expected an InitError, got {:?}", other)
+        }
+        for empty in [Vec::new(), vec![Vec::new()], vec![Vec::new(), vec![1]]] {
+            match Matrix::<i32>::try_from(empty) {
+                Err(MatrixError::InitError(_)) => {},
+                other => panic!("Expected an InitError, got {:?}", other)
+            }
+        }
+        match Matrix::try_from(vec![vec![1], Vec::new()]) {
+            Err(MatrixError::InitError(e)) => assert!(e.contains("Row 1")),
+            other => panic!("Expected an InitError, got {:?}", other)
+        }
+        let bar = AugmentedMatrix::try_from_parts(rows.clone(), vec![7, 8]).unwrap();
+        assert_eq!(bar.dimension(), (2, 3));
+        assert_eq!(bar[(1, 3)], 8);
+        assert_eq!(bar.solution_column(), vec![7, 8]);
+        assert!(AugmentedMatrix::try_from_parts(rows.clone(), vec![7]).is_err());
+        assert!(AugmentedMatrix::try_from_parts(vec![vec![1], vec![2, 3]], vec![7, 8]).is_err());
+    }
+
+    #[test]
+    fn display_snapshot_test() {
+        let row = Matrix::new_from_vec((1, 3), vec![1, -20, 3], RowAligned).unwrap();
+        assert_eq!(format!("{}", row), "[ 1  -20  3  ]");
+        let column = Matrix::new_from_vec((3, 1), vec![5, 100, -7], ColumnAligned).unwrap();
+        assert_eq!(format!("{}", column), "⎡   5  ⎤\n⎢ 100  ⎥\n⎣  -7  ⎦");
+        let mut foo = Matrix::new_from_vec((3, 4), vec![1, 2, 3, 4, 10, -20, 30, 40, 0, 0, 0,
+            -500], RowAligned).unwrap();
+        let expected = "⎡  1    2   3     4  ⎤\n\
+                        ⎢ 10  -20  30    40  ⎥\n\
+                        ⎣  0    0   0  -500  ⎦";
+        assert_eq!(format!("{}", foo), expected);
+        foo.column_align();
+        assert_eq!(format!("{}", foo), expected);
+        let bar = AugmentedMatrix::new_from_vec((1, 3), vec![1, 2, 3], RowAligned).unwrap();
+        assert_eq!(format!("{}", bar), "[ 1  2 │ 3 ]");
+        let baz = AugmentedMatrix::new_from_vec((2, 3), vec![1, 22, 3, 4, 5, 66], RowAligned)
+            .unwrap();
+        assert_eq!(format!("{}", baz), "┌ 1  22 │  3 ┐\n└ 4   5 │ 66 ┘");
+    }
+
+    #[test]
+    fn debug_format_test() {
+        // Logical rows, whatever the storage order
+        let foo = Matrix::new_from_vec((2, 3), vec![1, 4, 2, 5, 3, 6], ColumnAligned).unwrap();
+        assert_eq!(format!("{:?}", foo), "Matrix { dim: (2, 3), alignment: ColumnAligned, \
+            data: [[1, 2, 3], [4, 5, 6]] }");
+        assert_eq!(format!("{:#?}", foo), "Matrix {\n    \
+            dim: (\n        2,\n        3,\n    ),\n    \
+            alignment: ColumnAligned,\n    \
+            data: [\n        [1, 2, 3],\n        [4, 5, 6],\n    ],\n}");
+        let bar = AugmentedMatrix::new_from_vec((2, 3), vec![1, 2, 3, 4, 5, 6], RowAligned)
+            .unwrap();
+        assert_eq!(format!("{:?}", bar), "AugmentedMatrix { dim: (2, 2), alignment: RowAligned, \
+            data: [[1, 2], [4, 5]], solution: [3, 6] }");
+        let big = Matrix::new_from_vec((11, 12), (0..132).collect::<Vec<i32>>(), RowAligned)
+            .unwrap();
+        let debug = format!("{:?}", big);
+        assert!(debug.contains("[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, ...], [12, "), "{}", debug);
+        assert!(debug.ends_with("[108, 109, 110, 111, 112, 113, 114, 115, 116, 117, ...], ...] }"),
+            "{}", debug);
+    }
+
+    #[test]
+    fn new_default_test() {
+        for alignment in [RowAligned, ColumnAligned] {
+            let mut foo: Matrix<i32> = Matrix::new((2, 3), alignment.clone());
+            assert_eq!(foo.dimension(), (2, 3));
+            assert_eq!(foo[(1, 2)], 0);
+            assert_eq!(foo, Matrix::new_default((2, 3), RowAligned));
+            foo[(1, 2)] = 7;
+            assert_eq!(foo.iter().sum::<i32>(), 7);
+            let bar: AugmentedMatrix<f64> = AugmentedMatrix::new((2, 3), alignment.clone());
+            assert_eq!(bar.dimension(), (2, 2));
+            assert_eq!(bar[(1, 2)], 0.0);
+            assert_eq!(bar.solution_column(), vec![0.0, 0.0]);
+            let mut baz: Matrix<i32> = Matrix::with_capacity((2, 3), alignment);
+            baz.set_matrix(vec![1, 2, 3, 4, 5, 6]);
+            assert_eq!(baz.dimension(), (2, 3));
+        }
+    }
+
+    #[test]
+    fn column_aligned_dimension_test() {
+        // A 2x5 matrix from its columns
+        let foo = Matrix::new_from_vec((2, 5), vec![0, 5, 1, 6, 2, 7, 3, 8, 4, 9], ColumnAligned)
+            .unwrap();
+        assert_eq!(foo.dimension(), (2, 5));
+        assert_eq!((foo.num_rows(), foo.num_columns()), (2, 5));
+        assert_eq!(foo[(1, 4)], 9);
+        assert_eq!(foo[(0, 3)], 3);
+        let mut bar = foo.clone();
+        bar.row_align();
+        assert_eq!(bar.dimension(), (2, 5));
+        assert!(bar.exactly_equal_including_alignment(&Matrix::new_from_vec((2, 5),
+            (0..10).collect(), RowAligned).unwrap()));
+        bar.column_align();
+        assert!(bar.exactly_equal_including_alignment(&foo));
+        assert!(Matrix::new_from_vec((5, 3), vec![0; 10], ColumnAligned).is_err());
+        let baz = AugmentedMatrix::new_from_vec((2, 3), vec![1, 4, 2, 5, 3, 6], ColumnAligned)
+            .unwrap();
+        assert_eq!(baz.dimension(), (2, 2));
+        assert_eq!(baz[(1, 2)], 6);
+        // Every constructor takes the logical dimension
+        for alignment in [RowAligned, ColumnAligned] {
+            assert_eq!(Matrix::splat(&1, (2, 5), alignment.clone()).dimension(), (2, 5));
+            assert_eq!(Matrix::<i32>::new((2, 5), alignment.clone()).dimension(), (2, 5));
+            assert_eq!(Matrix::<i32>::zeros_aligned((2, 5), alignment.clone()).dimension(),
+                (2, 5));
+            assert_eq!(AugmentedMatrix::splat(&1, (2, 5), alignment).dimension(), (2, 4));
+        }
+    }
+
+    #[test]
+    fn set_matrix_test() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        // Six elements used to pass for a 2x2 matrix since 6 % 2 == 0
+        let mut foo: Matrix<i32> = Matrix::new((2, 2), RowAligned);
+        match foo.try_set_matrix(vec![0, 1, 2, 3, 4, 5]) {
+            Err(MatrixError::InitError(e)) => assert!(e.contains("4") && e.contains("6"), "{}", e),
+            other => panic!("Expected an InitError, got {:?}", other)
+        }
+        assert!(catch_unwind(AssertUnwindSafe(|| foo.set_matrix(vec![0, 1, 2, 3, 4, 5])))
+            .is_err());
+        assert_eq!(foo, Matrix::new((2, 2), RowAligned));
+        foo.try_set_matrix(vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(foo[(1, 0)], 3);
+        // Replacing the contents and the alignment together
+        let mut bar: Matrix<i32> = Matrix::new((2, 3), RowAligned);
+        bar.set_matrix_with_alignment(vec![1, 4, 2, 5, 3, 6], ColumnAligned);
+        assert!(bar.is_column_aligned());
+        assert_eq!(bar.dimension(), (2, 3));
+        assert_eq!(bar, Matrix::new_from_vec((2, 3), vec![1, 2, 3, 4, 5, 6], RowAligned).unwrap());
+        assert!(catch_unwind(AssertUnwindSafe(|| bar.set_matrix_with_alignment(vec![0; 5],
+            RowAligned))).is_err());
+        assert!(bar.is_column_aligned());
+        let mut baz: AugmentedMatrix<i32> = AugmentedMatrix::new((2, 3), ColumnAligned);
+        assert!(baz.try_set_matrix(vec![0; 4]).is_err());
+        baz.set_matrix_with_alignment(vec![1, 2, 3, 4, 5, 6], RowAligned);
+        assert_eq!(baz.solution_column(), vec![3, 6]);
+    }
+
+    #[test]
+    fn matrix_hash_test() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashSet;
+        use std::hash::{Hash, Hasher};
+        fn hash_of<H: Hash>(value: &H) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+        // Deterministic pseudo-random matrices, some of them equal to each other
+        let mut state = 12345u64;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as i64 % 3
+        };
+        let mut matrices = Vec::new();
+        for i in 0..200 {
+            let dim = [(1, 2), (2, 1), (2, 2)][i % 3];
+            let mut m = Matrix::new_from_vec(dim, (0..dim.0 * dim.1).map(|_| next()).collect(),
+                RowAligned).unwrap();
+            if next() == 0 {
+                m.column_align();
+            }
+            matrices.push(m);
+        }
+        for a in &matrices {
+            let mut realigned = a.clone();
+            realigned.in_place_transpose();
+            assert_eq!(hash_of(a), hash_of(&realigned));
+            for b in &matrices {
+                if a == b {
+                    assert_eq!(hash_of(a), hash_of(b), "{:?} {:?}", a, b);
+                }
+            }
+        }
+        // Same elements in a different shape aren't equal, and (here) don't hash the same
+        let row = Matrix::new_from_vec((1, 2), vec![1, 2], RowAligned).unwrap();
+        let column = Matrix::new_from_vec((2, 1), vec![1, 2], RowAligned).unwrap();
+        assert_ne!(row, column);
+        assert_ne!(hash_of(&row), hash_of(&column));
+        let mut set = HashSet::new();
+        set.insert(row.clone());
+        let mut realigned = row.clone();
+        realigned.column_align();
+        assert!(!set.insert(realigned));
+        assert!(set.insert(column));
+        let aug = AugmentedMatrix::new_from_vec((2, 2), vec![1, 2, 3, 4], RowAligned).unwrap();
+        let mut aug_realigned = aug.clone();
+        aug_realigned.column_align();
+        assert_eq!(hash_of(&aug), hash_of(&aug_realigned));
+        let distinct = matrices.iter().cloned().collect::<HashSet<Matrix<i64>>>();
+        assert!(distinct.len() < matrices.len());
+        for m in &matrices {
+            assert!(distinct.contains(m));
+        }
+    }
+
+    #[test]
+    fn augmented_equality_test() {
+        let foo = AugmentedMatrix::new_from_vec((2, 3), vec![1, 2, 5, 3, 4, 6], RowAligned)
+            .unwrap();
+        let mut foo_columns = foo.clone();
+        foo_columns.column_align();
+        assert!(foo.exactly_equal_including_alignment(&foo.clone()));
+        assert!(!foo.exactly_equal_including_alignment(&foo_columns));
+        assert!(!foo_columns.exactly_equal_including_alignment(&foo));
+        assert!(foo == foo_columns);
+        let other_solutions = AugmentedMatrix::new_from_vec((2, 3), vec![1, 2, 0, 3, 4, 0],
+            RowAligned).unwrap();
+        assert!(!foo.exactly_equal_including_alignment(&other_solutions));
+        let coefficients = Matrix::new_from_vec((2, 2), vec![1, 2, 3, 4], RowAligned).unwrap();
+        let mut coefficients_columns = coefficients.clone();
+        coefficients_columns.column_align();
+        for aug in [&foo, &foo_columns, &other_solutions] {
+            assert!(aug.coefficients_equal(&coefficients));
+            assert!(aug.coefficients_equal(&coefficients_columns));
+        }
+        let different = Matrix::new_from_vec((2, 2), vec![1, 2, 3, 5], RowAligned).unwrap();
+        let mut different_columns = different.clone();
+        different_columns.column_align();
+        let wrong_shape = Matrix::new_from_vec((1, 2), vec![1, 2], RowAligned).unwrap();
+        for aug in [&foo, &foo_columns] {
+            assert!(!aug.coefficients_equal(&different));
+            assert!(!aug.coefficients_equal(&different_columns));
+            assert!(!aug.coefficients_equal(&wrong_shape));
+        }
+    }
+
+    #[test]
+    fn convert_test() {
+        let foo = Matrix::new_from_vec((2, 3), vec![1i32, 4, 2, 5, 3, 6], ColumnAligned).unwrap();
+        let bar = foo.clone().convert::<Fraction>();
+        assert!(bar.is_column_aligned());
+        assert_eq!(bar[(1, 2)], Fraction::from(6));
+        let wide = foo.clone().convert::<i64>();
+        assert_eq!(wide.try_convert::<i32>().unwrap(), foo);
+        // The first failure going row by row, not in storage order
+        let big = Matrix::new_from_vec((2, 2), vec![0i64, 1 << 40, -(1 << 40), 0], ColumnAligned)
+            .unwrap();
+        match big.try_convert::<i32>() {
+            Err(MatrixError::FunctionError(e)) => assert!(e.contains("(0, 1)"), "{}", e),
+            other => panic!("Expected a FunctionError, got {:?}", other)
+        }
+        let negative = Matrix::new_from_vec((1, 2), vec![1i64, -1], RowAligned).unwrap();
+        match negative.try_convert::<u8>() {
+            Err(MatrixError::FunctionError(e)) => assert!(e.contains("(0, 1)"), "{}", e),
+            other => panic!("Expected a FunctionError, got {:?}", other)
+        }
+        // Augmented matrices convert their solutions too
+        let aug = AugmentedMatrix::new_from_vec((2, 2), vec![1u8, 2, 3, 255], RowAligned)
+            .unwrap();
+        let aug_wide = aug.clone().convert::<i64>();
+        assert_eq!(aug_wide.solution_column(), vec![2, 255]);
+        match aug_wide.try_convert::<i8>() {
+            Err(MatrixError::FunctionError(e)) => assert!(e.contains("(1, 1)"), "{}", e),
+            other => panic!("Expected a FunctionError, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn f64_round_trip_test() {
+        let values = [0.5, -3.25, 1.0 / 1024.0, 0.0, 7.0, -0.125];
+        let fractions = [Fraction::new(1, 2), Fraction::new(-13, 4), Fraction::new(1, 1024),
+            Fraction::from(0), Fraction::from(7), Fraction::new(-1, 8)];
+        for alignment in [RowAligned, ColumnAligned] {
+            let foo = Matrix::new_from_vec((2, 3), values.to_vec(), alignment.clone()).unwrap();
+            let bar = foo.to_fraction_matrix();
+            assert_eq!(bar.alignment, alignment);
+            assert_eq!(bar.dimension(), (2, 3));
+            for (&a, b) in foo.iter().zip(bar.iter()) {
+                let i = values.iter().position(|&v| v == a).unwrap();
+                assert_eq!(*b, fractions[i]);
+            }
+            assert_eq!(bar.to_f64_matrix(), foo);
+            let baz = AugmentedMatrix::new_from_vec((2, 3), values.to_vec(), alignment).unwrap();
+            assert_eq!(baz.to_fraction_matrix().to_f64_matrix(), baz);
+        }
+        let foo = Matrix::new_from_vec((1, 3), vec![f64::NAN, f64::INFINITY, 1e300], RowAligned)
+            .unwrap()
+            .to_fraction_matrix();
+        assert!(foo.iter().all(Fraction::is_ud));
+        let bar = Matrix::new_from_vec((1, 2), vec![Fraction::new(1, 2) / Fraction::from(0),
+            Fraction::new(3, 4)], RowAligned).unwrap().to_f64_matrix();
+        assert!(bar[(0, 0)].is_nan());
+        assert_eq!(bar[(0, 1)], 0.75);
+    }
+
+    #[test]
+    fn structural_predicates_test() {
+        // (upper, lower, symmetric) for row-aligned (row-major) vecs of 3x3 matrices
+        let cases = [
+            (vec![1, 2, 3, 0, 4, 5, 0, 0, 6], true, false, false),
+            (vec![1, 0, 0, 2, 4, 0, 3, 5, 6], false, true, false),
+            (vec![1, 0, 0, 0, 4, 0, 0, 0, 6], true, true, true),
+            (vec![1, 2, 3, 2, 4, 5, 3, 5, 6], false, false, true),
+            (vec![0, 0, 0, 0, 0, 0, 1, 0, 0], false, true, false)
+        ];
+        for (vec, upper, lower, symmetric) in cases.iter().cloned() {
+            let foo = Matrix::new_from_vec((3, 3), vec, RowAligned).unwrap();
+            let mut bar = foo.clone();
+            bar.column_align();
+            for m in [&foo, &bar] {
+                assert_eq!(m.is_upper_triangular(), upper);
+                assert_eq!(m.is_lower_triangular(), lower);
+                assert_eq!(m.is_diagonal(), upper && lower);
+                assert_eq!(m.is_symmetric(), symmetric);
+            }
+        }
+        for alignment in [RowAligned, ColumnAligned] {
+            let foo = Matrix::splat(&0, (2, 3), alignment);
+            assert!(!foo.is_upper_triangular());
+            assert!(!foo.is_lower_triangular());
+            assert!(!foo.is_diagonal());
+            assert!(!foo.is_symmetric());
+        }
+    }
+
+    #[test]
+    fn search_test() {
+        for column_aligned in [false, true] {
+            // Needs a row swap before the first and the last column can be eliminated
+            let mut foo = Matrix::new_from_vec((3, 3), vec![0, 2, 1, 3, 4, 0, 5, 0, 0], RowAligned)
+                .unwrap();
+            if column_aligned {
+                foo.column_align();
+            }
+            assert!(foo.contains(&5));
+            assert!(!foo.contains(&7));
+            assert_eq!(foo.position(&0), Some((0, 0)));
+            assert_eq!(foo.position(&4), Some((1, 1)));
+            assert_eq!(foo.position(&7), None);
+            assert_eq!(foo.find(|&e| e > 2), Some(((1, 0), &3)));
+            let zero_pivot = (0..3).find(|&i| foo[(i, i)] == 0);
+            assert_eq!(zero_pivot, Some(0));
+            let below = foo.submatrix(1..3, 0..1).unwrap();
+            assert_eq!(below.find(|&e| e != 0), Some(((0, 0), &3)));
+            let mut calls = 0;
+            foo.find(|_| { calls += 1; calls == 4 });
+            assert_eq!(calls, 4);
+            let bar = AugmentedMatrix::new_from_vec((2, 3), vec![1, 2, 9, 3, 9, 4], RowAligned)
+                .unwrap();
+            assert_eq!(bar.position(&9), Some((0, 2)));
+            assert_eq!(bar.position(&4), Some((1, 2)));
+        }
+    }
+
+    #[test]
+    fn fill_test() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        for column_aligned in [false, true] {
+            let mut foo = Matrix::new_from_vec((3, 2), (1..7).collect(), RowAligned).unwrap();
+            let mut bar = AugmentedMatrix::new_from_vec((2, 3), (1..7).collect(), RowAligned)
+                .unwrap();
+            if column_aligned {
+                foo.column_align();
+                bar.column_align();
+            }
+            foo.fill_row(1, 0);
+            foo.fill_column(1, -1);
+            let expected = Matrix::new_from_vec((3, 2), vec![1, -1, 0, -1, 5, -1], RowAligned)
+                .unwrap();
+            assert_eq!(foo, expected);
+            assert_eq!(foo.is_column_aligned(), column_aligned);
+            assert!(foo.try_fill_row(3, 0).is_err());
+            assert!(foo.try_fill_column(2, 0).is_err());
+            assert!(catch_unwind(AssertUnwindSafe(|| foo.fill_row(3, 0))).is_err());
+            assert!(catch_unwind(AssertUnwindSafe(|| foo.fill_column(2, 0))).is_err());
+            assert_eq!(foo, expected);
+            foo.fill(8);
+            assert!(foo.iter().all(|&e| e == 8));
+
+            bar.fill_column(0, 0);
+            assert_eq!(bar.solution_column(), vec![3, 6]);
+            assert!(bar.try_fill_column(2, 0).is_err());
+            assert_eq!(bar.solution_column(), vec![3, 6]);
+            bar.fill_row(0, 9);
+            assert_eq!(bar.solution_column(), vec![9, 6]);
+            bar.fill_solution(1);
+            assert_eq!(bar, AugmentedMatrix::new_from_vec((2, 3), vec![9, 9, 1, 0, 5, 1],
+                RowAligned).unwrap());
+        }
+    }
+
+    #[test]
+    fn rotate_flip_test() {
+        for column_aligned in [false, true] {
+            let mut foo = Matrix::new_from_vec((4, 4), (0..16).collect(), RowAligned).unwrap();
+            if column_aligned {
+                foo.column_align();
+            }
+            let mut bar = foo.clone();
+            for _ in 0..4 {
+                bar = bar.rotate_cw();
+            }
+            assert_eq!(bar, foo);
+            for _ in 0..4 {
+                bar = bar.rotate_ccw();
+            }
+            assert_eq!(bar, foo);
+            assert_eq!(foo.rotate_cw().rotate_ccw(), foo);
+            assert_eq!(foo.rotate_cw().rotate_cw(), foo.flip_horizontal().flip_vertical());
+            assert_eq!(foo.flip_horizontal().flip_horizontal(), foo);
+            assert_eq!(foo.flip_vertical().flip_vertical(), foo);
+            bar.flip_vertical_in_place();
+            assert_eq!(bar[(0, 0)], 12);
+            assert_eq!(bar.is_column_aligned(), column_aligned);
+            bar.flip_horizontal_in_place();
+            assert_eq!(bar[(0, 0)], 15);
+
+            let mut baz = Matrix::new_from_vec((2, 3), (0..6).collect(), RowAligned).unwrap();
+            if column_aligned {
+                baz.column_align();
+            }
+            let cw = baz.rotate_cw();
+            assert_eq!(cw.dimension(), (3, 2));
+            assert_eq!(cw, Matrix::new_from_vec((3, 2), vec![3, 0, 4, 1, 5, 2], RowAligned)
+                .unwrap());
+            assert_eq!(cw.is_column_aligned(), !column_aligned);
+            assert_eq!(baz.rotate_ccw(), Matrix::new_from_vec((3, 2), vec![2, 5, 1, 4, 0, 3],
+                RowAligned).unwrap());
+        }
+    }
+
+    #[test]
+    fn minor_cofactor_test() {
+        for column_aligned in [false, true] {
+            let mut foo = Matrix::new_from_vec((3, 3), vec![2, -1, 0, 1, 3, 4, 5, 2, -2],
+                RowAligned).unwrap();
+            if column_aligned {
+                foo.column_align();
+            }
+            let minor = foo.minor(1, 2).unwrap();
+            assert_eq!(minor, Matrix::new_from_vec((2, 2), vec![2, -1, 5, 2], RowAligned)
+                .unwrap());
+            assert_eq!(minor.is_column_aligned(), column_aligned);
+            assert_eq!(foo.minor(0, 0).unwrap(), Matrix::new_from_vec((2, 2), vec![3, 4, 2, -2],
+                RowAligned).unwrap());
+            // Cofactors worked out by hand
+            let cofactors = [-14, 22, -13, -2, -4, -9, -4, -8, 7];
+            for r in 0..3 {
+                for c in 0..3 {
+                    assert_eq!(foo.cofactor(r, c).unwrap(), cofactors[r * 3 + c]);
+                }
+            }
+            // Expanding along the first row gives the determinant, -50
+            assert_eq!((0..3).map(|c| foo[(0, c)] * cofactors[c]).sum::<i32>(), -50);
+            assert!(foo.minor(0, 3).is_err());
+            assert!(foo.cofactor(3, 0).is_err());
+        }
+        let bar = Matrix::new_from_vec((2, 3), (1..7).collect::<Vec<i32>>(), RowAligned).unwrap();
+        assert_eq!(bar.minor(1, 1).unwrap(), Matrix::new_from_vec((1, 2), vec![1, 3], RowAligned)
+            .unwrap());
+        assert!(bar.cofactor(0, 0).is_err());
+        let baz = Matrix::new_from_vec((1, 1), vec![Fraction::new(1, 2)], RowAligned).unwrap();
+        assert!(baz.minor(0, 0).is_err());
+        assert_eq!(baz.cofactor(0, 0).unwrap(), Fraction::from(1));
+    }
+
+    #[test]
+    fn raw_parts_test() {
+        for alignment in [RowAligned, ColumnAligned] {
+            let foo = Matrix::new_from_vec((2, 3), (1..7).collect::<Vec<i32>>(), alignment.clone())
+                .unwrap();
+            let copy = foo.clone();
+            let ptr = foo.as_slice().as_ptr();
+            let (vec, dimension, parts_alignment) = foo.into_inner();
+            assert_eq!(vec, (1..7).collect::<Vec<i32>>());
+            assert_eq!(dimension, (2, 3));
+            assert_eq!(parts_alignment, alignment);
+            assert_eq!(vec.as_ptr(), ptr);
+            let mut foo = Matrix::from_raw_parts(vec, dimension, parts_alignment).unwrap();
+            assert!(foo.exactly_equal_including_alignment(&copy));
+            foo.as_mut_slice()[1] = 0;
+            assert_eq!(foo.as_slice(), &[1, 0, 3, 4, 5, 6]);
+            assert!(Matrix::from_raw_parts(vec![1, 2, 3], (2, 2), alignment.clone()).is_err());
+
+            let bar = AugmentedMatrix::new_from_vec((2, 3), (1..7).collect::<Vec<i32>>(),
+                alignment.clone()).unwrap();
+            let (vec, dimension, parts_alignment) = bar.clone().into_inner();
+            assert_eq!(dimension, (2, 3));
+            let baz = AugmentedMatrix::from_raw_parts(vec, dimension, parts_alignment).unwrap();
+            assert!(baz.exactly_equal_including_alignment(&bar));
+            assert!(AugmentedMatrix::from_raw_parts(vec![1, 2, 3], (2, 3), alignment).is_err());
+        }
+    }
+
+    #[test]
+    fn range_index_methods_test() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        let mut foo: Matrix<i32> = matrix![
+             0  1  2  3;
+             4  5  6  7;
+             8  9 10 11;
+            12 13 14 15
+        ];
+        assert_eq!(foo[..1], [0, 1, 2, 3]);
+        assert_eq!(foo[0..=1], foo[0..2]);
+        assert_eq!(foo[3..], [12, 13, 14, 15]);
+        assert_eq!(foo[..].len(), 16);
+        assert_eq!(foo[..=0], foo[0]);
+        assert_eq!(foo[4..], []);
+        foo[1..=2].iter_mut().for_each(|e| *e = 0);
+        assert_eq!(foo[(1, 3)], 0);
+        assert_eq!(foo[(3, 0)], 12);
+        foo.column_align();
+        assert_eq!(foo[..2], [0, 0, 0, 12, 1, 0, 0, 13]);
+        assert_eq!(foo[3..=3], [3, 0, 0, 15]);
+        assert_eq!(foo[2..], foo[2..4]);
+        foo[..].iter_mut().for_each(|e| *e += 1);
+        assert_eq!(foo[(0, 0)], 1);
+        let mut bar = augmented_matrix![
+            1 2 => 3;
+            4 5 => 6
+        ];
+        assert_eq!(bar[1..], [4, 5, 6]);
+        bar.column_align();
+        assert_eq!(bar[2..=2], [3, 6]);
+        assert_eq!(bar[..], [1, 4, 2, 5, 3, 6]);
+        for range in [3..=3, 1..=3] {
+            let panic = catch_unwind(AssertUnwindSafe(|| bar[range.clone()].len())).unwrap_err();
+            let message = panic.downcast_ref::<String>().unwrap();
+            assert!(message.contains("3 stored columns of a 2x3 matrix"), "{}", message);
+        }
+        assert!(catch_unwind(AssertUnwindSafe(|| foo[5..].len())).is_err());
+        assert!(catch_unwind(AssertUnwindSafe(|| foo[..5].len())).is_err());
+    }
+
+    #[test]
+    fn from_rows_test() {
+        let foo = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert!(foo.is_row_aligned());
+        assert_eq!(foo.dimension(), (2, 3));
+        assert_eq!(foo[(1, 2)], 6);
+        let bar = Matrix::from_rows([&[1, 2][..], &[3, 4], &[5, 6]].iter()).unwrap();
+        assert_eq!(bar.dimension(), (3, 2));
+        let ragged = Matrix::from_rows(vec![vec![1, 2], vec![3, 4], vec![5]]).unwrap_err();
+        assert_eq!(ragged.kind(), MatrixErrorKind::Init);
+        assert!(ragged.to_string().contains("Row 2 has 1 elements, but row 0 has 2."));
+        assert!(Matrix::from_rows(vec![Vec::<i32>::new()]).is_err());
+        let empty = Matrix::<i32>::from_rows(Vec::<Vec<i32>>::new()).unwrap_err();
+        assert!(empty.to_string().contains("There are no rows."));
+
+        let baz = AugmentedMatrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert_eq!(baz.dimension(), (2, 2));
+        assert_eq!(baz.solution_column(), vec![3, 6]);
+        assert!(AugmentedMatrix::from_rows(vec![vec![1], vec![2]]).is_err());
+        let ragged = AugmentedMatrix::from_rows(vec![vec![1, 2, 3], vec![4, 5]]).unwrap_err();
+        assert!(ragged.to_string().contains("Row 1"));
+        assert!(AugmentedMatrix::<i32>::from_rows(Vec::<Vec<i32>>::new()).is_err());
+    }
+
+    #[test]
+    fn extend_rows_test() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        for column_aligned in [false, true] {
+            let mut foo = Matrix::new_from_vec((1, 3), vec![0, 1, 2], RowAligned).unwrap();
+            if column_aligned {
+                foo.column_align();
+            }
+            foo.extend(vec![[3, 4, 5], [6, 7, 8]]);
+            foo.extend(Vec::<Vec<i32>>::new());
+            assert_eq!(foo, Matrix::new_from_vec((3, 3), (0..9).collect(), RowAligned).unwrap());
+            assert_eq!(foo.is_column_aligned(), column_aligned);
+            let err = foo.try_extend_rows(vec![vec![9, 10, 11], vec![12, 13], vec![14, 15, 16]])
+                .unwrap_err();
+            assert!(err.to_string().contains("(1 rows were appended before it)"));
+            assert_eq!(foo.dimension(), (4, 3));
+            assert_eq!(foo[(3, 2)], 11);
+            assert!(foo.try_extend_rows([[12, 13, 14]]).is_ok());
+            assert_eq!(foo[(4, 0)], 12);
+            assert!(catch_unwind(AssertUnwindSafe(|| foo.extend(vec![vec![0]]))).is_err());
+            assert_eq!(foo.dimension(), (5, 3));
+        }
+    }
+
+    #[test]
+    fn elementwise_add_sub_test() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        let row = Matrix::new_from_vec((2, 3), vec![1, 2, 3, 4, 5, 6], RowAligned).unwrap();
+        let mut column = Matrix::new_from_vec((2, 3), vec![10, 20, 30, 40, 50, 60], RowAligned)
+            .unwrap();
+        column.column_align();
+        let sum = &row + &column;
+        assert!(sum.is_row_aligned());
+        assert_eq!(sum, Matrix::new_from_vec((2, 3), vec![11, 22, 33, 44, 55, 66], RowAligned)
+            .unwrap());
+        let difference = column.clone() - row.clone();
+        assert!(difference.is_column_aligned());
+        assert_eq!(difference, Matrix::new_from_vec((2, 3), vec![9, 18, 27, 36, 45, 54],
+            RowAligned).unwrap());
+        assert_eq!(&sum - &column, row);
+        assert_eq!(row.clone() + &column, sum);
+        assert_eq!(&row + column.clone(), sum);
+        assert_eq!(row.try_add(&column).unwrap(), sum);
+        assert_eq!(sum.try_sub(&row).unwrap(), column);
+
+        let mut foo = Matrix::new_from_vec((2, 2), vec![Fraction::new(1, 2), Fraction::new(-1, 3),
+            Fraction::from(0), Fraction::new(5, 4)], ColumnAligned).unwrap();
+        let bar = Matrix::new_from_vec((2, 2), vec![Fraction::new(1, 2), Fraction::new(1, 3),
+            Fraction::new(1, 7), Fraction::new(-1, 4)], RowAligned).unwrap();
+        foo += &bar;
+        assert_eq!(foo[(0, 0)], Fraction::from(1));
+        assert_eq!(foo[(1, 0)], Fraction::new(-1, 3) + Fraction::new(1, 7));
+        assert_eq!(foo[(0, 1)], Fraction::new(1, 3));
+        assert_eq!(foo[(1, 1)], Fraction::from(1));
+        foo -= bar.clone();
+        foo -= bar;
+        assert_eq!(foo[(1, 1)], Fraction::new(3, 2));
+
+        let wide = Matrix::new_from_vec((3, 2), vec![0; 6], RowAligned).unwrap();
+        let err = row.try_add(&wide).unwrap_err();
+        assert_eq!(err.kind(), MatrixErrorKind::Function);
+        assert!(err.to_string().contains("Can't add matrices of dimension (2, 3) and (3, 2)."));
+        assert!(row.try_sub(&wide).unwrap_err().to_string().contains("subtract"));
+        let panic = catch_unwind(AssertUnwindSafe(|| &row + &wide)).unwrap_err();
+        assert!(panic.downcast_ref::<String>().unwrap().contains("(2, 3) and (3, 2)"));
+        assert!(catch_unwind(AssertUnwindSafe(|| {
+            let mut foo = row.clone();
+            foo -= &wide;
+        })).is_err());
+    }
+
+    #[test]
+    fn matrix_product_test() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use matrices::base::Unit;
+        for (left_column, right_column) in [(false, false), (false, true), (true, false),
+            (true, true)] {
+            let mut a = Matrix::new_from_vec((2, 3), vec![1, 2, 3, 4, 5, 6], RowAligned).unwrap();
+            let mut b = Matrix::new_from_vec((3, 2), vec![7, 8, 9, 10, 11, 12], RowAligned)
+                .unwrap();
+            if left_column {
+                a.column_align();
+            }
+            if right_column {
+                b.column_align();
+            }
+            // Worked out by hand
+            let product = &a * &b;
+            assert_eq!(product, Matrix::new_from_vec((2, 2), vec![58, 64, 139, 154], RowAligned)
+                .unwrap());
+            assert_eq!(product.is_column_aligned(), left_column);
+            assert_eq!((&b * &a).dimension(), (3, 3));
+            assert_eq!((&b * &a)[(2, 1)], 11 * 2 + 12 * 5);
+            let identity: Matrix<i32> = Matrix::unit(2);
+            assert_eq!(&identity * &a, a);
+            assert_eq!(a.clone() * Matrix::<i32>::unit(3), a);
+            let mut c = a.clone();
+            c *= &b;
+            assert_eq!(c, product);
+            assert!(a.try_mul(&a).is_err());
+            // Empty operands multiply as long as the inner dimensions agree
+            let alignment = a.get_alignment();
+            let tall = Matrix::<i32>::new_from_vec((2, 0), vec![], alignment.clone()).unwrap();
+            let wide = Matrix::<i32>::new_from_vec((0, 3), vec![], b.get_alignment()).unwrap();
+            let zero = &tall * &wide;
+            assert_eq!(zero, Matrix::new_from_vec((2, 3), vec![0; 6], RowAligned).unwrap());
+            assert_eq!(zero.get_alignment(), alignment);
+            assert_eq!((&wide * &b).dimension(), (0, 2));
+            assert_eq!((&a * &b.try_mul(&tall).unwrap()).dimension(), (2, 0));
+            assert!(tall.try_mul(&tall).is_err());
+        }
+        let a = Matrix::new_from_vec((2, 2), vec![Fraction::new(1, 2), Fraction::new(1, 3),
+            Fraction::new(-2, 5), Fraction::new(3, 7)], RowAligned).unwrap();
+        let b = Matrix::new_from_vec((2, 1), vec![Fraction::new(6, 5), Fraction::new(-7, 9)],
+            ColumnAligned).unwrap();
+        let product = a * b;
+        assert_eq!(product.dimension(), (2, 1));
+        // 3/5 - 7/27 and -12/25 - 1/3
+        assert_eq!(product[(0, 0)], Fraction::new(46, 135));
+        assert_eq!(product[(1, 0)], Fraction::new(-61, 75));
+
+        let a = Matrix::new_from_vec((2, 3), vec![1, 2, 3, 4, 5, 6], RowAligned).unwrap();
+        let err = a.try_mul(&a).unwrap_err();
+        assert_eq!(err.kind(), MatrixErrorKind::Function);
+        assert!(err.to_string()
+            .contains("the left one has 3 columns but the right one has 2 rows"));
+        assert!(catch_unwind(AssertUnwindSafe(|| &a * &a)).is_err());
+    }
+
+    #[test]
+    fn neg_test() {
+        for column_aligned in [false, true] {
+            let mut foo = Matrix::new_from_vec((2, 3), vec![Fraction::new(-1, 2), Fraction::from(0),
+                Fraction::new(3, 4), Fraction::from(-5), Fraction::new(7, 3), Fraction::from(0)],
+                RowAligned).unwrap();
+            if column_aligned {
+                foo.column_align();
+            }
+            let negated = -&foo;
+            assert_eq!(negated.is_column_aligned(), column_aligned);
+            assert_eq!(negated[(0, 0)], Fraction::new(1, 2));
+            assert_eq!(negated[(0, 1)], Fraction::from(0));
+            assert_eq!(negated[(1, 0)], Fraction::from(5));
+            assert_eq!(negated[(1, 1)], Fraction::new(-7, 3));
+            assert_eq!(-(-foo.clone()), foo);
+            assert!((&foo + &negated).is_zero_matrix());
+        }
+        let bar = AugmentedMatrix::new_from_vec((2, 3), vec![1, -2, 3, 0, 4, -5], ColumnAligned)
+            .unwrap();
+        assert_eq!(-&bar, AugmentedMatrix::new_from_vec((2, 3), vec![-1, 2, -3, 0, -4, 5],
+            ColumnAligned).unwrap());
+        assert_eq!((-&bar).solution_column(), vec![-4, 5]);
+        assert_eq!(-(-bar.clone()), bar);
+    }
+
+    #[test]
+    fn hadamard_test() {
+        let mut a = Matrix::new_from_vec((2, 3), vec![1, -2, 3, 4, 5, -6], RowAligned).unwrap();
+        let mut b = Matrix::new_from_vec((2, 3), vec![7, 8, -9, 10, 11, 12], RowAligned).unwrap();
+        b.column_align();
+        for _ in 0..2 {
+            let product = a.hadamard(&b).unwrap();
+            let quotient = b.hadamard_div(&a).unwrap();
+            assert_eq!(product.alignment, a.alignment);
+            assert_eq!(quotient.alignment, b.alignment);
+            for r in 0..2 {
+                for c in 0..3 {
+                    assert_eq!(product[(r, c)], a[(r, c)] * b[(r, c)]);
+                    assert_eq!(quotient[(r, c)], b[(r, c)] / a[(r, c)]);
+                }
+            }
+            a.column_align();
+            b.row_align();
+        }
+        let c = Matrix::new_from_vec((2, 2), vec![Fraction::new(1, 2), Fraction::from(3),
+            Fraction::from(0), Fraction::new(-2, 3)], RowAligned).unwrap();
+        let d = Matrix::new_from_vec((2, 2), vec![Fraction::from(4), Fraction::new(1, 3),
+            Fraction::from(5), Fraction::from(0)], ColumnAligned).unwrap();
+        let quotient = c.hadamard_div(&d).unwrap();
+        assert_eq!(quotient[(0, 0)], Fraction::new(1, 8));
+        assert_eq!(quotient[(0, 1)], Fraction::new(3, 5));
+        assert!(quotient[(1, 1)].is_ud());
+        let err = a.hadamard(&Matrix::new_from_vec((3, 2), vec![0; 6], RowAligned).unwrap())
+            .unwrap_err();
+        assert_eq!(err.kind(), MatrixErrorKind::Function);
+        assert!(err.to_string().contains("(2, 3)"));
+        assert!(a.hadamard_div(&Matrix::splat(&1, (2, 2), RowAligned)).is_err());
+    }
+
+    #[test]
+    fn matrix_power_test() {
+        use matrices::base::Unit;
+        for column_aligned in [false, true] {
+            let mut fibonacci = Matrix::new_from_vec((2, 2), vec![1u64, 1, 1, 0], RowAligned)
+                .unwrap();
+            if column_aligned {
+                fibonacci.column_align();
+            }
+            let tenth = fibonacci.pow(10).unwrap();
+            assert_eq!(tenth, Matrix::new_from_vec((2, 2), vec![89, 55, 55, 34], RowAligned)
+                .unwrap());
+            assert_eq!(tenth.is_column_aligned(), column_aligned);
+            assert_eq!(fibonacci.pow(1).unwrap(), fibonacci);
+            assert_eq!(fibonacci.pow(7).unwrap(), &fibonacci.pow(3).unwrap()
+                * &fibonacci.pow(4).unwrap());
+            assert_eq!(fibonacci.pow(90).unwrap()[(0, 1)], 2_880_067_194_370_816_120);
+            let zeroth = fibonacci.pow(0).unwrap();
+            assert_eq!(zeroth, Matrix::unit(2));
+            assert_eq!(zeroth.is_column_aligned(), column_aligned);
+        }
+        let wide = Matrix::new_from_vec((2, 3), vec![1, 2, 3, 4, 5, 6], RowAligned).unwrap();
+        assert_eq!(wide.pow(2).unwrap_err().kind(), MatrixErrorKind::Function);
+        assert!(wide.pow(0).is_err());
+
+        let foo = Matrix::from_integer_slice((2, 2), &[2, 1, 1, 1], RowAligned).unwrap();
+        // The inverse is [1 -1; -1 2], whose square was worked out by hand
+        assert_eq!(foo.pow_i(-1).unwrap(), Matrix::from_integer_slice((2, 2), &[1, -1, -1, 2],
+            RowAligned).unwrap());
+        assert_eq!(foo.pow_i(-2).unwrap(), Matrix::from_integer_slice((2, 2), &[2, -3, -3, 5],
+            RowAligned).unwrap());
+        assert_eq!(&foo.pow_i(-3).unwrap() * &foo.pow_i(3).unwrap(), Matrix::unit(2));
+        assert_eq!(foo.pow_i(2).unwrap(), foo.pow(2).unwrap());
+        assert_eq!(foo.pow_i(0).unwrap(), Matrix::unit(2));
+        let singular = Matrix::from_integer_slice((2, 2), &[1, 2, 2, 4], RowAligned).unwrap();
+        assert_eq!(singular.pow_i(-1).unwrap_err().kind(), MatrixErrorKind::Transform);
+        let wide = Matrix::from_integer_slice((1, 2), &[1, 2], RowAligned).unwrap();
+        assert!(wide.pow_i(-1).is_err());
+    }
+
+    #[test]
+    fn vector_products_test() {
+        let row = Matrix::new_from_vec((1, 3), vec![1, -2, 3], RowAligned).unwrap();
+        let column = Matrix::new_from_vec((3, 1), vec![4, 5, 6], ColumnAligned).unwrap();
+        let other_column = Matrix::new_from_vec((3, 1), vec![4, 5, 6], RowAligned).unwrap();
+        // 4 - 10 + 18
+        assert_eq!(row.dot(&column).unwrap(), 12);
+        assert_eq!(column.dot(&row).unwrap(), 12);
+        assert_eq!(row.dot(&other_column).unwrap(), 12);
+        assert_eq!(column.dot(&other_column).unwrap(), 77);
+        let square = Matrix::new_from_vec((2, 2), vec![1, 2, 3, 4], RowAligned).unwrap();
+        assert_eq!(square.dot(&row).unwrap_err().kind(), MatrixErrorKind::Function);
+        assert!(row.dot(&square).is_err());
+        assert!(row.dot(&Matrix::new_from_vec((1, 2), vec![1, 2], RowAligned).unwrap()).is_err());
+
+        for column_aligned in [false, true] {
+            let mut foo = Matrix::new_from_vec((2, 3), vec![Fraction::new(1, 2), Fraction::from(2),
+                Fraction::from(0), Fraction::from(-1), Fraction::new(1, 3), Fraction::from(4)],
+                RowAligned).unwrap();
+            let mut system = AugmentedMatrix::new_from_vec((2, 4), vec![1, 2, 3, 14, 4, 5, 6, 32],
+                RowAligned).unwrap();
+            if column_aligned {
+                foo.column_align();
+                system.column_align();
+            }
+            let v = [Fraction::from(2), Fraction::new(1, 4), Fraction::new(-1, 2)];
+            // [1 + 1/2 + 0, -2 + 1/12 - 2]
+            assert_eq!(foo.mul_vec(&v).unwrap(), vec![Fraction::new(3, 2), Fraction::new(-47, 12)]);
+            assert!(foo.mul_vec(&v[..2]).is_err());
+            assert_eq!(system.mul_vec(&[1, 2, 3]).unwrap(), vec![14, 32]);
+            assert_eq!(system.residual(&[1, 2, 3]).unwrap(), vec![0, 0]);
+            // [3 + 2 - 14, 12 + 5 - 32]
+            assert_eq!(system.residual(&[3, 1, 0]).unwrap(), vec![-9, -15]);
+            assert!(system.residual(&[1, 2, 3, 4]).is_err());
+        }
+    }
+
+    #[test]
+    fn sums_test() {
+        for (foo, bar) in sample_matrices() {
+            let mut row_sums = vec![0; 3];
+            let mut column_sums = vec![0; 4];
+            let mut squares = 0;
+            for r in 0..3 {
+                for c in 0..4 {
+                    row_sums[r] += foo[(r, c)];
+                    column_sums[c] += foo[(r, c)];
+                    squares += foo[(r, c)] * foo[(r, c)];
+                }
+            }
+            assert_eq!(foo.row_sums(), row_sums);
+            assert_eq!(foo.column_sums(), column_sums);
+            assert_eq!(foo.sum(), 30);
+            assert_eq!(foo.frobenius_norm_squared(), squares);
+            // The solution column (1, 6, 8) is left out
+            assert_eq!(bar.row_sums(), vec![6, 6, 3]);
+            assert_eq!(bar.column_sums(), vec![-7, 11, 11]);
+            assert_eq!(bar.sum(), 15);
+            assert_eq!(bar.frobenius_norm_squared(), squares - 1 - 36 - 64);
+        }
+        let halves = Matrix::new_from_vec((2, 2), vec![Fraction::new(1, 2), Fraction::new(1, 3),
+            Fraction::new(-1, 6), Fraction::from(1)], ColumnAligned).unwrap();
+        assert_eq!(halves.row_sums(), vec![Fraction::new(1, 3), Fraction::new(4, 3)]);
+        assert_eq!(halves.column_sums(), vec![Fraction::new(5, 6), Fraction::new(5, 6)]);
+        assert_eq!(halves.frobenius_norm_squared(), Fraction::new(25, 18));
+        let empty: Matrix<i32> = Matrix::new_from_vec((0, 3), vec![], RowAligned).unwrap();
+        assert_eq!(empty.row_sums(), vec![]);
+        assert_eq!(empty.column_sums(), vec![0, 0, 0]);
+        assert_eq!(empty.sum(), 0);
+    }
+
+    #[test]
+    fn determinant_test() {
+        use matrices::base::Unit;
+        use matrices::determinant::Determinant;
+        assert_eq!(Matrix::<i64>::unit(4).determinant().unwrap(), 1);
+        assert_eq!(Matrix::<Fraction>::unit(3).determinant().unwrap(), Fraction::from(1));
+        for column_aligned in [false, true] {
+            // The third row is the sum of the first two
+            let mut singular = Matrix::new_from_vec((3, 3), vec![1, 2, 3, 4, 5, 6, 5, 7, 9],
+                RowAligned).unwrap();
+            // Needs a row swap after the first column has been eliminated
+            let mut foo = Matrix::new_from_vec((4, 4), vec![1i32, 2, 0, 3, 0, 0, 1, 2, 2, 1, 3,
+                0, 1, 0, 2, 1], RowAligned).unwrap();
+            let mut bar = Matrix::new_from_vec((3, 3), vec![Fraction::new(1, 2),
+                Fraction::new(2, 3), Fraction::from(1), Fraction::new(-1, 4), Fraction::from(0),
+                Fraction::new(3, 5), Fraction::from(2), Fraction::new(1, 3), Fraction::new(-1, 2)],
+                RowAligned).unwrap();
+            if column_aligned {
+                singular.column_align();
+                foo.column_align();
+                bar.column_align();
+            }
+            assert_eq!(singular.determinant().unwrap(), 0);
+            assert_eq!(singular.convert::<f64>().determinant().unwrap(), 0.0);
+            assert_eq!(foo.determinant().unwrap(), 6);
+            assert_eq!(foo.convert::<Fraction>().determinant().unwrap(), Fraction::from(6));
+            assert_eq!(bar.determinant().unwrap(), Fraction::new(8, 15));
+        }
+        // Swapping two rows flips the sign
+        let swapped = Matrix::new_from_vec((2, 2), vec![0i64, 1, 1, 0], RowAligned).unwrap();
+        assert_eq!(swapped.determinant().unwrap(), -1);
+        let wide = Matrix::new_from_vec((2, 3), vec![1i64; 6], RowAligned).unwrap();
+        assert_eq!(wide.determinant().unwrap_err().kind(), MatrixErrorKind::Function);
+    }
+
+    #[test]
+    fn determinant_display_test() {
+        use matrices::determinant::{Determinant, DeterminantDisplay};
+        for column_aligned in [false, true] {
+            let mut foo = Matrix::new_from_vec((3, 3), vec![0i64, 2, 1, 3, 1, 0, 1, 1, 1],
+                RowAligned).unwrap();
+            if column_aligned {
+                foo.column_align();
+            }
+            let (determinant, steps) = foo.determinant_display().unwrap();
+            assert_eq!(determinant, foo.determinant().unwrap());
+            assert_eq!(steps, vec![
+                "R0 ↔ R1 (sign: -)",
+                "((3) * R1 - (0) * R0) / (1) -> R1",
+                "((3) * R2 - (1) * R0) / (1) -> R2",
+                "((6) * R2 - (2) * R1) / (3) -> R2",
+                "det = -(4) = -4"
+            ]);
+            let bar = foo.convert::<Fraction>();
+            let (determinant, steps) = bar.determinant_display().unwrap();
+            assert_eq!(determinant, bar.determinant().unwrap());
+            assert_eq!(steps, vec![
+                "R0 ↔ R1 (sign: -)",
+                "R2 - (1 / 3) * R0 -> R2",
+                "R2 - (1 / 3) * R1 -> R2",
+                "det = -(3) * (2) * (2 / 3) = -4"
+            ]);
+        }
+        let singular = Matrix::new_from_vec((2, 2), vec![1.0, 2.0, 2.0, 4.0], RowAligned)
+            .unwrap();
+        let (determinant, steps) = singular.determinant_display().unwrap();
+        assert_eq!(determinant, 0.0);
+        assert_eq!(steps, vec!["R1 - (2) * R0 -> R1", "det = (1) * (0) = 0"]);
+        let single = Matrix::new_from_vec((1, 1), vec![7i32], RowAligned).unwrap();
+        assert_eq!(single.determinant_display().unwrap(), (7, Vec::new()));
+        assert!(Matrix::new_from_vec((1, 2), vec![1i32, 2], RowAligned).unwrap()
+            .determinant_display().is_err());
+    }
+
+    #[test]
+    fn rank_test() {
+        use matrices::base::Unit;
+        for column_aligned in [false, true] {
+            // The third row is twice the second minus the first
+            let mut deficient = Matrix::new_from_vec((3, 3), vec![2, 3, 5, 3, 4, 7, 4, 5, 9],
+                RowAligned).unwrap();
+            let mut wide = Matrix::new_from_vec((2, 4), vec![0, 0, 1, 2, 0, 0, 3, 7], RowAligned)
+                .unwrap();
+            let mut fractions = Matrix::new_from_vec((2, 2), vec![Fraction::new(1, 2),
+                Fraction::new(1, 3), Fraction::new(3, 4), Fraction::new(1, 2)], RowAligned)
+                .unwrap();
+            // x + y = 1, x - y = 3, 2x = 5
+            let mut inconsistent = AugmentedMatrix::new_from_vec((3, 3), vec![1, 1, 1, 1, -1, 3,
+                2, 0, 5], RowAligned).unwrap();
+            let mut consistent = AugmentedMatrix::new_from_vec((3, 3), vec![1, 1, 1, 1, -1, 3,
+                2, 0, 4], RowAligned).unwrap();
+            if column_aligned {
+                deficient.column_align();
+                wide.column_align();
+                fractions.column_align();
+                inconsistent.column_align();
+                consistent.column_align();
+            }
+            assert_eq!(deficient.rank(), 2);
+            assert_eq!(wide.rank(), 2);
+            assert_eq!(wide.transpose().rank(), 2);
+            assert_eq!(fractions.rank(), 1);
+            assert_eq!(inconsistent.rank(), 2);
+            assert_eq!(inconsistent.augmented_rank(), 3);
+            assert_eq!(consistent.rank(), consistent.augmented_rank());
+            // Untouched
+            assert_eq!(deficient[(2, 2)], 9);
+        }
+        assert_eq!(Matrix::new_from_vec((2, 2), vec![0; 4], RowAligned).unwrap().rank(), 0);
+        assert_eq!(Matrix::<Fraction>::unit(3).rank(), 3);
+        // Integer division would turn the first row into [1, 0] and miss that the rows are
+        // parallel
+        assert_eq!(Matrix::new_from_vec((2, 2), vec![2, 1, 4, 2], RowAligned).unwrap().rank(), 1);
+        let floats = Matrix::new_from_vec((1, 3), vec![0.5, 0.0, 2.0], RowAligned).unwrap();
+        assert_eq!(floats.rank(), 1);
+        // Unsigned elements, where 1 * 4 - 3 * 2 can't be stored, and wide integers, whose
+        // elements don't fit in a `Fraction`
+        let unsigned: Matrix<u64> = matrix![1 2; 3 4];
+        assert_eq!(unsigned.rank(), 2);
+        let unsigned: Matrix<u64> = matrix![2 4; 3 6];
+        assert_eq!(unsigned.rank(), 1);
+        let sizes = Matrix::new_from_vec((3, 3), vec![2usize, 3, 5, 3, 4, 7, 4, 5, 9],
+            ColumnAligned).unwrap();
+        assert_eq!(sizes.rank(), 2);
+        let wide = Matrix::new_from_vec((2, 2), vec![1i128 << 100, 1 << 101, 3, 6], RowAligned)
+            .unwrap();
+        assert_eq!(wide.rank(), 1);
+        let unsigned_system: AugmentedMatrix<u64> = augmented_matrix![
+            1 1 => 1;
+            2 2 => 3
+        ];
+        assert_eq!(unsigned_system.rank(), 1);
+        assert_eq!(unsigned_system.augmented_rank(), 2);
+    }
+
+    #[test]
+    fn adjugate_test() {
+        use matrices::base::Unit;
+        for column_aligned in [false, true] {
+            let mut foo = Matrix::new_from_vec((3, 3), vec![2i64, -1, 0, 1, 3, 2, 0, 1, 4],
+                RowAligned).unwrap();
+            if column_aligned {
+                foo.column_align();
+            }
+            // det = 2 * (12 - 2) + 1 * (4 - 0) = 24
+            let adjugate = foo.adjugate().unwrap();
+            assert_eq!(adjugate.is_column_aligned(), column_aligned);
+            assert_eq!(adjugate, Matrix::new_from_vec((3, 3), vec![10, 4, -2, -4, 8, -4, 1, -2,
+                7], RowAligned).unwrap());
+            assert_eq!(&foo * &adjugate, Matrix::<i64>::unit(3).map(|e| e * 24));
+            assert_eq!(&adjugate * &foo, Matrix::<i64>::unit(3).map(|e| e * 24));
+            let fractions = foo.convert::<Fraction>();
+            assert_eq!(fractions.adjugate().unwrap(), adjugate.convert::<Fraction>());
+        }
+        let single = Matrix::new_from_vec((1, 1), vec![5i32], RowAligned).unwrap();
+        assert_eq!(single.adjugate().unwrap()[(0, 0)], 1);
+        let wide = Matrix::new_from_vec((2, 3), vec![1i32; 6], RowAligned).unwrap();
+        assert_eq!(wide.adjugate().unwrap_err().kind(), MatrixErrorKind::Function);
+    }
+
+    #[test]
+    fn cross_product_test() {
+        let axis = |i: usize| {
+            let mut v = vec![Fraction::from(0); 3];
+            v[i] = Fraction::from(1);
+            Matrix::new_from_vec((3, 1), v, RowAligned).unwrap()
+        };
+        for i in 0..3 {
+            let (x, y, z) = (axis(i), axis((i + 1) % 3), axis((i + 2) % 3));
+            assert_eq!(x.cross(&y).unwrap(), z);
+            assert_eq!(y.cross(&x).unwrap(), z.map(|&e| -e));
+            assert_eq!(Matrix::triple_product(&x, &y, &z).unwrap(), Fraction::from(1));
+        }
+        for column_aligned in [false, true] {
+            let mut a = Matrix::new_from_vec((1, 3), vec![Fraction::new(1, 2), Fraction::from(-2),
+                Fraction::new(3, 4)], RowAligned).unwrap();
+            let mut b = Matrix::new_from_vec((1, 3), vec![Fraction::from(2), Fraction::new(1, 3),
+                Fraction::from(0)], RowAligned).unwrap();
+            if column_aligned {
+                a.column_align();
+                b.column_align();
+            }
+            let zero = Matrix::new_from_vec((1, 3), vec![Fraction::from(0); 3], RowAligned)
+                .unwrap();
+            assert_eq!(a.cross(&a).unwrap(), zero);
+            // (-2 * 0 - 3/4 * 1/3, 3/4 * 2 - 1/2 * 0, 1/2 * 1/3 + 2 * 2)
+            let c = a.cross(&b).unwrap();
+            assert_eq!(c.is_column_aligned(), column_aligned);
+            assert_eq!(c, Matrix::new_from_vec((1, 3), vec![Fraction::new(-1, 4),
+                Fraction::new(3, 2), Fraction::new(25, 6)], RowAligned).unwrap());
+            // Perpendicular to both
+            assert_eq!(a.dot(&c).unwrap(), Fraction::from(0));
+            assert_eq!(b.dot(&c).unwrap(), Fraction::from(0));
+            assert_eq!(Matrix::triple_product(&a, &a, &b).unwrap(), Fraction::from(0));
+            assert_eq!(a.cross(&b.transpose()).unwrap_err().kind(), MatrixErrorKind::Function);
+        }
+        let short = Matrix::new_from_vec((1, 2), vec![1, 2], RowAligned).unwrap();
+        assert!(short.cross(&short).is_err());
+    }
+
+    #[test]
+    fn matrix_division_test() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use matrices::base::Unit;
+        let fractions = |v: Vec<i64>| v.into_iter().map(Fraction::from).collect::<Vec<_>>();
+        for column_aligned in [false, true] {
+            let mut a = Matrix::new_from_vec((2, 3), fractions(vec![1, -2, 3, 4, 0, -1]),
+                RowAligned).unwrap();
+            let mut b = Matrix::new_from_vec((3, 3), fractions(vec![2, 1, 1, 1, 3, 2, 1, 0, 0]),
+                RowAligned).unwrap();
+            if column_aligned {
+                a.column_align();
+                b.column_align();
+            }
+            let product = &a * &b;
+            assert_eq!(product.try_div(&b).unwrap(), a);
+            assert_eq!(&product / &b, a);
+            assert_eq!(product.clone() / b.clone(), a);
+            let mut quotient = product.clone();
+            quotient /= &b;
+            assert_eq!(quotient, a);
+            assert_eq!(quotient.is_column_aligned(), column_aligned);
+            // b * b^-1 is the identity
+            let identity = &b / &b;
+            assert!(identity.is_unit());
+
+            let singular = Matrix::new_from_vec((3, 3), fractions(vec![1, 2, 3, 2, 4, 6, 0, 1, 1]),
+                RowAligned).unwrap();
+            assert_eq!(a.try_div(&singular).unwrap_err().kind(), MatrixErrorKind::Transform);
+            let small = Matrix::new_from_vec((2, 2), fractions(vec![1, 0, 0, 1]), RowAligned)
+                .unwrap();
+            assert_eq!(a.try_div(&small).unwrap_err().kind(), MatrixErrorKind::Function);
+            assert_eq!(a.try_div(&a).unwrap_err().kind(), MatrixErrorKind::Function);
+            assert!(catch_unwind(AssertUnwindSafe(|| &a / &small)).is_err());
+            assert!(catch_unwind(AssertUnwindSafe(|| &a / &singular)).is_err());
+        }
+    }
+
+    #[test]
+    fn diagonal_broadcast_test() {
+        use matrices::determinant::Determinant;
+        for column_aligned in [false, true] {
+            // Upper triangular, so the eigenvalues are 1/2 and -2/3
+            let mut foo = Matrix::new_from_vec((2, 2), vec![Fraction::new(1, 2), Fraction::from(5),
+                Fraction::from(0), Fraction::new(-2, 3)], RowAligned).unwrap();
+            let mut bar = AugmentedMatrix::new_from_vec((2, 3), vec![1, 2, 3, 4, 5, 6], RowAligned)
+                .unwrap();
+            if column_aligned {
+                foo.column_align();
+                bar.column_align();
+            }
+            for lambda in [Fraction::new(1, 2), Fraction::new(-2, 3)] {
+                let mut shifted = foo.clone();
+                shifted.sub_from_diagonal_in_place(lambda).unwrap();
+                assert_eq!(shifted.determinant().unwrap(), Fraction::from(0));
+                shifted.add_to_diagonal_in_place(lambda).unwrap();
+                assert_eq!(shifted, foo);
+                let by_value = foo.sub_from_diagonal(&lambda).unwrap();
+                assert_eq!(by_value.determinant().unwrap(), Fraction::from(0));
+            }
+            foo.add_to_diagonal_in_place(Fraction::new(1, 3)).unwrap();
+            assert_eq!(foo[(0, 0)], Fraction::new(5, 6));
+            assert_eq!(foo[(1, 1)], Fraction::new(-1, 3));
+            assert_eq!(foo[(0, 1)], Fraction::from(5));
+            assert_eq!(foo[(1, 0)], Fraction::from(0));
+            // The solution column isn't part of the diagonal
+            bar.add_to_diagonal_in_place(10).unwrap();
+            assert_eq!(bar, {
+                let mut expected = AugmentedMatrix::new_from_vec((2, 3), vec![11, 2, 3, 4, 15, 6],
+                    RowAligned).unwrap();
+                if column_aligned {
+                    expected.column_align();
+                }
+                expected
+            });
+            bar.add_scalar_in_place(1);
+            assert_eq!(bar[(1, 2)], 6);
+            assert_eq!(bar[(1, 1)], 16);
+            // The by-value versions leave the original alone
+            let lambda = Fraction::new(1, 3);
+            let shifted = foo.sub_from_diagonal(&lambda).unwrap();
+            assert_eq!(foo[(0, 0)], Fraction::new(5, 6));
+            assert_eq!(shifted[(0, 0)], Fraction::new(1, 2));
+            assert_eq!(shifted[(1, 1)], Fraction::new(-2, 3));
+            assert_eq!(shifted.get_alignment(), foo.get_alignment());
+            assert_eq!(shifted.add_to_diagonal(&lambda).unwrap(), foo);
+            let raised = foo.add_scalar(&lambda);
+            assert_eq!(raised[(0, 1)], Fraction::new(16, 3));
+            assert_eq!(raised[(1, 0)], lambda);
+            assert_eq!(raised.sub_scalar(&lambda), foo);
+            let bumped = bar.add_scalar(&1);
+            assert_eq!((bumped[(1, 1)], bumped[(1, 2)]), (17, 6));
+            assert_eq!(bar.add_to_diagonal(&1).unwrap()[(1, 1)], 17);
+        }
+        let mut wide = Matrix::new_from_vec((2, 3), vec![0; 6], RowAligned).unwrap();
+        assert_eq!(wide.sub_from_diagonal_in_place(1).unwrap_err().kind(),
+            MatrixErrorKind::Function);
+        assert_eq!(wide.sub_from_diagonal(&1).unwrap_err().kind(), MatrixErrorKind::Function);
+        assert!(wide.add_to_diagonal(&1).is_err());
+        assert_eq!(wide, Matrix::new_from_vec((2, 3), vec![0; 6], RowAligned).unwrap());
+    }
+
+    #[test]
+    fn elementwise_min_max_clamp_test() {
+        let values = vec![4, -1, 7, 0, 3, -8];
+        let others = vec![2, 2, 2, 0, 5, -9];
+        for column_aligned in [false, true] {
+            let foo = Matrix::new_from_vec((2, 3), values.clone(), RowAligned).unwrap();
+            let mut bar = Matrix::new_from_vec((2, 3), others.clone(), RowAligned).unwrap();
+            if column_aligned {
+                bar.column_align();
+            }
+            let min = foo.elementwise_min(&bar).unwrap();
+            let max = foo.elementwise_max(&bar).unwrap();
+            for r in 0..2 {
+                for c in 0..3 {
+                    assert_eq!(min[(r, c)], foo[(r, c)].min(bar[(r, c)]));
+                    assert_eq!(max[(r, c)], foo[(r, c)].max(bar[(r, c)]));
+                }
+            }
+            assert!(min.is_row_aligned());
+            assert_eq!(foo.clamp(&-1, &3), Matrix::new_from_vec((2, 3), vec![3, -1, 3, 0, 3, -1],
+                RowAligned).unwrap());
+            assert_eq!(bar.elementwise_min(&foo.transpose()).unwrap_err().kind(),
+                MatrixErrorKind::Function);
+        }
+        // An undefined fraction can't be compared, so the element of `self` is kept
+        let ud = Fraction::undefined();
+        let foo = Matrix::new_from_vec((1, 3), vec![Fraction::new(1, 2), ud, Fraction::new(-3, 4)],
+            RowAligned).unwrap();
+        let bar = Matrix::new_from_vec((1, 3), vec![ud, Fraction::from(0), Fraction::new(-2, 3)],
+            RowAligned).unwrap();
+        let min = foo.elementwise_min(&bar).unwrap();
+        assert_eq!(min[(0, 0)], Fraction::new(1, 2));
+        assert!(min[(0, 1)].is_ud());
+        assert_eq!(min[(0, 2)], Fraction::new(-3, 4));
+        let max = bar.elementwise_max(&foo).unwrap();
+        assert!(max[(0, 0)].is_ud());
+        assert_eq!(max[(0, 1)], Fraction::from(0));
+        assert_eq!(max[(0, 2)], Fraction::new(-2, 3));
+        let clamped = foo.clamp(&Fraction::from(0), &Fraction::new(1, 3));
+        assert_eq!(clamped[(0, 0)], Fraction::new(1, 3));
+        assert!(clamped[(0, 1)].is_ud());
+        assert_eq!(clamped[(0, 2)], Fraction::from(0));
+    }
+
+    #[test]
+    fn reductions_test() {
+        for (foo, bar) in sample_matrices() {
+            let rows = (0..3).map(|r| (0..4).map(|c| foo[(r, c)]).collect::<Vec<i64>>())
+                .collect::<Vec<_>>();
+            let columns = (0..4).map(|c| (0..3).map(|r| foo[(r, c)]).collect::<Vec<i64>>())
+                .collect::<Vec<_>>();
+            assert_eq!(foo.row_min(), rows.iter().map(|r| *r.iter().min().unwrap())
+                .collect::<Vec<_>>());
+            assert_eq!(foo.row_max(), rows.iter().map(|r| *r.iter().max().unwrap())
+                .collect::<Vec<_>>());
+            assert_eq!(foo.column_min(), columns.iter().map(|c| *c.iter().min().unwrap())
+                .collect::<Vec<_>>());
+            assert_eq!(foo.column_max(), columns.iter().map(|c| *c.iter().max().unwrap())
+                .collect::<Vec<_>>());
+            assert_eq!(foo.min(), Some(-5));
+            assert_eq!(foo.max(), Some(9));
+            assert_eq!(foo.product(), SAMPLE.iter().product::<i64>());
+            // The solution column (1, 6, 8) is left out
+            assert_eq!(bar.row_max(), vec![4, 9, 5]);
+            assert_eq!(bar.column_min(), vec![-5, -1, 2]);
+            assert_eq!(bar.max(), Some(9));
+            assert_eq!(bar.product(), SAMPLE.iter().product::<i64>() / 48);
+        }
+        // Undefined fractions can't be compared, so they're passed over
+        let ud = Fraction::undefined();
+        let fractions = Matrix::new_from_vec((2, 2), vec![ud, Fraction::new(1, 2),
+            Fraction::new(-1, 3), ud], RowAligned).unwrap();
+        assert_eq!(fractions.min(), Some(Fraction::new(-1, 3)));
+        assert_eq!(fractions.max(), Some(Fraction::new(1, 2)));
+        assert_eq!(fractions.row_max(), vec![Fraction::new(1, 2), Fraction::new(-1, 3)]);
+        assert_eq!(fractions.column_min(), vec![Fraction::new(-1, 3), Fraction::new(1, 2)]);
+        assert!(fractions.product().is_ud());
+        let undefined = Matrix::new_from_vec((1, 2), vec![ud, ud], RowAligned).unwrap();
+        assert!(undefined.max().unwrap().is_ud());
+        let empty: Matrix<i64> = Matrix::new_from_vec((0, 3), vec![], ColumnAligned).unwrap();
+        assert_eq!(empty.min(), None);
+        assert_eq!(empty.max(), None);
+        assert_eq!(empty.product(), 1);
+        assert_eq!(empty.row_max(), vec![]);
+        assert_eq!(empty.column_min(), vec![]);
+        let no_columns: Matrix<i64> = Matrix::new_from_vec((2, 0), vec![], RowAligned).unwrap();
+        assert_eq!(no_columns.row_min(), vec![]);
+    }
+
+    #[test]
+    fn means_test() {
+        for column_aligned in [false, true] {
+            let mut foo = Matrix::new_from_vec((2, 3), vec![Fraction::new(1, 2),
+                Fraction::new(1, 3), Fraction::from(1), Fraction::new(-2, 7), Fraction::from(0),
+                Fraction::new(5, 7)], RowAligned).unwrap();
+            let mut bar = AugmentedMatrix::new_from_vec((2, 3), vec![Fraction::from(1),
+                Fraction::from(2), Fraction::from(100), Fraction::from(4), Fraction::from(4),
+                Fraction::from(100)], RowAligned).unwrap();
+            if column_aligned {
+                foo.column_align();
+                bar.column_align();
+            }
+            // (1/2 + 1/3 + 1) / 3 and (-2/7 + 5/7) / 3
+            assert_eq!(foo.row_means(), vec![Fraction::new(11, 18), Fraction::new(1, 7)]);
+            assert_eq!(foo.column_means(), vec![Fraction::new(3, 28), Fraction::new(1, 6),
+                Fraction::new(6, 7)]);
+            // 1/2 + 1/3 + 1 + 3/7 = 95/42, over 6 elements
+            assert_eq!(foo.mean(), Some(Fraction::new(95, 252)));
+            // The solution column is left out
+            assert_eq!(bar.row_means(), vec![Fraction::new(3, 2), Fraction::from(4)]);
+            assert_eq!(bar.column_means(), vec![Fraction::new(5, 2), Fraction::from(3)]);
+            assert_eq!(bar.mean(), Some(Fraction::new(11, 4)));
+        }
+        // Integer means truncate like integer division
+        let ints = Matrix::new_from_vec((1, 3), vec![1, 2, 2], RowAligned).unwrap();
+        assert_eq!(ints.row_means(), vec![1]);
+        let empty: Matrix<Fraction> = Matrix::new_from_vec((0, 2), vec![], RowAligned).unwrap();
+        assert_eq!(empty.mean(), None);
+        assert_eq!(empty.row_means(), vec![]);
+        assert_eq!(empty.column_means(), vec![]);
+    }
+
+    #[test]
+    fn fold_reduce_test() {
+        for (foo, bar) in sample_matrices() {
+            let rows = foo.fold_rows(Vec::new(), |mut acc, row| {
+                acc.push(row.to_vec());
+                acc
+            });
+            assert_eq!(rows, vec![vec![3, -1, 4, 1], vec![-5, 9, 2, 6], vec![-5, 3, 5, 8]]);
+            let columns = foo.fold_columns(Vec::new(), |mut acc, column| {
+                acc.push(column.to_vec());
+                acc
+            });
+            assert_eq!(columns[1], vec![-1, 9, 3]);
+            assert_eq!(columns.len(), 4);
+            let sums = foo.fold_rows(Vec::new(), |mut acc, row| {
+                acc.push(row.iter().sum::<i64>());
+                acc
+            });
+            assert_eq!(sums, foo.row_sums());
+            assert_eq!(foo.reduce_rows(|a, b| a + b), foo.row_sums());
+            assert_eq!(foo.reduce_columns(|a, b| a * b), vec![75, -27, 40, 48]);
+            // The solution column is left out
+            assert_eq!(bar.fold_rows(0, |acc, row| acc + row.len()), 9);
+            assert_eq!(bar.fold_columns(Vec::new(), |mut acc, column| {
+                acc.push(column[2]);
+                acc
+            }), vec![-5, 3, 5]);
+            assert_eq!(bar.reduce_rows(|a, b| a - b), vec![0, -16, -13]);
+        }
+        let empty: Matrix<i64> = Matrix::new_from_vec((0, 2), vec![], ColumnAligned).unwrap();
+        assert_eq!(empty.fold_rows(0, |acc, _| acc + 1), 0);
+        assert_eq!(empty.fold_columns(0, |acc, column| acc + 1 + column.len()), 2);
+        assert_eq!(empty.reduce_columns(|a, b| a + b), vec![]);
+    }
+
+    #[test]
+    fn partial_pivoting_test() {
+        use matrices::base::Unit;
+        use matrices::transforms::{RREF, RREFDisplay, Inverse};
+        for column_aligned in [false, true] {
+            // Zero in the top left, so row 0 has to be swapped with row 1
+            let values = vec![Fraction::from(0), Fraction::from(1), Fraction::from(1),
+                Fraction::from(0)];
+            let mut foo = Matrix::new_from_vec((2, 2), values.clone(), RowAligned).unwrap();
+            let mut bar = Matrix::new_from_vec((2, 2), values, RowAligned).unwrap();
+            if column_aligned {
+                foo.column_align();
+                bar.column_align();
+            }
+            foo.gauss_jordan();
+            assert!(foo.is_gauss_jordan());
+            assert_eq!(foo, {
+                let mut unit = Matrix::<Fraction>::unit(2);
+                if column_aligned {
+                    unit.column_align();
+                }
+                unit
+            });
+            let steps = bar.clone().gauss_jordan_display().unwrap();
+            assert!(steps.contains(&"R0 ↔ R1".to_string()));
+            let original = bar.clone();
+            bar.try_inverse().unwrap();
+            assert_eq!(bar, original);
+            // 0x + 2y + z = 7, x + y + z = 6, 2x + y + 3z = 13 has the solution (1, 2, 3)
+            let values = vec![0, 2, 1, 7, 1, 1, 1, 6, 2, 1, 3, 13].into_iter()
+                .map(Fraction::from).collect::<Vec<Fraction>>();
+            let mut baz = AugmentedMatrix::new_from_vec((3, 4), values, RowAligned).unwrap();
+            if column_aligned {
+                baz.column_align();
+            }
+            baz.gauss_jordan();
+            assert!(baz.is_gauss_jordan());
+            assert_eq!(baz.solution_column(), vec![Fraction::from(1), Fraction::from(2),
+                Fraction::from(3)]);
+        }
+        // Singular matrices still can't be inverted
+        let mut singular = Matrix::new_from_vec((2, 2), vec![Fraction::from(0), Fraction::from(1),
+            Fraction::from(0), Fraction::from(2)], RowAligned).unwrap();
+        assert!(singular.try_inverse().is_err());
+    }
+
+    #[test]
+    fn row_op_swap_test() {
+        use matrices::transforms::RowOpSwap;
+        for column_aligned in [false, true] {
+            let mut foo = matrix![
+                1 2 3;
+                4 5 6;
+                7 8 9
+            ];
+            let mut bar = augmented_matrix![
+                1 2 => 3;
+                4 5 => 6;
+                7 8 => 9
+            ];
+            if column_aligned {
+                foo.column_align();
+                bar.column_align();
+            }
+            foo.row_op_swap(0, 2);
+            assert_eq!(foo.rows().map(|row| row.iter().cloned().collect()).collect::<Vec<Vec<_>>>(),
+                vec![vec![7, 8, 9], vec![4, 5, 6], vec![1, 2, 3]]);
+            foo.row_op_swap(1, 1);
+            assert_eq!(foo[(1, 0)], 4);
+            // Solutions travel with their rows
+            bar.row_op_swap(1, 0);
+            assert_eq!(bar[(0, 0)], 4);
+            assert_eq!(bar[(1, 1)], 2);
+            assert_eq!(bar.solution_column(), vec![6, 3, 9]);
+            assert_eq!(bar.is_column_aligned(), column_aligned);
+        }
+    }
+
+    #[test]
+    fn row_op_add_mul_test() {
+        use std::num::Wrapping;
+        use matrices::transforms::{RowOpAddMul, RowOpDiv, RowOpMul, RowOpSub, REF, RREF};
+        for column_aligned in [false, true] {
+            let mut foo = augmented_matrix![
+                1 2 => 3;
+                4 5 => 6
+            ];
+            if column_aligned {
+                foo.column_align();
+            }
+            foo.row_op_sub_mul(1, 0, 4);
+            assert_eq!(foo[(1, 0)], 0);
+            assert_eq!(foo[(1, 1)], -3);
+            assert_eq!(foo.solution_column(), vec![3, -6]);
+            foo.row_op_add_mul(0, 1, 2);
+            assert_eq!(foo[(0, 1)], -4);
+            assert_eq!(foo.solution_column(), vec![-9, -6]);
+            // Elimination results for fractions are as before
+            let values = vec![2, 1, -1, 8, -3, -1, 2, -11, -2, 1, 2, -3].into_iter()
+                .map(Fraction::from).collect::<Vec<Fraction>>();
+            let mut bar = AugmentedMatrix::new_from_vec((3, 4), values, RowAligned).unwrap();
+            if column_aligned {
+                bar.column_align();
+            }
+            bar.gauss_jordan();
+            assert_eq!(bar.solution_column(), vec![Fraction::from(2), Fraction::from(3),
+                Fraction::from(-1)]);
+        }
+        // The tool row is left exactly as it was, where multiplying it by 3 and dividing it by 3
+        // again wouldn't have
+        assert_ne!(0.1f64 * 3.0 / 3.0, 0.1);
+        let mut baz = Matrix::new_from_vec((2, 2), vec![1.0f64, 0.1, 3.0, 1.0], RowAligned)
+            .unwrap();
+        baz.gaussian_elim();
+        assert_eq!(baz[(0, 1)], 0.1);
+        assert_eq!(baz[(1, 0)], 0.0);
+        // Nor is a tool row whose multiple overflows: scaling [100, 1] by 2 in place wraps 200
+        // around to -56, and scaling back gives -28
+        let qux = Matrix::new_from_vec((2, 2), vec![100i8, 1, 127, 5], RowAligned).unwrap()
+            .map(|&e| Wrapping(e));
+        let mut scaled = qux.clone();
+        scaled.row_op_mul(0, Wrapping(2));
+        scaled.row_op_sub(1, 0);
+        scaled.row_op_div(0, Wrapping(2));
+        assert_eq!(scaled[(0, 0)], Wrapping(-28));
+        let mut qux = qux;
+        qux.row_op_sub_mul(1, 0, Wrapping(2));
+        assert_eq!(qux.iter().map(|e| e.0).collect::<Vec<i8>>(), vec![100, 1, -73, 3]);
+        // The same goes for elimination itself, which used to leave R0 of [[1, 100], [2, 1]] as
+        // [1, -28] after clearing R1
+        for column_aligned in [false, true] {
+            let mut quux = Matrix::new_from_vec((2, 2), vec![1i8, 100, 2, 1], RowAligned).unwrap()
+                .map(|&e| Wrapping(e));
+            if column_aligned {
+                quux.column_align();
+            }
+            quux.gaussian_elim();
+            assert_eq!((quux[(0, 0)].0, quux[(0, 1)].0), (1, 100));
+            assert_eq!(quux[(1, 0)].0, 0);
+        }
+    }
+
+    #[test]
+    fn non_square_elimination_test() {
+        use matrices::transforms::{REF, RREF};
+        let fractions = |v: Vec<i64>| v.into_iter().map(Fraction::from).collect::<Vec<Fraction>>();
+        let cases = vec![
+            // Wide, needing a swap
+            ((2, 4), vec![0, 2, 4, 6, 1, 1, 1, 1], vec![1, 1, 1, 1, 0, 1, 2, 3],
+                vec![1, 0, -1, -2, 0, 1, 2, 3]),
+            // Tall, with a zero row to skip over
+            ((4, 2), vec![0, 0, 2, 4, 1, 3, 3, 5], vec![1, 2, 0, 1, 0, 0, 0, 0],
+                vec![1, 0, 0, 1, 0, 0, 0, 0]),
+            // Square but rank-deficient, so column 2 has no pivot
+            ((3, 3), vec![1, 2, 3, 2, 4, 6, 1, 1, 1], vec![1, 2, 3, 0, 1, 2, 0, 0, 0],
+                vec![1, 0, -1, 0, 1, 2, 0, 0, 0])
+        ];
+        for (dimension, start, row_echelon, reduced) in cases {
+            for column_aligned in [false, true] {
+                let mut foo = Matrix::new_from_vec(dimension, fractions(start.clone()), RowAligned)
+                    .unwrap();
+                let mut ref_expected = Matrix::new_from_vec(dimension,
+                    fractions(row_echelon.clone()), RowAligned).unwrap();
+                let mut rref_expected = Matrix::new_from_vec(dimension,
+                    fractions(reduced.clone()), RowAligned).unwrap();
+                if column_aligned {
+                    foo.column_align();
+                    ref_expected.column_align();
+                    rref_expected.column_align();
+                }
+                assert!(!foo.is_row_reduced());
+                let mut bar = foo.clone();
+                foo.gaussian_elim();
+                assert!(foo.is_row_reduced());
+                assert_eq!(foo, ref_expected);
+                bar.gauss_jordan();
+                assert!(bar.is_gauss_jordan());
+                assert_eq!(bar, rref_expected);
+            }
+        }
+        // A 2x4 augmented system: x + y + z = 1, 2y + 4z = 6
+        let mut baz = AugmentedMatrix::new_from_vec((2, 4), fractions(vec![0, 2, 4, 6, 1, 1, 1, 1]),
+            RowAligned).unwrap();
+        baz.gauss_jordan();
+        assert!(baz.is_gauss_jordan());
+        assert_eq!(baz.solution_column(), vec![Fraction::from(-2), Fraction::from(3)]);
+        assert_eq!(baz[(0, 2)], Fraction::from(-1));
+        assert_eq!(baz[(1, 2)], Fraction::from(2));
+    }
+
+    #[test]
+    fn reduce_and_report_test() {
+        use matrices::transforms::{ReductionReport, RREF};
+        // x + 2y + z + w = 4, 2x + 4y + z + 3w = 7 and their sum: y and w are free
+        let values = vec![1, 2, 1, 1, 4, 2, 4, 1, 3, 7, 3, 6, 2, 4, 11].into_iter()
+            .map(Fraction::from).collect::<Vec<Fraction>>();
+        let expected = vec![1, 2, 0, 2, 3, 0, 0, 1, -1, 1, 0, 0, 0, 0, 0].into_iter()
+            .map(Fraction::from).collect::<Vec<Fraction>>();
+        for column_aligned in [false, true] {
+            let mut foo = AugmentedMatrix::new_from_vec((3, 5), values.clone(), RowAligned)
+                .unwrap();
+            let mut bar = AugmentedMatrix::new_from_vec((3, 5), expected.clone(), RowAligned)
+                .unwrap();
+            if column_aligned {
+                foo.column_align();
+                bar.column_align();
+            }
+            let mut baz = foo.clone();
+            let report = foo.reduce_and_report();
+            assert_eq!(report, ReductionReport {
+                pivot_columns: vec![0, 2],
+                free_columns: vec![1, 3],
+                rank: 2
+            });
+            assert!(foo.is_gauss_jordan());
+            // Row 0 had to be cleared above the pivot in column 2, not the diagonal in column 1
+            assert_eq!(foo, bar);
+            baz.gauss_jordan();
+            assert_eq!(baz, bar);
+            assert_eq!(baz.reduce_and_report(), report);
+        }
+        let mut full = Matrix::new_from_vec((2, 2), vec![Fraction::from(0), Fraction::from(3),
+            Fraction::from(2), Fraction::from(1)], RowAligned).unwrap();
+        assert_eq!(full.reduce_and_report(), ReductionReport {
+            pivot_columns: vec![0, 1],
+            free_columns: vec![],
+            rank: 2
+        });
+    }
+
+    #[test]
+    fn solve_test() {
+        use matrices::elimination::EliminationOptions;
+        use matrices::transforms::{RREF, Solve};
+        let fractions = |v: Vec<i64>| v.into_iter().map(Fraction::from).collect::<Vec<Fraction>>();
+        for column_aligned in [false, true] {
+            // 2x + y - z = 8, -3x - y + 2z = -11, -2x + y + 2z = -3
+            let mut foo = AugmentedMatrix::new_from_vec((3, 4),
+                fractions(vec![2, 1, -1, 8, -3, -1, 2, -11, -2, 1, 2, -3]), RowAligned).unwrap();
+            // x + y + z = 1, x - y = 2, 2x + z = 4
+            let mut bar = AugmentedMatrix::new_from_vec((3, 4),
+                fractions(vec![1, 1, 1, 1, 1, -1, 0, 2, 2, 0, 1, 4]), RowAligned).unwrap();
+            // x + y + z = 1, x - y = 2, 2x + z = 3
+            let mut baz = AugmentedMatrix::new_from_vec((3, 4),
+                fractions(vec![1, 1, 1, 1, 1, -1, 0, 2, 2, 0, 1, 3]), RowAligned).unwrap();
+            if column_aligned {
+                foo.column_align();
+                bar.column_align();
+                baz.column_align();
+            }
+            let original = foo.clone();
+            assert_eq!(foo.solve().unwrap(), fractions(vec![2, 3, -1]));
+            assert_eq!(foo, original);
+            assert_eq!(foo.solve_in_place().unwrap(), fractions(vec![2, 3, -1]));
+            assert!(foo.is_gauss_jordan());
+            let inconsistent = bar.solve().unwrap_err();
+            assert_eq!(inconsistent.kind(), MatrixErrorKind::Transform);
+            assert!(inconsistent.to_string().contains("inconsistent"), "{}", inconsistent);
+            // solve_exact() fails the same way
+            let exact = bar.solve_exact(EliminationOptions::default()).unwrap_err();
+            assert_eq!(exact.kind(), MatrixErrorKind::Transform);
+            assert_eq!(exact.to_string(), inconsistent.to_string());
+            let exact = baz.solve_exact(EliminationOptions { preorder: true }).unwrap_err();
+            let underdetermined = baz.solve_in_place().unwrap_err();
+            assert_eq!(exact.to_string(), underdetermined.to_string());
+            assert_eq!(underdetermined.kind(), MatrixErrorKind::Transform);
+            assert!(underdetermined.to_string().contains("underdetermined"), "{}",
+                underdetermined);
+            assert!(underdetermined.to_string().contains("[2]"), "{}", underdetermined);
+            // Left in RREF even without a unique solution
+            assert!(baz.is_gauss_jordan());
+        }
+        // Consistent extra equations are fine
+        let qux = AugmentedMatrix::new_from_vec((3, 3), fractions(vec![1, 1, 3, 1, -1, 1, 2, 2, 6]),
+            RowAligned).unwrap();
+        assert_eq!(qux.solve().unwrap(), fractions(vec![2, 1]));
     }
 }
\ No newline at end of file
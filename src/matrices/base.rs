@@ -3,11 +3,14 @@
 
 #![allow(dead_code)]
 
-use num::{Zero, One};
+use num::{Zero, One, FromPrimitive};
 
-use std::ops::{Index, IndexMut, Range};
+use std::ops::{Bound, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull,
+    RangeInclusive, RangeTo, RangeToInclusive};
+use std::convert::TryFrom;
+use std::error::Error;
 use std::fmt;
-use std::mem::swap;
+use std::mem::{self, swap};
 
 use fractions::base::Fraction;
 
@@ -23,6 +26,59 @@ pub enum Alignment {
     ColumnAligned
 }
 
+/// A row index reported by the library (in `MergeReport`, `ExactSolution`, ...).
+///
+/// The payload is always 0-based, like the `(row, column)` indices the matrices take. `Display`
+/// prints it 0-based by default and 1-based with the alternate flag (`{:#}`), so the numbers
+/// shown to users can follow either convention without the value in code becoming ambiguous.
+/// Methods that *take* row indices still take plain 0-based `usize`s.
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::matrices::base::RowIdx;
+/// let foo = RowIdx(2);
+/// assert_eq!(format!("R{}", foo), "R2");
+/// assert_eq!(format!("R{:#}", foo), "R3");
+/// assert_eq!(foo.to_one_based(), 3);
+/// assert_eq!(RowIdx::from_one_based(3), foo);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct RowIdx(pub usize);
+
+impl RowIdx {
+    /// Makes a `RowIdx` from a 1-based row number. Panics if `row` is 0.
+    /// # Panics
+    /// ```should_panic
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::RowIdx;
+    /// let foo = RowIdx::from_one_based(0);
+    /// ```
+    pub fn from_one_based(row: usize) -> RowIdx {
+        assert!(row != 0, "1-based row numbers start at 1.");
+        RowIdx(row - 1)
+    }
+
+    /// Gives the 0-based index - the same value as the payload.
+    pub fn to_zero_based(self) -> usize {
+        self.0
+    }
+
+    /// Gives the 1-based row number.
+    pub fn to_one_based(self) -> usize {
+        self.0 + 1
+    }
+}
+
+impl fmt::Display for RowIdx {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.to_one_based())
+        } else {
+            write!(f, "{}", self.to_zero_based())
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Matrix<T> {
     pub(crate) rows: usize,
@@ -31,6 +87,33 @@ pub struct Matrix<T> {
     pub(crate) alignment: Alignment
 }
 
+/// A system of linear equations: a coefficient matrix with a solution column attached.
+///
+/// The solution column is always the last *logical* column, i.e. `(r, num_columns())` for every
+/// row `r`. Changing the alignment (through [`row_align()`], [`column_align()`] or
+/// [`in_place_transpose()`]) only changes the storage order - it never changes which values make
+/// up the solution column, and `dimension()`/`num_columns()` stay the same.
+///
+/// `==` (and [`logically_equal()`]) compares the whole system, solution column included, and
+/// ignores the alignment; [`exactly_equal_including_alignment()`] also needs the alignments to
+/// match. Either way the other side has to be an augmented matrix too:
+/// ```compile_fail
+/// # #[macro_use] extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::matrices::base::{AugmentedMatrix, Matrix};
+/// let foo = augmented_matrix![
+///     1 2 => 3
+/// ];
+/// let bar = matrix![
+///     1 2 3
+/// ];
+/// foo.logically_equal(&bar);
+/// ```
+///
+/// [`logically_equal()`]: #method.logically_equal
+/// [`exactly_equal_including_alignment()`]: #method.exactly_equal_including_alignment
+/// [`row_align()`]: #method.row_align
+/// [`column_align()`]: #method.column_align
+/// [`in_place_transpose()`]: #method.in_place_transpose
 #[derive(Clone)]
 pub struct AugmentedMatrix<T> {
     pub(crate) rows: usize,
@@ -39,6 +122,91 @@ pub struct AugmentedMatrix<T> {
     pub(crate) alignment: Alignment
 }
 
+#[cold]
+#[inline(never)]
+fn index_out_of_bounds(index: (usize, usize), dimension: (usize, usize)) -> ! {
+    panic!("index {:?} out of bounds for {}x{} matrix", index, dimension.0, dimension.1)
+}
+
+// Position of `(row, column)` in the backing vec. Both parts are checked against the logical
+// dimension (which includes the solution column of augmented matrices) - checking the flat
+// position alone would let a too-large row of a column-aligned matrix read the next column.
+#[inline]
+fn flat_index(index: (usize, usize), rows: usize, columns: usize, alignment: &Alignment)
+    -> usize {
+    let (line, position) = match *alignment {
+        Alignment::RowAligned => (index.0, index.1),
+        Alignment::ColumnAligned => (index.1, index.0)
+    };
+    if line >= rows || position >= columns {
+        index_out_of_bounds(index, match *alignment {
+            Alignment::RowAligned => (rows, columns),
+            Alignment::ColumnAligned => (columns, rows)
+        });
+    }
+    line * columns + position
+}
+
+// The part of the backing vec taken up by the stored lines in `lines`. Panics if the range is
+// backwards or goes past the last of the `rows` stored lines.
+fn stored_lines<R: RangeBounds<usize>>(lines: R, rows: usize, columns: usize,
+    alignment: &Alignment) -> Range<usize> {
+    let start = match lines.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0
+    };
+    let end = match lines.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => rows
+    };
+    if start > end || end > rows {
+        let (line_kind, dimension) = match *alignment {
+            Alignment::RowAligned => ("rows", (rows, columns)),
+            Alignment::ColumnAligned => ("columns", (columns, rows))
+        };
+        panic!("line range {:?} out of bounds for the {} stored {} of a {}x{} matrix",
+            start..end, rows, line_kind, dimension.0, dimension.1);
+    }
+    start * columns..end * columns
+}
+
+// The stored `(rows, columns)` - the number of stored lines and their length - of a matrix with
+// the given logical `(rows, columns)` dimension.
+fn storage_dimension(dimension: (usize, usize), alignment: &Alignment) -> (usize, usize) {
+    match *alignment {
+        Alignment::RowAligned => dimension,
+        Alignment::ColumnAligned => (dimension.1, dimension.0)
+    }
+}
+
+// The stored `(rows, columns)` and backing vec of a matrix with the given logical dimension whose
+// element at `(r, c)` is `f(r, c)`. `f` is called once per element, in storage order.
+fn storage_from_fn<T, F: FnMut(usize, usize) -> T>(dimension: (usize, usize),
+    alignment: &Alignment, mut f: F) -> (usize, usize, Vec<T>) {
+    let (rows, columns) = dimension;
+    let mut matrix = Vec::with_capacity(rows * columns);
+    match *alignment {
+        Alignment::RowAligned => {
+            for r in 0..rows {
+                for c in 0..columns {
+                    matrix.push(f(r, c));
+                }
+            }
+            (rows, columns, matrix)
+        },
+        Alignment::ColumnAligned => {
+            for c in 0..columns {
+                for r in 0..rows {
+                    matrix.push(f(r, c));
+                }
+            }
+            (columns, rows, matrix)
+        }
+    }
+}
+
 macro_rules! matrix_index_methods {
     ($($target_type:ty) *) => ($(
         impl<T> Index<(usize, usize)> for $target_type {
@@ -46,10 +214,7 @@ macro_rules! matrix_index_methods {
 
             #[inline]
             fn index<'a>(&'a self, index: (usize, usize)) -> &'a T {
-                match self.alignment {
-                    Alignment::RowAligned => &self[index.0][index.1],
-                    Alignment::ColumnAligned => &self[index.1][index.0]
-                }
+                &self.matrix[flat_index(index, self.rows, self.columns, &self.alignment)]
             }
         }
 
@@ -61,21 +226,10 @@ macro_rules! matrix_index_methods {
             }
         }
 
-        impl<T> Index<Range<usize>> for $target_type {
-            type Output = [T];
-
-            fn index<'a>(&'a self, index: Range<usize>) -> &'a [T] {
-                &self.matrix.as_slice()[(index.start * self.columns)..(index.end * self.columns)]
-            }
-        }
-
         impl<T> IndexMut<(usize, usize)> for $target_type {
             #[inline]
             fn index_mut<'a>(&'a mut self, index: (usize, usize)) -> &'a mut T {
-                match self.alignment {
-                    Alignment::RowAligned => &mut self[index.0][index.1],
-                    Alignment::ColumnAligned => &mut self[index.1][index.0]
-                }
+                &mut self.matrix[flat_index(index, self.rows, self.columns, &self.alignment)]
             }
         }
 
@@ -84,21 +238,43 @@ macro_rules! matrix_index_methods {
                 &mut self.matrix.as_mut_slice()[(index * self.columns)..((index + 1) * self.columns)]
             }
         }
+    )*)
+}
+
+matrix_index_methods!{AugmentedMatrix<T> Matrix<T>}
+
+// Indexing with a range gets the block of whole stored lines (rows of row-aligned matrices,
+// columns of column-aligned ones) in the range, like indexing with a `usize` gets one of them.
+macro_rules! range_index_methods {
+    ($target_type:ty: $($range_type:ty),*) => ($(
+        impl<T> Index<$range_type> for $target_type {
+            type Output = [T];
 
-        impl<T> IndexMut<Range<usize>> for $target_type {
-            fn index_mut<'a>(&'a mut self, index: Range<usize>) -> &'a mut [T] {
-                &mut self.matrix.as_mut_slice()[(index.start * self.columns)..(index.end * self.columns)]
+            fn index(&self, index: $range_type) -> &[T] {
+                &self.matrix[stored_lines(index, self.rows, self.columns, &self.alignment)]
+            }
+        }
+
+        impl<T> IndexMut<$range_type> for $target_type {
+            fn index_mut(&mut self, index: $range_type) -> &mut [T] {
+                let lines = stored_lines(index, self.rows, self.columns, &self.alignment);
+                &mut self.matrix[lines]
             }
         }
     )*)
 }
 
-matrix_index_methods!{AugmentedMatrix<T> Matrix<T>}
+range_index_methods!{Matrix<T>: Range<usize>, RangeFrom<usize>, RangeTo<usize>, RangeFull,
+    RangeInclusive<usize>, RangeToInclusive<usize>}
+range_index_methods!{AugmentedMatrix<T>: Range<usize>, RangeFrom<usize>, RangeTo<usize>, RangeFull,
+    RangeInclusive<usize>, RangeToInclusive<usize>}
 
 macro_rules! matrix_base_impls {
     ($($target_type:ty, $name:ident, {
         $splat_doc_expr:expr,
         $new_doc_expr:expr,
+        $new_default_doc_expr:expr,
+        $with_capacity_doc_expr:expr,
         $new_from_vec_doc_expr:expr,
         $set_matrix_doc_expr:expr,
         $in_place_transpose_doc_expr:expr,
@@ -109,69 +285,66 @@ macro_rules! matrix_base_impls {
         $is_row_aligned_doc_expr:expr,
         $is_column_aligned_doc_expr:expr,
         $into_vec_doc_expr:expr,
-        $exactly_equal_doc_expr:expr
+        $exactly_equal_doc_expr:expr,
+        $logically_equal_doc_expr:expr
     });* ) => ($(
         impl<T: Clone> $target_type {
             #[doc = $splat_doc_expr]
             pub fn splat(value: &T, dimension: (usize, usize), alignment: Alignment) -> Self {
-                let matr = vec![value.clone(); dimension.0 * dimension.1];
+                let (rows, columns) = storage_dimension(dimension, &alignment);
                 $name {
-                    rows: dimension.0,
-                    columns: dimension.1,
-                    matrix: matr,
-                    alignment: alignment
+                    rows,
+                    columns,
+                    matrix: vec![value.clone(); rows * columns],
+                    alignment
                 }
             }
 
-            #[doc = $new_doc_expr]
-            pub fn new(dimension: (usize, usize), alignment: Alignment) -> Self {
-                let matr: Vec<T> = Vec::with_capacity(dimension.0 * dimension.1);
-                if alignment == Alignment::RowAligned {
-                    $name {
-                        rows: dimension.0,
-                        columns: dimension.1,
-                        matrix: matr,
-                        alignment: alignment
-                    }
-                } else {
-                    $name {
-                        rows: dimension.1,
-                        columns: dimension.0,
-                        matrix: matr,
-                        alignment: alignment
-                    }
+            #[doc = $with_capacity_doc_expr]
+            pub fn with_capacity(dimension: (usize, usize), alignment: Alignment) -> Self {
+                let (rows, columns) = storage_dimension(dimension, &alignment);
+                $name {
+                    rows,
+                    columns,
+                    matrix: Vec::with_capacity(rows * columns),
+                    alignment
                 }
             }
 
             #[doc = $new_from_vec_doc_expr]
             pub fn new_from_vec(dimension: (usize, usize), vec: Vec<T>, alignment: Alignment)
                 -> Result<$target_type, MatrixError> {
-                if vec.len() != dimension.0 * dimension.1 {
-                    return Err(MatrixError::InitError(format!("The supplied vec does not have the \
-                    same number of elements as the dimension specifies (len: {}, \
-                    supplied dimension: {:?}).", vec.len(), dimension)));
-                }
-                if alignment == Alignment::RowAligned {
-                    Ok($name {
-                        rows: dimension.0,
-                        columns: dimension.1,
-                        matrix: vec,
-                        alignment: alignment
-                    })
-                } else {
-                    Ok($name {
-                        rows: dimension.1,
-                        columns: dimension.0,
-                        matrix: vec,
-                        alignment: alignment
-                    })
-                }
+                Self::from_raw_parts(vec, dimension, alignment)
             }
 
             #[doc = $set_matrix_doc_expr]
             pub fn set_matrix(&mut self, vec: Vec<T>) {
-                assert!(vec.len() % self.rows == 0 && vec.len() % self.columns == 0);
+                if let Err(e) = self.try_set_matrix(vec) {
+                    panic!("{}", e);
+                }
+            }
+
+            /// Like `set_matrix()`, but returns an `InitError` with the expected and actual
+            /// lengths instead of panicking if the vec has the wrong number of elements.
+            pub fn try_set_matrix(&mut self, vec: Vec<T>) -> Result<(), MatrixError> {
+                if vec.len() != self.rows * self.columns {
+                    return Err(MatrixError::InitError(format!("Expected a vec of {} elements, \
+                    but got one of {}.", self.rows * self.columns, vec.len())));
+                }
                 self.matrix = vec;
+                Ok(())
+            }
+
+            /// Replaces the contents of the matrix with `vec` in the order given by `alignment`
+            /// (row-major or column-major), which becomes the alignment of the matrix. The
+            /// dimension stays the same. Panics if the vec has the wrong number of elements,
+            /// leaving the matrix as it was.
+            pub fn set_matrix_with_alignment(&mut self, vec: Vec<T>, alignment: Alignment) {
+                self.set_matrix(vec);
+                if alignment != self.alignment {
+                    swap(&mut self.rows, &mut self.columns);
+                    self.alignment = alignment;
+                }
             }
 
             #[doc = $in_place_transpose_doc_expr]
@@ -233,6 +406,20 @@ macro_rules! matrix_base_impls {
             }
         }
 
+        impl<T: Clone + Default> $target_type {
+            #[doc = $new_doc_expr]
+            pub fn new(dimension: (usize, usize), alignment: Alignment) -> Self {
+                Self::new_default(dimension, alignment)
+            }
+
+            #[doc = $new_default_doc_expr]
+            pub fn new_default(dimension: (usize, usize), alignment: Alignment) -> Self {
+                let mut res = Self::with_capacity(dimension, alignment);
+                res.matrix.resize(dimension.0 * dimension.1, T::default());
+                res
+            }
+        }
+
         impl<T> $target_type {
             #[doc = $get_alignment_doc_expr]
             pub fn get_alignment(&self) -> Alignment {
@@ -270,11 +457,71 @@ macro_rules! matrix_base_impls {
             pub fn into_vec(self) -> Vec<T> {
                 self.matrix
             }
+
+            /// Takes the matrix apart into its backing vec, in storage order (row-major for
+            /// row-aligned matrices, column-major for column-aligned ones), its dimension and its
+            /// alignment, without copying anything. The dimension is the one `new_from_vec` and
+            /// `from_raw_parts` take, so augmented matrices count their solution column.
+            pub fn into_inner(self) -> (Vec<T>, (usize, usize), Alignment) {
+                let dimension = match self.alignment {
+                    Alignment::RowAligned => (self.rows, self.columns),
+                    Alignment::ColumnAligned => (self.columns, self.rows)
+                };
+                (self.matrix, dimension, self.alignment)
+            }
+
+            /// Puts a matrix back together from the parts given by `into_inner`, taking
+            /// ownership of `vec` without copying it. Same as `new_from_vec`, so it returns an
+            /// `InitError` if the length of `vec` doesn't match the dimension.
+            pub fn from_raw_parts(vec: Vec<T>, dimension: (usize, usize), alignment: Alignment)
+                -> Result<$target_type, MatrixError> {
+                if vec.len() != dimension.0 * dimension.1 {
+                    return Err(MatrixError::InitError(format!("The supplied vec does not have the \
+                    same number of elements as the dimension specifies (len: {}, \
+                    supplied dimension: {:?}).", vec.len(), dimension)));
+                }
+                let (rows, columns) = storage_dimension(dimension, &alignment);
+                Ok($name {
+                    rows,
+                    columns,
+                    matrix: vec,
+                    alignment
+                })
+            }
+
+            /// Borrows the backing vec, in storage order (see `into_inner`).
+            pub fn as_slice(&self) -> &[T] {
+                &self.matrix
+            }
+
+            /// Borrows the backing vec mutably, in storage order (see `into_inner`).
+            pub fn as_mut_slice(&mut self) -> &mut [T] {
+                &mut self.matrix
+            }
         }
 
         impl<T: PartialEq> $target_type {
-            #[doc = $exactly_equal_doc_expr]
+            /// Old name for `exactly_equal_including_alignment`.
+            #[deprecated(note = "use `exactly_equal_including_alignment` (or `logically_equal` to \
+            ignore the alignment)")]
             pub fn exactly_equal_to(&self, other: &Self) -> bool {
+                self.exactly_equal_including_alignment(other)
+            }
+
+            #[doc = $logically_equal_doc_expr]
+            pub fn logically_equal(&self, other: &Self) -> bool {
+                if self.dimension() != other.dimension() {
+                    return false;
+                }
+                if self.alignment == other.alignment {
+                    return self.matrix == other.matrix;
+                }
+                // The stored rows of one are the stored columns of the other
+                (0..self.rows).all(|a| (0..self.columns).all(|b| self[a][b] == other[b][a]))
+            }
+
+            #[doc = $exactly_equal_doc_expr]
+            pub fn exactly_equal_including_alignment(&self, other: &Self) -> bool {
                 if self.alignment != other.alignment {
                     return false;
                 }
@@ -296,7 +543,8 @@ macro_rules! matrix_base_impls {
 
 matrix_base_impls!{AugmentedMatrix<T>, AugmentedMatrix,
 {
-    "Makes a new matrix where all values are the supplied value.
+    "Makes a new matrix of the given `(rows, columns)` dimension where all values are the
+    supplied value.
     # Example
     ```rust
     # extern crate fractions_and_matrices;
@@ -307,13 +555,30 @@ matrix_base_impls!{AugmentedMatrix<T>, AugmentedMatrix,
     bar.set_matrix(vec![2, 2, 2, 2]);
     assert_eq!(foo, bar);
     ```",
-    "Creates a new empty matrix. Its contents can be initialized with [`.set_matrix()`].
+    "Same as [`new_default()`].
+    [`new_default()`]: ../base/struct.AugmentedMatrix.html#method.new_default",
+    "Makes a new augmented matrix (the dimension includes the solution column) where every value
+    is `T::default()`.
+    # Example
+    ```rust
+    # #[macro_use] extern crate fractions_and_matrices;
+    # use fractions_and_matrices::matrices::base::{AugmentedMatrix, Alignment::ColumnAligned};
+    let mut foo = AugmentedMatrix::new_default((2, 3), ColumnAligned);
+    foo[(1, 2)] = 5;
+    assert_eq!(foo, augmented_matrix![
+        0 0 => 0;
+        0 0 => 5
+    ]);
+    ```",
+    "Makes a new augmented matrix of the given dimension with room for its elements but no
+    elements yet. It isn't valid until its contents are set with [`set_matrix()`]: indexing it or
+    calling almost any other method before that panics.
     # Example
     ```rust
     # #[macro_use] extern crate fractions_and_matrices;
     # use fractions_and_matrices::matrices::base::{AugmentedMatrix, Alignment::{RowAligned,
     #    ColumnAligned}};
-    let mut foo = AugmentedMatrix::new((3, 3), ColumnAligned);
+    let mut foo = AugmentedMatrix::with_capacity((3, 3), ColumnAligned);
     foo.set_matrix(vec![0, 3, 6, 1, 4, 7, 2, 5, 8]);
     let bar = augmented_matrix![
         0 1 => 2;
@@ -322,10 +587,12 @@ matrix_base_impls!{AugmentedMatrix<T>, AugmentedMatrix,
     ];
     assert_eq!(foo, bar);
     ```
-    [`set_matrix()`]: ../base/struct.Matrix.html#method.set_matrix",
+    [`set_matrix()`]: ../base/struct.AugmentedMatrix.html#method.set_matrix",
     "Makes a new `AugmentedMatrix<T>` from a supplied `Vec<T>` and `(usize, usize)` designating the
     dimension. The product of the two tuple elements and the length of the `Vec<T>` must be
-    equal to get an `Ok(AugmentedMatrix<T>)`.
+    equal to get an `Ok(AugmentedMatrix<T>)`. The dimension is always the logical `(rows, columns)`,
+    whatever the alignment: the alignment only says whether the vec is in row-major or
+    column-major order.
     # Examples
     ```rust
     # extern crate fractions_and_matrices;
@@ -341,8 +608,9 @@ matrix_base_impls!{AugmentedMatrix<T>, AugmentedMatrix,
     let aug_matr_res = AugmentedMatrix::new_from_vec((3, 4), aug_matr_vec, RowAligned);
     assert!(aug_matr_res.is_err());
     ```",
-    "Sets the contents of a currently existing `AugmentedMatrix<T>` to a provided `Vec<T>`. The
-    provided vector must have the same number of elements as are in the matrix before modification.
+    "Sets the contents of a currently existing `AugmentedMatrix<T>` to a provided `Vec<T>`, in the
+    order of its current alignment. The provided vector must have exactly as many elements as the
+    dimension (including the solution column) specifies, or this panics.
     # Example
     ```rust
     # #[macro_use] extern crate fractions_and_matrices;
@@ -360,24 +628,26 @@ matrix_base_impls!{AugmentedMatrix<T>, AugmentedMatrix,
     ```should_panic
     # extern crate fractions_and_matrices;
     # use fractions_and_matrices::matrices::base::{AugmentedMatrix, Alignment::RowAligned};
-    let mut foo = AugmentedMatrix::new((3, 2), RowAligned);
-    foo.set_matrix(vec![0, 1, 2, 3]);
+    let mut foo = AugmentedMatrix::new((2, 2), RowAligned);
+    foo.set_matrix(vec![0, 1, 2, 3, 4, 5]);
     ```",
     "Swaps the alignment of an augmented matrix (row-aligned => column-aligned and vice versa).
+    Only the storage order changes: the solution column stays the last logical column.
     # Example
     ```rust
     # #[macro_use] extern crate fractions_and_matrices;
     # use fractions_and_matrices::matrices::base::{AugmentedMatrix,
     #    Alignment::{RowAligned, ColumnAligned}};
     let mut foo = augmented_matrix![
-        0 1 => 2;
-        3 4 => 5;
-        6 7 => 8
+        0 1 2 3 => 4;
+        5 6 7 8 => 9
     ];
     foo.in_place_transpose();
-    let bar = AugmentedMatrix::new_from_vec((3, 3), vec![0, 3, 6, 1, 4, 7, 2, 5, 8], ColumnAligned)
-        .unwrap();
-    assert_eq!(foo, bar);
+    let bar = AugmentedMatrix::new_from_vec((2, 5), vec![0, 5, 1, 6, 2, 7, 3, 8, 4, 9],
+        ColumnAligned).unwrap();
+    assert!(foo.exactly_equal_including_alignment(&bar));
+    assert_eq!(foo.dimension(), (2, 4));
+    assert_eq!(foo.solution_column(), vec![4, 9]);
     ```",
     "Row-aligns an augmented matrix. If it is already row-aligned, then nothing happens.
     # Example
@@ -395,7 +665,7 @@ matrix_base_impls!{AugmentedMatrix<T>, AugmentedMatrix,
         20 21 22 23 => 24
     ];
     foo.row_align();
-    assert!(foo.exactly_equal_to(&bar));
+    assert!(foo.exactly_equal_including_alignment(&bar));
     ```",
     "Column-aligns an augmented matrix. If it is already column-aligned, then nothing happens.
     # Example
@@ -413,7 +683,7 @@ matrix_base_impls!{AugmentedMatrix<T>, AugmentedMatrix,
     let bar = AugmentedMatrix::new_from_vec((5, 5), vec![0, 5, 10, 15, 20, 1, 6, 11, 16, 21, 2, 7,
         12, 17, 22, 3, 8, 13, 18, 23, 4, 9, 14, 19, 24], ColumnAligned).unwrap();
     foo.column_align();
-    assert!(foo.exactly_equal_to(&bar));
+    assert!(foo.exactly_equal_including_alignment(&bar));
     ```",
     "Gets the alignment of a given augmented matrix.
     # Example
@@ -453,7 +723,10 @@ matrix_base_impls!{AugmentedMatrix<T>, AugmentedMatrix,
     let bar = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
     assert_eq!(foo.into_vec(), bar);
     ```",
-    "Tests whether a matrix is exactly equal to another, taking alignment into account.
+    "Tests whether an augmented matrix is exactly equal to another, taking alignment into
+    account: the same dimension, the same alignment and the same elements, solution column
+    included. Only augmented matrices can be compared, since comparing one with a plain matrix
+    would ignore what the last column means.
     # Example
     ```rust
     # #[macro_use] extern crate fractions_and_matrices;
@@ -465,14 +738,35 @@ matrix_base_impls!{AugmentedMatrix<T>, AugmentedMatrix,
         10 11 12 13 => 14
     ];
     let mut bar = foo.clone();
-    assert!(foo.exactly_equal_to(&bar));
+    assert!(foo.exactly_equal_including_alignment(&bar));
     bar.column_align();
-    assert!(!foo.exactly_equal_to(&bar));
+    assert!(!foo.exactly_equal_including_alignment(&bar));
+    ```",
+    "Tests whether an augmented matrix holds the same system as another: the same dimension and
+    the same elements, solution column included, whatever the alignment. This is what `==` uses.
+    # Example
+    ```rust
+    # #[macro_use] extern crate fractions_and_matrices;
+    # use fractions_and_matrices::matrices::base::{AugmentedMatrix,
+    #    Alignment::{RowAligned, ColumnAligned}};
+    let foo = augmented_matrix![
+        1 2 => 3;
+        4 5 => 6
+    ];
+    let mut bar = foo.clone();
+    bar.column_align();
+    assert!(foo.logically_equal(&bar));
+    let baz = augmented_matrix![
+        1 2 => 3;
+        4 5 => 7
+    ];
+    assert!(!foo.logically_equal(&baz));
     ```"
 };
 Matrix<T>, Matrix,
 {
-    "Makes a new matrix where all values are the supplied value.
+    "Makes a new matrix of the given `(rows, columns)` dimension where all values are the
+    supplied value.
     # Example
     ```rust
     # extern crate fractions_and_matrices;
@@ -483,12 +777,28 @@ Matrix<T>, Matrix,
     bar.set_matrix(vec![2, 2, 2, 2]);
     assert_eq!(foo, bar);
     ```",
-    "Creates a new empty matrix. Its contents can be initialized with [`set_matrix()`].
+    "Same as [`new_default()`].
+    [`new_default()`]: ../base/struct.Matrix.html#method.new_default",
+    "Makes a new matrix where every value is `T::default()`.
+    # Example
+    ```rust
+    # #[macro_use] extern crate fractions_and_matrices;
+    # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+    let mut foo = Matrix::new_default((2, 3), RowAligned);
+    foo[(1, 2)] = 5;
+    assert_eq!(foo, matrix![
+        0 0 0;
+        0 0 5
+    ]);
+    ```",
+    "Makes a new matrix of the given dimension with room for its elements but no elements yet. It
+    isn't valid until its contents are set with [`set_matrix()`]: indexing it or calling almost
+    any other method before that panics.
     # Example
     ```rust
     # #[macro_use] extern crate fractions_and_matrices;
     # use fractions_and_matrices::matrices::base::{Matrix, Alignment::{RowAligned, ColumnAligned}};
-    let mut foo = Matrix::new((3, 2), ColumnAligned);
+    let mut foo = Matrix::with_capacity((3, 2), ColumnAligned);
     foo.set_matrix(vec![0, 2, 4, 1, 3, 5]);
     let bar = matrix![
         0 1;
@@ -500,7 +810,9 @@ Matrix<T>, Matrix,
     [`set_matrix()`]: ../base/struct.Matrix.html#method.set_matrix",
     "Makes a new `Matrix<T>` from a supplied `Vec<T>` and `(usize, usize)` designating the
     dimension. The product of the two tuple elements and the length of the `Vec<T>` must be
-    equal to get an `Ok(Matrix<T>)`.
+    equal to get an `Ok(Matrix<T>)`. The dimension is always the logical `(rows, columns)`,
+    whatever the alignment: the alignment only says whether the vec is in row-major or
+    column-major order.
     # Examples
     ```rust
     # extern crate fractions_and_matrices;
@@ -515,9 +827,20 @@ Matrix<T>, Matrix,
     let matr_vec = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
     let matr_res = Matrix::new_from_vec((3, 4), matr_vec, RowAligned);
     assert!(matr_res.is_err());
+    ```
+    ```rust
+    # #[macro_use] extern crate fractions_and_matrices;
+    # use fractions_and_matrices::matrices::base::{Matrix, Alignment::ColumnAligned};
+    let foo = Matrix::new_from_vec((2, 3), vec![1, 4, 2, 5, 3, 6], ColumnAligned).unwrap();
+    assert_eq!(foo.dimension(), (2, 3));
+    assert_eq!(foo, matrix![
+        1 2 3;
+        4 5 6
+    ]);
     ```",
-    "Sets the contents of a currently existing `Matrix<T>` to a provided `Vec<T>`. The provided
-    vector must have the same number of elements as are in the matrix before modification.
+    "Sets the contents of a currently existing `Matrix<T>` to a provided `Vec<T>`, in the order of
+    its current alignment. The provided vector must have exactly as many elements as the dimension
+    specifies, or this panics.
     # Example
     ```rust
     # #[macro_use] extern crate fractions_and_matrices;
@@ -535,10 +858,13 @@ Matrix<T>, Matrix,
     ```should_panic
     # extern crate fractions_and_matrices;
     # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
-    let mut foo = Matrix::new((3, 2), RowAligned);
-    foo.set_matrix(vec![0, 1, 2, 3]);
+    let mut foo = Matrix::new((2, 2), RowAligned);
+    foo.set_matrix(vec![0, 1, 2, 3, 4, 5]);
     ```",
-    "Swaps the alignment of a matrix (row-aligned => column-aligned and vice versa).
+    "Swaps the alignment of a matrix (row-aligned => column-aligned and vice versa). Despite the
+    name, the logical matrix doesn't change: the dimension stays the same and `(r, c)` still gets
+    the same element, only the storage order is transposed. Use [`transpose()`] or
+    [`transposed()`] for the mathematical transpose.
     # Example
     ```rust
     # #[macro_use] extern crate fractions_and_matrices;
@@ -552,7 +878,9 @@ Matrix<T>, Matrix,
     let bar = Matrix::new_from_vec((3, 3), vec![0, 3, 6, 1, 4, 7, 2, 5, 8],
         ColumnAligned).unwrap();
     assert_eq!(foo, bar);
-    ```",
+    ```
+    [`transpose()`]: ../base/struct.Matrix.html#method.transpose
+    [`transposed()`]: ../base/struct.Matrix.html#method.transposed",
     "Row-aligns a matrix. If a matrix is already row-aligned, then nothing happens.
     # Example
     ```rust
@@ -568,7 +896,7 @@ Matrix<T>, Matrix,
         20 21 22 23 24
     ];
     foo.row_align();
-    assert!(foo.exactly_equal_to(&bar));
+    assert!(foo.exactly_equal_including_alignment(&bar));
     ```",
     "Column-aligns a matrix. If a matrix is already column-aligned, then nothing happens.
     # Example
@@ -585,7 +913,7 @@ Matrix<T>, Matrix,
     let bar = Matrix::new_from_vec((5, 5), vec![0, 5, 10, 15, 20, 1, 6, 11, 16, 21, 2, 7, 12, 17,
         22, 3, 8, 13, 18, 23, 4, 9, 14, 19, 24], ColumnAligned).unwrap();
     foo.column_align();
-    assert!(foo.exactly_equal_to(&bar));
+    assert!(foo.exactly_equal_including_alignment(&bar));
     ```",
     "Gets the alignment of a given matrix.
     # Example
@@ -634,13 +962,156 @@ Matrix<T>, Matrix,
         10 11 12 13 14
     ];
     let mut bar = foo.clone();
-    assert!(foo.exactly_equal_to(&bar));
+    assert!(foo.exactly_equal_including_alignment(&bar));
     bar.column_align();
-    assert!(!foo.exactly_equal_to(&bar));
+    assert!(!foo.exactly_equal_including_alignment(&bar));
+    ```",
+    "Tests whether a matrix has the same dimension and elements as another, whatever the
+    alignment. This is what `==` uses.
+    # Example
+    ```rust
+    # #[macro_use] extern crate fractions_and_matrices;
+    # use fractions_and_matrices::matrices::base::{Matrix, Alignment::{RowAligned, ColumnAligned}};
+    let foo = matrix![
+        0 1 2;
+        3 4 5
+    ];
+    let mut bar = foo.clone();
+    bar.column_align();
+    assert!(foo.logically_equal(&bar));
+    assert!(!foo.exactly_equal_including_alignment(&bar));
     ```"
 }}
 
 impl<T> Matrix<T> {
+    /// Makes a new matrix of the given dimension whose element at `(r, c)` is `f(r, c)`, without
+    /// building a vec first. `f` is called exactly once per element, in storage order: row by row
+    /// (left to right within each row) if `alignment` is `RowAligned`, and column by column (top
+    /// to bottom within each column) if it's `ColumnAligned`.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+    /// let hilbert = Matrix::from_fn((4, 4), RowAligned, |i, j| {
+    ///     Fraction::new(1, (i + j + 1) as i64)
+    /// });
+    /// assert_eq!(hilbert[(0, 0)], Fraction::from(1));
+    /// assert_eq!(hilbert[(1, 2)], Fraction::new(1, 4));
+    /// assert_eq!(hilbert[(3, 3)], Fraction::new(1, 7));
+    /// ```
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(dimension: (usize, usize), alignment: Alignment,
+        f: F) -> Self {
+        let (rows, columns, matrix) = storage_from_fn(dimension, &alignment, f);
+        Matrix { rows, columns, matrix, alignment }
+    }
+
+    /// Consumes the matrix and returns its transpose: an `n` x `m` matrix for an `m` x `n` one,
+    /// with `(j, i)` of the result being `(i, j)` of the original. This is O(1): the backing vec
+    /// is reused as it is and only the alignment flips, so the transpose of a row-aligned matrix is
+    /// column-aligned and vice versa. Realign it afterwards if that matters. Unlike
+    /// `in_place_transpose()`, which only transposes the storage order, this changes the logical
+    /// matrix. See `transpose()` for a copy that keeps the original.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// let foo = matrix![
+    ///     1 2 3;
+    ///     4 5 6
+    /// ];
+    /// let bar = foo.transposed();
+    /// assert_eq!(bar.dimension(), (3, 2));
+    /// assert!(bar.is_column_aligned());
+    /// assert_eq!(bar, matrix![
+    ///     1 4;
+    ///     2 5;
+    ///     3 6
+    /// ]);
+    /// ```
+    pub fn transposed(self) -> Matrix<T> {
+        // The `rows` stored lines of `columns` elements are read the other way round.
+        let alignment = match self.alignment {
+            Alignment::RowAligned => Alignment::ColumnAligned,
+            Alignment::ColumnAligned => Alignment::RowAligned
+        };
+        Matrix { alignment, ..self }
+    }
+
+    /// Reads the elements in row-major order (left to right, top to bottom) into a matrix of
+    /// dimension `new_dim`, so a 2x6 matrix becomes a 3x4 one with the first four elements of its
+    /// first row as its first row. The result is row-aligned: a row-aligned matrix reuses its
+    /// vec as it is, and a column-aligned one is realigned first. Returns an error if `new_dim`
+    /// doesn't have the same number of elements.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// let foo = matrix![
+    ///     1 2 3 4 5 6;
+    ///     7 8 9 10 11 12
+    /// ];
+    /// assert_eq!(foo.clone().reshape((3, 4)).unwrap(), matrix![
+    ///     1 2 3 4;
+    ///     5 6 7 8;
+    ///     9 10 11 12
+    /// ]);
+    /// assert!(foo.reshape((5, 2)).is_err());
+    /// ```
+    pub fn reshape(self, new_dim: (usize, usize)) -> Result<Matrix<T>, MatrixError> {
+        if new_dim.0 * new_dim.1 != self.matrix.len() {
+            return Err(MatrixError::FunctionError(format!("Can't reshape a {:?} matrix into a \
+            {:?} matrix: the numbers of elements differ.", self.dimension(), new_dim)));
+        }
+        let matrix = match self.alignment {
+            Alignment::RowAligned => self.matrix,
+            Alignment::ColumnAligned => self.into_iter().collect()
+        };
+        Ok(Matrix {
+            rows: new_dim.0,
+            columns: new_dim.1,
+            matrix,
+            alignment: Alignment::RowAligned
+        })
+    }
+
+    /// Reshapes the matrix into a single row (see `reshape()`).
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// let foo = matrix![
+    ///     1 2;
+    ///     3 4
+    /// ];
+    /// assert_eq!(foo.into_row_vector(), matrix![1 2 3 4]);
+    /// ```
+    pub fn into_row_vector(self) -> Matrix<T> {
+        let len = self.matrix.len();
+        self.reshape((1, len)).unwrap()
+    }
+
+    /// Reshapes the matrix into a single column (see `reshape()`).
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// let foo = matrix![
+    ///     1 2;
+    ///     3 4
+    /// ];
+    /// assert_eq!(foo.into_column_vector(), matrix![
+    ///     1;
+    ///     2;
+    ///     3;
+    ///     4
+    /// ]);
+    /// ```
+    pub fn into_column_vector(self) -> Matrix<T> {
+        let len = self.matrix.len();
+        self.reshape((len, 1)).unwrap()
+    }
+
     /// Gets the dimension of a given matrix as a `(usize, usize)` tuple. The result is the same
     /// regardless of alignment.
     /// # Example
@@ -683,7 +1154,182 @@ impl<T> Matrix<T> {
     }
 }
 
+impl<T: Zero> Matrix<T> {
+    /// Checks whether every element is zero. Stops at the first one that isn't, reading the
+    /// elements in storage order.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::ColumnAligned};
+    /// let foo = Matrix::splat(&0, (3, 2), ColumnAligned);
+    /// assert!(foo.is_zero_matrix());
+    /// let bar = matrix![
+    ///     0 0;
+    ///     0 1
+    /// ];
+    /// assert!(!bar.is_zero_matrix());
+    /// ```
+    pub fn is_zero_matrix(&self) -> bool {
+        self.matrix.iter().all(Zero::is_zero)
+    }
+
+    /// Checks whether the matrix is square and every element below the diagonal is zero.
+    /// Non-square matrices aren't upper triangular.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::ColumnAligned};
+    /// let foo = matrix![
+    ///     1 2 3;
+    ///     0 4 5;
+    ///     0 0 6
+    /// ];
+    /// assert!(foo.is_upper_triangular());
+    /// assert!(!foo.is_lower_triangular());
+    /// let bar = Matrix::new_from_vec((3, 3), vec![1, 2, 3, 0, 4, 5, 0, 0, 6], ColumnAligned)
+    ///     .unwrap();
+    /// assert!(bar.is_lower_triangular());
+    /// assert!(!Matrix::splat(&0, (2, 3), ColumnAligned).is_upper_triangular());
+    /// ```
+    pub fn is_upper_triangular(&self) -> bool {
+        self.rows == self.columns && self.stored_triangle_is_zero(true)
+    }
+
+    /// Checks whether the matrix is square and every element above the diagonal is zero.
+    /// Non-square matrices aren't lower triangular.
+    pub fn is_lower_triangular(&self) -> bool {
+        self.rows == self.columns && self.stored_triangle_is_zero(false)
+    }
+
+    /// Checks whether the matrix is square and every element off the diagonal is zero.
+    /// Non-square matrices aren't diagonal.
+    pub fn is_diagonal(&self) -> bool {
+        self.rows == self.columns && self.stored_triangle_is_zero(true)
+            && self.stored_triangle_is_zero(false)
+    }
+
+    // Checks the logical lower (`lower`) or upper triangle of a square matrix in storage order.
+    // Stored rows have the lower triangle before the diagonal, stored columns after it.
+    fn stored_triangle_is_zero(&self, lower: bool) -> bool {
+        if self.columns == 0 {
+            return true;
+        }
+        let before = lower == self.is_row_aligned();
+        self.matrix.chunks(self.columns)
+            .enumerate()
+            .all(|(i, line)| {
+                let triangle = if before { &line[..i] } else { &line[i + 1..] };
+                triangle.iter().all(Zero::is_zero)
+            })
+    }
+}
+
+impl<T: PartialEq> Matrix<T> {
+    /// Checks whether the matrix is square and equal to its transpose. Non-square matrices
+    /// aren't symmetric.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// let foo = matrix![
+    ///     1 2 3;
+    ///     2 4 5;
+    ///     3 5 6
+    /// ];
+    /// assert!(foo.is_symmetric());
+    /// assert!(!matrix![1 2; 3 4].is_symmetric());
+    /// assert!(!matrix![1 2 3; 2 4 5].is_symmetric());
+    /// ```
+    pub fn is_symmetric(&self) -> bool {
+        // Transposing doesn't change this, so the alignment doesn't matter
+        let n = self.columns;
+        self.rows == n && (0..n).all(|i| (i + 1..n).all(|j| self.matrix[i * n + j]
+            == self.matrix[j * n + i]))
+    }
+}
+
+macro_rules! zeros_ones_impls {
+    ($($target_type:ident),*) => ($(
+        impl<T: Zero> $target_type<T> {
+            /// Makes a new row-aligned matrix of the given dimension filled with zeros. For
+            /// augmented matrices the dimension includes the solution column, as in
+            /// `new_from_vec`, and the solutions are zeros too.
+            pub fn zeros(dimension: (usize, usize)) -> Self {
+                $target_type::zeros_aligned(dimension, Alignment::RowAligned)
+            }
+
+            /// Like `zeros()`, but with the given alignment.
+            pub fn zeros_aligned(dimension: (usize, usize), alignment: Alignment) -> Self {
+                $target_type::from_fn(dimension, alignment, |_, _| T::zero())
+            }
+        }
+
+        impl<T: One> $target_type<T> {
+            /// Makes a new row-aligned matrix of the given dimension filled with ones. For
+            /// augmented matrices the dimension includes the solution column, as in
+            /// `new_from_vec`, and the solutions are ones too.
+            pub fn ones(dimension: (usize, usize)) -> Self {
+                $target_type::ones_aligned(dimension, Alignment::RowAligned)
+            }
+
+            /// Like `ones()`, but with the given alignment.
+            pub fn ones_aligned(dimension: (usize, usize), alignment: Alignment) -> Self {
+                $target_type::from_fn(dimension, alignment, |_, _| T::one())
+            }
+        }
+    )*)
+}
+
+zeros_ones_impls!{Matrix, AugmentedMatrix}
+
+impl<T: Clone + Zero> Matrix<T> {
+    /// Makes a row-aligned square matrix with `diag` on its main diagonal and zeros everywhere
+    /// else.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// let foo = Matrix::from_diagonal(&[1, 2, 3]);
+    /// assert_eq!(foo, matrix![
+    ///     1 0 0;
+    ///     0 2 0;
+    ///     0 0 3
+    /// ]);
+    /// assert_eq!(foo.diagonal(), vec![1, 2, 3]);
+    /// ```
+    pub fn from_diagonal(diag: &[T]) -> Matrix<T> {
+        let n = diag.len();
+        let mut res = Matrix::zeros((n, n));
+        res.set_diagonal(diag);
+        res
+    }
+}
+
 impl<T> AugmentedMatrix<T> {
+    /// Makes a new augmented matrix whose element at `(r, c)` is `f(r, c)`, without building a vec
+    /// first. Like `new_from_vec`, the dimension includes the solution column, so `f` fills the
+    /// solution column too: it's called with `c == dimension.1 - 1` for the solution of row `r`.
+    /// `f` is called exactly once per element, in storage order: row by row if `alignment` is
+    /// `RowAligned`, and column by column if it's `ColumnAligned`.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::{AugmentedMatrix, Alignment::ColumnAligned};
+    /// // The Vandermonde system for the polynomial through (1, 1), (2, 4) and (3, 9)
+    /// let foo = AugmentedMatrix::from_fn((3, 4), ColumnAligned, |r, c| {
+    ///     let x = r as i64 + 1;
+    ///     if c == 3 { x * x } else { x.pow(c as u32) }
+    /// });
+    /// assert_eq!(foo.dimension(), (3, 3));
+    /// assert_eq!(foo[(2, 1)], 3);
+    /// assert_eq!(foo.solution_column(), vec![1, 4, 9]);
+    /// ```
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(dimension: (usize, usize), alignment: Alignment,
+        f: F) -> Self {
+        let (rows, columns, matrix) = storage_from_fn(dimension, &alignment, f);
+        AugmentedMatrix { rows, columns, matrix, alignment }
+    }
+
     /// Gets the dimension of a given `AugmentedMatrix<T>` as a `(usize, usize)` tuple. NB: the
     /// returned dimension does not include the solution column.
     /// # Example
@@ -725,7 +1371,299 @@ impl<T> AugmentedMatrix<T> {
     }
 }
 
+// Swaps stored lines `a` and `b` (each `stride` elements long) as whole slices.
+fn swap_lines<T>(matrix: &mut [T], stride: usize, a: usize, b: usize) {
+    let (a, b) = (a.min(b), a.max(b));
+    let (head, tail) = matrix.split_at_mut(b * stride);
+    head[a * stride..(a + 1) * stride].swap_with_slice(&mut tail[..stride]);
+}
+
+// Swaps positions `a` and `b` within every stored line.
+fn swap_within_lines<T>(matrix: &mut [T], stride: usize, a: usize, b: usize) {
+    for line in matrix.chunks_mut(stride) {
+        line.swap(a, b);
+    }
+}
+
+// Rebuilds a backing vec of `lines` stored lines of `within` elements as one of `new.0` lines of
+// `new.1` elements in a single pass, keeping the overlapping lines and the start of each of them
+// and filling the rest with `fill`. With `pinned_line` the last old line stays the last line, and
+// with `pinned_element` the last element of each old line stays the last element of its line -
+// that's how the solution column of an augmented matrix stays last.
+fn resize_lines<T: Clone>(vec: Vec<T>, (lines, within): (usize, usize), new: (usize, usize),
+    fill: &T, pinned_line: bool, pinned_element: bool) -> Vec<T> {
+    let (pinned_lines, pinned_elements) = (pinned_line as usize, pinned_element as usize);
+    let kept_lines = (lines - pinned_lines).min(new.0 - pinned_lines);
+    let kept = (within - pinned_elements).min(new.1 - pinned_elements);
+    let mut res = Vec::with_capacity(new.0 * new.1);
+    let mut old = vec.into_iter();
+    for _ in 0..kept_lines {
+        resize_line(&mut res, old.by_ref().take(within), kept, new.1, fill, pinned_element);
+    }
+    old.by_ref().take((lines - pinned_lines - kept_lines) * within).for_each(drop);
+    let filled = res.len() + (new.0 - pinned_lines - kept_lines) * new.1;
+    res.resize(filled, fill.clone());
+    if pinned_line {
+        resize_line(&mut res, old, kept, new.1, fill, false);
+    }
+    res
+}
+
+// Pushes the first `kept` elements of `line`, then `fill` up to `len` elements (one short of that
+// if `pinned_element`, to end with the last element of `line`).
+fn resize_line<T: Clone, I: Iterator<Item = T>>(res: &mut Vec<T>, mut line: I, kept: usize,
+    len: usize, fill: &T, pinned_element: bool) {
+    res.extend(line.by_ref().take(kept));
+    let last = line.last();
+    let filled = res.len() + len - kept - pinned_element as usize;
+    res.resize(filled, fill.clone());
+    if pinned_element {
+        res.extend(last);
+    }
+}
+
+macro_rules! swap_impls {
+    ($($target_type:ty),*) => ($(
+        impl<T> $target_type {
+            /// Swaps rows `a` and `b` (including their solutions, for augmented matrices). Rows
+            /// of row-aligned matrices are swapped as whole slices; otherwise the two elements
+            /// in each stored column are swapped. Panics if either row is out of bounds.
+            pub fn swap_rows(&mut self, a: usize, b: usize) {
+                let rows = self.num_rows();
+                assert!(a < rows && b < rows, "row index {} out of bounds for matrix with {} \
+                rows", a.max(b), rows);
+                if a == b {
+                    return;
+                }
+                let stride = self.columns;
+                match self.alignment {
+                    Alignment::RowAligned => swap_lines(&mut self.matrix, stride, a, b),
+                    Alignment::ColumnAligned => swap_within_lines(&mut self.matrix, stride, a, b)
+                }
+            }
+
+            /// Swaps columns `a` and `b`. Columns of column-aligned matrices are swapped as whole
+            /// slices; otherwise the two elements in each stored row are swapped. Panics if
+            /// either column is out of bounds - for augmented matrices that includes the
+            /// solution column, which can't be swapped with a coefficient column.
+            pub fn swap_columns(&mut self, a: usize, b: usize) {
+                let columns = self.num_columns();
+                assert!(a < columns && b < columns, "column index {} out of bounds for matrix \
+                with {} columns", a.max(b), columns);
+                if a == b {
+                    return;
+                }
+                let stride = self.columns;
+                match self.alignment {
+                    Alignment::RowAligned => swap_within_lines(&mut self.matrix, stride, a, b),
+                    Alignment::ColumnAligned => swap_lines(&mut self.matrix, stride, a, b)
+                }
+            }
+        }
+    )*)
+}
+
+swap_impls!{Matrix<T>, AugmentedMatrix<T>}
+
+impl<T: PartialEq> AugmentedMatrix<T> {
+    /// Checks whether the coefficient matrix (everything but the solution column) is logically
+    /// equal to `m`, whatever the alignments. This is the intended way to compare an augmented
+    /// matrix with a plain one.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, AugmentedMatrix};
+    /// let foo = augmented_matrix![
+    ///     1 2 => 5;
+    ///     3 4 => 6
+    /// ];
+    /// let mut bar = matrix![
+    ///     1 2;
+    ///     3 4
+    /// ];
+    /// bar.column_align();
+    /// assert!(foo.coefficients_equal(&bar));
+    /// assert!(!foo.coefficients_equal(&matrix![1 2]));
+    /// ```
+    pub fn coefficients_equal(&self, m: &Matrix<T>) -> bool {
+        let (rows, columns) = self.dimension();
+        if m.dimension() != (rows, columns) {
+            return false;
+        } else if columns == 0 {
+            return true;
+        }
+        match (&self.alignment, &m.alignment) {
+            // The solutions are the last stored column, after the coefficients
+            (&Alignment::ColumnAligned, &Alignment::ColumnAligned) => {
+                self.matrix[..m.matrix.len()] == m.matrix[..]
+            },
+            // Each stored row ends with its solution
+            (&Alignment::RowAligned, &Alignment::RowAligned) => self.matrix.chunks(self.columns)
+                .zip(m.matrix.chunks(columns))
+                .all(|(a, b)| a[..columns] == *b),
+            _ => (0..rows).all(|r| (0..columns).all(|c| self[(r, c)] == m[(r, c)]))
+        }
+    }
+}
+
+// The number of elements in each of `rows`, or an `InitError` if there are no rows, they're empty
+// or they're ragged (naming the first row whose length differs from the first one's).
+fn nested_row_len<T>(rows: &[Vec<T>]) -> Result<usize, MatrixError> {
+    let len = match rows.first() {
+        Some(first) if !first.is_empty() => first.len(),
+        Some(_) => return Err(MatrixError::InitError("Row 0 is empty.".to_string())),
+        None => return Err(MatrixError::InitError("There are no rows.".to_string()))
+    };
+    match rows.iter().position(|row| row.len() != len) {
+        Some(r) => Err(MatrixError::InitError(format!("Row {} has {} elements, but row 0 has {}.",
+            r, rows[r].len(), len))),
+        None => Ok(len)
+    }
+}
+
+/// Builds a row-aligned matrix from its rows, moving the elements out of them. Returns an
+/// `InitError` if there are no rows, if the rows are empty, or if they don't all have the same
+/// length (naming the first row that doesn't).
+/// # Example
+/// ```rust
+/// # #[macro_use] extern crate fractions_and_matrices;
+/// # use std::convert::TryFrom;
+/// # use fractions_and_matrices::matrices::base::Matrix;
+/// let foo = Matrix::try_from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+/// assert_eq!(foo, matrix![
+///     1 2;
+///     3 4
+/// ]);
+/// assert_eq!(foo.to_nested_vec(), vec![vec![1, 2], vec![3, 4]]);
+/// assert!(Matrix::try_from(vec![vec![1, 2], vec![3]]).is_err());
+/// ```
+impl<T> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = MatrixError;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Matrix<T>, MatrixError> {
+        let columns = nested_row_len(&rows)?;
+        Ok(Matrix {
+            rows: rows.len(),
+            columns,
+            matrix: rows.into_iter().flatten().collect(),
+            alignment: Alignment::RowAligned
+        })
+    }
+}
+
+impl<T> AugmentedMatrix<T> {
+    /// Builds a row-aligned augmented matrix from the rows of its coefficient matrix and its
+    /// solution column, moving the elements out of them. Returns an `InitError` in the same cases
+    /// as `Matrix::try_from` does, or if there isn't one solution per row.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::AugmentedMatrix;
+    /// let foo = AugmentedMatrix::try_from_parts(vec![vec![1, 2], vec![3, 4]], vec![5, 6])
+    ///     .unwrap();
+    /// assert_eq!(foo, augmented_matrix![
+    ///     1 2 => 5;
+    ///     3 4 => 6
+    /// ]);
+    /// assert!(AugmentedMatrix::try_from_parts(vec![vec![1, 2]], vec![5, 6]).is_err());
+    /// ```
+    pub fn try_from_parts(coefficients: Vec<Vec<T>>, solutions: Vec<T>)
+        -> Result<AugmentedMatrix<T>, MatrixError> {
+        let columns = nested_row_len(&coefficients)?;
+        if solutions.len() != coefficients.len() {
+            return Err(MatrixError::InitError(format!("There are {} rows but {} solutions.",
+                coefficients.len(), solutions.len())));
+        }
+        let rows = coefficients.len();
+        let mut matrix = Vec::with_capacity(rows * (columns + 1));
+        for (row, solution) in coefficients.into_iter().zip(solutions) {
+            matrix.extend(row);
+            matrix.push(solution);
+        }
+        Ok(AugmentedMatrix {
+            rows,
+            columns: columns + 1,
+            matrix,
+            alignment: Alignment::RowAligned
+        })
+    }
+}
+
+macro_rules! from_rows_impls {
+    ($($target_type:ident, $min_len:expr, $too_short_msg:expr, $from_rows_doc_expr:expr);*) => ($(
+        impl<T: Clone> $target_type<T> {
+            #[doc = $from_rows_doc_expr]
+            pub fn from_rows<I, R>(rows: I) -> Result<$target_type<T>, MatrixError>
+                where I: IntoIterator<Item = R>, R: AsRef<[T]> {
+                let mut rows = rows.into_iter();
+                let first = match rows.next() {
+                    Some(first) => first,
+                    None => return Err(MatrixError::InitError("There are no rows.".to_string()))
+                };
+                let columns = first.as_ref().len();
+                if columns < $min_len {
+                    return Err(MatrixError::InitError($too_short_msg.to_string()));
+                }
+                let mut matrix = first.as_ref().to_vec();
+                for (r, row) in rows.enumerate() {
+                    let row = row.as_ref();
+                    if row.len() != columns {
+                        return Err(MatrixError::InitError(format!("Row {} has {} elements, but \
+                        row 0 has {}.", r + 1, row.len(), columns)));
+                    }
+                    matrix.extend_from_slice(row);
+                }
+                Ok($target_type {
+                    rows: matrix.len() / columns,
+                    columns,
+                    matrix,
+                    alignment: Alignment::RowAligned
+                })
+            }
+        }
+    )*)
+}
+
+from_rows_impls!{
+    Matrix, 1, "Row 0 is empty.",
+    "Builds a row-aligned matrix by copying the rows yielded by `rows`, which can be anything
+    that can be borrowed as a slice. Returns an `InitError` if there are no rows, if the rows are
+    empty, or if they don't all have the same length (naming the first row that doesn't).
+    # Example
+    ```rust
+    # #[macro_use] extern crate fractions_and_matrices;
+    # use fractions_and_matrices::matrices::base::Matrix;
+    let input = \"1 2 3\\n4 5 6\";
+    let foo = Matrix::from_rows(input.lines()
+        .map(|line| line.split(' ').map(|n| n.parse().unwrap()).collect::<Vec<i32>>()))
+        .unwrap();
+    assert_eq!(foo, matrix![
+        1 2 3;
+        4 5 6
+    ]);
+    assert!(Matrix::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+    assert!(Matrix::<i32>::from_rows(Vec::<Vec<i32>>::new()).is_err());
+    ```";
+    AugmentedMatrix, 2, "Row 0 needs at least one coefficient and a solution.",
+    "Builds a row-aligned augmented matrix by copying the rows yielded by `rows`, which can be
+    anything that can be borrowed as a slice. The last element of each row is its solution.
+    Returns an `InitError` if there are no rows, if the rows don't have at least two elements, or
+    if they don't all have the same length (naming the first row that doesn't).
+    # Example
+    ```rust
+    # #[macro_use] extern crate fractions_and_matrices;
+    # use fractions_and_matrices::matrices::base::AugmentedMatrix;
+    let foo = AugmentedMatrix::from_rows(&[[1, 2, 3], [4, 5, 6]]).unwrap();
+    assert_eq!(foo, augmented_matrix![
+        1 2 => 3;
+        4 5 => 6
+    ]);
+    assert!(AugmentedMatrix::from_rows(&[[1], [2]]).is_err());
+    ```"
+}
+
 /// Used to specify general types of errors in matrices.
+#[derive(Debug)]
 pub enum MatrixError {
     /// Returned when an attempt to create a `Matrix<T>` or `AugmentedMatrix<T>` fails.
     InitError(String),
@@ -736,12 +1674,32 @@ pub enum MatrixError {
     FunctionError(String)
 }
 
-impl fmt::Debug for MatrixError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            &MatrixError::InitError(ref e) => write!(f, "Initialization error: {}", e),
-            &MatrixError::TransformError(ref e) => write!(f, "Row/Matrix operation error: {}", e),
-            &MatrixError::FunctionError(ref e) => write!(f, "Function error: {}", e)
+/// The category of a `MatrixError`, for matching on without looking at the message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum MatrixErrorKind {
+    /// See `MatrixError::InitError`.
+    Init,
+    /// See `MatrixError::TransformError`.
+    Transform,
+    /// See `MatrixError::FunctionError`.
+    Function
+}
+
+impl MatrixError {
+    /// Gets the category of the error.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, MatrixErrorKind,
+    /// #     Alignment::RowAligned};
+    /// let foo = Matrix::new_from_vec((2, 2), vec![1, 2, 3], RowAligned);
+    /// assert_eq!(foo.unwrap_err().kind(), MatrixErrorKind::Init);
+    /// ```
+    pub fn kind(&self) -> MatrixErrorKind {
+        match *self {
+            MatrixError::InitError(_) => MatrixErrorKind::Init,
+            MatrixError::TransformError(_) => MatrixErrorKind::Transform,
+            MatrixError::FunctionError(_) => MatrixErrorKind::Function
         }
     }
 }
@@ -756,6 +1714,71 @@ impl fmt::Display for MatrixError {
     }
 }
 
+impl Error for MatrixError {}
+
+// Checks the leading `n`x`n` block of a matrix's backing vec (`stride` elements per stored row or
+// column) in storage order, stopping at the first violation: the diagonal has to be ones, the
+// logical lower triangle zeroes and, if `upper`, the logical upper triangle zeroes too. Used by
+// `is_unit` so that column-aligned matrices aren't read across the grain. The caller makes sure
+// there are at least `n` stored lines of `n` elements.
+pub(crate) fn leading_block_is_triangular_unit<T: PartialEq + Zero + One>(matrix: &[T],
+    stride: usize, alignment: &Alignment, n: usize, upper: bool) -> bool {
+    if n == 0 {
+        return true;
+    }
+    // Stored rows have the lower triangle before the diagonal, stored columns after it
+    let (check_before, check_after) = match *alignment {
+        Alignment::RowAligned => (true, upper),
+        Alignment::ColumnAligned => (upper, true)
+    };
+    for (i, line) in matrix.chunks(stride).take(n).enumerate() {
+        if check_before && !line[..i].iter().all(Zero::is_zero) {
+            return false;
+        }
+        if !line[i].is_one() {
+            return false;
+        }
+        if check_after && !line[i + 1..n].iter().all(Zero::is_zero) {
+            return false;
+        }
+    }
+    true
+}
+
+// Checks whether the first `columns` logical columns of a matrix with `rows` logical rows are in
+// row echelon form: every non-zero row starts with a one strictly to the right of the leading one
+// of the row above, and zero rows come last. With `reduced`, the leading ones also have to be the
+// only non-zero elements of their columns. Goes column by column, since a column is either a
+// pivot column (a one in the next pivot row, zeros below it) or has only zeros below the pivot
+// rows so far, which keeps column-aligned matrices from being read across the grain.
+pub(crate) fn is_echelon<T: PartialEq + Zero + One>(matrix: &[T], stride: usize,
+    alignment: &Alignment, rows: usize, columns: usize, reduced: bool) -> bool {
+    let get = |r: usize, c: usize| match *alignment {
+        Alignment::RowAligned => &matrix[r * stride + c],
+        Alignment::ColumnAligned => &matrix[c * stride + r]
+    };
+    let mut pivots = 0;
+    for c in 0..columns {
+        if pivots == rows {
+            break;
+        }
+        if get(pivots, c).is_zero() {
+            if !(pivots + 1..rows).all(|r| get(r, c).is_zero()) {
+                return false;
+            }
+            continue;
+        }
+        if !get(pivots, c).is_one() || !(pivots + 1..rows).all(|r| get(r, c).is_zero()) {
+            return false;
+        }
+        if reduced && !(0..pivots).all(|r| get(r, c).is_zero()) {
+            return false;
+        }
+        pivots += 1;
+    }
+    true
+}
+
 /// Used for conveniently testing whether a matrix/augmented matrix is a unit or creating a unit
 /// `Matrix<T>`/`AugmentedMatrix<T>`.
 pub trait Unit {
@@ -782,7 +1805,7 @@ impl<T: PartialEq + Clone + Zero + One> Unit for Matrix<T> {
     /// assert_eq!(foo, bar);
     /// ```
     fn unit(dimension: usize) -> Matrix<T> {
-        let mut res = Matrix::splat(&T::zero(), (dimension, dimension), Alignment::RowAligned);
+        let mut res = Matrix::zeros((dimension, dimension));
         for a in 0..res.rows {
             res[(a, a)] = T::one();
         }
@@ -836,23 +1859,9 @@ impl<T: PartialEq + Clone + Zero + One> Unit for Matrix<T> {
     /// assert!(!foo.is_unit());
     /// ```
     fn is_unit(&self) -> bool {
-        if !self.is_unit_dimension() {
-            return false;
-        }
-        for r in 0..self.rows {
-            for c in 0..self.columns {
-                if r != c {
-                    if !self[(r, c)].is_zero() {
-                        return false;
-                    }
-                } else {
-                    if !self[(r, c)].is_one() {
-                        return false;
-                    }
-                }
-            }
-        }
-        true
+        self.is_unit_dimension()
+            && leading_block_is_triangular_unit(&self.matrix, self.columns, &self.alignment,
+                self.rows, true)
     }
 }
 
@@ -872,8 +1881,7 @@ impl<T: PartialEq + Clone + Zero + One> Unit for AugmentedMatrix<T> {
     /// assert_eq!(foo, bar);
     /// ```
     fn unit(dimension: usize) -> AugmentedMatrix<T> {
-        let mut res = AugmentedMatrix::splat(&T::zero(), (dimension, dimension + 1),
-                                             Alignment::RowAligned);
+        let mut res = AugmentedMatrix::zeros((dimension, dimension + 1));
         for a in 0..res.rows {
             res[(a, a)] = T::one();
         }
@@ -938,27 +1946,305 @@ impl<T: PartialEq + Clone + Zero + One> Unit for AugmentedMatrix<T> {
     /// assert!(!foo.is_unit());
     /// ```
     fn is_unit(&self) -> bool {
-        if !self.is_unit_dimension() {
-            return false;
+        self.is_unit_dimension()
+            && leading_block_is_triangular_unit(&self.matrix, self.columns, &self.alignment,
+                self.num_rows(), true)
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Resizes the matrix to `new_dim` like `Vec::resize` does in one dimension: the overlapping
+    /// top left block stays where it is, rows and columns past the new dimension are dropped, and
+    /// new elements are `fill`. The backing vec is rebuilt in a single pass, keeping the
+    /// alignment.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// let mut foo = matrix![
+    ///     1 2 3;
+    ///     4 5 6
+    /// ];
+    /// foo.resize((3, 2), 0);
+    /// assert_eq!(foo, matrix![
+    ///     1 2;
+    ///     4 5;
+    ///     0 0
+    /// ]);
+    /// ```
+    pub fn resize(&mut self, new_dim: (usize, usize), fill: T) {
+        let new = match self.alignment {
+            Alignment::RowAligned => new_dim,
+            Alignment::ColumnAligned => (new_dim.1, new_dim.0)
+        };
+        let old = mem::take(&mut self.matrix);
+        self.matrix = resize_lines(old, (self.rows, self.columns), new, &fill, false, false);
+        self.rows = new.0;
+        self.columns = new.1;
+    }
+
+    /// Returns a copy of the main diagonal, i.e. the elements at `(i, i)`. Its length is the
+    /// smaller of the number of rows and the number of columns.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, Unit};
+    /// let foo: Matrix<i32> = Matrix::unit(3);
+    /// assert_eq!(foo.diagonal(), vec![1, 1, 1]);
+    /// let bar = matrix![
+    ///     1 2 3;
+    ///     4 5 6
+    /// ];
+    /// assert_eq!(bar.diagonal(), vec![1, 5]);
+    /// ```
+    pub fn diagonal(&self) -> Vec<T> {
+        // (i, i) is at i * stride + i in either alignment.
+        let n = self.rows.min(self.columns);
+        (0..n).map(|i| self.matrix[i * self.columns + i].clone()).collect()
+    }
+
+    /// Sets the main diagonal (see `diagonal()`) to `values`, leaving every other element alone.
+    /// Panics unless `values` has one element per diagonal element.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// let mut foo = matrix![
+    ///     1 2 3;
+    ///     4 5 6
+    /// ];
+    /// foo.set_diagonal(&[0, 0]);
+    /// assert_eq!(foo, matrix![
+    ///     0 2 3;
+    ///     4 0 6
+    /// ]);
+    /// ```
+    pub fn set_diagonal(&mut self, values: &[T]) {
+        let n = self.rows.min(self.columns);
+        assert_eq!(values.len(), n, "The new diagonal must have {} elements.", n);
+        for (i, value) in values.iter().enumerate() {
+            self.matrix[i * self.columns + i] = value.clone();
         }
-        for r in 0..self.rows {
-            for c in 0..self.columns - 1 {
-                if r != c {
-                    if !self[(r, c)].is_zero() {
-                        return false;
-                    }
-                } else {
-                    if !self[(r, c)].is_one() {
-                        return false;
-                    }
-                }
+    }
+
+    /// Returns the transpose of the matrix (see `transposed()`), leaving the original alone. The
+    /// result has the other alignment.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// let foo = matrix![
+    ///     1 2 3;
+    ///     4 5 6
+    /// ];
+    /// assert_eq!(foo.transpose()[(2, 0)], foo[(0, 2)]);
+    /// assert_eq!(foo.transpose().transpose(), foo);
+    /// ```
+    pub fn transpose(&self) -> Matrix<T> {
+        self.clone().transposed()
+    }
+
+    /// Copies out the block of the matrix at the rows in `rows` and the columns in `cols`. The
+    /// result has the same alignment as the matrix. Returns an error if either range is empty
+    /// or goes past the end of the matrix.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// let foo = matrix![
+    ///     1 2 3;
+    ///     4 5 6;
+    ///     7 8 9
+    /// ];
+    /// assert_eq!(foo.submatrix(1..3, 1..3).unwrap(), matrix![
+    ///     5 6;
+    ///     8 9
+    /// ]);
+    /// assert!(foo.submatrix(0..4, 0..1).is_err());
+    /// assert!(foo.submatrix(1..1, 0..3).is_err());
+    /// ```
+    pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>)
+        -> Result<Matrix<T>, MatrixError> {
+        let dimension = self.dimension();
+        if rows.start >= rows.end || cols.start >= cols.end || rows.end > dimension.0
+            || cols.end > dimension.1 {
+            return Err(MatrixError::FunctionError(format!("Can't take the submatrix at rows {:?} \
+            and columns {:?} of a {:?} matrix: the ranges must be non-empty and in bounds.", rows,
+                cols, dimension)));
+        }
+        // The stored lines to take, and the part of each of them
+        let (lines, within) = match self.alignment {
+            Alignment::RowAligned => (rows, cols),
+            Alignment::ColumnAligned => (cols, rows)
+        };
+        let mut matrix = Vec::with_capacity((lines.end - lines.start) * (within.end
+            - within.start));
+        for line in lines.clone() {
+            let start = line * self.columns;
+            matrix.extend_from_slice(&self.matrix[start + within.start..start + within.end]);
+        }
+        Ok(Matrix {
+            rows: lines.end - lines.start,
+            columns: within.end - within.start,
+            matrix,
+            alignment: self.alignment.clone()
+        })
+    }
+
+    /// Copies the matrix into a vec of its rows, whatever its alignment. This is the reverse of
+    /// `Matrix::try_from` on a vec of rows.
+    pub fn to_nested_vec(&self) -> Vec<Vec<T>> {
+        self.rows().map(|row| row.iter().cloned().collect()).collect()
+    }
+
+    /// Turns the matrix into an augmented matrix with `solutions` as its solution column, reusing
+    /// the matrix's storage. Row-aligned matrices grow their vec by one element per row (a single
+    /// `reserve_exact`, which doesn't reallocate if there's already room) and the rows are then
+    /// shifted into place from the back, so no second full-size buffer is ever allocated.
+    /// Column-aligned matrices just get the solutions appended as their last stored column.
+    /// Returns an error if the number of solutions isn't the number of rows.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, AugmentedMatrix};
+    /// let foo = matrix![
+    ///     1 2;
+    ///     3 4
+    /// ];
+    /// let bar = foo.augment_in_place(&[5, 6]).unwrap();
+    /// assert_eq!(bar, augmented_matrix![
+    ///     1 2 => 5;
+    ///     3 4 => 6
+    /// ]);
+    /// let (baz, solutions) = bar.strip_augment_in_place();
+    /// assert_eq!(baz, matrix![
+    ///     1 2;
+    ///     3 4
+    /// ]);
+    /// assert_eq!(solutions, vec![5, 6]);
+    /// ```
+    pub fn augment_in_place(self, solutions: &[T]) -> Result<AugmentedMatrix<T>, MatrixError> {
+        let (rows, columns) = self.dimension();
+        if solutions.len() != rows {
+            return Err(MatrixError::FunctionError(format!("Attempted to augment a matrix with {} \
+            rows with {} solutions.", rows, solutions.len())));
+        }
+        let Matrix { matrix: mut vec, alignment, .. } = self;
+        vec.reserve_exact(rows);
+        vec.extend(solutions.iter().cloned());
+        if alignment == Alignment::ColumnAligned {
+            return Ok(AugmentedMatrix {
+                rows: columns + 1,
+                columns: rows,
+                matrix: vec,
+                alignment
+            });
+        }
+        // Element (r, c) moves from r * columns + c to r * (columns + 1) + c. Going from the back,
+        // the destination always holds either a placeholder or an element that has already been
+        // moved on, and the source has never been touched.
+        for r in (1..rows).rev() {
+            for c in (0..columns).rev() {
+                vec.swap(r * columns + c, r * (columns + 1) + c);
             }
         }
-        true
+        for (r, solution) in solutions.iter().enumerate() {
+            vec[r * (columns + 1) + columns] = solution.clone();
+        }
+        Ok(AugmentedMatrix {
+            rows,
+            columns: columns + 1,
+            matrix: vec,
+            alignment
+        })
     }
 }
 
 impl<T: Clone> AugmentedMatrix<T> {
+    /// Resizes the coefficient matrix to `new_dim` (see `Matrix::resize()`). The solution column
+    /// stays the last column; the solutions of new rows are `fill` too.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::AugmentedMatrix;
+    /// let mut foo = augmented_matrix![
+    ///     1 2 => 3;
+    ///     4 5 => 6
+    /// ];
+    /// foo.resize((3, 1), 0);
+    /// assert_eq!(foo, augmented_matrix![
+    ///     1 => 3;
+    ///     4 => 6;
+    ///     0 => 0
+    /// ]);
+    /// ```
+    pub fn resize(&mut self, new_dim: (usize, usize), fill: T) {
+        let column_aligned = self.is_column_aligned();
+        let new = if column_aligned {
+            (new_dim.1 + 1, new_dim.0)
+        } else {
+            (new_dim.0, new_dim.1 + 1)
+        };
+        let old = mem::take(&mut self.matrix);
+        self.matrix = resize_lines(old, (self.rows, self.columns), new, &fill, column_aligned,
+            !column_aligned);
+        self.rows = new.0;
+        self.columns = new.1;
+    }
+
+    /// Splits an augmented matrix into its coefficient matrix and its solution column, reusing
+    /// the storage for the coefficients (and keeping its capacity). This is the reverse of
+    /// `Matrix::augment_in_place`: row-aligned matrices have their rows shifted forward over the
+    /// solution slots and the vec truncated, and column-aligned ones have their last stored
+    /// column split off.
+    pub fn strip_augment_in_place(self) -> (Matrix<T>, Vec<T>) {
+        let (rows, columns) = self.dimension();
+        let AugmentedMatrix { matrix: mut vec, alignment, .. } = self;
+        if alignment == Alignment::ColumnAligned {
+            let solutions = vec.split_off(rows * columns);
+            return (Matrix {
+                rows: columns,
+                columns: rows,
+                matrix: vec,
+                alignment
+            }, solutions);
+        }
+        let solutions = (0..rows).map(|r| vec[r * (columns + 1) + columns].clone()).collect();
+        // The mirror image of `augment_in_place`: going from the front, the destination has
+        // already been moved out of and the source hasn't been touched yet.
+        for r in 1..rows {
+            for c in 0..columns {
+                vec.swap(r * columns + c, r * (columns + 1) + c);
+            }
+        }
+        vec.truncate(rows * columns);
+        (Matrix {
+            rows,
+            columns,
+            matrix: vec,
+            alignment
+        }, solutions)
+    }
+
+    /// Returns a copy of the solution column, top to bottom. The result doesn't depend on the
+    /// alignment of the augmented matrix.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::AugmentedMatrix;
+    /// let mut foo = augmented_matrix![
+    ///     1 2 3 => 4;
+    ///     5 6 7 => 8
+    /// ];
+    /// assert_eq!(foo.solution_column(), vec![4, 8]);
+    /// foo.column_align();
+    /// assert_eq!(foo.solution_column(), vec![4, 8]);
+    /// ```
+    pub fn solution_column(&self) -> Vec<T> {
+        let c = self.num_columns();
+        (0..self.num_rows()).map(|r| self[(r, c)].clone()).collect()
+    }
+
     /// Change the solution column of an augmented matrix. Panics if the length of the new solution
     /// column is not equal to the length of the current one.
     /// # Example
@@ -972,7 +2258,7 @@ impl<T: Clone> AugmentedMatrix<T> {
     ///     1.0 0.0 => 1.0;
     ///     0.0 1.0 => 2.0
     /// ];
-    /// assert!(foo.exactly_equal_to(&bar));
+    /// assert!(foo.exactly_equal_including_alignment(&bar));
     /// ```
     /// # Panics
     /// ```should_panic
@@ -1026,4 +2312,157 @@ impl<T: Clone> AugmentedMatrix<T> {
         }
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+impl Matrix<Fraction> {
+    /// Makes a new `Matrix<Fraction>` from `(numerator, denominator)` pairs, laid out the same way
+    /// as the vec given to `new_from_vec`. Returns an `InitError` if any pair has a zero
+    /// denominator (see `Fraction::try_new`) or if the number of pairs doesn't match the
+    /// dimension.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+    /// let foo = Matrix::new_from_pairs((2, 2), vec![(1, 2), (3, 4), (-5, 6), (7, 1)], RowAligned)
+    ///     .unwrap();
+    /// assert_eq!(foo[(1, 0)], Fraction::new(-5, 6));
+    /// assert!(Matrix::new_from_pairs((1, 2), vec![(1, 2), (3, 0)], RowAligned).is_err());
+    /// ```
+    pub fn new_from_pairs(dimension: (usize, usize), pairs: Vec<(i64, i64)>, alignment: Alignment)
+        -> Result<Matrix<Fraction>, MatrixError> {
+        Matrix::from_pairs(dimension, &pairs, alignment)
+    }
+
+    /// Converts every element into an `i64`, keeping the dimension and alignment. Unlike mapping
+    /// with `i64::from`, which truncates, this returns a `FunctionError` naming the first element
+    /// (going row by row) that isn't a whole number or doesn't fit in an `i64`.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+    /// let foo = Matrix::new_from_vec((1, 3), vec![
+    ///     Fraction::new(4, 2), Fraction::from(-3), Fraction::new(0, 5)
+    /// ], RowAligned).unwrap();
+    /// assert_eq!(foo.try_into_integers().unwrap(),
+    ///     Matrix::new_from_vec((1, 3), vec![2, -3, 0], RowAligned).unwrap());
+    /// let bar = Matrix::new_from_vec((1, 2), vec![Fraction::from(1), Fraction::new(7, 2)],
+    ///     RowAligned).unwrap();
+    /// assert!(bar.try_into_integers().is_err());
+    /// ```
+    pub fn try_into_integers(&self) -> Result<Matrix<i64>, MatrixError> {
+        match self.matrix.iter().map(i64::try_from).collect::<Result<Vec<i64>, _>>() {
+            Ok(vec) => Matrix::new_from_vec(self.dimension(), vec, self.alignment.clone()),
+            Err(_) => {
+                let (rows, columns) = self.dimension();
+                let (r, c, e) = (0..rows)
+                    .flat_map(|r| (0..columns).map(move |c| (r, c)))
+                    .filter_map(|(r, c)| i64::try_from(&self[(r, c)]).err().map(|e| (r, c, e)))
+                    .next()
+                    .unwrap();
+                Err(MatrixError::FunctionError(format!("Element ({}, {}) ({}) can't be converted \
+                into an integer: {}", r, c, self[(r, c)], e)))
+            }
+        }
+    }
+}
+
+macro_rules! fraction_matrix_constructors {
+    ($($target_type:ident),*) => ($(
+        impl $target_type<Fraction> {
+            /// Makes a matrix of whole-number fractions from a slice of integers, laid out the
+            /// same way as the vec given to `new_from_vec`. Returns an `InitError` if the number
+            /// of elements doesn't match the dimension.
+            pub fn from_integer_slice(dimension: (usize, usize), slice: &[i64],
+                alignment: Alignment) -> Result<Self, MatrixError> {
+                $target_type::new_from_vec(dimension, slice.iter().map(|&n| Fraction::from(n))
+                    .collect(), alignment)
+            }
+
+            /// Makes a matrix from `(numerator, denominator)` pairs, laid out the same way as the
+            /// vec given to `new_from_vec`. Returns an `InitError` naming the index of the first
+            /// pair with a zero denominator (see `Fraction::try_new`), or if the number of pairs
+            /// doesn't match the dimension.
+            pub fn from_pairs(dimension: (usize, usize), pairs: &[(i64, i64)],
+                alignment: Alignment) -> Result<Self, MatrixError> {
+                let vec = pairs.iter()
+                    .enumerate()
+                    .map(|(i, &(num, den))| Fraction::try_new(num, den)
+                        .map_err(|e| MatrixError::InitError(format!("Element {} ({} / {}) is not \
+                        a valid fraction: {}", i, num, den, e))))
+                    .collect::<Result<Vec<Fraction>, MatrixError>>()?;
+                $target_type::new_from_vec(dimension, vec, alignment)
+            }
+
+            /// Makes a matrix from floats, laid out the same way as the vec given to
+            /// `new_from_vec`. With a `max_den`, each value becomes the closest fraction whose
+            /// denominator is at most `max_den` (see `Fraction::approximate`); without one, the
+            /// shortest decimal that round-trips the float is converted exactly (see
+            /// `From<f64>`). Returns an `InitError` naming the index of the first value that
+            /// isn't finite or doesn't fit in a fraction, if `max_den` is less than 1, or if the
+            /// number of values doesn't match the dimension.
+            pub fn from_f64_slice(dimension: (usize, usize), slice: &[f64], max_den: Option<i64>,
+                alignment: Alignment) -> Result<Self, MatrixError> {
+                if let Some(max_den) = max_den {
+                    if max_den < 1 {
+                        return Err(MatrixError::InitError(format!("The maximum denominator must \
+                        be at least 1 (got {}).", max_den)));
+                    }
+                }
+                let vec = slice.iter()
+                    .enumerate()
+                    .map(|(i, &value)| {
+                        if !value.is_finite() || value.abs() >= i64::MAX as f64 {
+                            return Err(MatrixError::InitError(format!("Element {} ({}) can't \
+                            be converted into a fraction.", i, value)));
+                        }
+                        Ok(match max_den {
+                            Some(max_den) => Fraction::approximate(value, max_den),
+                            None => Fraction::from(value)
+                        })
+                    })
+                    .collect::<Result<Vec<Fraction>, MatrixError>>()?;
+                $target_type::new_from_vec(dimension, vec, alignment)
+            }
+
+            /// Converts every element into an `f64`, keeping the dimension and alignment.
+            /// Undefined fractions become NaN. See `to_fraction_matrix()` for the way back.
+            pub fn to_f64_matrix(&self) -> $target_type<f64> {
+                $target_type {
+                    rows: self.rows,
+                    columns: self.columns,
+                    matrix: self.matrix.iter().map(|&f| f64::from(f)).collect(),
+                    alignment: self.alignment.clone()
+                }
+            }
+        }
+    )*)
+}
+
+fraction_matrix_constructors!{Matrix, AugmentedMatrix}
+
+macro_rules! float_matrix_conversions {
+    ($($target_type:ident),*) => ($(
+        impl $target_type<f64> {
+            /// Converts every element into a `Fraction`, keeping the dimension and alignment. The
+            /// exact binary value of each float is converted (see `FromPrimitive::from_f64`), so
+            /// a matrix of dyadic fractions makes the round trip through `to_f64_matrix()`
+            /// unchanged, but e.g. `0.1` doesn't become `1 / 10` (`from_f64_slice()` does that).
+            /// NaN, infinities and values that don't fit in a fraction become undefined
+            /// fractions.
+            pub fn to_fraction_matrix(&self) -> $target_type<Fraction> {
+                $target_type {
+                    rows: self.rows,
+                    columns: self.columns,
+                    matrix: self.matrix.iter()
+                        .map(|&e| Fraction::from_f64(e).unwrap_or_else(Fraction::undefined))
+                        .collect(),
+                    alignment: self.alignment.clone()
+                }
+            }
+        }
+    )*)
+}
+
+float_matrix_conversions!{Matrix, AugmentedMatrix}
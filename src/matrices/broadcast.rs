@@ -0,0 +1,246 @@
+//! Adding a scalar to every element, or to one row, column or the diagonal, and subtracting a
+//! vector from every row or column - e.g. to center the columns of a data matrix. Each of these is
+//! a single pass over the backing vec in storage order. On augmented matrices they only touch the
+//! coefficients: the solution column is left alone.
+//! # Example
+//! ```rust
+//! # extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::fractions::base::Fraction;
+//! # use fractions_and_matrices::matrices::base::{Matrix, Alignment::ColumnAligned};
+//! let mut foo = Matrix::new_from_vec((3, 2), vec![
+//!     Fraction::from(1), Fraction::from(2), Fraction::from(6),
+//!     Fraction::from(0), Fraction::new(1, 2), Fraction::from(4)
+//! ], ColumnAligned).unwrap();
+//! let means = (0..2).map(|c| (0..3).map(|r| foo[(r, c)]).sum::<Fraction>() / 3)
+//!     .collect::<Vec<Fraction>>();
+//! foo.sub_row_vector(&means).unwrap();
+//! for c in 0..2 {
+//!     assert_eq!((0..3).map(|r| foo[(r, c)]).sum::<Fraction>(), Fraction::from(0));
+//! }
+//! assert_eq!(foo[(0, 0)], Fraction::from(-2));
+//! ```
+//!
+//! `add_to_diagonal()` and `sub_from_diagonal()` give `A + sI` and `A - sI` as new matrices, as
+//! `add_scalar()` and `sub_scalar()` do for `A + s` and `A - s`. Each has an `_in_place` version
+//! that changes the matrix instead.
+//! # Example
+//! ```rust
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::matrices::base::Matrix;
+//! use fractions_and_matrices::matrices::determinant::Determinant;
+//!
+//! let mut foo: Matrix<i64> = matrix![
+//!     2 1;
+//!     1 2
+//! ];
+//! // 3 is an eigenvalue, so A - 3I is singular
+//! foo.sub_from_diagonal_in_place(3).unwrap();
+//! assert_eq!(foo.determinant().unwrap(), 0);
+//! assert_eq!(foo.add_to_diagonal(&3).unwrap(), matrix![
+//!     2 1;
+//!     1 2
+//! ]);
+//! assert!(matrix![1 2].add_to_diagonal(&1).is_err());
+//! ```
+
+use std::ops::{AddAssign, SubAssign};
+
+use matrices::base::{Alignment, AugmentedMatrix, Matrix, MatrixError};
+
+macro_rules! broadcast_impls {
+    ($($target_type:ty),*) => ($(
+        impl<T: Clone + AddAssign + SubAssign> $target_type {
+            /// Adds `s` to every element (every coefficient, for augmented matrices).
+            pub fn add_scalar_in_place(&mut self, s: T) {
+                self.for_each_coefficient(|e| *e += s.clone());
+            }
+
+            /// Subtracts `s` from every element (every coefficient, for augmented matrices).
+            pub fn sub_scalar_in_place(&mut self, s: T) {
+                self.for_each_coefficient(|e| *e -= s.clone());
+            }
+
+            /// Adds `s` to every element (every coefficient, for augmented matrices) of row `r`.
+            /// Returns an error if there's no such row.
+            pub fn add_to_row(&mut self, r: usize, s: T) -> Result<(), MatrixError> {
+                if r >= self.num_rows() {
+                    return Err(MatrixError::FunctionError(format!("Attempted to add to row {} \
+                    of a matrix with {} rows.", r, self.num_rows())));
+                }
+                let (stride, columns) = (self.columns, self.num_columns());
+                match self.alignment {
+                    Alignment::RowAligned => for e in &mut self[r][..columns] {
+                        *e += s.clone();
+                    },
+                    Alignment::ColumnAligned => for line in self.matrix.chunks_mut(stride)
+                        .take(columns) {
+                        line[r] += s.clone();
+                    }
+                }
+                Ok(())
+            }
+
+            /// Adds `s` to every element of column `c`. On augmented matrices `c` has to be a
+            /// coefficient column. Returns an error if there's no such column.
+            pub fn add_to_column(&mut self, c: usize, s: T) -> Result<(), MatrixError> {
+                if c >= self.num_columns() {
+                    return Err(MatrixError::FunctionError(format!("Attempted to add to column {} \
+                    of a matrix with {} columns.", c, self.num_columns())));
+                }
+                let stride = self.columns;
+                match self.alignment {
+                    Alignment::RowAligned => for line in self.matrix.chunks_mut(stride) {
+                        line[c] += s.clone();
+                    },
+                    Alignment::ColumnAligned => for e in &mut self[c] {
+                        *e += s.clone();
+                    }
+                }
+                Ok(())
+            }
+
+            /// Adds `s` to every element of the main diagonal, e.g. to make `A + sI` without
+            /// building the identity. Returns an error unless the matrix (the coefficient matrix,
+            /// for augmented matrices) is square.
+            pub fn add_to_diagonal_in_place(&mut self, s: T) -> Result<(), MatrixError> {
+                self.for_each_diagonal("add to", |e| *e += s.clone())
+            }
+
+            /// Subtracts `s` from every element of the main diagonal, e.g. to make `A - λI` for
+            /// the characteristic polynomial. Returns an error unless the matrix (the coefficient
+            /// matrix, for augmented matrices) is square.
+            pub fn sub_from_diagonal_in_place(&mut self, s: T) -> Result<(), MatrixError> {
+                self.for_each_diagonal("subtract from", |e| *e -= s.clone())
+            }
+
+            /// Like `add_scalar_in_place()`, but gives a new matrix instead of changing this one.
+            pub fn add_scalar(&self, k: &T) -> $target_type {
+                let mut res = self.clone();
+                res.add_scalar_in_place(k.clone());
+                res
+            }
+
+            /// Like `sub_scalar_in_place()`, but gives a new matrix instead of changing this one.
+            pub fn sub_scalar(&self, k: &T) -> $target_type {
+                let mut res = self.clone();
+                res.sub_scalar_in_place(k.clone());
+                res
+            }
+
+            /// Gives `A + kI`, like `add_to_diagonal_in_place()` but as a new matrix, leaving this
+            /// one alone. Returns an error unless the matrix (the coefficient matrix, for
+            /// augmented matrices) is square.
+            pub fn add_to_diagonal(&self, k: &T) -> Result<$target_type, MatrixError> {
+                let mut res = self.clone();
+                res.add_to_diagonal_in_place(k.clone())?;
+                Ok(res)
+            }
+
+            /// Gives `A - kI`, like `sub_from_diagonal_in_place()` but as a new matrix, leaving
+            /// this one alone - e.g. `A - λI` for evaluating the characteristic polynomial at `λ`.
+            /// Returns an error unless the matrix (the coefficient matrix, for augmented matrices)
+            /// is square.
+            pub fn sub_from_diagonal(&self, k: &T) -> Result<$target_type, MatrixError> {
+                let mut res = self.clone();
+                res.sub_from_diagonal_in_place(k.clone())?;
+                Ok(res)
+            }
+
+            /// Subtracts `v[j]` from every element of column `j`, for every (coefficient) column.
+            /// Returns an error unless `v` has one element per column.
+            pub fn sub_row_vector(&mut self, v: &[T]) -> Result<(), MatrixError> {
+                if v.len() != self.num_columns() {
+                    return Err(MatrixError::FunctionError(format!("Attempted to subtract a row \
+                    vector of length {} from a matrix with {} columns.", v.len(),
+                        self.num_columns())));
+                }
+                if self.matrix.is_empty() {
+                    return Ok(());
+                }
+                let stride = self.columns;
+                match self.alignment {
+                    Alignment::RowAligned => for line in self.matrix.chunks_mut(stride) {
+                        for (e, x) in line.iter_mut().zip(v.iter()) {
+                            *e -= x.clone();
+                        }
+                    },
+                    Alignment::ColumnAligned => {
+                        for (line, x) in self.matrix.chunks_mut(stride).zip(v.iter()) {
+                            for e in line {
+                                *e -= x.clone();
+                            }
+                        }
+                    }
+                }
+                Ok(())
+            }
+
+            /// Subtracts `v[i]` from every element (every coefficient, for augmented matrices) of
+            /// row `i`. Returns an error unless `v` has one element per row.
+            pub fn sub_column_vector(&mut self, v: &[T]) -> Result<(), MatrixError> {
+                if v.len() != self.num_rows() {
+                    return Err(MatrixError::FunctionError(format!("Attempted to subtract a \
+                    column vector of length {} from a matrix with {} rows.", v.len(),
+                        self.num_rows())));
+                }
+                if self.matrix.is_empty() {
+                    return Ok(());
+                }
+                let (stride, columns) = (self.columns, self.num_columns());
+                match self.alignment {
+                    Alignment::RowAligned => {
+                        for (line, x) in self.matrix.chunks_mut(stride).zip(v.iter()) {
+                            for e in &mut line[..columns] {
+                                *e -= x.clone();
+                            }
+                        }
+                    },
+                    Alignment::ColumnAligned => {
+                        for line in self.matrix.chunks_mut(stride).take(columns) {
+                            for (e, x) in line.iter_mut().zip(v.iter()) {
+                                *e -= x.clone();
+                            }
+                        }
+                    }
+                }
+                Ok(())
+            }
+
+            // Applies `f` to every element of the main diagonal of a square (coefficient) matrix.
+            // Element `(i, i)` is at offset `i` of stored line `i` whichever the alignment.
+            fn for_each_diagonal<F: FnMut(&mut T)>(&mut self, action: &str, mut f: F)
+                -> Result<(), MatrixError> {
+                if self.num_rows() != self.num_columns() {
+                    return Err(MatrixError::FunctionError(format!("Attempted to {} the diagonal \
+                    of a matrix with {} rows and {} columns.", action, self.num_rows(),
+                        self.num_columns())));
+                }
+                let stride = self.columns;
+                for i in 0..self.num_rows() {
+                    f(&mut self.matrix[i * stride + i]);
+                }
+                Ok(())
+            }
+
+            // Applies `f` to every coefficient in storage order, skipping the solution column of
+            // augmented matrices (the last element of each stored row, or the last stored column).
+            fn for_each_coefficient<F: FnMut(&mut T)>(&mut self, mut f: F) {
+                if self.matrix.is_empty() {
+                    return;
+                }
+                let (stride, columns) = (self.columns, self.num_columns());
+                match self.alignment {
+                    Alignment::RowAligned => for line in self.matrix.chunks_mut(stride) {
+                        line[..columns].iter_mut().for_each(&mut f);
+                    },
+                    Alignment::ColumnAligned => for line in self.matrix.chunks_mut(stride)
+                        .take(columns) {
+                        line.iter_mut().for_each(&mut f);
+                    }
+                }
+            }
+        }
+    )*)
+}
+
+broadcast_impls!{Matrix<T>, AugmentedMatrix<T>}
@@ -0,0 +1,147 @@
+//! Minors and cofactors. `minor()` copies the matrix without one row and one column, straight
+//! from the backing vec. `cofactor()` is the signed determinant of a minor, worked out by Laplace
+//! expansion along the first row, which takes factorial time - fine for the small matrices
+//! cofactors are usually wanted for. `adjugate()` needs every cofactor, so it finds their
+//! determinants with `Determinant` instead.
+//! # Example
+//! ```rust
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::matrices::base::Matrix;
+//! let foo = matrix![
+//!     1 2 3;
+//!     0 4 5;
+//!     1 0 6
+//! ];
+//! assert_eq!(foo.minor(0, 1).unwrap(), matrix![
+//!     0 5;
+//!     1 6
+//! ]);
+//! // -(0 * 6 - 5 * 1)
+//! assert_eq!(foo.cofactor(0, 1).unwrap(), 5);
+//! assert!(foo.minor(3, 0).is_err());
+//! ```
+//!
+//! The adjugate is the transpose of the matrix of cofactors, and `A * adj(A) = det(A) * I`. For
+//! integer matrices that's an inverse scaled up to integers.
+//! # Example
+//! ```rust
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+//! let foo: Matrix<i64> = matrix![
+//!     2 1;
+//!     5 3
+//! ];
+//! let adjugate = foo.adjugate().unwrap();
+//! assert_eq!(adjugate, Matrix::new_from_vec((2, 2), vec![3, -1, -5, 2], RowAligned).unwrap());
+//! assert_eq!(&foo * &adjugate, matrix![
+//!     1 0;
+//!     0 1
+//! ]);
+//! ```
+
+use std::ops::{Mul, Neg};
+
+use num::{One, Zero};
+
+use matrices::base::{Alignment, Matrix, MatrixError};
+use matrices::determinant::Determinant;
+
+impl<T: Clone> Matrix<T> {
+    /// Copies the matrix without row `row` and column `col`, keeping the alignment. Returns an
+    /// error if there's no such row or column, or if the result would be empty.
+    pub fn minor(&self, row: usize, col: usize) -> Result<Matrix<T>, MatrixError> {
+        let dimension = self.dimension();
+        if row >= dimension.0 || col >= dimension.1 {
+            return Err(MatrixError::FunctionError(format!("There's no element ({}, {}) in a \
+            {:?} matrix to take the minor at.", row, col, dimension)));
+        }
+        if dimension.0 < 2 || dimension.1 < 2 {
+            return Err(MatrixError::FunctionError(format!("The minors of a {:?} matrix would \
+            be empty.", dimension)));
+        }
+        let (skip_line, skip_offset) = match self.alignment {
+            Alignment::RowAligned => (row, col),
+            Alignment::ColumnAligned => (col, row)
+        };
+        let mut matrix = Vec::with_capacity((self.rows - 1) * (self.columns - 1));
+        for (i, line) in self.matrix.chunks(self.columns).enumerate() {
+            if i != skip_line {
+                matrix.extend(line[..skip_offset].iter().cloned());
+                matrix.extend(line[skip_offset + 1..].iter().cloned());
+            }
+        }
+        Ok(Matrix {
+            rows: self.rows - 1,
+            columns: self.columns - 1,
+            matrix,
+            alignment: self.alignment.clone()
+        })
+    }
+}
+
+impl<T: Clone + Zero + One + Mul<Output = T> + Neg<Output = T>> Matrix<T> {
+    /// Returns the cofactor of element `(row, col)`: the determinant of `minor(row, col)`,
+    /// negated if `row + col` is odd. The only cofactor of a 1x1 matrix is one. Returns an error
+    /// if the matrix isn't square or there's no such element.
+    pub fn cofactor(&self, row: usize, col: usize) -> Result<T, MatrixError> {
+        let dimension = self.dimension();
+        if dimension.0 != dimension.1 {
+            return Err(MatrixError::FunctionError(format!("Cofactors are only defined for \
+            square matrices, not {:?} ones.", dimension)));
+        }
+        if dimension == (1, 1) && row == 0 && col == 0 {
+            return Ok(T::one());
+        }
+        let determinant = Matrix::laplace_determinant(&self.minor(row, col)?);
+        Ok(if (row + col) % 2 == 1 { -determinant } else { determinant })
+    }
+
+    // Expands along the first row of a square matrix.
+    fn laplace_determinant(matrix: &Matrix<T>) -> T {
+        let n = matrix.num_columns();
+        if n == 1 {
+            return matrix[(0, 0)].clone();
+        }
+        (0..n).fold(T::zero(), |acc, j| {
+            let term = matrix[(0, j)].clone()
+                * Matrix::laplace_determinant(&matrix.minor(0, j).unwrap());
+            if j % 2 == 1 { acc + -term } else { acc + term }
+        })
+    }
+}
+
+impl<T: Clone + One + Neg<Output = T>> Matrix<T> where Matrix<T>: Determinant<T> {
+    /// Returns the adjugate, i.e. the transpose of the matrix of cofactors, with the same
+    /// alignment. The adjugate of a 1x1 matrix is `[1]`. Each of the `n * n` cofactors takes a
+    /// determinant by elimination, so this is O(n^5) rather than the factorial time of Laplace
+    /// expansion. Returns an error if the matrix isn't square.
+    pub fn adjugate(&self) -> Result<Matrix<T>, MatrixError> {
+        let dimension = self.dimension();
+        if dimension.0 != dimension.1 {
+            return Err(MatrixError::FunctionError(format!("The adjugate is only defined for \
+            square matrices, not {:?} ones.", dimension)));
+        }
+        let n = dimension.0;
+        if n < 2 {
+            return Ok(self.map(|_| T::one()));
+        }
+        let mut matrix = Vec::with_capacity(n * n);
+        for line in 0..n {
+            for offset in 0..n {
+                // Stored rows of the adjugate are columns of cofactors, and vice versa
+                let (row, col) = match self.alignment {
+                    Alignment::RowAligned => (offset, line),
+                    Alignment::ColumnAligned => (line, offset)
+                };
+                let determinant = self.minor(row, col)?.determinant()?;
+                matrix.push(if (row + col) % 2 == 1 { -determinant } else { determinant });
+            }
+        }
+        Ok(Matrix {
+            rows: n,
+            columns: n,
+            matrix,
+            alignment: self.alignment.clone()
+        })
+    }
+}
@@ -0,0 +1,206 @@
+//! Exact determinants. Integer matrices use Bareiss' fraction-free elimination, where every
+//! division is exact, so nothing ever leaves the integers. Field types like `Fraction` use plain
+//! elimination, multiplying the pivots together. Both swap rows to find a non-zero pivot and keep
+//! track of the sign that costs; `gaussian_elim` can't be used for this, since it scales the
+//! pivots to 1 and never swaps rows.
+//! # Example
+//! ```rust
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::fractions::base::Fraction;
+//! # use fractions_and_matrices::matrices::base::Matrix;
+//! use fractions_and_matrices::matrices::determinant::Determinant;
+//!
+//! let foo: Matrix<i64> = matrix![
+//!     0 2 1;
+//!     3 1 0;
+//!     1 1 1
+//! ];
+//! assert_eq!(foo.determinant().unwrap(), -4);
+//! let bar = foo.convert::<Fraction>();
+//! assert_eq!(bar.determinant().unwrap(), Fraction::from(-4));
+//! assert!(matrix![1 2].determinant().is_err());
+//! ```
+//!
+//! `determinant_display()` also gives the row operations it took, in the same style as
+//! `gaussian_elim_display()`, with a row swap noting the sign the determinant is left with.
+//! # Example
+//! ```rust
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::fractions::base::Fraction;
+//! # use fractions_and_matrices::matrices::base::Matrix;
+//! use fractions_and_matrices::matrices::determinant::DeterminantDisplay;
+//!
+//! let foo = matrix![
+//!     0 2;
+//!     3 1
+//! ].convert::<Fraction>();
+//! let (determinant, steps) = foo.determinant_display().unwrap();
+//! assert_eq!(determinant, Fraction::from(-6));
+//! assert_eq!(steps, vec!["R0 ↔ R1 (sign: -)", "det = -(3) * (2) = -6"]);
+//! ```
+
+use std::fmt::Display;
+use std::ops::{Div, Mul, Neg, Sub};
+
+use num::{One, Zero};
+
+use fractions::base::Fraction;
+use matrices::base::{Matrix, MatrixError};
+
+/// Trait to find the determinant of a square matrix.
+pub trait Determinant<T> {
+    /// Gives the determinant, or a `FunctionError` if the matrix isn't square. Integer matrices
+    /// go through Bareiss' algorithm, whose intermediate values are products of two minors of
+    /// the matrix, so they can overflow even when the determinant itself fits. That overflow
+    /// is plain integer overflow: a panic in debug builds and a wrong result in release ones.
+    /// Convert to a wider type (or to `Fraction`, which panics on overflow in either) first if
+    /// the elements are large.
+    fn determinant(&self) -> Result<T, MatrixError>;
+}
+
+/// Trait to find the determinant of a square matrix along with the steps taken, generated with
+/// the `Display` trait. There are no steps for matrices smaller than 2x2.
+pub trait DeterminantDisplay<T> {
+    fn determinant_display(&self) -> Result<(T, Vec<String>), MatrixError>;
+}
+
+// Errors unless the matrix is square, otherwise gives a row-major copy of its elements and its
+// size.
+fn square_elements<T: Clone>(matrix: &Matrix<T>) -> Result<(Vec<T>, usize), MatrixError> {
+    let (rows, columns) = matrix.dimension();
+    if rows != columns {
+        return Err(MatrixError::FunctionError(format!("Can't find the determinant of a {:?} \
+        matrix: it isn't square.", matrix.dimension())));
+    }
+    Ok((matrix.iter().cloned().collect(), rows))
+}
+
+// Finds the first row at or below `k` with a non-zero element in column `k` and swaps it into row
+// `k`, recording the swap and the sign it leaves the determinant with. Returns `false` if there's
+// no such row.
+fn find_pivot<T: Zero>(a: &mut [T], n: usize, k: usize, negate: &mut bool,
+    steps: &mut Option<&mut Vec<String>>) -> bool {
+    match (k..n).find(|&r| !a[r * n + k].is_zero()) {
+        None => {
+            if let Some(ref mut steps) = *steps {
+                steps.push(format!("Column {} has no pivot, so det = 0", k));
+            }
+            false
+        },
+        Some(r) => {
+            if r != k {
+                for j in 0..n {
+                    a.swap(r * n + j, k * n + j);
+                }
+                *negate = !*negate;
+                if let Some(ref mut steps) = *steps {
+                    steps.push(format!("R{} ↔ R{} (sign: {})", k, r,
+                        if *negate { "-" } else { "+" }));
+                }
+            }
+            true
+        }
+    }
+}
+
+// Bareiss' algorithm: after step `k`, each element below and to the right of the pivot is a
+// `(k + 2)`x`(k + 2)` minor of the original matrix, so dividing by the previous pivot is exact.
+// The last pivot is then the determinant, up to the sign of the row swaps.
+fn bareiss_determinant<T>(mut a: Vec<T>, n: usize, mut steps: Option<&mut Vec<String>>) -> T
+    where T: Clone + Zero + One + Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+        + Neg<Output = T> + Display {
+    let mut negate = false;
+    let mut previous = T::one();
+    for k in 0..n.saturating_sub(1) {
+        if !find_pivot(&mut a, n, k, &mut negate, &mut steps) {
+            return T::zero();
+        }
+        let pivot = a[k * n + k].clone();
+        for i in k + 1..n {
+            if let Some(ref mut steps) = steps {
+                steps.push(format!("(({}) * R{} - ({}) * R{}) / ({}) -> R{1}", pivot, i,
+                    a[i * n + k], k, previous));
+            }
+            for j in k + 1..n {
+                a[i * n + j] = (pivot.clone() * a[i * n + j].clone()
+                    - a[i * n + k].clone() * a[k * n + j].clone()) / previous.clone();
+            }
+            a[i * n + k] = T::zero();
+        }
+        previous = pivot;
+    }
+    let last = match n {
+        0 => return T::one(),
+        _ => a[n * n - 1].clone()
+    };
+    let determinant = if negate { -last.clone() } else { last.clone() };
+    if let Some(steps) = steps {
+        if n > 1 {
+            steps.push(format!("det = {}({}) = {}", if negate { "-" } else { "" }, last,
+                determinant));
+        }
+    }
+    determinant
+}
+
+// Gaussian elimination without scaling the pivot rows, so the determinant is the product of the
+// pivots, up to the sign of the row swaps.
+fn eliminated_determinant<T>(mut a: Vec<T>, n: usize, mut steps: Option<&mut Vec<String>>) -> T
+    where T: Clone + Zero + One + Sub<Output = T> + Mul<Output = T> + Div<Output = T>
+        + Neg<Output = T> + Display {
+    let mut negate = false;
+    for k in 0..n.saturating_sub(1) {
+        if !find_pivot(&mut a, n, k, &mut negate, &mut steps) {
+            return T::zero();
+        }
+        let pivot = a[k * n + k].clone();
+        for i in k + 1..n {
+            let factor = a[i * n + k].clone() / pivot.clone();
+            if factor.is_zero() {
+                continue;
+            }
+            if let Some(ref mut steps) = steps {
+                steps.push(format!("R{} - ({}) * R{} -> R{0}", i, factor, k));
+            }
+            for j in k + 1..n {
+                a[i * n + j] = a[i * n + j].clone() - factor.clone() * a[k * n + j].clone();
+            }
+            a[i * n + k] = T::zero();
+        }
+    }
+    let pivots = (0..n).map(|k| a[k * n + k].clone()).collect::<Vec<T>>();
+    let product = pivots.iter().fold(T::one(), |acc, p| acc * p.clone());
+    let determinant = if negate { -product } else { product };
+    if let Some(steps) = steps {
+        if n > 1 {
+            let factors = pivots.iter().map(|p| format!("({})", p)).collect::<Vec<_>>();
+            steps.push(format!("det = {}{} = {}", if negate { "-" } else { "" },
+                factors.join(" * "), determinant));
+        }
+    }
+    determinant
+}
+
+macro_rules! determinant_impls {
+    ($algorithm:ident: $($t:ty),*) => ($(
+        impl Determinant<$t> for Matrix<$t> {
+            /// Returns an error if the matrix isn't square. The determinant of a 0x0 matrix is 1.
+            fn determinant(&self) -> Result<$t, MatrixError> {
+                let (elements, n) = square_elements(self)?;
+                Ok($algorithm(elements, n, None))
+            }
+        }
+
+        impl DeterminantDisplay<$t> for Matrix<$t> {
+            fn determinant_display(&self) -> Result<($t, Vec<String>), MatrixError> {
+                let (elements, n) = square_elements(self)?;
+                let mut steps = Vec::new();
+                let determinant = $algorithm(elements, n, Some(&mut steps));
+                Ok((determinant, steps))
+            }
+        }
+    )*)
+}
+
+determinant_impls!{bareiss_determinant: i8, i16, i32, i64, i128, isize}
+determinant_impls!{eliminated_determinant: Fraction, f32, f64}
@@ -1,199 +1,217 @@
-use std::fmt::{Display, Debug, Formatter, Result};
+use std::fmt::{Display, Debug, Formatter, Result, Write};
 
-use matrices::base::{AugmentedMatrix, Matrix, MatrixError};
+use matrices::base::{Alignment, AugmentedMatrix, Matrix};
 
-impl<T: Debug> Debug for Matrix<T> {
-    fn fmt(&self, f: &mut Formatter) -> Result {
-        let mut matr = String::from(""); // Will contain string for entire matrix
-        let mut longest_in_column: Vec<usize> = Vec::with_capacity(self.num_columns());
-        for _ in 0..self.num_columns() {
-            longest_in_column.push(0);
-        }
-        for a in 0..self.num_rows() {
-            for b in 0..self.num_columns() {
-                if format!("{:?}", self[(a, b)]).len() > longest_in_column[b] {
-                    longest_in_column[b] = format!("{:?}", self[(a, b)]).len();
-                }
-            }
-        }
-        for a in 0..self.num_rows() {
-            let mut line = format!("Row {}: ", a); // String for each individual line
-            // Add the appropriate character for the section of the bracket at the start of each line
-            // Add spacing to line up the right side of the numbers in each column
-            for b in 0..self.num_columns() {
-                let mut spacer_left = String::from("");
-                let elem_string = format!("{:?}", self[(a, b)]);
-                for _ in 0..longest_in_column[b] - elem_string.len() {
-                    spacer_left = format!("{}{}", spacer_left, " ");
-                }
-                if b == self.num_columns() - 1 {
-                    line = format!("{}{}{} ", line, spacer_left, elem_string);
-                } else {
-                    line = format!("{}{}{}, ", line, spacer_left, elem_string);
-                }
+// Debug output shows at most this many rows and (coefficient) columns - the top left corner - so
+// that logging a huge matrix only formats the elements that actually get printed.
+const DEBUG_MAX_ROWS: usize = 10;
+const DEBUG_MAX_COLUMNS: usize = 10;
+
+// A sink that only counts the `char`s written to it, so that elements can be measured without
+// being kept around. Columns are aligned by width in `char`s rather than bytes so that elements
+// such as `"½"` don't get too little padding.
+struct WidthCounter(usize);
+
+impl Write for WidthCounter {
+    fn write_str(&mut self, s: &str) -> Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+fn display_width<T: Display>(e: &T) -> usize {
+    let mut counter = WidthCounter(0);
+    let _ = write!(counter, "{}", e);
+    counter.0
+}
+
+// Widths of the logical columns in `columns` over the first `rows` rows. This reads the backing
+// vec in storage order - one stored line at a time - so column-aligned matrices are measured
+// where they are instead of being realigned first.
+fn column_widths<T, I, F>(matrix: &[T], stride: usize, alignment: &Alignment, rows: usize,
+    columns: I, width: F) -> Vec<usize>
+    where I: Iterator<Item = usize> + Clone, F: Fn(&T) -> usize {
+    let mut widths = vec![0; columns.clone().count()];
+    if rows == 0 || stride == 0 {
+        return widths;
+    }
+    match *alignment {
+        Alignment::RowAligned => for line in matrix.chunks(stride).take(rows) {
+            for (w, c) in widths.iter_mut().zip(columns.clone()) {
+                *w = (*w).max(width(&line[c]));
             }
-            // Add line to matrix string, add newline if it's not the last line
-            if a < self.num_rows() {
-                matr = format!("{}{}\n", matr, line);
+        },
+        Alignment::ColumnAligned => for (w, c) in widths.iter_mut().zip(columns) {
+            for e in &matrix[c * stride..c * stride + rows] {
+                *w = (*w).max(width(e));
             }
         }
-        write!(f, "{}", format!("Dimension: ({}, {}), alignment: {:?}\n{}", self.num_rows(),
-                                self.num_columns(), self.alignment, matr))
     }
+    widths
 }
 
-impl<T: Debug> Debug for AugmentedMatrix<T> {
+// Writes `e` right-aligned to `width`. It's formatted into `cell` first to find its own width,
+// so `cell` never holds more than one element at a time.
+fn write_padded<T: Display>(f: &mut Formatter, cell: &mut String, e: &T, width: usize) -> Result {
+    cell.clear();
+    let _ = write!(cell, "{}", e);
+    for _ in cell.chars().count()..width {
+        f.write_char(' ')?;
+    }
+    f.write_str(cell)
+}
+
+// A list of elements for Debug output, followed by `...` if `more` of them aren't shown. It stays
+// on one line even with `{:#?}`, so pretty-printed matrices get one line per row.
+struct DebugRow<'a, T: 'a> {
+    elements: Vec<&'a T>,
+    more: bool
+}
+
+impl<'a, T: Debug> Debug for DebugRow<'a, T> {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        let mut matr = String::from(""); // Will contain string for entire matrix
-        let mut longest_in_column: Vec<usize> = Vec::with_capacity(self.num_columns() + 1);
-        for _ in 0..self.num_columns() + 1 {
-            longest_in_column.push(0);
-        }
-        for a in 0..self.num_rows() {
-            for b in 0..self.num_columns() + 1 {
-                if format!("{:?}", self[(a, b)]).len() > longest_in_column[b] {
-                    longest_in_column[b] = format!("{:?}", self[(a, b)]).len();
-                }
+        f.write_str("[")?;
+        for (i, e) in self.elements.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
             }
+            write!(f, "{:?}", e)?;
         }
-        for a in 0..self.num_rows() {
-            let mut line = format!("Row {}: ", a); // String for each individual line
-            // Add the appropriate character for the section of the bracket at the start of each line
-            // Add spacing to line up the right side of the numbers in each column
-            for b in 0..self.num_columns() + 1 {
-                let mut spacer_left = String::from("");
-                let elem_string = format!("{:?}", self[(a, b)]);
-                for _ in 0..longest_in_column[b] - elem_string.len() {
-                    spacer_left = format!("{}{}", spacer_left, " ");
-                }
-                if b == self.num_columns() {
-                    line = format!("{}| {}{}", line, spacer_left, elem_string);
-                } else if b == self.num_columns() - 1 {
-                    line = format!("{}{}{} ", line, spacer_left, elem_string);
-                } else {
-                    line = format!("{}{}{}, ", line, spacer_left, elem_string);
-                }
-            }
-            // Add line to matrix string, add newline if it's not the last line
-            if a < self.num_rows() {
-                matr = format!("{}{}\n", matr, line);
-            }
+        f.write_str(if self.more { ", ...]" } else { "]" })
+    }
+}
+
+// The shown rows of a matrix for Debug output, followed by `...` if some aren't shown.
+struct DebugRows<'a, T: 'a> {
+    rows: Vec<DebugRow<'a, T>>,
+    more: bool
+}
+
+impl<'a, T: Debug> Debug for DebugRows<'a, T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let mut list = f.debug_list();
+        list.entries(&self.rows);
+        if self.more {
+            list.entry(&format_args!("..."));
         }
-        write!(f, "{}", format!("Dimension: ({}, {}), alignment: {:?}\n{}", self.num_rows(),
-                                self.num_columns() + 1, self.alignment, matr))
+        list.finish()
     }
 }
 
+// The top left corner of the logical rows of a matrix of dimension `(rows, columns)`, read with
+// `get`.
+fn debug_rows<'a, T, F>(rows: usize, columns: usize, get: F) -> DebugRows<'a, T>
+    where F: Fn(usize, usize) -> &'a T {
+    let shown_columns = columns.min(DEBUG_MAX_COLUMNS);
+    DebugRows {
+        rows: (0..rows.min(DEBUG_MAX_ROWS)).map(|r| DebugRow {
+            elements: (0..shown_columns).map(|c| get(r, c)).collect(),
+            more: shown_columns < columns
+        }).collect(),
+        more: rows > DEBUG_MAX_ROWS
+    }
+}
+
+impl<T: Debug> Debug for Matrix<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let (rows, columns) = self.dimension();
+        f.debug_struct("Matrix")
+            .field("dim", &(rows, columns))
+            .field("alignment", &self.alignment)
+            .field("data", &debug_rows(rows, columns, |r, c| &self[(r, c)]))
+            .finish()
+    }
+}
+
+impl<T: Debug> Debug for AugmentedMatrix<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let (rows, columns) = self.dimension();
+        let shown_rows = rows.min(DEBUG_MAX_ROWS);
+        f.debug_struct("AugmentedMatrix")
+            .field("dim", &(rows, columns))
+            .field("alignment", &self.alignment)
+            .field("data", &debug_rows(rows, columns, |r, c| &self[(r, c)]))
+            .field("solution", &DebugRow {
+                elements: (0..shown_rows).map(|r| &self[(r, columns)]).collect(),
+                more: shown_rows < rows
+            })
+            .finish()
+    }
+}
+
+/// Shows the matrix one row per line, with the solution column after a bar. Each element is
+/// measured and then written, right-aligned in its column by its width in `char`s.
 impl<T: Display> Display for AugmentedMatrix<T> {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        let mut matr = String::from(""); // Will contain string for entire matrix
-        let mut longest_in_column: Vec<usize> = Vec::with_capacity(self.num_columns() + 1);
-        for _ in 0..self.num_columns() + 1 {
-            longest_in_column.push(0);
-        }
-        for a in 0..self.num_rows() {
-            for b in 0..self.num_columns() + 1 {
-                if self[(a, b)].to_string().len() > longest_in_column[b] {
-                    longest_in_column[b] = self[(a, b)].to_string().len();
-                }
-            }
-        }
-        for a in 0..self.num_rows() {
-            let mut line = String::from(""); // String for each individual line
-            // Add the appropriate character for the section of the bracket at the start of each line
-            if a == 0 {
-                line = format!("┌ {}", line);
-            } else if a == self.num_rows() - 1 {
-                line = format!("└ {}", line);
+        let (rows, columns) = self.dimension();
+        let widths = column_widths(&self.matrix, self.columns, &self.alignment, rows,
+            0..columns + 1, display_width);
+        let mut cell = String::new();
+        for a in 0..rows {
+            // The bracket section at the start and end of each line (plain brackets for a single
+            // row, which has no top or bottom)
+            let (open, close) = if rows == 1 {
+                ("[", "]")
+            } else if a == 0 {
+                ("┌", "┐")
+            } else if a == rows - 1 {
+                ("└", "┘")
             } else {
-                line = format!("│ {}", line);
-            }
-            // Add spacing to line up the right side of the numbers in each column
-            for b in 0..self.num_columns() + 1 {
-                let mut spacer_left = String::from("");
-                let elem_string = self[(a, b)].to_string();
-                for _ in 0..longest_in_column[b] - elem_string.len() {
-                    spacer_left = format!("{}{}", spacer_left, " ");
+                ("│", "│")
+            };
+            if a > 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{} ", open)?;
+            // Right-align each element in its column
+            for (b, &width) in widths.iter().enumerate() {
+                if b == columns {
+                    f.write_str("│ ")?;
                 }
-                if b == self.num_columns() {
-                    line = format!("{}│ {}{}", line, spacer_left, elem_string);
-                } else if b == self.num_columns() - 1 {
-                    line = format!("{}{}{} ", line, spacer_left, elem_string);
-                } else {
-                    line = format!("{}{}{}  ", line, spacer_left, elem_string);
+                write_padded(f, &mut cell, &self[(a, b)], width)?;
+                if b + 1 == columns {
+                    f.write_str(" ")?;
+                } else if b < columns {
+                    f.write_str("  ")?;
                 }
             }
-            // Append appropriate end symbol for bracket section at the end of each line
-            if a == 0 {
-                line = format!("{} ┐", line);
-            } else if a == self.num_rows() - 1 {
-                line = format!("{} ┘", line);
-            } else {
-                line = format!("{} │", line);
-            }
-            // Add line to matrix string, add newline if it's not the last line
-            if a == self.num_rows() - 1 {
-                matr = format!("{}{}", matr, line);
-            } else {
-                matr = format!("{}{}\n", matr, line);
-            }
+            write!(f, " {}", close)?;
         }
-        write!(f, "{}", matr)
+        Ok(())
     }
 }
 
+/// Shows the matrix one row per line. Each element is measured and then written, right-aligned in
+/// its column by its width in `char`s.
 impl<T: Display> Display for Matrix<T> {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        let mut matr = String::from(""); // Will contain string for entire matrix
-        let mut longest_in_column: Vec<usize> = Vec::with_capacity(self.num_columns());
-        for _ in 0..self.num_columns() {
-            longest_in_column.push(0);
-        }
-        for a in 0..self.num_rows() {
-            for b in 0..self.num_columns() {
-                if self[(a, b)].to_string().len() > longest_in_column[b] {
-                    longest_in_column[b] = self[(a, b)].to_string().len();
-                }
-            }
-        }
-        for a in 0..self.num_rows() {
-            let mut line = String::from(""); // String for each individual line
-            // Add the appropriate character for the section of the bracket at the start of each line
-            if a == 0 {
-                line = format!("⎡ {}", line);
-            } else if a == self.num_rows() - 1 {
-                line = format!("⎣ {}", line);
+        let (rows, columns) = self.dimension();
+        let widths = column_widths(&self.matrix, self.columns, &self.alignment, rows, 0..columns,
+            display_width);
+        let mut cell = String::new();
+        for a in 0..rows {
+            // The bracket section at the start and end of each line (plain brackets for a single
+            // row, which has no top or bottom)
+            let (open, close) = if rows == 1 {
+                ("[", "]")
+            } else if a == 0 {
+                ("⎡", "⎤")
+            } else if a == rows - 1 {
+                ("⎣", "⎦")
             } else {
-                line = format!("⎢ {}", line);
-            }
-            // Add spacing to line up the right side of the numbers in each column
-            for b in 0..self.num_columns() {
-                let mut spacer_left = String::from("");
-                let elem_string = self[(a, b)].to_string();
-                for _ in 0..longest_in_column[b] - elem_string.len() {
-                    spacer_left = format!("{}{}", spacer_left, " ");
-                }
-                if b == self.num_columns() - 1 {
-                    line = format!("{}{}{} ", line, spacer_left, elem_string);
-                } else {
-                    line = format!("{}{}{}  ", line, spacer_left, elem_string);
-                }
-            }
-            // Append appropriate end symbol for bracket section at the end of each line
-            if a == 0 {
-                line = format!("{} ⎤", line);
-            } else if a == self.num_rows() - 1 {
-                line = format!("{} ⎦", line);
-            } else {
-                line = format!("{} ⎥", line);
-            }
-            // Add line to matrix string, add newline if it's not the last line
-            if a == self.num_rows() - 1 {
-                matr = format!("{}{}", matr, line);
-            } else {
-                matr = format!("{}{}\n", matr, line);
-            }
+                ("⎢", "⎥")
+            };
+            if a > 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{} ", open)?;
+            // Right-align each element in its column
+            for (b, &width) in widths.iter().enumerate() {
+                write_padded(f, &mut cell, &self[(a, b)], width)?;
+                f.write_str(if b + 1 == columns { " " } else { "  " })?;
+            }
+            write!(f, " {}", close)?;
         }
-        write!(f, "{}", matr)
+        Ok(())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,211 @@
+//! Iterative eigenvalue estimates for `Matrix<f64>`.
+
+use matrices::base::{Matrix, MatrixError};
+
+// Dense LU factorization (with partial pivoting) of a square matrix, kept around so that repeated
+// solves against the same matrix only pay for the factorization once.
+struct LuFactors {
+    n: usize,
+    lu: Vec<f64>,
+    perm: Vec<usize>
+}
+
+impl LuFactors {
+    fn new(mut lu: Vec<f64>, n: usize) -> Result<LuFactors, MatrixError> {
+        let scale = lu.iter().fold(0.0f64, |acc, e| acc.max(e.abs()));
+        let threshold = f64::EPSILON * scale * n as f64;
+        let mut perm = (0..n).collect::<Vec<usize>>();
+        for c in 0..n {
+            let mut pivot_row = c;
+            for r in c + 1..n {
+                if lu[r * n + c].abs() > lu[pivot_row * n + c].abs() {
+                    pivot_row = r;
+                }
+            }
+            if lu[pivot_row * n + c].abs() <= threshold {
+                return Err(MatrixError::FunctionError("Matrix is singular (or numerically \
+                singular) - unable to factorize it.".to_string()));
+            }
+            if pivot_row != c {
+                for k in 0..n {
+                    lu.swap(c * n + k, pivot_row * n + k);
+                }
+                perm.swap(c, pivot_row);
+            }
+            for r in c + 1..n {
+                let factor = lu[r * n + c] / lu[c * n + c];
+                lu[r * n + c] = factor;
+                for k in c + 1..n {
+                    lu[r * n + k] -= factor * lu[c * n + k];
+                }
+            }
+        }
+        Ok(LuFactors {
+            n,
+            lu,
+            perm
+        })
+    }
+
+    fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.n;
+        let mut x = self.perm.iter().map(|&p| b[p]).collect::<Vec<f64>>();
+        for r in 0..n {
+            for k in 0..r {
+                x[r] -= self.lu[r * n + k] * x[k];
+            }
+        }
+        for r in (0..n).rev() {
+            for k in r + 1..n {
+                x[r] -= self.lu[r * n + k] * x[k];
+            }
+            x[r] /= self.lu[r * n + r];
+        }
+        x
+    }
+}
+
+fn norm(v: &[f64]) -> f64 {
+    v.iter().map(|e| e * e).sum::<f64>().sqrt()
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+// Deterministic starting vector with a component along every axis, so it isn't orthogonal to any
+// eigenvector of a diagonal matrix.
+fn start_vector(n: usize) -> Vec<f64> {
+    let v = (0..n).map(|i| (i + 1) as f64).collect::<Vec<f64>>();
+    let v_norm = norm(&v);
+    v.into_iter().map(|e| e / v_norm).collect()
+}
+
+fn square_dimension(matrix: &Matrix<f64>) -> Result<usize, MatrixError> {
+    let (rows, columns) = matrix.dimension();
+    if rows != columns || rows == 0 {
+        return Err(MatrixError::FunctionError(format!("Eigenvalue estimates need a non-empty \
+        square matrix (dimension: {:?}).", matrix.dimension())));
+    }
+    Ok(rows)
+}
+
+fn mul_vector(matrix: &Matrix<f64>, v: &[f64]) -> Vec<f64> {
+    (0..matrix.num_rows())
+        .map(|r| (0..matrix.num_columns()).map(|c| matrix[(r, c)] * v[c]).sum())
+        .collect()
+}
+
+// Power iteration; returns the magnitude of the dominant eigenvalue. `||Av||` is used as the
+// estimate rather than the Rayleigh quotient so that a ±λ pair still converges.
+fn dominant_eigen_magnitude(matrix: &Matrix<f64>, max_iter: usize, tol: f64)
+    -> Result<f64, MatrixError> {
+    let n = square_dimension(matrix)?;
+    let mut v = start_vector(n);
+    let mut prev = 0.0;
+    for _ in 0..max_iter {
+        let w = mul_vector(matrix, &v);
+        let magnitude = norm(&w);
+        if magnitude == 0.0 {
+            return Ok(0.0);
+        }
+        if (magnitude - prev).abs() <= tol * magnitude {
+            return Ok(magnitude);
+        }
+        prev = magnitude;
+        v = w.into_iter().map(|e| e / magnitude).collect();
+    }
+    Err(MatrixError::FunctionError(format!("Power iteration did not converge within {} \
+    iterations.", max_iter)))
+}
+
+impl Matrix<f64> {
+    /// Finds the eigenvalue closest to `shift` (and a unit eigenvector for it) using shifted
+    /// inverse iteration. With a `shift` of `0.0` this is the smallest-magnitude eigenvalue. `A -
+    /// shift * I` is factorized once and every iteration reuses the factorization.
+    ///
+    /// Returns an error if the matrix isn't square, if `A - shift * I` is singular (i.e. `shift`
+    /// is already an eigenvalue), or if the estimate doesn't settle to within a relative `tol`
+    /// in `max_iter` iterations.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+    /// let foo = Matrix::new_from_vec((3, 3), vec![
+    ///     4.0, 0.0, 0.0,
+    ///     0.0, 2.0, 0.0,
+    ///     0.0, 0.0, 7.0
+    /// ], RowAligned).unwrap();
+    /// let (value, vector) = foo.smallest_eigen(0.0, 200, 1e-14).unwrap();
+    /// assert!((value - 2.0).abs() < 1e-12);
+    /// assert!((vector[1].abs() - 1.0).abs() < 1e-12);
+    /// assert!(foo.smallest_eigen(2.0, 200, 1e-14).is_err());
+    /// ```
+    pub fn smallest_eigen(&self, shift: f64, max_iter: usize, tol: f64)
+        -> Result<(f64, Vec<f64>), MatrixError> {
+        let n = square_dimension(self)?;
+        let mut shifted = Vec::with_capacity(n * n);
+        for r in 0..n {
+            for c in 0..n {
+                if r == c {
+                    shifted.push(self[(r, c)] - shift);
+                } else {
+                    shifted.push(self[(r, c)]);
+                }
+            }
+        }
+        let factors = LuFactors::new(shifted, n)?;
+        let mut v = start_vector(n);
+        let mut prev: Option<f64> = None;
+        for _ in 0..max_iter {
+            let w = factors.solve(&v);
+            let w_norm = norm(&w);
+            // v is a unit vector, so v . w approaches 1 / (λ - shift) as v approaches an
+            // eigenvector.
+            let mu = dot(&v, &w);
+            if mu == 0.0 || w_norm == 0.0 || !w_norm.is_finite() {
+                return Err(MatrixError::FunctionError("Inverse iteration broke down - the shifted \
+                matrix is numerically singular.".to_string()));
+            }
+            let value = shift + 1.0 / mu;
+            v = w.into_iter().map(|e| e / w_norm).collect();
+            if let Some(p) = prev {
+                if (value - p).abs() <= tol * value.abs().max(f64::MIN_POSITIVE) {
+                    return Ok((value, v));
+                }
+            }
+            prev = Some(value);
+        }
+        Err(MatrixError::FunctionError(format!("Inverse iteration did not converge within {} \
+        iterations.", max_iter)))
+    }
+
+    /// Estimates the spectral condition number `|λ|max / |λ|min` of a symmetric matrix, using
+    /// power iteration for the dominant eigenvalue and inverse iteration for the smallest one.
+    /// Returns an error for non-symmetric or singular matrices.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+    /// let foo = Matrix::new_from_vec((2, 2), vec![4.0, 0.0, 0.0, 0.5], RowAligned).unwrap();
+    /// assert!((foo.spectral_condition_sym().unwrap() - 8.0).abs() < 1e-9);
+    /// let bar = Matrix::new_from_vec((2, 2), vec![1.0, 2.0, 3.0, 4.0], RowAligned).unwrap();
+    /// assert!(bar.spectral_condition_sym().is_err());
+    /// ```
+    pub fn spectral_condition_sym(&self) -> Result<f64, MatrixError> {
+        let n = square_dimension(self)?;
+        for r in 0..n {
+            for c in r + 1..n {
+                if self[(r, c)] != self[(c, r)] {
+                    return Err(MatrixError::FunctionError(format!("Matrix is not symmetric \
+                    (element ({}, {}) differs from element ({1}, {0})).", r, c)));
+                }
+            }
+        }
+        let max_iter = 10_000;
+        let tol = 1e-12;
+        let largest = dominant_eigen_magnitude(self, max_iter, tol)?;
+        let (smallest, _) = self.smallest_eigen(0.0, max_iter, tol)?;
+        Ok(largest / smallest.abs())
+    }
+}
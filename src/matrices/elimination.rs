@@ -0,0 +1,345 @@
+//! Exact Gauss-Jordan solving for `AugmentedMatrix<Fraction>`, with an optional preordering of
+//! the equations to keep intermediate denominators small, and `Rref` for building up a reduced
+//! system one equation at a time.
+
+use num::Zero;
+
+use fractions::base::Fraction;
+use matrices::base::{Alignment, AugmentedMatrix, Matrix, MatrixError, RowIdx};
+use matrices::transforms::no_unique_solution;
+
+/// Options for `AugmentedMatrix::<Fraction>::solve_exact`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EliminationOptions {
+    /// Reorder the equations with `reorder_for_elimination` before eliminating.
+    pub preorder: bool
+}
+
+/// The result of `AugmentedMatrix::<Fraction>::solve_exact`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExactSolution {
+    /// The value of each variable, in column order.
+    pub values: Vec<Fraction>,
+    /// `row_order[i]` is the index (in the original system) of the equation that ended up as row
+    /// `i` of the reduced system, after any preordering and pivoting.
+    pub row_order: Vec<RowIdx>,
+    /// The largest denominator of any entry seen during elimination.
+    pub max_denominator: i64
+}
+
+// Sort key for the preordering: rows with fewer non-zero coefficients first, then rows whose
+// largest |num * den| is smaller. Only the first `coefficients` entries of the row are looked at.
+fn elimination_key(row: &[Fraction], coefficients: usize) -> (usize, i128) {
+    let mut nonzeros = 0;
+    let mut size = 0;
+    for f in &row[..coefficients] {
+        let mut f = *f;
+        f.simplify();
+        if f.num != 0 {
+            nonzeros += 1;
+            size = size.max((f.num as i128 * f.den as i128).abs());
+        }
+    }
+    (nonzeros, size)
+}
+
+// Stable-sorts the logical rows of either matrix type by `elimination_key` and returns the
+// permutation (new row `i` is old row `perm[i]`).
+macro_rules! reorder_rows {
+    ($matrix:expr) => {{
+        let width = if $matrix.is_row_aligned() { $matrix.columns } else { $matrix.rows };
+        let coefficients = $matrix.num_columns();
+        let rows = (0..$matrix.num_rows())
+            .map(|r| (0..width).map(|c| $matrix[(r, c)]).collect::<Vec<Fraction>>())
+            .collect::<Vec<Vec<Fraction>>>();
+        let mut perm = (0..rows.len()).collect::<Vec<usize>>();
+        perm.sort_by_key(|&r| elimination_key(&rows[r], coefficients));
+        for (new, &old) in perm.iter().enumerate() {
+            for c in 0..width {
+                $matrix[(new, c)] = rows[old][c];
+            }
+        }
+        perm
+    }};
+}
+
+impl Matrix<Fraction> {
+    /// Reorders the rows to reduce fill-in and denominator growth during exact elimination: rows
+    /// with the fewest non-zero entries go first, ties broken by the smallest largest
+    /// `|num * den|` of their entries (a cheap Markowitz-style ordering). The sort is stable.
+    /// Returns the permutation applied - row `i` afterwards is row `perm[i]` from before.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+    /// let mut foo = Matrix::new_from_vec((3, 2), vec![
+    ///     Fraction::from(3), Fraction::new(1, 2),
+    ///     Fraction::from(0), Fraction::from(5),
+    ///     Fraction::from(0), Fraction::from(2)
+    /// ], RowAligned).unwrap();
+    /// assert_eq!(foo.reorder_for_elimination(), vec![2, 1, 0]);
+    /// assert_eq!(foo[(0, 1)], Fraction::from(2));
+    /// assert_eq!(foo[(2, 0)], Fraction::from(3));
+    /// ```
+    pub fn reorder_for_elimination(&mut self) -> Vec<usize> {
+        reorder_rows!(self)
+    }
+}
+
+impl AugmentedMatrix<Fraction> {
+    /// Reorders the equations the same way as `Matrix::<Fraction>::reorder_for_elimination`,
+    /// looking only at the coefficients (the solution column moves with its row but doesn't
+    /// affect the order). Returns the permutation applied - equation `i` afterwards is equation
+    /// `perm[i]` from before.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// # use fractions_and_matrices::matrices::base::{AugmentedMatrix, Alignment::RowAligned};
+    /// let mut foo = AugmentedMatrix::new_from_vec((2, 3), vec![
+    ///     Fraction::from(1), Fraction::from(1), Fraction::from(100),
+    ///     Fraction::from(0), Fraction::from(4), Fraction::from(1)
+    /// ], RowAligned).unwrap();
+    /// assert_eq!(foo.reorder_for_elimination(), vec![1, 0]);
+    /// assert_eq!(foo.solution_column(), vec![Fraction::from(1), Fraction::from(100)]);
+    /// ```
+    pub fn reorder_for_elimination(&mut self) -> Vec<usize> {
+        reorder_rows!(self)
+    }
+
+    /// Solves a square system exactly with Gauss-Jordan elimination, taking the first row (in
+    /// the current order) with a non-zero entry as each pivot. With `options.preorder` the
+    /// equations are first reordered by `reorder_for_elimination`; the solution is the same
+    /// either way, and `row_order` in the result maps the reduced rows back to the original
+    /// equations. Returns a `FunctionError` if the system isn't square, and the same kind of
+    /// `TransformError` as `Solve::solve()` (saying whether the reduced system is inconsistent or
+    /// underdetermined) if it doesn't have a unique solution. `Solve` works on any augmented
+    /// matrix, but doesn't preorder or keep track of `row_order` and `max_denominator`.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// # use fractions_and_matrices::matrices::base::{AugmentedMatrix, Alignment::RowAligned,
+    /// #     RowIdx};
+    /// # use fractions_and_matrices::matrices::elimination::EliminationOptions;
+    /// let foo = AugmentedMatrix::new_from_vec((2, 3), vec![
+    ///     Fraction::from(3), Fraction::from(5), Fraction::from(8),
+    ///     Fraction::from(1), Fraction::new(1, 2), Fraction::new(3, 2)
+    /// ], RowAligned).unwrap();
+    /// let plain = foo.solve_exact(EliminationOptions::default()).unwrap();
+    /// assert_eq!(plain.values, vec![Fraction::from(1), Fraction::from(1)]);
+    /// assert_eq!(plain.row_order, vec![RowIdx(0), RowIdx(1)]);
+    /// let preordered = foo.solve_exact(EliminationOptions { preorder: true }).unwrap();
+    /// assert_eq!(preordered.values, plain.values);
+    /// assert_eq!(preordered.row_order, vec![RowIdx(1), RowIdx(0)]);
+    /// ```
+    pub fn solve_exact(&self, options: EliminationOptions) -> Result<ExactSolution, MatrixError> {
+        let n = self.num_columns();
+        if self.num_rows() != n {
+            return Err(MatrixError::FunctionError(format!("Exact solving needs a square system \
+            (dimension: {:?}).", self.dimension())));
+        }
+        let mut work = self.clone();
+        let mut row_order = if options.preorder {
+            work.reorder_for_elimination()
+        } else {
+            (0..n).collect()
+        };
+        let mut rows = (0..n)
+            .map(|r| (0..n + 1).map(|c| work[(r, c)]).collect::<Vec<Fraction>>())
+            .collect::<Vec<Vec<Fraction>>>();
+        let max_den = |rows: &Vec<Vec<Fraction>>| rows.iter()
+            .flat_map(|row| row.iter())
+            .map(|f| f.den.abs())
+            .max()
+            .unwrap_or(1);
+        let mut max_denominator = max_den(&rows);
+        // The pivot row moves down independently of the column, and a column without a pivot is
+        // skipped, so that a singular system is still fully reduced and can say why it has no
+        // unique solution
+        let mut rank = 0;
+        let mut free_columns = Vec::new();
+        for c in 0..n {
+            let pivot = match (rank..n).find(|&r| !rows[r][c].is_zero()) {
+                Some(pivot) => pivot,
+                None => {
+                    free_columns.push(c);
+                    continue;
+                }
+            };
+            rows.swap(rank, pivot);
+            row_order.swap(rank, pivot);
+            let p = rows[rank][c];
+            for e in rows[rank][c..].iter_mut() {
+                *e /= p;
+            }
+            let pivot_row = rows[rank].clone();
+            for (r, row) in rows.iter_mut().enumerate() {
+                let factor = row[c];
+                if r == rank || factor.is_zero() {
+                    continue;
+                }
+                for (e, &pivot_e) in row[c..].iter_mut().zip(pivot_row[c..].iter()) {
+                    *e -= factor * pivot_e;
+                }
+            }
+            max_denominator = max_denominator.max(max_den(&rows));
+            rank += 1;
+        }
+        if !free_columns.is_empty() {
+            let inconsistent_row = (rank..n).find(|&r| !rows[r][n].is_zero());
+            return Err(no_unique_solution(inconsistent_row, &free_columns));
+        }
+        Ok(ExactSolution {
+            values: rows.iter().map(|row| row[n]).collect(),
+            row_order: row_order.into_iter().map(RowIdx).collect(),
+            max_denominator
+        })
+    }
+}
+
+/// What `Rref::push_equation_and_reduce` did with a new equation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IncrementalOutcome<T> {
+    /// The equation is a combination of the ones already in the system, so nothing changed.
+    /// `solution` is the unique solution if the system already had one.
+    Redundant { solution: Option<Vec<T>> },
+    /// The equation gave a new pivot in `column` and was added to the system. `solution` is the
+    /// unique solution if the system now has one.
+    NewPivot { column: usize, solution: Option<Vec<T>> },
+    /// The equation contradicts the system (it reduces to `0 = c` with `c != 0`). It wasn't
+    /// added, and the system is unchanged.
+    Inconsistent
+}
+
+/// A consistent system of equations kept in reduced row echelon form, so that equations can be
+/// added one at a time: each new equation only has to be reduced against the existing pivots
+/// (and its own pivot column cleared from the other rows), which is `O(n²)` rather than the
+/// `O(n³)` of solving again from scratch. Only independent equations are stored, sorted by pivot
+/// column.
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::fractions::base::Fraction;
+/// # use fractions_and_matrices::matrices::elimination::{IncrementalOutcome, Rref};
+/// let f = |v: &[i64]| v.iter().map(|&x| Fraction::from(x)).collect::<Vec<Fraction>>();
+/// let mut foo = Rref::new(2);
+/// // x + y = 3
+/// let first = foo.push_equation_and_reduce(&f(&[1, 1, 3])).unwrap();
+/// assert_eq!(first, IncrementalOutcome::NewPivot { column: 0, solution: None });
+/// // 2x + 2y = 6 doesn't add anything, 2x + 2y = 7 contradicts the system
+/// assert_eq!(foo.push_equation_and_reduce(&f(&[2, 2, 6])).unwrap(),
+///     IncrementalOutcome::Redundant { solution: None });
+/// assert_eq!(foo.push_equation_and_reduce(&f(&[2, 2, 7])).unwrap(),
+///     IncrementalOutcome::Inconsistent);
+/// // x - y = 1
+/// assert_eq!(foo.push_equation_and_reduce(&f(&[1, -1, 1])).unwrap(),
+///     IncrementalOutcome::NewPivot { column: 1, solution: Some(f(&[2, 1])) });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rref {
+    columns: usize,
+    // Each row has `columns` coefficients followed by the solution, a 1 in its pivot column and
+    // 0 in every other row's pivot column.
+    rows: Vec<Vec<Fraction>>,
+    pivots: Vec<usize>
+}
+
+impl Rref {
+    /// Makes an empty system in `columns` unknowns.
+    pub fn new(columns: usize) -> Rref {
+        Rref {
+            columns,
+            rows: Vec::new(),
+            pivots: Vec::new()
+        }
+    }
+
+    /// Reduces an augmented system by pushing its equations one at a time. Returns an error if
+    /// the system is inconsistent.
+    pub fn from_system(system: &AugmentedMatrix<Fraction>) -> Result<Rref, MatrixError> {
+        let columns = system.num_columns();
+        let mut rref = Rref::new(columns);
+        for r in 0..system.num_rows() {
+            let row = (0..columns + 1).map(|c| system[(r, c)]).collect::<Vec<Fraction>>();
+            if rref.push_equation_and_reduce(&row)? == IncrementalOutcome::Inconsistent {
+                return Err(MatrixError::FunctionError(format!("Equation {} is inconsistent \
+                with the ones before it.", r)));
+            }
+        }
+        Ok(rref)
+    }
+
+    /// Gets the pivot column of each stored equation, in increasing order.
+    pub fn pivot_columns(&self) -> &[usize] {
+        &self.pivots
+    }
+
+    /// Gets the unique solution, or `None` if some unknowns aren't determined yet.
+    pub fn solution(&self) -> Option<Vec<Fraction>> {
+        if self.pivots.len() < self.columns {
+            return None;
+        }
+        Some(self.rows.iter().map(|row| row[self.columns]).collect())
+    }
+
+    /// Copies the reduced system (the independent equations only) into a row-aligned
+    /// `AugmentedMatrix`.
+    pub fn to_augmented_matrix(&self) -> Result<AugmentedMatrix<Fraction>, MatrixError> {
+        AugmentedMatrix::new_from_vec((self.rows.len(), self.columns + 1),
+            self.rows.iter().flat_map(|row| row.iter().cloned()).collect(),
+            Alignment::RowAligned)
+    }
+
+    /// Adds an equation (`columns` coefficients followed by the right hand side) to the system.
+    /// The equation is first reduced against the existing pivots. If that leaves no coefficients
+    /// it's either redundant or inconsistent, and the system is left alone; otherwise its first
+    /// remaining coefficient becomes a new pivot, which is then eliminated from the other
+    /// equations. Returns an error if the equation has the wrong length.
+    pub fn push_equation_and_reduce(&mut self, row: &[Fraction])
+        -> Result<IncrementalOutcome<Fraction>, MatrixError> {
+        let n = self.columns;
+        if row.len() != n + 1 {
+            return Err(MatrixError::FunctionError(format!("Attempted to add an equation with {} \
+            elements to a system in {} unknowns.", row.len(), n)));
+        }
+        let mut new = row.to_vec();
+        for (existing, &p) in self.rows.iter().zip(self.pivots.iter()) {
+            let factor = new[p];
+            if factor.is_zero() {
+                continue;
+            }
+            for (e, &x) in new[p..].iter_mut().zip(existing[p..].iter()) {
+                *e -= factor * x;
+            }
+        }
+        let column = match new[..n].iter().position(|e| !e.is_zero()) {
+            Some(column) => column,
+            None if new[n].is_zero() => return Ok(IncrementalOutcome::Redundant {
+                solution: self.solution()
+            }),
+            None => return Ok(IncrementalOutcome::Inconsistent)
+        };
+        let p = new[column];
+        for e in new[column..].iter_mut() {
+            *e /= p;
+        }
+        for existing in self.rows.iter_mut() {
+            let factor = existing[column];
+            if factor.is_zero() {
+                continue;
+            }
+            for (e, &x) in existing[column..].iter_mut().zip(new[column..].iter()) {
+                *e -= factor * x;
+            }
+        }
+        let at = self.pivots.iter().position(|&q| q > column).unwrap_or(self.pivots.len());
+        self.rows.insert(at, new);
+        self.pivots.insert(at, column);
+        Ok(IncrementalOutcome::NewPivot {
+            column,
+            solution: self.solution()
+        })
+    }
+}
@@ -23,20 +23,13 @@ impl<T> Matrix<T> {
     /// assert_eq!(foo, bar);
     /// ```
     pub fn pop_column(&mut self) {
-        if self.is_column_aligned() {
-            for _ in 0..self.rows {
-                drop(self.matrix.pop());
-            }
-            self.rows -= 1;
-        } else {
-            for c in (1..self.num_rows() + 1).rev() {
-                self.matrix.remove(self.columns * c - 1);
-            }
-            self.columns -= 1;
-        }
+        assert!(self.num_columns() > 0, "Tried to pop a column from a matrix with no columns.");
+        let last = self.num_columns() - 1;
+        self.remove_column(last);
     }
 
-    /// Removes a column from a matrix. Panics on out of bounds.
+    /// Removes a column from a matrix. Valid columns are `0..num_columns()`; anything else panics
+    /// (use `pop_column()` to remove the last column).
     /// # Example
     /// ```rust
     /// # #[macro_use] extern crate fractions_and_matrices;
@@ -59,13 +52,10 @@ impl<T> Matrix<T> {
     /// assert_eq!(foo, bar);
     /// ```
     pub fn remove_column(&mut self, column: usize) {
-        assert!(column <= self.num_columns());
-        if column == self.num_columns() {
-            self.pop_column();
-            return;
-        }
+        assert!(column < self.num_columns(), "Tried to remove column {} from a matrix with {} \
+        columns (use `pop_column()` to remove the last one).", column, self.num_columns());
         if self.is_column_aligned() {
-            self.matrix.drain(column * self.rows..(column + 1) * self.rows);
+            self.matrix.drain(column * self.columns..(column + 1) * self.columns);
             self.rows -= 1;
         } else {
             for r in (0..self.num_rows()).rev() {
@@ -77,7 +67,8 @@ impl<T> Matrix<T> {
 }
 
 impl<T> AugmentedMatrix<T> {
-    /// Removes the last column from an augmented matrix, similarly to `pop()` for vectors.
+    /// Removes the last coefficient column from an augmented matrix, similarly to `pop()` for
+    /// vectors. The solution column stays.
     /// # Example
     /// ```rust
     /// # #[macro_use] extern crate fractions_and_matrices;
@@ -98,20 +89,14 @@ impl<T> AugmentedMatrix<T> {
     /// assert_eq!(foo, bar);
     /// ```
     pub fn pop_column(&mut self) {
-        if self.is_column_aligned() {
-            for _ in 0..self.rows {
-                drop(self.matrix.pop());
-            }
-            self.rows -= 1;
-        } else {
-            for c in (1..self.num_rows() + 1).rev() {
-                self.matrix.remove(self.columns * c - 1);
-            }
-            self.columns -= 1;
-        }
+        assert!(self.num_columns() > 0, "Tried to pop a column from a matrix with no columns.");
+        let last = self.num_columns() - 1;
+        self.remove_column(last);
     }
 
     /// Removes a specified column from an augmented matrix, similarly to `remove(n)` for vectors.
+    /// Valid columns are `0..num_columns()` - the solution column can't be removed - and anything
+    /// else panics.
     /// # Example
     /// ```rust
     /// # #[macro_use] extern crate fractions_and_matrices;
@@ -132,13 +117,10 @@ impl<T> AugmentedMatrix<T> {
     /// assert_eq!(foo, bar);
     /// ```
     pub fn remove_column(&mut self, column: usize) {
-        assert!(column <= self.num_columns());
-        if column == self.num_columns() {
-            self.pop_column();
-            return;
-        }
+        assert!(column < self.num_columns(), "Tried to remove column {} from a matrix with {} \
+        columns (use `pop_column()` to remove the last one).", column, self.num_columns());
         if self.is_column_aligned() {
-            self.matrix.drain(column * self.rows..(column + 1) * self.rows);
+            self.matrix.drain(column * self.columns..(column + 1) * self.columns);
             self.rows -= 1;
         } else {
             for r in (0..self.num_rows()).rev() {
@@ -153,52 +135,47 @@ macro_rules! pop_remove_rows_columns {
     ($($target_type:ty {
         $pop_row_expr:expr,
         $remove_row_expr:expr,
+        $try_remove_row_expr:expr,
         $remove_rows_expr:expr,
         $remove_columns_expr:expr
     }),*) => ($(
         impl<T> $target_type {
             #[doc = $pop_row_expr]
             pub fn pop_row(&mut self) {
-                if self.is_row_aligned() {
-                    for _ in 0..self.columns {
-                        drop(self.matrix.pop());
-                    }
-                    self.rows -= 1;
-                } else {
-                    let r_max = if self.is_row_aligned() {
-                        self.columns
-                    } else {
-                        self.rows
-                    };
-                    for r in (1..r_max).rev() {
-                        self.matrix.remove(r_max * r - 1);
-                    }
-                    self.columns -= 1;
-                }
+                assert!(self.num_rows() > 0, "Tried to pop a row from a matrix with no rows.");
+                let last = self.num_rows() - 1;
+                self.remove_row(last);
             }
 
             #[doc = $remove_row_expr]
             pub fn remove_row(&mut self, row: usize) {
-                assert!(row <= self.num_rows());
-                if row == self.num_rows() {
-                    self.pop_row();
-                    return;
-                }
+                assert!(row < self.num_rows(), "Tried to remove row {} from a matrix with {} rows \
+                (use `pop_row()` to remove the last one).", row, self.num_rows());
                 if self.is_row_aligned() {
                     self.matrix.drain(row * self.columns..(row + 1) * self.columns);
                     self.rows -= 1;
                 } else {
                     for c in (0..self.rows).rev() {
-                        self.matrix.remove(c * self.rows + row);
+                        self.matrix.remove(c * self.columns + row);
                     }
                     self.columns -= 1;
                 }
             }
 
+            #[doc = $try_remove_row_expr]
+            pub fn try_remove_row(&mut self, row: usize) -> Result<(), MatrixError> {
+                if row >= self.num_rows() {
+                    return Err(MatrixError::FunctionError(format!("Attempted to remove row {} \
+                    from a matrix with {} rows.", row, self.num_rows())));
+                }
+                self.remove_row(row);
+                Ok(())
+            }
+
             #[doc = $remove_rows_expr]
             pub fn remove_rows(&mut self, rows: Range<usize>) {
-                assert!(rows.start <= self.num_rows());
-                assert!(rows.end < self.num_rows() + 1);
+                assert!(rows.start <= rows.end && rows.end <= self.num_rows(), "Row range {:?} is \
+                out of bounds for a matrix with {} rows.", rows, self.num_rows());
                 for r in rows.rev() {
                     self.remove_row(r);
                 }
@@ -206,8 +183,9 @@ macro_rules! pop_remove_rows_columns {
 
             #[doc = $remove_columns_expr]
             pub fn remove_columns(&mut self, columns: Range<usize>) {
-                assert!(columns.start <= self.num_columns());
-                assert!(columns.end < self.num_columns() + 1);
+                assert!(columns.start <= columns.end && columns.end <= self.num_columns(), "Column \
+                range {:?} is out of bounds for a matrix with {} columns.", columns,
+                    self.num_columns());
                 for c in columns.rev() {
                     self.remove_column(c);
                 }
@@ -234,8 +212,8 @@ pop_remove_rows_columns!{Matrix<T> {
     ];
     assert_eq!(foo, bar);
     ```",
-    "Removes a row from a matrix, similarly to `remove()` for vectors. Panics if the specified row
-    is outside of the bounds of the matrix.
+    "Removes a row from a matrix, similarly to `remove()` for vectors. Valid rows are
+    `0..num_rows()`; anything else panics (use `pop_row()` to remove the last row).
     # Example
     ```rust
     # #[macro_use] extern crate fractions_and_matrices;
@@ -262,7 +240,24 @@ pop_remove_rows_columns!{Matrix<T> {
          6  7  8  9 10 11;
         12 13 14 15 16 17
     ];
-    foo.remove_row(4);
+    foo.remove_row(3);
+    ```",
+    "Removes a row from a matrix, or returns an error if the row is outside of `0..num_rows()`.
+    # Example
+    ```rust
+    # #[macro_use] extern crate fractions_and_matrices;
+    # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+    let mut foo = matrix![
+        0 1;
+        2 3;
+        4 5
+    ];
+    assert!(foo.try_remove_row(3).is_err());
+    assert!(foo.try_remove_row(2).is_ok());
+    assert_eq!(foo, matrix![
+        0 1;
+        2 3
+    ]);
     ```",
     "Removes a `Range<usize>` of rows from a `Matrix<T>`. Panics if the range goes outside of the
     bounds of the matrix.
@@ -343,8 +338,8 @@ pop_remove_rows_columns!{Matrix<T> {
     ];
     assert_eq!(foo, bar);
     ```",
-    "Removes a row from an augmented matrix, similarly to `remove()` for vectors. Panics if the
-    specified row is outside of the bounds of the augmented matrix.
+    "Removes a row from an augmented matrix, similarly to `remove()` for vectors. Valid rows are
+    `0..num_rows()`; anything else panics (use `pop_row()` to remove the last row).
     # Example
     ```rust
     # #[macro_use] extern crate fractions_and_matrices;
@@ -370,7 +365,21 @@ pop_remove_rows_columns!{Matrix<T> {
          6  7  8  9 10 11 => 1;
         12 13 14 15 16 17 => 2
     ];
-    foo.remove_row(4);
+    foo.remove_row(3);
+    ```",
+    "Removes a row from an augmented matrix, or returns an error if the row is outside of
+    `0..num_rows()`.
+    # Example
+    ```rust
+    # #[macro_use] extern crate fractions_and_matrices;
+    # use fractions_and_matrices::matrices::base::{AugmentedMatrix, Alignment::RowAligned};
+    let mut foo = augmented_matrix![
+        0 1 => 2;
+        3 4 => 5
+    ];
+    assert!(foo.try_remove_row(2).is_err());
+    assert!(foo.try_remove_row(0).is_ok());
+    assert_eq!(foo, augmented_matrix![3 4 => 5]);
     ```",
     "Removes a `Range<usize>` of rows from an `AugmentedMatrix<T>`. Panics if the range goes outside
     of the bounds of the augmented matrix.
@@ -1320,6 +1329,63 @@ impl<T: Clone + Display> AddElements<T> for Matrix<T> {
     }
 }
 
+/// Appends each row yielded by the iterator, as by `push_row()`. Panics at the first row whose
+/// length isn't the number of columns, keeping the rows before it; use `try_extend_rows()` to get
+/// an error instead.
+/// # Example
+/// ```rust
+/// # #[macro_use] extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::matrices::base::Matrix;
+/// let mut foo = matrix![0 1 2];
+/// foo.extend((1..3).map(|r| vec![3 * r, 3 * r + 1, 3 * r + 2]));
+/// assert_eq!(foo, matrix![
+///     0 1 2;
+///     3 4 5;
+///     6 7 8
+/// ]);
+/// ```
+impl<T: Clone + Display, R: AsRef<[T]>> Extend<R> for Matrix<T> {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, rows: I) {
+        if let Err(e) = self.try_extend_rows(rows) {
+            panic!("{}", e);
+        }
+    }
+}
+
+impl<T: Clone + Display> Matrix<T> {
+    /// Like `extend()`, but stops at the first row whose length isn't the number of columns and
+    /// returns a `FunctionError` saying how many rows were appended before it, instead of
+    /// panicking. Those rows stay appended.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// let mut foo = matrix![0 1];
+    /// assert!(foo.try_extend_rows(vec![vec![2, 3], vec![4], vec![5, 6]]).is_err());
+    /// assert_eq!(foo, matrix![
+    ///     0 1;
+    ///     2 3
+    /// ]);
+    /// ```
+    pub fn try_extend_rows<I, R>(&mut self, rows: I) -> Result<(), MatrixError>
+        where I: IntoIterator<Item = R>, R: AsRef<[T]> {
+        let rows = rows.into_iter();
+        if self.is_row_aligned() {
+            self.matrix.reserve(rows.size_hint().0 * self.columns);
+        }
+        for (appended, row) in rows.enumerate() {
+            let row = row.as_ref();
+            if row.len() != self.num_columns() {
+                return Err(MatrixError::FunctionError(format!("Can't append a row of {} elements \
+                to a matrix with {} columns ({} rows were appended before it).", row.len(),
+                    self.num_columns(), appended)));
+            }
+            self.push_row(row);
+        }
+        Ok(())
+    }
+}
+
 impl<T: Clone> AddElements<T> for AugmentedMatrix<T> {
     /// Appends a row to the end of an augmented matrix. Panics of the supplied row does not have
     /// a length equal to the number of columns in the augmented matrix (including the solution
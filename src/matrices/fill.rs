@@ -0,0 +1,107 @@
+//! Overwriting every element, or every element of a row or column, with the same value. A row of a
+//! row-aligned matrix (or a column of a column-aligned one) is one contiguous stretch of the
+//! backing vec and is filled as such; otherwise every `columns`-th element is written.
+//! # Example
+//! ```rust
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::matrices::base::{Matrix, AugmentedMatrix};
+//! let mut foo = matrix![
+//!     1 2 3;
+//!     4 5 6
+//! ];
+//! foo.fill_column(0, 0);
+//! foo.fill_row(1, 9);
+//! assert_eq!(foo, matrix![
+//!     0 2 3;
+//!     9 9 9
+//! ]);
+//! assert!(foo.try_fill_column(3, 0).is_err());
+//! let mut bar = augmented_matrix![
+//!     1 2 => 3;
+//!     4 5 => 6
+//! ];
+//! bar.fill_column(1, 0);
+//! bar.fill_solution(7);
+//! assert_eq!(bar, augmented_matrix![
+//!     1 0 => 7;
+//!     4 0 => 7
+//! ]);
+//! ```
+
+use matrices::base::{AugmentedMatrix, Matrix, MatrixError};
+
+macro_rules! fill_impls {
+    ($($target_type:ident),*) => ($(
+        impl<T: Clone> $target_type<T> {
+            /// Sets every element (including the solution column of augmented matrices) to
+            /// `value`.
+            pub fn fill(&mut self, value: T) {
+                self.matrix.fill(value);
+            }
+
+            /// Sets every element of row `r` (including its solution, for augmented matrices) to
+            /// `value`. Panics if there's no row `r`.
+            pub fn fill_row(&mut self, r: usize, value: T) {
+                if let Err(e) = self.try_fill_row(r, value) {
+                    panic!("{}", e);
+                }
+            }
+
+            /// Like `fill_row()`, but returns a `FunctionError` instead of panicking if there's
+            /// no row `r`.
+            pub fn try_fill_row(&mut self, r: usize, value: T) -> Result<(), MatrixError> {
+                if r >= self.num_rows() {
+                    return Err(MatrixError::FunctionError(format!("Can't fill row {} of a \
+                    matrix with {} rows.", r, self.num_rows())));
+                }
+                let stored = self.is_row_aligned();
+                self.fill_line(r, stored, value);
+                Ok(())
+            }
+
+            /// Sets every element of column `c` to `value`. Panics if there's no column `c`. The
+            /// solution column of augmented matrices doesn't count: see `fill_solution()`.
+            pub fn fill_column(&mut self, c: usize, value: T) {
+                if let Err(e) = self.try_fill_column(c, value) {
+                    panic!("{}", e);
+                }
+            }
+
+            /// Like `fill_column()`, but returns a `FunctionError` instead of panicking if there's
+            /// no column `c`.
+            pub fn try_fill_column(&mut self, c: usize, value: T) -> Result<(), MatrixError> {
+                if c >= self.num_columns() {
+                    return Err(MatrixError::FunctionError(format!("Can't fill column {} of a \
+                    matrix with {} columns.", c, self.num_columns())));
+                }
+                let stored = self.is_column_aligned();
+                self.fill_line(c, stored, value);
+                Ok(())
+            }
+
+            // Fills stored line `index` if `stored`, or else the `index`th element of every
+            // stored line.
+            fn fill_line(&mut self, index: usize, stored: bool, value: T) {
+                let stride = self.columns;
+                if stored {
+                    self.matrix[index * stride..(index + 1) * stride].fill(value);
+                } else {
+                    for e in self.matrix.iter_mut().skip(index).step_by(stride) {
+                        *e = value.clone();
+                    }
+                }
+            }
+        }
+    )*)
+}
+
+fill_impls!{Matrix, AugmentedMatrix}
+
+impl<T: Clone> AugmentedMatrix<T> {
+    /// Sets every element of the solution column to `value`, which `fill_column()` won't.
+    pub fn fill_solution(&mut self, value: T) {
+        let c = self.num_columns();
+        let stored = self.is_column_aligned();
+        self.fill_line(c, stored, value);
+    }
+}
@@ -0,0 +1,793 @@
+//! Iterators over every element of a matrix in logical row-major order - `(0, 0)`, `(0, 1)`, ...,
+//! `(1, 0)`, ... - whatever the alignment, so code that visits every element doesn't need nested
+//! index loops or to know how the backing vec is laid out. On augmented matrices each row's
+//! solution is yielded after its coefficients.
+//! # Example
+//! ```rust
+//! # extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::matrices::base::{Matrix, Alignment::ColumnAligned};
+//! let mut foo = Matrix::new_from_vec((2, 3), vec![1, 4, 2, 5, 3, 6], ColumnAligned).unwrap();
+//! assert_eq!(foo.iter().cloned().collect::<Vec<i32>>(), vec![1, 2, 3, 4, 5, 6]);
+//! for e in &mut foo {
+//!     *e *= 10;
+//! }
+//! assert_eq!(foo[(1, 0)], 40);
+//! assert_eq!(foo.into_iter().rev().next(), Some(60));
+//! ```
+//!
+//! `rows()` and `columns()` (and their `_mut` versions) go a row or column at a time instead.
+//! Only one of the two is contiguous in storage, depending on the alignment, so each row is a
+//! [`RowView`] and each column a [`ColumnView`] (or [`RowViewMut`] and [`ColumnViewMut`]) that can
+//! be indexed and iterated over either way.
+//!
+//! [`RowView`]: struct.RowView.html
+//! [`ColumnView`]: struct.ColumnView.html
+//! [`RowViewMut`]: struct.RowViewMut.html
+//! [`ColumnViewMut`]: struct.ColumnViewMut.html
+
+use std::iter::StepBy;
+use std::mem;
+use std::ops::{Index, IndexMut};
+use std::{slice, vec};
+
+use matrices::base::{Alignment, AugmentedMatrix, Matrix};
+
+// Row-major traversal over the stored lines of a matrix. Row-aligned storage is already in
+// row-major order, so it's a single line covering the whole backing vec. For column-aligned
+// storage every stored column is its own line and each row takes the next element from every line
+// in turn (and `next_back` takes from the back of every line in reverse), so element `i` from the
+// front always comes from line `i % lines.len()`.
+struct Lines<I> {
+    lines: Vec<I>,
+    front: usize,
+    back: usize
+}
+
+impl<I: Iterator> Lines<I> {
+    fn new(lines: Vec<I>, len: usize) -> Self {
+        Lines { lines, front: 0, back: len }
+    }
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let line = self.front % self.lines.len();
+        self.front += 1;
+        self.lines[line].next()
+    }
+
+    fn next_back(&mut self) -> Option<I::Item> where I: DoubleEndedIterator {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let line = self.back % self.lines.len();
+        self.lines[line].next_back()
+    }
+
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+macro_rules! element_iterator {
+    ($(#[$attr:meta])* $name:ident<$($lifetime:lifetime)*>, $inner:ty, $item:ty) => {
+        $(#[$attr])*
+        pub struct $name<$($lifetime,)* T: $($lifetime)*>(Lines<$inner>);
+
+        impl<$($lifetime,)* T> Iterator for $name<$($lifetime,)* T> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<$item> {
+                self.0.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.0.len(), Some(self.0.len()))
+            }
+        }
+
+        impl<$($lifetime,)* T> DoubleEndedIterator for $name<$($lifetime,)* T> {
+            fn next_back(&mut self) -> Option<$item> {
+                self.0.next_back()
+            }
+        }
+
+        impl<$($lifetime,)* T> ExactSizeIterator for $name<$($lifetime,)* T> {}
+    }
+}
+
+element_iterator!{
+    /// Iterator over references to the elements of a matrix in row-major order, returned by
+    /// `iter()` and by iterating over `&Matrix<T>` or `&AugmentedMatrix<T>`.
+    Iter<'a>, slice::Iter<'a, T>, &'a T
+}
+
+element_iterator!{
+    /// Iterator over mutable references to the elements of a matrix in row-major order, returned
+    /// by `iter_mut()` and by iterating over `&mut Matrix<T>` or `&mut AugmentedMatrix<T>`.
+    IterMut<'a>, slice::IterMut<'a, T>, &'a mut T
+}
+
+element_iterator!{
+    /// Iterator that moves the elements out of a matrix in row-major order, returned by iterating
+    /// over a `Matrix<T>` or `AugmentedMatrix<T>`.
+    IntoIter<>, vec::IntoIter<T>, T
+}
+
+macro_rules! indexed_iterator {
+    ($(#[$attr:meta])* $name:ident, $inner:ident, $item:ty) => {
+        $(#[$attr])*
+        pub struct $name<'a, T: 'a> {
+            inner: $inner<'a, T>,
+            // The number of elements in each row, and the position of the next one from the front
+            width: usize,
+            front: usize
+        }
+
+        impl<'a, T> Iterator for $name<'a, T> {
+            type Item = ((usize, usize), $item);
+
+            fn next(&mut self) -> Option<((usize, usize), $item)> {
+                let e = self.inner.next()?;
+                self.front += 1;
+                Some((((self.front - 1) / self.width, (self.front - 1) % self.width), e))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        impl<'a, T> DoubleEndedIterator for $name<'a, T> {
+            fn next_back(&mut self) -> Option<((usize, usize), $item)> {
+                let e = self.inner.next_back()?;
+                let back = self.front + self.inner.len();
+                Some(((back / self.width, back % self.width), e))
+            }
+        }
+
+        impl<'a, T> ExactSizeIterator for $name<'a, T> {}
+    }
+}
+
+indexed_iterator!{
+    /// Iterator over the `(row, column)` indices of the elements of a matrix and references to
+    /// them in row-major order, returned by `indexed_iter()`.
+    IndexedIter, Iter, &'a T
+}
+
+indexed_iterator!{
+    /// Iterator over the `(row, column)` indices of the elements of a matrix and mutable
+    /// references to them in row-major order, returned by `indexed_iter_mut()`.
+    IndexedIterMut, IterMut, &'a mut T
+}
+
+macro_rules! element_iterator_impls {
+    ($($target_type:ident),*) => ($(
+        impl<T> $target_type<T> {
+            /// Iterates over references to the elements in row-major order, regardless of the
+            /// alignment.
+            pub fn iter(&self) -> Iter<'_, T> {
+                let len = self.matrix.len();
+                Iter(Lines::new(match self.alignment {
+                    Alignment::RowAligned => vec![self.matrix.iter()],
+                    Alignment::ColumnAligned if len == 0 => Vec::new(),
+                    Alignment::ColumnAligned => self.matrix.chunks(self.columns)
+                        .map(|line| line.iter())
+                        .collect()
+                }, len))
+            }
+
+            /// Iterates over mutable references to the elements in row-major order, regardless
+            /// of the alignment.
+            pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+                let len = self.matrix.len();
+                IterMut(Lines::new(match self.alignment {
+                    Alignment::RowAligned => vec![self.matrix.iter_mut()],
+                    Alignment::ColumnAligned if len == 0 => Vec::new(),
+                    Alignment::ColumnAligned => self.matrix.chunks_mut(self.columns)
+                        .map(|line| line.iter_mut())
+                        .collect()
+                }, len))
+            }
+
+            /// Like `iter()`, but also yields the `(row, column)` index of each element - the
+            /// logical index, as taken by `matrix[(row, column)]`, whatever the alignment. On
+            /// augmented matrices the solution is in column `num_columns()`.
+            pub fn indexed_iter(&self) -> IndexedIter<'_, T> {
+                IndexedIter { inner: self.iter(), width: self.row_width(), front: 0 }
+            }
+
+            /// Like `iter_mut()`, but also yields the `(row, column)` index of each element. See
+            /// `indexed_iter()`.
+            pub fn indexed_iter_mut(&mut self) -> IndexedIterMut<'_, T> {
+                let width = self.row_width();
+                IndexedIterMut { inner: self.iter_mut(), width, front: 0 }
+            }
+
+            // The number of elements in each row, counting the solution of augmented matrices.
+            pub(crate) fn row_width(&self) -> usize {
+                match self.alignment {
+                    Alignment::RowAligned => self.columns,
+                    Alignment::ColumnAligned => self.rows
+                }
+            }
+
+            /// Iterates over the rows, top to bottom. Each row is a [`RowView`] of its elements
+            /// (including the solution, last, for augmented matrices), which is just a slice of
+            /// the backing vec if the matrix is row-aligned.
+            ///
+            /// [`RowView`]: ../iter/struct.RowView.html
+            pub fn rows(&self) -> Rows<'_, T> {
+                let count = self.num_rows();
+                Rows(Views::new(&self.matrix, self.rows, self.columns, self.is_row_aligned(),
+                    count))
+            }
+
+            /// Iterates over the rows, top to bottom, with mutable access to their elements
+            /// (including the solution, last, for augmented matrices). See [`RowViewMut`].
+            ///
+            /// [`RowViewMut`]: ../iter/struct.RowViewMut.html
+            pub fn rows_mut(&mut self) -> RowsMut<'_, T> {
+                let (lines, stride, count) = (self.rows, self.columns, self.num_rows());
+                let contiguous = self.is_row_aligned();
+                RowsMut(ViewsMut::new(&mut self.matrix, lines, stride, contiguous, count))
+            }
+
+            /// Iterates over the columns, left to right. Each column is a [`ColumnView`] of its
+            /// elements, which is just a slice of the backing vec if the matrix is
+            /// column-aligned. The solution column of augmented matrices isn't included: see
+            /// `solution_view()`.
+            ///
+            /// [`ColumnView`]: ../iter/struct.ColumnView.html
+            pub fn columns(&self) -> Columns<'_, T> {
+                let count = self.num_columns();
+                Columns(Views::new(&self.matrix, self.rows, self.columns,
+                    !self.is_row_aligned(), count))
+            }
+
+            /// Iterates over the columns, left to right, with mutable access to their elements.
+            /// The solution column of augmented matrices isn't included: see
+            /// `solution_view_mut()`. See [`ColumnViewMut`].
+            ///
+            /// [`ColumnViewMut`]: ../iter/struct.ColumnViewMut.html
+            pub fn columns_mut(&mut self) -> ColumnsMut<'_, T> {
+                let (lines, stride, count) = (self.rows, self.columns, self.num_columns());
+                let contiguous = !self.is_row_aligned();
+                ColumnsMut(ViewsMut::new(&mut self.matrix, lines, stride, contiguous, count))
+            }
+        }
+
+        impl<T> IntoIterator for $target_type<T> {
+            type Item = T;
+            type IntoIter = IntoIter<T>;
+
+            fn into_iter(mut self) -> IntoIter<T> {
+                let len = self.matrix.len();
+                IntoIter(Lines::new(match self.alignment {
+                    Alignment::RowAligned => vec![self.matrix.into_iter()],
+                    Alignment::ColumnAligned => {
+                        // Split the stored columns off the back so no element is moved twice.
+                        let mut lines = Vec::with_capacity(self.rows);
+                        while !self.matrix.is_empty() {
+                            let at = self.matrix.len() - self.columns;
+                            lines.push(self.matrix.split_off(at).into_iter());
+                        }
+                        lines.reverse();
+                        lines
+                    }
+                }, len))
+            }
+        }
+
+        impl<'a, T> IntoIterator for &'a $target_type<T> {
+            type Item = &'a T;
+            type IntoIter = Iter<'a, T>;
+
+            fn into_iter(self) -> Iter<'a, T> {
+                self.iter()
+            }
+        }
+
+        impl<'a, T> IntoIterator for &'a mut $target_type<T> {
+            type Item = &'a mut T;
+            type IntoIter = IterMut<'a, T>;
+
+            fn into_iter(self) -> IterMut<'a, T> {
+                self.iter_mut()
+            }
+        }
+    )*)
+}
+
+element_iterator_impls!{Matrix, AugmentedMatrix}
+
+impl<T> AugmentedMatrix<T> {
+    /// Gets a view of the solution column, which `columns()` leaves out. Use `solution_column()`
+    /// for a copy of it instead.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::AugmentedMatrix;
+    /// let mut foo = augmented_matrix![
+    ///     1 2 => 3;
+    ///     4 5 => 6
+    /// ];
+    /// assert_eq!(foo.columns().len(), 2);
+    /// assert_eq!(foo.solution_view().iter().sum::<i32>(), 9);
+    /// foo.column_align();
+    /// assert_eq!(foo.solution_view().as_slice(), Some(&[3, 6][..]));
+    /// ```
+    pub fn solution_view(&self) -> ColumnView<'_, T> {
+        let c = self.num_columns();
+        let views = Views::new(&self.matrix, self.rows, self.columns, !self.is_row_aligned(),
+            c + 1);
+        ColumnView(views.view(c))
+    }
+
+    /// Gets a view of the solution column with mutable access to its elements. See
+    /// `solution_view()`.
+    pub fn solution_view_mut(&mut self) -> ColumnViewMut<'_, T> {
+        let (lines, stride, c) = (self.rows, self.columns, self.num_columns());
+        let contiguous = !self.is_row_aligned();
+        ColumnViewMut(ViewsMut::new(&mut self.matrix, lines, stride, contiguous, c + 1)
+            .next_back().unwrap())
+    }
+}
+
+/// One row of a matrix, returned by `rows()`. A row of a row-aligned matrix is a plain slice of
+/// the backing vec (see `as_slice`); a row of a column-aligned matrix takes one element from each
+/// stored column.
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::ColumnAligned};
+/// let foo = Matrix::new_from_vec((2, 3), vec![1, 4, 2, 5, 3, 6], ColumnAligned).unwrap();
+/// let second = foo.rows().nth(1).unwrap();
+/// assert_eq!(second.len(), 3);
+/// assert_eq!(second[2], 6);
+/// assert_eq!(second.iter().sum::<i32>(), 15);
+/// assert_eq!(second.as_slice(), None);
+/// ```
+pub struct RowView<'a, T: 'a>(View<'a, T>);
+
+/// One column of a matrix, returned by `columns()` and `solution_view()`. A column of a
+/// column-aligned matrix is a plain slice of the backing vec (see `as_slice`); a column of a
+/// row-aligned matrix takes one element from each stored row.
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+/// let foo = Matrix::new_from_vec((2, 3), vec![1, 2, 3, 4, 5, 6], RowAligned).unwrap();
+/// let maxima = foo.columns().map(|c| *c.iter().max().unwrap()).collect::<Vec<i32>>();
+/// assert_eq!(maxima, vec![4, 5, 6]);
+/// assert_eq!(foo.columns().last().unwrap()[0], 3);
+/// ```
+pub struct ColumnView<'a, T: 'a>(View<'a, T>);
+
+/// One row of a matrix with mutable access to its elements, returned by `rows_mut()`. A row of a
+/// row-aligned matrix is a plain slice (see `as_mut_slice`); a row of a column-aligned matrix
+/// holds a reference to its element in each stored column.
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::ColumnAligned};
+/// let mut foo = Matrix::new_from_vec((2, 2), vec![1, 3, 2, 4], ColumnAligned).unwrap();
+/// for (r, mut row) in foo.rows_mut().enumerate() {
+///     row[0] *= 10;
+///     for e in row.iter_mut() {
+///         *e += r as i32;
+///     }
+/// }
+/// assert_eq!(foo[(0, 0)], 10);
+/// assert_eq!(foo[(1, 0)], 31);
+/// assert_eq!(foo[(1, 1)], 5);
+/// ```
+pub struct RowViewMut<'a, T: 'a>(ViewElements<'a, T>);
+
+/// One column of a matrix with mutable access to its elements, returned by `columns_mut()` and
+/// `solution_view_mut()`. A column of a column-aligned matrix is a plain slice (see
+/// `as_mut_slice`); a column of a row-aligned matrix holds a reference to its element in each
+/// stored row.
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+/// let mut foo = Matrix::new_from_vec((2, 2), vec![1, 2, 3, 4], RowAligned).unwrap();
+/// for mut column in foo.columns_mut() {
+///     let top = column[0];
+///     for e in column.iter_mut() {
+///         *e -= top;
+///     }
+/// }
+/// assert_eq!(foo[(0, 1)], 0);
+/// assert_eq!(foo[(1, 0)], 2);
+/// ```
+pub struct ColumnViewMut<'a, T: 'a>(ViewElements<'a, T>);
+
+/// Iterator over the rows of a matrix, returned by `rows()`.
+pub struct Rows<'a, T: 'a>(Views<'a, T>);
+
+/// Iterator over the columns of a matrix, returned by `columns()`.
+pub struct Columns<'a, T: 'a>(Views<'a, T>);
+
+/// Iterator over the rows of a matrix with mutable access to their elements, returned by
+/// `rows_mut()`.
+pub struct RowsMut<'a, T: 'a>(ViewsMut<'a, T>);
+
+/// Iterator over the columns of a matrix with mutable access to their elements, returned by
+/// `columns_mut()`.
+pub struct ColumnsMut<'a, T: 'a>(ViewsMut<'a, T>);
+
+/// Iterator over mutable references to the elements of a `RowViewMut` or `ColumnViewMut`,
+/// returned by `iter_mut()`.
+pub struct ViewIterMut<'b, 'a: 'b, T: 'a>(ViewElementsIter<'b, 'a, T>);
+
+// A row or column that's every `step`th element of `data`, which ends just after its last element.
+struct View<'a, T: 'a> {
+    data: &'a [T],
+    step: usize,
+    len: usize
+}
+
+impl<'a, T> Clone for View<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for View<'a, T> {}
+
+impl<'a, T> View<'a, T> {
+    fn get(&self, i: usize) -> Option<&'a T> {
+        if i < self.len {
+            self.data.get(i * self.step)
+        } else {
+            None
+        }
+    }
+
+    fn as_slice(&self) -> Option<&'a [T]> {
+        if self.step == 1 || self.len <= 1 {
+            Some(self.data)
+        } else {
+            None
+        }
+    }
+}
+
+// The rows or columns of a matrix. Vectors that are contiguous in storage each take `len`
+// elements, one after another; otherwise vector `i` starts at element `i` and takes one element
+// from each stored line.
+struct Views<'a, T: 'a> {
+    data: &'a [T],
+    contiguous: bool,
+    step: usize,
+    len: usize,
+    front: usize,
+    back: usize
+}
+
+impl<'a, T> Views<'a, T> {
+    // The first `count` vectors of a matrix stored as `lines` lines of `stride` elements each.
+    fn new(data: &'a [T], lines: usize, stride: usize, contiguous: bool, count: usize) -> Self {
+        let (step, len) = if contiguous { (1, stride) } else { (stride, lines) };
+        Views { data, contiguous, step, len, front: 0, back: count }
+    }
+
+    fn view(&self, i: usize) -> View<'a, T> {
+        let start = if self.contiguous { i * self.len } else { i };
+        View {
+            data: if self.len == 0 {
+                &[]
+            } else {
+                &self.data[start..start + (self.len - 1) * self.step + 1]
+            },
+            step: self.step,
+            len: self.len
+        }
+    }
+
+    fn next(&mut self) -> Option<View<'a, T>> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.view(self.front - 1))
+    }
+
+    fn next_back(&mut self) -> Option<View<'a, T>> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.view(self.back))
+    }
+}
+
+// The elements of one row or column: a slice if it's contiguous in storage, or a reference to its
+// element in each stored line otherwise.
+enum ViewElements<'a, T: 'a> {
+    Contiguous(&'a mut [T]),
+    Gathered(Vec<&'a mut T>)
+}
+
+impl<'a, T> ViewElements<'a, T> {
+    fn len(&self) -> usize {
+        match *self {
+            ViewElements::Contiguous(ref v) => v.len(),
+            ViewElements::Gathered(ref v) => v.len()
+        }
+    }
+
+    fn get(&self, i: usize) -> &T {
+        match *self {
+            ViewElements::Contiguous(ref v) => &v[i],
+            ViewElements::Gathered(ref v) => &*v[i]
+        }
+    }
+
+    fn get_mut(&mut self, i: usize) -> &mut T {
+        match *self {
+            ViewElements::Contiguous(ref mut v) => &mut v[i],
+            ViewElements::Gathered(ref mut v) => &mut *v[i]
+        }
+    }
+
+    fn iter_mut(&mut self) -> ViewIterMut<'_, 'a, T> {
+        ViewIterMut(match *self {
+            ViewElements::Contiguous(ref mut v) => ViewElementsIter::Contiguous(v.iter_mut()),
+            ViewElements::Gathered(ref mut v) => ViewElementsIter::Gathered(v.iter_mut())
+        })
+    }
+
+    fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+        match *self {
+            ViewElements::Contiguous(ref mut v) => Some(v),
+            ViewElements::Gathered(_) => None
+        }
+    }
+}
+
+enum ViewElementsIter<'b, 'a: 'b, T: 'a> {
+    Contiguous(slice::IterMut<'b, T>),
+    Gathered(slice::IterMut<'b, &'a mut T>)
+}
+
+impl<'b, 'a, T> Iterator for ViewIterMut<'b, 'a, T> {
+    type Item = &'b mut T;
+
+    fn next(&mut self) -> Option<&'b mut T> {
+        match self.0 {
+            ViewElementsIter::Contiguous(ref mut iter) => iter.next(),
+            ViewElementsIter::Gathered(ref mut iter) => iter.next().map(|e| &mut **e)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.0 {
+            ViewElementsIter::Contiguous(ref iter) => iter.size_hint(),
+            ViewElementsIter::Gathered(ref iter) => iter.size_hint()
+        }
+    }
+}
+
+impl<'b, 'a, T> DoubleEndedIterator for ViewIterMut<'b, 'a, T> {
+    fn next_back(&mut self) -> Option<&'b mut T> {
+        match self.0 {
+            ViewElementsIter::Contiguous(ref mut iter) => iter.next_back(),
+            ViewElementsIter::Gathered(ref mut iter) => iter.next_back().map(|e| &mut **e)
+        }
+    }
+}
+
+impl<'b, 'a, T> ExactSizeIterator for ViewIterMut<'b, 'a, T> {}
+
+// The rows or columns of a matrix with mutable access: what's left of the vectors that are
+// contiguous in storage (and their length), or an iterator over each stored line otherwise.
+struct ViewsMut<'a, T: 'a> {
+    source: ViewSource<'a, T>,
+    front: usize,
+    back: usize
+}
+
+enum ViewSource<'a, T: 'a> {
+    Contiguous(&'a mut [T], usize),
+    Gathered(Vec<slice::IterMut<'a, T>>)
+}
+
+impl<'a, T> ViewsMut<'a, T> {
+    // The first `count` vectors of a matrix stored as `lines` lines of `stride` elements each.
+    fn new(data: &'a mut [T], lines: usize, stride: usize, contiguous: bool, count: usize)
+        -> Self {
+        let source = if contiguous {
+            ViewSource::Contiguous(&mut data[..count * stride], stride)
+        } else if data.is_empty() {
+            ViewSource::Gathered(Vec::new())
+        } else {
+            ViewSource::Gathered(data.chunks_mut(stride).take(lines)
+                .map(|line| line[..count].iter_mut())
+                .collect())
+        };
+        ViewsMut { source, front: 0, back: count }
+    }
+
+    fn next(&mut self) -> Option<ViewElements<'a, T>> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(match self.source {
+            ViewSource::Contiguous(ref mut rest, len) => {
+                let (view, rest_) = mem::take(rest).split_at_mut(len);
+                *rest = rest_;
+                ViewElements::Contiguous(view)
+            },
+            ViewSource::Gathered(ref mut lines) => ViewElements::Gathered(lines.iter_mut()
+                .map(|line| line.next().unwrap())
+                .collect())
+        })
+    }
+
+    fn next_back(&mut self) -> Option<ViewElements<'a, T>> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(match self.source {
+            ViewSource::Contiguous(ref mut rest, len) => {
+                let at = rest.len() - len;
+                let (rest_, view) = mem::take(rest).split_at_mut(at);
+                *rest = rest_;
+                ViewElements::Contiguous(view)
+            },
+            ViewSource::Gathered(ref mut lines) => ViewElements::Gathered(lines.iter_mut()
+                .map(|line| line.next_back().unwrap())
+                .collect())
+        })
+    }
+}
+
+macro_rules! view_impls {
+    ($($view:ident, $view_mut:ident, $views:ident, $views_mut:ident);*) => ($(
+        impl<'a, T> Clone for $view<'a, T> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<'a, T> Copy for $view<'a, T> {}
+
+        impl<'a, T> $view<'a, T> {
+            /// Gets the number of elements.
+            pub fn len(&self) -> usize {
+                self.0.len
+            }
+
+            /// Returns `true` if there are no elements.
+            pub fn is_empty(&self) -> bool {
+                self.0.len == 0
+            }
+
+            /// Gets a reference to the element at position `i`, or `None` if it's out of bounds.
+            pub fn get(&self, i: usize) -> Option<&'a T> {
+                self.0.get(i)
+            }
+
+            /// Iterates over the elements in order.
+            pub fn iter(&self) -> StepBy<slice::Iter<'a, T>> {
+                self.0.data.iter().step_by(self.0.step)
+            }
+
+            /// Gets the elements as a slice if they're contiguous in memory, and `None`
+            /// otherwise. There's always a slice if there's at most one element.
+            pub fn as_slice(&self) -> Option<&'a [T]> {
+                self.0.as_slice()
+            }
+        }
+
+        impl<'a, T> Index<usize> for $view<'a, T> {
+            type Output = T;
+
+            fn index(&self, i: usize) -> &T {
+                match self.0.get(i) {
+                    Some(e) => e,
+                    None => panic!("index {} out of bounds for a view of length {}", i,
+                        self.0.len)
+                }
+            }
+        }
+
+        impl<'a, T> IntoIterator for $view<'a, T> {
+            type Item = &'a T;
+            type IntoIter = StepBy<slice::Iter<'a, T>>;
+
+            fn into_iter(self) -> StepBy<slice::Iter<'a, T>> {
+                self.iter()
+            }
+        }
+
+        impl<'a, T> $view_mut<'a, T> {
+            /// Gets the number of elements.
+            pub fn len(&self) -> usize {
+                self.0.len()
+            }
+
+            /// Returns `true` if there are no elements.
+            pub fn is_empty(&self) -> bool {
+                self.0.len() == 0
+            }
+
+            /// Iterates over mutable references to the elements in order.
+            pub fn iter_mut(&mut self) -> ViewIterMut<'_, 'a, T> {
+                self.0.iter_mut()
+            }
+
+            /// Gets the elements as a mutable slice if they're contiguous in memory, and `None`
+            /// otherwise.
+            pub fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+                self.0.as_mut_slice()
+            }
+        }
+
+        impl<'a, T> Index<usize> for $view_mut<'a, T> {
+            type Output = T;
+
+            fn index(&self, i: usize) -> &T {
+                self.0.get(i)
+            }
+        }
+
+        impl<'a, T> IndexMut<usize> for $view_mut<'a, T> {
+            fn index_mut(&mut self, i: usize) -> &mut T {
+                self.0.get_mut(i)
+            }
+        }
+
+        impl<'a, T> Iterator for $views<'a, T> {
+            type Item = $view<'a, T>;
+
+            fn next(&mut self) -> Option<$view<'a, T>> {
+                self.0.next().map($view)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let remaining = self.0.back - self.0.front;
+                (remaining, Some(remaining))
+            }
+        }
+
+        impl<'a, T> DoubleEndedIterator for $views<'a, T> {
+            fn next_back(&mut self) -> Option<$view<'a, T>> {
+                self.0.next_back().map($view)
+            }
+        }
+
+        impl<'a, T> ExactSizeIterator for $views<'a, T> {}
+
+        impl<'a, T> Iterator for $views_mut<'a, T> {
+            type Item = $view_mut<'a, T>;
+
+            fn next(&mut self) -> Option<$view_mut<'a, T>> {
+                self.0.next().map($view_mut)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let remaining = self.0.back - self.0.front;
+                (remaining, Some(remaining))
+            }
+        }
+
+        impl<'a, T> DoubleEndedIterator for $views_mut<'a, T> {
+            fn next_back(&mut self) -> Option<$view_mut<'a, T>> {
+                self.0.next_back().map($view_mut)
+            }
+        }
+
+        impl<'a, T> ExactSizeIterator for $views_mut<'a, T> {}
+    )*)
+}
+
+view_impls!{RowView, RowViewMut, Rows, RowsMut; ColumnView, ColumnViewMut, Columns, ColumnsMut}
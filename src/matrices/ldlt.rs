@@ -0,0 +1,149 @@
+//! Exact LDLᵀ decomposition of symmetric `Matrix<Fraction>`s. Where a QR or Cholesky
+//! factorization would need square roots, LDLᵀ (unit lower-triangular `L`, diagonal `D`) only
+//! needs rational arithmetic, so it gives exact positive-definiteness certificates and a fast
+//! solver for symmetric systems.
+
+use num::{Zero, Signed};
+
+use fractions::base::Fraction;
+use matrices::base::{Alignment, Matrix, MatrixError};
+
+fn simplified(mut f: Fraction) -> Fraction {
+    f.simplify();
+    f
+}
+
+impl Matrix<Fraction> {
+    // Errors unless the matrix is square and equal to its transpose.
+    fn check_symmetric(&self) -> Result<(), MatrixError> {
+        let (rows, columns) = self.dimension();
+        if rows != columns {
+            return Err(MatrixError::FunctionError(format!("LDLT decomposition needs a square \
+            matrix (dimension: {:?}).", self.dimension())));
+        }
+        for r in 0..rows {
+            for c in 0..r {
+                if self[(r, c)] != self[(c, r)] {
+                    return Err(MatrixError::FunctionError(format!("LDLT decomposition needs a \
+                    symmetric matrix, but ({}, {}) != ({}, {}).", r, c, c, r)));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Decomposes a symmetric matrix as `L * diag(D) * Lᵀ` with exact arithmetic, returning `L`
+    /// (unit lower-triangular, row-aligned) and the diagonal of `D`. A zero in `D` is fine as long
+    /// as nothing below it in `L` would have to be divided by it (the positive semi-definite
+    /// boundary); otherwise the index of the zero pivot is reported in the error instead of
+    /// dividing by zero. Also returns an error if the matrix isn't square and symmetric.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+    /// let foo = Matrix::new_from_vec((2, 2), vec![
+    ///     Fraction::from(4), Fraction::from(2),
+    ///     Fraction::from(2), Fraction::from(3)
+    /// ], RowAligned).unwrap();
+    /// let (l, d) = foo.ldlt().unwrap();
+    /// assert_eq!(l[(1, 0)], Fraction::new(1, 2));
+    /// assert_eq!(d, vec![Fraction::from(4), Fraction::from(2)]);
+    /// let bar = Matrix::new_from_vec((2, 2), vec![
+    ///     Fraction::from(0), Fraction::from(1),
+    ///     Fraction::from(1), Fraction::from(0)
+    /// ], RowAligned).unwrap();
+    /// assert!(bar.ldlt().is_err());
+    /// ```
+    pub fn ldlt(&self) -> Result<(Matrix<Fraction>, Vec<Fraction>), MatrixError> {
+        self.check_symmetric()?;
+        let n = self.num_rows();
+        let mut l = vec![Fraction::zero(); n * n];
+        let mut d = Vec::with_capacity(n);
+        for j in 0..n {
+            l[j * n + j] = Fraction::from(1);
+            let dj = simplified((0..j)
+                .fold(self[(j, j)], |acc, k| acc - l[j * n + k] * l[j * n + k] * d[k]));
+            for i in j + 1..n {
+                let v = (0..j)
+                    .fold(self[(i, j)], |acc, k| acc - l[i * n + k] * l[j * n + k] * d[k]);
+                if dj.is_zero() {
+                    if !v.is_zero() {
+                        return Err(MatrixError::FunctionError(format!("Zero pivot in D at \
+                        index {} (entry ({}, {}) can't be eliminated).", j, i, j)));
+                    }
+                } else {
+                    l[i * n + j] = simplified(v / dj);
+                }
+            }
+            d.push(dj);
+        }
+        Ok((Matrix::new_from_vec((n, n), l, Alignment::RowAligned)?, d))
+    }
+
+    /// Checks exactly whether a symmetric matrix is positive definite, i.e. whether every entry
+    /// of `D` in its LDLᵀ decomposition is positive. A zero pivot means it isn't. Returns an
+    /// error if the matrix isn't square and symmetric.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+    /// let foo = Matrix::new_from_vec((2, 2), vec![
+    ///     Fraction::from(2), Fraction::from(1),
+    ///     Fraction::from(1), Fraction::from(2)
+    /// ], RowAligned).unwrap();
+    /// assert!(foo.is_positive_definite_exact().unwrap());
+    /// let bar = Matrix::new_from_vec((2, 2), vec![
+    ///     Fraction::from(1), Fraction::from(2),
+    ///     Fraction::from(2), Fraction::from(1)
+    /// ], RowAligned).unwrap();
+    /// assert!(!bar.is_positive_definite_exact().unwrap());
+    /// ```
+    pub fn is_positive_definite_exact(&self) -> Result<bool, MatrixError> {
+        self.check_symmetric()?;
+        Ok(match self.ldlt() {
+            Ok((_, d)) => d.iter().all(|e| e.is_positive()),
+            Err(_) => false
+        })
+    }
+
+    /// Solves `self * x = b` given the LDLᵀ decomposition of `self` (as returned by `ldlt`), by
+    /// forward substitution with `L`, scaling by `D` and back substitution with `Lᵀ`. Panics if
+    /// the sizes don't match `self` or if `D` has a zero entry (the system is singular).
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+    /// let foo = Matrix::new_from_vec((2, 2), vec![
+    ///     Fraction::from(4), Fraction::from(2),
+    ///     Fraction::from(2), Fraction::from(3)
+    /// ], RowAligned).unwrap();
+    /// let (l, d) = foo.ldlt().unwrap();
+    /// let x = foo.solve_ldlt(&l, &d, &[Fraction::from(6), Fraction::from(5)]);
+    /// assert_eq!(x, vec![Fraction::from(1), Fraction::from(1)]);
+    /// ```
+    pub fn solve_ldlt(&self, l: &Matrix<Fraction>, d: &[Fraction], b: &[Fraction])
+        -> Vec<Fraction> {
+        let n = self.num_rows();
+        assert!(l.dimension() == (n, n) && d.len() == n && b.len() == n, "The decomposition and \
+        right hand side don't match a {:?} matrix.", self.dimension());
+        let mut x = b.to_vec();
+        for i in 0..n {
+            for k in 0..i {
+                x[i] = simplified(x[i] - l[(i, k)] * x[k]);
+            }
+        }
+        for i in 0..n {
+            assert!(!d[i].is_zero(), "Zero pivot in D at index {}: the system is singular.", i);
+            x[i] = simplified(x[i] / d[i]);
+        }
+        for i in (0..n).rev() {
+            for k in i + 1..n {
+                x[i] = simplified(x[i] - l[(k, i)] * x[k]);
+            }
+        }
+        x
+    }
+}
@@ -42,7 +42,7 @@ macro_rules! matrix {
             matr.extend_from_slice(&row);
         )*
         use $crate::matrices::base::Alignment::RowAligned;
-        let mut res = Matrix::new((matr.len() / lens[0], lens[0]), RowAligned);
+        let mut res = Matrix::with_capacity((matr.len() / lens[0], lens[0]), RowAligned);
         res.set_matrix(matr);
         res
     }};
@@ -98,14 +98,15 @@ macro_rules! augmented_matrix {
                 matr.insert((r + 1) * lens[0], solution_column[r]);
             }
             use $crate::matrices::base::Alignment::RowAligned;
-            let mut res = AugmentedMatrix::new((solution_column.len(), lens[0] + 1), RowAligned);
+            let mut res = AugmentedMatrix::with_capacity((solution_column.len(), lens[0] + 1),
+                RowAligned);
             res.set_matrix(matr);
             res
         }
     }};
 }
 
-/// Allows the user to get a window into a matrix or augmented matrix. There are four distinct ways
+/// Allows the user to get a window into a matrix or augmented matrix. There are five distinct ways
 /// of using this macro:
 /// - Getting a single row or column (`window!(matrix, row: n)` or `window!(matrix, col: n)`)
 /// - Getting part of a single row or column (`window!(matrix, (r, c_start..c_end))`
@@ -114,6 +115,8 @@ macro_rules! augmented_matrix {
 /// `window!(matrix, cols: c_start..c_end)`)
 /// - Getting parts of multiple rows or columns
 /// (`window!(matrix, (r_start..r_end, c_start..c_end))`)
+/// - Getting a block of a matrix (`window!(matrix, rows: r_start..r_end, cols: c_start..c_end)`)
+///   (matrices only), which is `Matrix::submatrix` and panics where that returns an error
 ///
 /// Notes:
 /// - This macro always returns a Matrix<T>.
@@ -171,6 +174,11 @@ macro_rules! augmented_matrix {
 /// let c: Matrix<u32> = Matrix::new_from_vec((5, 2), vec![0, 1, 1, 0, 0, 0, 0, 0, 0, 0],
 ///     RowAligned).unwrap();
 /// assert_eq!(columns, c);
+/// let block = window!(matrix, rows: 3..5, cols: 0..2);
+/// assert_eq!(block, matrix![
+///     0 1;
+///     1 0
+/// ]);
 /// ```
 /// ```rust
 /// # #[macro_use] extern crate fractions_and_matrices;
@@ -265,6 +273,9 @@ macro_rules! window {
                 .unwrap()
         }
     };
+    ($matrix:ident, rows: $rows:expr, cols: $cols:expr) => {
+        $matrix.submatrix($rows, $cols).unwrap()
+    };
     ($matrix:ident, cols: ($c_start:tt..$c_end:tt)) => {
         if $matrix.is_column_aligned() {
             use $crate::matrices::base::Alignment::ColumnAligned;
@@ -0,0 +1,308 @@
+//! Applying a function to every element of a matrix, either into a new matrix (possibly of a
+//! different element type) or in place. These go over the backing vec in storage order and keep
+//! the dimension and alignment, so nothing is realigned. On augmented matrices the solution column
+//! is mapped too.
+//! # Example
+//! ```rust
+//! # extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::fractions::base::Fraction;
+//! # use fractions_and_matrices::matrices::base::{Matrix, Alignment::ColumnAligned};
+//! let mut foo = Matrix::new_from_vec((2, 2), vec![1, 3, 2, 4], ColumnAligned).unwrap();
+//! foo.map_in_place(|e| *e = -*e);
+//! let halves = foo.map(|&e| Fraction::new(e as i64, 2));
+//! assert!(halves.is_column_aligned());
+//! assert_eq!(halves[(1, 1)], Fraction::from(-2));
+//! let traces = foo.map_indexed(|(r, c), &e| if r == c { e } else { 0 });
+//! assert_eq!(traces[(0, 0)] + traces[(1, 1)], -5);
+//! ```
+//!
+//! `zip_map()` and `zip_apply()` combine two matrices of the same dimension element by element,
+//! e.g. to mask one with another. The operands can have different alignments: the other matrix is
+//! read where its elements are instead of being realigned first.
+//! # Example
+//! ```rust
+//! # extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::matrices::base::{Matrix, Alignment::{RowAligned, ColumnAligned}};
+//! let mut foo = Matrix::new_from_vec((2, 2), vec![1, 2, 3, 4], RowAligned).unwrap();
+//! let mask = Matrix::new_from_vec((2, 2), vec![true, false, true, true], ColumnAligned).unwrap();
+//! let masked = foo.zip_map(&mask, |&e, &keep| if keep { e } else { 0 }).unwrap();
+//! assert_eq!(masked[(0, 1)], 2);
+//! assert_eq!(masked[(1, 0)], 0);
+//! foo.zip_apply(&masked, |e, &m| *e -= m).unwrap();
+//! assert_eq!(foo[(1, 0)], 3);
+//! assert!(foo.zip_map(&Matrix::splat(&0, (2, 3), RowAligned), |a, b| a + b).is_err());
+//! ```
+
+//!
+//! `convert()` and `try_convert()` change the element type with `From` and `TryFrom`, e.g. to go
+//! from integers to fractions and back.
+//! # Example
+//! ```rust
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::fractions::base::Fraction;
+//! # use fractions_and_matrices::matrices::base::{Matrix, Unit};
+//! # use fractions_and_matrices::matrices::transforms::RREF;
+//! let foo: Matrix<i64> = matrix![
+//!     2 4;
+//!     1 3
+//! ];
+//! let mut bar = foo.convert::<Fraction>();
+//! assert_eq!(bar[(0, 1)], Fraction::from(4));
+//! bar.gauss_jordan();
+//! assert_eq!(bar.try_convert::<i64>().unwrap(), Matrix::unit(2));
+//! let big: Matrix<i64> = matrix![1 5_000_000_000];
+//! assert!(big.try_convert::<i32>().is_err());
+//! ```
+
+use std::convert::TryFrom;
+use std::fmt::Debug;
+use std::ops::{Div, Mul};
+
+use matrices::base::{Alignment, AugmentedMatrix, Matrix, MatrixError};
+
+// The logical `(row, column)` index of the element at `position` in the backing vec.
+fn logical_index(position: usize, stride: usize, alignment: &Alignment) -> (usize, usize) {
+    let (line, offset) = (position / stride, position % stride);
+    match *alignment {
+        Alignment::RowAligned => (line, offset),
+        Alignment::ColumnAligned => (offset, line)
+    }
+}
+
+// Where the element at `position` in the backing vec of a matrix with stored lines of length
+// `stride` is in the backing vec of a matrix of the same dimension but the other alignment.
+fn transposed_position(position: usize, stride: usize, other_stride: usize) -> usize {
+    (position % stride) * other_stride + position / stride
+}
+
+macro_rules! map_impls {
+    ($($target_type:ident),*) => ($(
+        impl<T> $target_type<T> {
+            /// Makes a new matrix of the same dimension and alignment whose elements are `f`
+            /// applied to the elements of this one. `f` is called once per element, in storage
+            /// order.
+            pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> $target_type<U> {
+                $target_type {
+                    rows: self.rows,
+                    columns: self.columns,
+                    matrix: self.matrix.iter().map(f).collect(),
+                    alignment: self.alignment.clone()
+                }
+            }
+
+            /// Applies `f` to every element, in storage order.
+            pub fn map_in_place<F: FnMut(&mut T)>(&mut self, f: F) {
+                self.matrix.iter_mut().for_each(f);
+            }
+
+            /// Like `map()`, but `f` also gets the logical `(row, column)` index of each element.
+            pub fn map_indexed<U, F: FnMut((usize, usize), &T) -> U>(&self, mut f: F)
+                -> $target_type<U> {
+                let stride = self.columns;
+                $target_type {
+                    rows: self.rows,
+                    columns: self.columns,
+                    matrix: self.matrix.iter()
+                        .enumerate()
+                        .map(|(i, e)| f(logical_index(i, stride, &self.alignment), e))
+                        .collect(),
+                    alignment: self.alignment.clone()
+                }
+            }
+
+            /// Like `map_in_place()`, but `f` also gets the logical `(row, column)` index of each
+            /// element.
+            pub fn map_indexed_in_place<F: FnMut((usize, usize), &mut T)>(&mut self, mut f: F) {
+                let stride = self.columns;
+                for (i, e) in self.matrix.iter_mut().enumerate() {
+                    f(logical_index(i, stride, &self.alignment), e);
+                }
+            }
+
+            /// Makes a new matrix whose element at each index is `f` applied to the elements of
+            /// `self` and `other` at that index. The result has the alignment of `self`, and `f`
+            /// is called in its storage order. `other` can have either alignment; it isn't
+            /// realigned. Returns an error if the dimensions differ.
+            pub fn zip_map<U, V, F: FnMut(&T, &U) -> V>(&self, other: &$target_type<U>, mut f: F)
+                -> Result<$target_type<V>, MatrixError> {
+                self.check_zip_dimension(other)?;
+                let matrix = if self.alignment == other.alignment {
+                    self.matrix.iter().zip(other.matrix.iter()).map(|(a, b)| f(a, b)).collect()
+                } else {
+                    let (stride, other_stride) = (self.columns, other.columns);
+                    self.matrix.iter()
+                        .enumerate()
+                        .map(|(i, a)| f(a, &other.matrix[transposed_position(i, stride,
+                            other_stride)]))
+                        .collect()
+                };
+                Ok($target_type {
+                    rows: self.rows,
+                    columns: self.columns,
+                    matrix,
+                    alignment: self.alignment.clone()
+                })
+            }
+
+            /// Applies `f` to every element of `self` and the element of `other` at the same
+            /// index, in the storage order of `self`. `other` can have either alignment; it isn't
+            /// realigned. Returns an error if the dimensions differ.
+            pub fn zip_apply<U, F: FnMut(&mut T, &U)>(&mut self, other: &$target_type<U>,
+                mut f: F) -> Result<(), MatrixError> {
+                self.check_zip_dimension(other)?;
+                if self.alignment == other.alignment {
+                    for (a, b) in self.matrix.iter_mut().zip(other.matrix.iter()) {
+                        f(a, b);
+                    }
+                } else {
+                    let (stride, other_stride) = (self.columns, other.columns);
+                    for (i, a) in self.matrix.iter_mut().enumerate() {
+                        f(a, &other.matrix[transposed_position(i, stride, other_stride)]);
+                    }
+                }
+                Ok(())
+            }
+
+            /// Converts every element with `U::from`, keeping the dimension and alignment (and
+            /// converting the solution column of augmented matrices too).
+            pub fn convert<U: From<T>>(self) -> $target_type<U> {
+                $target_type {
+                    rows: self.rows,
+                    columns: self.columns,
+                    matrix: self.matrix.into_iter().map(U::from).collect(),
+                    alignment: self.alignment
+                }
+            }
+
+            /// Like `convert()`, but with `U::try_from`. Returns a `FunctionError` naming the
+            /// first element (going row by row) that can't be converted. Note that for
+            /// `Fraction`s, whose conversions into integers truncate, `try_into_integers()` is
+            /// the checked version.
+            pub fn try_convert<U: TryFrom<T>>(self) -> Result<$target_type<U>, MatrixError>
+                where U::Error: Debug {
+                let $target_type { rows, columns, matrix, alignment } = self;
+                let converted = matrix.into_iter().map(U::try_from).collect::<Vec<_>>();
+                let failed = converted.iter()
+                    .enumerate()
+                    .filter(|&(_, e)| e.is_err())
+                    .map(|(i, _)| (logical_index(i, columns, &alignment), i))
+                    .min();
+                if let Some(((r, c), i)) = failed {
+                    return Err(MatrixError::FunctionError(format!("Element ({}, {}) can't be \
+                    converted: {:?}", r, c, converted[i].as_ref().err().unwrap())));
+                }
+                Ok($target_type {
+                    rows,
+                    columns,
+                    matrix: converted.into_iter().filter_map(Result::ok).collect(),
+                    alignment
+                })
+            }
+
+            fn check_zip_dimension<U>(&self, other: &$target_type<U>) -> Result<(), MatrixError> {
+                if self.dimension() != other.dimension() {
+                    return Err(MatrixError::FunctionError(format!("Can't combine a matrix of \
+                    dimension {:?} elementwise with one of dimension {:?}.", self.dimension(),
+                        other.dimension())));
+                }
+                Ok(())
+            }
+        }
+    )*)
+}
+
+map_impls!{Matrix, AugmentedMatrix}
+
+impl<T: Clone> Matrix<T> {
+    /// Multiplies each element by the element of `other` at the same index (the Hadamard
+    /// product, unlike `*`, which is the matrix product). The result has the alignment of `self`,
+    /// and `other` can have either. Returns an error if the dimensions differ.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// let foo = matrix![
+    ///     1 2;
+    ///     3 4
+    /// ];
+    /// let mask = matrix![
+    ///     1 0;
+    ///     0 1
+    /// ];
+    /// assert_eq!(foo.hadamard(&mask).unwrap(), matrix![
+    ///     1 0;
+    ///     0 4
+    /// ]);
+    /// assert_eq!(foo.hadamard_div(&foo).unwrap(), matrix![
+    ///     1 1;
+    ///     1 1
+    /// ]);
+    /// assert!(foo.hadamard(&matrix![1 2]).is_err());
+    /// ```
+    pub fn hadamard(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: Mul<Output = T> {
+        self.zip_map(other, |a, b| a.clone() * b.clone())
+    }
+
+    /// Divides each element by the element of `other` at the same index. See `hadamard()`.
+    /// Dividing by zero does whatever it does for `T`, e.g. gives an undefined fraction for
+    /// `Fraction`s and panics for integers.
+    pub fn hadamard_div(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: Div<Output = T> {
+        self.zip_map(other, |a, b| a.clone() / b.clone())
+    }
+
+    /// Makes a matrix of the smaller of the elements of `self` and `other` at each index. On a
+    /// tie, or if the two can't be compared (e.g. an undefined `Fraction`), the element of `self`
+    /// is kept. The result has the alignment of `self`, and `other` can have either. Returns an
+    /// error if the dimensions differ.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// let foo = matrix![
+    ///     1 5;
+    ///     7 2
+    /// ];
+    /// let bar = matrix![
+    ///     3 3;
+    ///     3 3
+    /// ];
+    /// assert_eq!(foo.elementwise_min(&bar).unwrap(), matrix![
+    ///     1 3;
+    ///     3 2
+    /// ]);
+    /// assert_eq!(foo.elementwise_max(&bar).unwrap(), matrix![
+    ///     3 5;
+    ///     7 3
+    /// ]);
+    /// assert_eq!(foo.clamp(&2, &6), matrix![
+    ///     2 5;
+    ///     6 2
+    /// ]);
+    /// ```
+    pub fn elementwise_min(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: PartialOrd {
+        self.zip_map(other, |a, b| if b < a { b.clone() } else { a.clone() })
+    }
+
+    /// Makes a matrix of the larger of the elements of `self` and `other` at each index. See
+    /// `elementwise_min()`.
+    pub fn elementwise_max(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: PartialOrd {
+        self.zip_map(other, |a, b| if b > a { b.clone() } else { a.clone() })
+    }
+
+    /// Makes a matrix with every element below `lo` replaced by `lo` and every element above `hi`
+    /// replaced by `hi`. Elements that can't be compared with them (e.g. undefined `Fraction`s)
+    /// are kept. If `lo > hi`, elements below `lo` still become `lo`.
+    pub fn clamp(&self, lo: &T, hi: &T) -> Matrix<T> where T: PartialOrd {
+        self.map(|e| if e < lo {
+            lo.clone()
+        } else if e > hi {
+            hi.clone()
+        } else {
+            e.clone()
+        })
+    }
+}
@@ -0,0 +1,177 @@
+//! Read-only matrices backed by a memory-mapped file of raw little-endian elements, for data too
+//! big to load. Only compiled with the `mmap` feature on Unix targets. Nothing is read up front:
+//! pages are loaded by the OS as elements are touched, so norms, row sampling and products against
+//! small vectors work on files much larger than memory.
+
+use libc;
+
+use std::fs::File;
+use std::io;
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::ops::{Index, Range};
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+use std::ptr;
+use std::slice;
+
+use matrices::base::{Alignment, Matrix, MatrixError};
+use matrices::matrix_ref::MatrixRef;
+
+/// Plain-old-data element types: every bit pattern is a valid value and there's no padding, so a
+/// `MappedMatrix` can hand out references straight into the mapped file.
+/// # Safety
+/// Implementors must be `Copy` types with no padding and no invalid bit patterns.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($t:ty) *) => ($(
+        unsafe impl Pod for $t {}
+    )*)
+}
+
+impl_pod!{u8 i8 u16 i16 u32 i32 u64 i64 f32 f64}
+
+/// A read-only matrix backed by a memory-mapped file. The file holds the elements back to back as
+/// little-endian values with no header, row by row for `RowAligned` files and column by column
+/// for `ColumnAligned` ones - the same order as the vec given to `Matrix::new_from_vec`.
+pub struct MappedMatrix<T: Pod> {
+    ptr: *const T,
+    rows: usize,
+    columns: usize,
+    alignment: Alignment,
+    _element: PhantomData<T>
+}
+
+// The mapping is private and read-only, so sharing it between threads is the same as sharing a
+// `&[T]`.
+unsafe impl<T: Pod + Sync> Sync for MappedMatrix<T> {}
+unsafe impl<T: Pod + Send> Send for MappedMatrix<T> {}
+
+impl<T: Pod> MappedMatrix<T> {
+    /// Maps the file at `path` as a matrix with the given dimension and layout. Returns an
+    /// `InitError` if the file can't be opened or mapped, if its size isn't exactly
+    /// `rows * columns` elements, if the dimension is empty, or if the target is big-endian (the
+    /// file is read in place, so its byte order has to match).
+    pub fn open<P: AsRef<Path>>(path: P, dimension: (usize, usize), layout: Alignment)
+        -> Result<MappedMatrix<T>, MatrixError> {
+        if cfg!(target_endian = "big") && size_of::<T>() > 1 {
+            return Err(MatrixError::InitError("Little-endian data can't be mapped in place on a \
+            big-endian target.".to_string()));
+        }
+        let path = path.as_ref();
+        let io_error = |e: io::Error| MatrixError::InitError(format!("Couldn't map '{}': {}",
+            path.display(), e));
+        let len = match dimension.0.checked_mul(dimension.1)
+            .and_then(|n| n.checked_mul(size_of::<T>())) {
+            Some(0) => return Err(MatrixError::InitError(format!("Can't map an empty matrix \
+            (supplied dimension: {:?}).", dimension))),
+            Some(len) => len,
+            None => return Err(MatrixError::InitError(format!("The supplied dimension is too \
+            large to map ({:?}).", dimension)))
+        };
+        let file = File::open(path).map_err(&io_error)?;
+        let file_len = file.metadata().map_err(&io_error)?.len();
+        if file_len != len as u64 {
+            return Err(MatrixError::InitError(format!("'{}' is {} bytes, but a {:?} matrix of \
+            {}-byte elements needs {} bytes.", path.display(), file_len, dimension,
+                size_of::<T>(), len)));
+        }
+        let ptr = unsafe {
+            libc::mmap(ptr::null_mut(), len, libc::PROT_READ, libc::MAP_PRIVATE, file.as_raw_fd(),
+                0)
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io_error(io::Error::last_os_error()));
+        }
+        let (rows, columns) = match layout {
+            Alignment::RowAligned => dimension,
+            Alignment::ColumnAligned => (dimension.1, dimension.0)
+        };
+        // Mappings are page-aligned, so `ptr` is aligned for any `Pod` type.
+        Ok(MappedMatrix {
+            ptr: ptr as *const T,
+            rows,
+            columns,
+            alignment: layout,
+            _element: PhantomData
+        })
+    }
+
+    /// Returns the layout the file was opened with.
+    pub fn get_alignment(&self) -> Alignment {
+        self.alignment.clone()
+    }
+
+    /// Copies the rows in `rows` into an in-memory `Matrix<T>` with the same layout as the file.
+    /// Only the part of the file holding those rows is read. Panics if the range goes outside of
+    /// the matrix.
+    pub fn to_matrix(&self, rows: Range<usize>) -> Matrix<T> {
+        let (num_rows, num_columns) = self.dimension();
+        assert!(rows.start <= rows.end && rows.end <= num_rows, "Row range {:?} is out of \
+        bounds for a matrix with {} rows.", rows, num_rows);
+        let window = rows.end - rows.start;
+        let vec = match self.alignment {
+            Alignment::RowAligned => {
+                self.as_slice()[rows.start * num_columns..rows.end * num_columns].to_vec()
+            },
+            Alignment::ColumnAligned => (0..num_columns)
+                .flat_map(|c| self.as_slice()[c * num_rows + rows.start..c * num_rows + rows.end]
+                    .iter().cloned())
+                .collect()
+        };
+        Matrix::new_from_vec((window, num_columns), vec, self.alignment.clone()).unwrap()
+    }
+
+    fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr, self.rows * self.columns) }
+    }
+}
+
+impl<T: Pod> MatrixRef<T> for MappedMatrix<T> {
+    fn dimension(&self) -> (usize, usize) {
+        match self.alignment {
+            Alignment::RowAligned => (self.rows, self.columns),
+            Alignment::ColumnAligned => (self.columns, self.rows)
+        }
+    }
+
+    fn get(&self, index: (usize, usize)) -> Option<&T> {
+        let (rows, columns) = self.dimension();
+        if index.0 >= rows || index.1 >= columns {
+            return None;
+        }
+        match self.alignment {
+            Alignment::RowAligned => self.as_slice().get(index.0 * columns + index.1),
+            Alignment::ColumnAligned => self.as_slice().get(index.1 * rows + index.0)
+        }
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[T]> {
+        if self.alignment == Alignment::RowAligned && row < self.rows {
+            Some(&self.as_slice()[row * self.columns..(row + 1) * self.columns])
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Pod> Index<(usize, usize)> for MappedMatrix<T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &T {
+        match self.get(index) {
+            Some(e) => e,
+            None => panic!("Index {:?} is out of bounds for a matrix with dimension {:?}.", index,
+                self.dimension())
+        }
+    }
+}
+
+impl<T: Pod> Drop for MappedMatrix<T> {
+    fn drop(&mut self) {
+        unsafe {
+            libc::munmap(self.ptr as *mut libc::c_void, self.rows * self.columns * size_of::<T>());
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! Read-only access shared by in-memory matrices and matrices that live somewhere else (like
+//! `mapped::MappedMatrix` with the `mmap` feature), so that norms and products can be written once
+//! for both.
+
+use num::Zero;
+
+use std::ops::Mul;
+
+use matrices::base::{Alignment, Matrix, MatrixError};
+
+/// Read-only view of a matrix. Indices are 0-based `(row, column)` pairs, regardless of how the
+/// elements are stored.
+pub trait MatrixRef<T> {
+    /// Gets the dimension as a `(rows, columns)` tuple.
+    fn dimension(&self) -> (usize, usize);
+
+    /// Gets a reference to the element at `(row, column)`, or `None` if it's out of bounds.
+    fn get(&self, index: (usize, usize)) -> Option<&T>;
+
+    /// Gets a row as a slice. Only rows that are contiguous in memory (i.e. row-aligned storage)
+    /// can be returned, so this is `None` for column-aligned storage and for out of bounds rows.
+    fn row_slice(&self, row: usize) -> Option<&[T]>;
+
+    /// Iterates over the elements row by row, regardless of the storage order.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// # use fractions_and_matrices::matrices::matrix_ref::MatrixRef;
+    /// let mut foo = matrix![
+    ///     1 2;
+    ///     3 4
+    /// ];
+    /// foo.column_align();
+    /// assert_eq!(foo.iter().cloned().collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+    /// ```
+    fn iter<'a>(&'a self) -> Elements<'a, T, Self> where Self: Sized {
+        Elements {
+            source: self,
+            dimension: self.dimension(),
+            next: 0,
+            _element: ::std::marker::PhantomData
+        }
+    }
+
+    /// Multiplies the matrix by a column vector. Returns an error if the length of `v` isn't the
+    /// number of columns.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// # use fractions_and_matrices::matrices::matrix_ref::MatrixRef;
+    /// let foo = matrix![
+    ///     1 2 3;
+    ///     4 5 6
+    /// ];
+    /// assert_eq!(foo.mul_vector(&[1, 0, 2]).unwrap(), vec![7, 16]);
+    /// assert!(foo.mul_vector(&[1, 2]).is_err());
+    /// ```
+    fn mul_vector(&self, v: &[T]) -> Result<Vec<T>, MatrixError>
+        where T: Clone + Zero + Mul<Output = T> {
+        let (rows, columns) = self.dimension();
+        if v.len() != columns {
+            return Err(MatrixError::FunctionError(format!("Can't multiply a matrix with {} \
+            columns by a vector of length {}.", columns, v.len())));
+        }
+        Ok((0..rows).map(|r| match self.row_slice(r) {
+            Some(row) => row.iter().zip(v.iter())
+                .fold(T::zero(), |acc, (a, b)| acc + a.clone() * b.clone()),
+            None => (0..columns)
+                .fold(T::zero(), |acc, c| acc + self.get((r, c)).unwrap().clone() * v[c].clone())
+        }).collect())
+    }
+
+    /// Gives the Frobenius norm (the square root of the sum of the squares of all the elements).
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// # use fractions_and_matrices::matrices::matrix_ref::MatrixRef;
+    /// let foo = matrix![
+    ///     1.0 2.0;
+    ///     2.0 4.0
+    /// ];
+    /// assert_eq!(foo.frobenius_norm(), 5.0);
+    /// ```
+    fn frobenius_norm(&self) -> f64 where Self: Sized, T: Clone + Into<f64> {
+        self.iter().fold(0.0, |acc, e| {
+            let e: f64 = e.clone().into();
+            acc + e * e
+        }).sqrt()
+    }
+}
+
+/// Row-major iterator over the elements of a `MatrixRef`, returned by `MatrixRef::iter`.
+pub struct Elements<'a, T: 'a, M: 'a> {
+    source: &'a M,
+    dimension: (usize, usize),
+    next: usize,
+    _element: ::std::marker::PhantomData<&'a T>
+}
+
+impl<'a, T: 'a, M: MatrixRef<T> + 'a> Iterator for Elements<'a, T, M> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let (rows, columns) = self.dimension;
+        if columns == 0 || self.next >= rows * columns {
+            return None;
+        }
+        let index = (self.next / columns, self.next % columns);
+        self.next += 1;
+        self.source.get(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.dimension.0 * self.dimension.1).saturating_sub(self.next);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> MatrixRef<T> for Matrix<T> {
+    fn dimension(&self) -> (usize, usize) {
+        Matrix::dimension(self)
+    }
+
+    fn get(&self, index: (usize, usize)) -> Option<&T> {
+        let (rows, columns) = Matrix::dimension(self);
+        if index.0 < rows && index.1 < columns {
+            Some(&self[index])
+        } else {
+            None
+        }
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[T]> {
+        if self.alignment == Alignment::RowAligned && row < self.rows {
+            Some(&self[row])
+        } else {
+            None
+        }
+    }
+}
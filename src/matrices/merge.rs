@@ -0,0 +1,124 @@
+//! Merging augmented matrices (systems of equations) over the same variables.
+
+use num::Zero;
+
+use std::ops::Mul;
+
+use matrices::base::{AugmentedMatrix, MatrixError, RowIdx};
+use matrices::extras::AddElements;
+
+/// Summary of what `AugmentedMatrix::merge` did with the equations of the other system.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MergeReport {
+    /// Number of equations appended to the system.
+    pub appended: usize,
+    /// Number of equations skipped because they were duplicates or scalar multiples of an
+    /// equation already in the system.
+    pub duplicates: usize,
+    /// Equations that weren't appended because their left hand side is a scalar multiple of an
+    /// equation already in the system but their right hand side disagrees, as `(row in self, row
+    /// in other)` pairs.
+    pub conflicts: Vec<(RowIdx, RowIdx)>
+}
+
+enum Relation {
+    Unrelated,
+    Duplicate,
+    Conflict
+}
+
+// Compares two equations (coefficients followed by the solution) without dividing, so that
+// integer and `Fraction` systems are compared exactly. Two equations are related if their left
+// hand sides are scalar multiples of each other (non-zero scalar); a zero left hand side only
+// relates to another zero left hand side.
+fn relation<T: Clone + PartialEq + Zero + Mul<Output = T>>(a: &[T], b: &[T]) -> Relation {
+    let (a_lhs, b_lhs) = (&a[..a.len() - 1], &b[..b.len() - 1]);
+    let (a_rhs, b_rhs) = (a[a.len() - 1].clone(), b[b.len() - 1].clone());
+    if a_lhs.iter().zip(b_lhs.iter()).any(|(x, y)| x.is_zero() != y.is_zero()) {
+        return Relation::Unrelated;
+    }
+    let (a_p, b_p) = match a_lhs.iter().position(|x| !x.is_zero()) {
+        Some(p) => (a_lhs[p].clone(), b_lhs[p].clone()),
+        None => {
+            return if a_rhs.is_zero() == b_rhs.is_zero() {
+                Relation::Duplicate
+            } else {
+                Relation::Conflict
+            };
+        }
+    };
+    let proportional = |x: &T, y: &T| x.clone() * b_p.clone() == y.clone() * a_p.clone();
+    if !a_lhs.iter().zip(b_lhs.iter()).all(|(x, y)| proportional(x, y)) {
+        Relation::Unrelated
+    } else if proportional(&a_rhs, &b_rhs) {
+        Relation::Duplicate
+    } else {
+        Relation::Conflict
+    }
+}
+
+impl<T: Clone + PartialEq + Zero + Mul<Output = T>> AugmentedMatrix<T> {
+    /// Appends the equations of `other` to `self`. Both systems need the same number of
+    /// coefficient columns. With `dedup` set, each equation of `other` is first compared to the
+    /// equations already in `self` (including ones appended earlier in the same merge): exact
+    /// duplicates and scalar multiples are skipped, and equations whose left hand side is a
+    /// scalar multiple of an existing one but whose right hand side disagrees are reported as
+    /// conflicts and not appended. The comparison uses cross-multiplication, so it's exact for
+    /// integer and `Fraction` systems.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::{AugmentedMatrix, RowIdx};
+    /// let mut foo = augmented_matrix![
+    ///     1 2 => 3;
+    ///     0 1 => 1
+    /// ];
+    /// let bar = augmented_matrix![
+    ///     2 4 => 6;
+    ///     0 3 => 4;
+    ///     1 1 => 0
+    /// ];
+    /// let report = foo.merge(&bar, true).unwrap();
+    /// assert_eq!(report.appended, 1);
+    /// assert_eq!(report.duplicates, 1);
+    /// assert_eq!(report.conflicts, vec![(RowIdx(1), RowIdx(1))]);
+    /// assert_eq!(foo, augmented_matrix![
+    ///     1 2 => 3;
+    ///     0 1 => 1;
+    ///     1 1 => 0
+    /// ]);
+    /// assert!(foo.merge(&augmented_matrix![1 2 3 => 4], false).is_err());
+    /// ```
+    pub fn merge(&mut self, other: &AugmentedMatrix<T>, dedup: bool)
+        -> Result<MergeReport, MatrixError> {
+        if self.num_columns() != other.num_columns() {
+            return Err(MatrixError::FunctionError(format!("Can't merge systems with different \
+            numbers of coefficient columns ({} and {}).", self.num_columns(),
+                other.num_columns())));
+        }
+        let width = other.num_columns() + 1;
+        let mut report = MergeReport {
+            appended: 0,
+            duplicates: 0,
+            conflicts: Vec::new()
+        };
+        'equations: for r in 0..other.num_rows() {
+            let equation = (0..width).map(|c| other[(r, c)].clone()).collect::<Vec<T>>();
+            if dedup {
+                for existing in 0..self.num_rows() {
+                    let current = (0..width).map(|c| self[(existing, c)].clone())
+                        .collect::<Vec<T>>();
+                    match relation(&current, &equation) {
+                        Relation::Unrelated => continue,
+                        Relation::Duplicate => report.duplicates += 1,
+                        Relation::Conflict => report.conflicts.push((RowIdx(existing), RowIdx(r)))
+                    }
+                    continue 'equations;
+                }
+            }
+            self.push_row(equation);
+            report.appended += 1;
+        }
+        Ok(report)
+    }
+}
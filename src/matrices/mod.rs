@@ -5,7 +5,28 @@ mod format;
 pub mod try_arithmetic;
 pub mod functions;
 pub mod transforms;
+pub mod eigen;
+pub mod merge;
+pub mod elimination;
+pub mod ldlt;
+pub mod broadcast;
+pub mod iter;
+pub mod map;
+pub mod search;
+pub mod fill;
+pub mod rotate;
+pub mod cofactor;
+pub mod power;
+pub mod determinant;
+pub mod rank;
+pub mod vector;
+pub mod reductions;
+pub mod stack;
+pub mod slice;
+pub mod matrix_ref;
 #[macro_use] pub mod macros;
-//#[cfg(nightly)] pub mod matrix_simd_functions;
-//#[cfg(nightly)] pub mod matrix_simd_transforms;
+#[cfg(feature = "simd")] pub mod simd_arithmetic;
+#[cfg(feature = "simd")] pub mod simd_functions;
+#[cfg(feature = "simd")] pub mod simd_transforms;
+#[cfg(all(feature = "mmap", unix))] pub mod mapped;
 pub mod extras;
\ No newline at end of file
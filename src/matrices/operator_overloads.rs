@@ -1,30 +1,18 @@
-use num::Zero;
+use num::{One, Zero};
 
-use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Range};
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg, Range};
 use std::cmp::PartialEq;
+use std::hash::{Hash, Hasher};
 use std::fmt::Display;
 
-use matrices::base::{AugmentedMatrix, Matrix, Alignment};
+use matrices::base::{AugmentedMatrix, Matrix, MatrixError, Alignment, Unit};
 use matrices::transforms::Inverse;
 
 macro_rules! partial_eq_impl {
     ($($target_type:ty | $ref_target_type:ty),*) => ($(
         impl<T: PartialEq> PartialEq for $target_type {
             fn eq(&self, other: $ref_target_type) -> bool {
-                if self.num_columns() != other.num_columns() {
-                    return false;
-                }
-                if self.num_rows() != other.num_rows() {
-                    return false;
-                }
-                for i in 0..self.num_rows() {
-                    for j in 0..self.num_columns() {
-                        if self[(i, j)] != other[(i, j)] {
-                            return false;
-                        }
-                    }
-                }
-                true
+                self.logically_equal(other)
             }
         }
     )*)
@@ -32,401 +20,447 @@ macro_rules! partial_eq_impl {
 
 partial_eq_impl!{Matrix<T> | &Matrix<T>, AugmentedMatrix<T> | &AugmentedMatrix<T>}
 
-fn valid_operation_check(d1: (usize, usize), d2: (usize, usize), ) {
-    if d1.0 == 0 {
-        panic!("Matrix on the left of the operand has 0 rows.");
-    }
-    if d1.1 == 0 {
-        panic!("Matrix on the left of the operand has 0 columns.");
-    }
-    if d2.0 == 0 {
-        panic!("Matrix on the right of the operand has 0 rows.");
-    }
-    if d2.1 == 0 {
-        panic!("Matrix on the right of the operand has 0 columns.");
-    }
-}
-
-fn add_sub_valid_operation_check(d1: (usize, usize), d2: (usize, usize)) {
-    if d1.0 != d2.0 && d1.1 != d2.1 {
-        panic!("The matrices do not have an equal number of rows or columns.");
-    }
-    if d1.0 != d2.0 {
-        panic!("The matrices do not have an equal number of rows.");
-    }
-    if d1.1 != d2.1 {
-        panic!("The matrices do not have an equal number of columns.");
-    }
-    valid_operation_check(d1, d2);
-}
-
-impl<T, U> Add<Matrix<U>> for Matrix<T>
-    where
-        T: AddAssign<T> + Clone,
-        U: Into<T> + Clone, {
-    type Output = Matrix<T>;
-
-    fn add(mut self, rhs: Matrix<U>) -> Self {
-        add_sub_valid_operation_check(self.dimension(), rhs.dimension());
-        if self.alignment == rhs.alignment {
-            for i in 0..self.rows {
-                for j in 0..self.columns {
-                    self[i][j] += rhs[i][j].clone().into();
+// Hashing goes over the dimension and then the elements in row-major order, so that matrices
+// that are logically equal but aligned differently hash the same, as `Eq` requires.
+macro_rules! eq_hash_impl {
+    ($($target_type:ty),*) => ($(
+        impl<T: Eq> Eq for $target_type {}
+
+        impl<T: Hash> Hash for $target_type {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.dimension().hash(state);
+                for e in self.iter() {
+                    e.hash(state);
                 }
             }
-            self
-        } else {
-            for i in 0..self.rows {
-                for j in 0..self.columns {
-                    self[(i, j)] += rhs[(i, j)].clone().into();
+        }
+    )*)
+}
+
+eq_hash_impl!{Matrix<T>, AugmentedMatrix<T>}
+
+// Negates every element (including the solution column of augmented matrices), keeping the
+// dimension and alignment.
+macro_rules! neg_impl {
+    ($($target_type:ident),*) => ($(
+        impl<T: Neg<Output = T>> Neg for $target_type<T> {
+            type Output = $target_type<T>;
+
+            fn neg(self) -> $target_type<T> {
+                $target_type {
+                    rows: self.rows,
+                    columns: self.columns,
+                    matrix: self.matrix.into_iter().map(Neg::neg).collect(),
+                    alignment: self.alignment
                 }
             }
-            self
         }
-    }
-}
 
-impl<'a, T, U> Add<&'a Matrix<U>> for Matrix<T>
-    where
-        T: AddAssign<T> + Clone,
-        U: Clone,
-        Matrix<T>: Add<Matrix<U>>,
-        <Matrix<T> as Add<Matrix<U>>>::Output: Into<Matrix<T>> {
-    type Output = Matrix<T>;
+        impl<'a, T: Neg<Output = T> + Clone> Neg for &'a $target_type<T> {
+            type Output = $target_type<T>;
 
-    fn add(self, rhs: &'a Matrix<U>) -> Self {
-        (self + rhs.clone()).into()
-    }
+            fn neg(self) -> $target_type<T> {
+                self.map(|e| -e.clone())
+            }
+        }
+    )*)
 }
 
-impl<'a, T, U> Add<Matrix<U>> for &'a Matrix<T>
-    where
-        T: AddAssign<T> + Clone,
-        U: Clone,
-        Matrix<T>: Add<Matrix<U>>,
-        <Matrix<T> as Add<Matrix<U>>>::Output: Into<Matrix<T>> {
-    type Output = Matrix<T>;
+neg_impl!{Matrix, AugmentedMatrix}
 
-    fn add(self, rhs: Matrix<U>) -> Self::Output {
-        (self.clone() + rhs).into()
-    }
-}
+// Below this many elements in the left hand side, multiplication doesn't bother looking for
+// zero or unit operands first.
+const SHORT_CIRCUIT_MIN_ELEMENTS: usize = 64;
 
-impl<'a, 'b, T, U> Add<&'b Matrix<U>> for &'a Matrix<T>
+// Checks that zero times every element of `matrix` is zero. The zero and unit shortcuts of
+// multiplication rely on it: a NaN or an undefined fraction would otherwise spread through the
+// sums of the general product.
+fn absorbs_zero<T>(matrix: &Matrix<T>) -> bool
     where
-        T: AddAssign<T> + Clone,
-        U: Clone,
-        Matrix<T>: Add<Matrix<U>>,
-        <Matrix<T> as Add<Matrix<U>>>::Output: Into<Matrix<T>> {
-    type Output = Matrix<T>;
+        T: Mul<T> + Clone + Zero,
+        <T as Mul<T>>::Output: Into<T>, {
+    matrix.matrix.iter().all(|e| (T::zero() * e.clone()).into().is_zero())
+}
 
-    fn add(self, rhs: &'b Matrix<U>) -> Self::Output {
-        (self.clone() + rhs.clone()).into()
-    }
+// The error for elementwise operations on matrices of different dimensions.
+fn dimension_mismatch(op: &str, d1: (usize, usize), d2: (usize, usize)) -> MatrixError {
+    MatrixError::FunctionError(format!("Can't {} matrices of dimension {:?} and {:?}.", op, d1,
+        d2))
 }
 
-impl<T, U> Sub<Matrix<U>> for Matrix<T>
-    where
-        T: SubAssign<T> + Clone,
-        U: Into<T> + Clone, {
-    type Output = Matrix<T>;
+// Elementwise `+`, `-` and their assigning versions, for owned and borrowed operands. Everything
+// goes through `$op_assign` with a borrowed right hand side, which applies `$tokens` with
+// `zip_apply()` so that operands with different alignments are combined without realigning
+// either. Only the left hand side is ever cloned, and only when it's borrowed.
+macro_rules! elementwise_op_impls {
+    ($imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident, $tokens:tt, $op:expr,
+        $try_method:ident, $try_doc:expr) => {
+        impl<T: $assign_imp + Clone> Matrix<T> {
+            #[doc = $try_doc]
+            pub fn $try_method<U: Into<T> + Clone>(&self, other: &Matrix<U>)
+                -> Result<Matrix<T>, MatrixError> {
+                let (d1, d2) = (self.dimension(), other.dimension());
+                let mut res = self.clone();
+                res.zip_apply(other, |a, b| *a $tokens b.clone().into())
+                    .map_err(|_| dimension_mismatch($op, d1, d2))?;
+                Ok(res)
+            }
+        }
 
-    fn sub(mut self, rhs: Matrix<U>) -> Self {
-        add_sub_valid_operation_check(self.dimension(), rhs.dimension());
-        if self.alignment == rhs.alignment {
-            for i in 0..self.rows {
-                for j in 0..self.columns {
-                    self[i][j] -= rhs[i][j].clone().into();
+        impl<'a, T, U> $assign_imp<&'a Matrix<U>> for Matrix<T>
+            where
+                T: $assign_imp + Clone,
+                U: Into<T> + Clone, {
+            fn $assign_method(&mut self, rhs: &'a Matrix<U>) {
+                let (d1, d2) = (self.dimension(), rhs.dimension());
+                if self.zip_apply(rhs, |a, b| *a $tokens b.clone().into()).is_err() {
+                    panic!("{}", dimension_mismatch($op, d1, d2));
                 }
             }
-            self
-        } else {
-            for i in 0..self.rows {
-                for j in 0..self.columns {
-                    self[(i, j)] -= rhs[(i, j)].clone().into();
-                }
+        }
+
+        impl<T, U> $assign_imp<Matrix<U>> for Matrix<T>
+            where
+                T: $assign_imp + Clone,
+                U: Into<T> + Clone, {
+            fn $assign_method(&mut self, rhs: Matrix<U>) {
+                *self $tokens &rhs;
             }
-            self
         }
-    }
-}
 
-impl<'a, T, U> Sub<&'a Matrix<U>> for Matrix<T>
-    where
-        T: SubAssign<T> + Clone,
-        U: Clone,
-        Matrix<T>: Sub<Matrix<U>>,
-        <Matrix<T> as Sub<Matrix<U>>>::Output: Into<Matrix<T>> {
-    type Output = Matrix<T>;
+        impl<'a, T, U> $imp<&'a Matrix<U>> for Matrix<T>
+            where
+                T: $assign_imp + Clone,
+                U: Into<T> + Clone, {
+            type Output = Matrix<T>;
 
-    fn sub(self, rhs: &'a Matrix<U>) -> Self {
-        (self - rhs.clone()).into()
-    }
-}
+            fn $method(mut self, rhs: &'a Matrix<U>) -> Matrix<T> {
+                self $tokens rhs;
+                self
+            }
+        }
 
-impl<'a, T, U> Sub<Matrix<U>> for &'a Matrix<T>
-    where
-        T: SubAssign<T> + Clone,
-        U: Clone,
-        Matrix<T>: Sub<Matrix<U>>,
-        <Matrix<T> as Sub<Matrix<U>>>::Output: Into<Matrix<T>> {
-    type Output = Matrix<T>;
+        impl<T, U> $imp<Matrix<U>> for Matrix<T>
+            where
+                T: $assign_imp + Clone,
+                U: Into<T> + Clone, {
+            type Output = Matrix<T>;
 
-    fn sub(self, rhs: Matrix<U>) -> Self::Output {
-        (self.clone() - rhs).into()
-    }
-}
+            fn $method(mut self, rhs: Matrix<U>) -> Matrix<T> {
+                self $tokens &rhs;
+                self
+            }
+        }
 
-impl<'a, 'b, T, U> Sub<&'b Matrix<U>> for &'a Matrix<T>
-    where
-        T: SubAssign<T> + Clone,
-        U: Clone,
-        Matrix<T>: Sub<Matrix<U>>,
-        <Matrix<T> as Sub<Matrix<U>>>::Output: Into<Matrix<T>> {
-    type Output = Matrix<T>;
+        impl<'a, T, U> $imp<Matrix<U>> for &'a Matrix<T>
+            where
+                T: $assign_imp + Clone,
+                U: Into<T> + Clone, {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: Matrix<U>) -> Matrix<T> {
+                let mut res = self.clone();
+                res $tokens &rhs;
+                res
+            }
+        }
+
+        impl<'a, 'b, T, U> $imp<&'b Matrix<U>> for &'a Matrix<T>
+            where
+                T: $assign_imp + Clone,
+                U: Into<T> + Clone, {
+            type Output = Matrix<T>;
 
-    fn sub(self, rhs: &'b Matrix<U>) -> Self::Output {
-        (self.clone() - rhs.clone()).into()
+            fn $method(self, rhs: &'b Matrix<U>) -> Matrix<T> {
+                let mut res = self.clone();
+                res $tokens rhs;
+                res
+            }
+        }
     }
 }
 
-fn mul_div_valid_operation_check(d1: (usize, usize), d2: (usize, usize)) {
+elementwise_op_impls!{Add, add, AddAssign, add_assign, +=, "add", try_add,
+    "Adds `other` to the matrix elementwise, like `+`, but returns a `FunctionError` with both
+    dimensions instead of panicking if they differ. `other` can have either alignment; the
+    result has the alignment of `self`."}
+elementwise_op_impls!{Sub, sub, SubAssign, sub_assign, -=, "subtract", try_sub,
+    "Subtracts `other` from the matrix elementwise, like `-`, but returns a `FunctionError` with
+    both dimensions instead of panicking if they differ. `other` can have either alignment; the
+    result has the alignment of `self`."}
+
+// Errors unless a `d1` matrix can be multiplied by a `d2` one.
+fn product_dimension_check(d1: (usize, usize), d2: (usize, usize)) -> Result<(), MatrixError> {
     if d1.1 != d2.0 {
-        panic!("The matrix on the left of the operand does not have the same number of columns as \
-        the number of rows in the matrix on the right of the operand.");
+        return Err(MatrixError::FunctionError(format!("Can't multiply a {:?} matrix by a {:?} \
+        matrix: the left one has {} columns but the right one has {} rows.", d1, d2, d1.1,
+            d2.0)));
     }
-    valid_operation_check(d1, d2);
+    Ok(())
 }
 
-impl<T, U> Mul<Matrix<U>> for Matrix<T>
+impl<T> Matrix<T>
     where
-        T: AddAssign + Mul<T> + Clone + Zero,
-        U: Into<T> + Clone,
+        T: AddAssign + Mul<T> + PartialEq + Clone + Zero + One,
         <T as Mul<T>>::Output: Into<T>, {
-    type Output = Matrix<T>;
-
-    fn mul(self, rhs: Matrix<U>) -> Self {
-        mul_div_valid_operation_check(self.dimension(), rhs.dimension());
-        if self.alignment != rhs.alignment {
-            let mut matr = Matrix::splat(&T::zero(), (self.rows, rhs.rows), self.alignment.clone());
-            for a in 0..self.rows {
-                for b in 0..rhs.rows {
-                    matr[(a,b)] += (self[a][b].clone() * rhs[b][a].clone().into()).into();
-                }
+    /// Multiplies the matrix by `other` (the matrix product, not the elementwise one), like `*`,
+    /// but returns a `FunctionError` instead of panicking if the number of columns of `self`
+    /// isn't the number of rows of `other`. Empty operands are fine: an m x 0 matrix times a
+    /// 0 x p one is the m x p zero matrix, and an empty dimension on the outside gives an empty
+    /// product.
+    pub fn try_mul<U: Into<T> + Clone>(&self, other: &Matrix<U>)
+        -> Result<Matrix<T>, MatrixError> {
+        product_dimension_check(self.dimension(), other.dimension())?;
+        let (m, p) = (self.num_rows(), other.num_columns());
+        let mut rhs: Matrix<T> = other.map(|e| e.clone().into());
+        // Every term has a factor from each side, so a zero operand gives a zero product, and a
+        // unit one gives the other operand, as long as the other one has no NaNs or undefined
+        // fractions. The checks aren't worth it for small matrices.
+        if self.matrix.len() >= SHORT_CIRCUIT_MIN_ELEMENTS {
+            if (self.is_zero_matrix() && absorbs_zero(&rhs))
+                || (rhs.is_zero_matrix() && absorbs_zero(self)) {
+                return Ok(Matrix::zeros_aligned((m, p), self.alignment.clone()));
             }
-            matr
-        } else {
-            let mut matr = Matrix::splat(&T::zero(), (self.rows, rhs.rows), self.alignment.clone());
-            for a in 0..self.rows {
-                for b in 0..rhs.rows {
-                    matr[(a,b)] += (self[(a, b)].clone() * rhs[(b, a)].clone().into()).into();
+            if self.is_unit() && absorbs_zero(&rhs) {
+                match self.alignment {
+                    Alignment::RowAligned => rhs.row_align(),
+                    Alignment::ColumnAligned => rhs.column_align()
                 }
+                return Ok(rhs);
+            }
+            if rhs.is_unit() && absorbs_zero(self) {
+                return Ok(self.clone());
             }
-            matr
         }
+        // Each element of the product runs along a row of `self` and a column of `other`, so read
+        // both from stored lines: the rows of a row-aligned copy of `self` (if it isn't one
+        // already) and the columns of a column-aligned copy of `other`.
+        let realigned;
+        let lhs = if self.is_row_aligned() {
+            self
+        } else {
+            realigned = { let mut lhs = self.clone(); lhs.row_align(); lhs };
+            &realigned
+        };
+        rhs.column_align();
+        let dot = |i: usize, j: usize| {
+            let row = &lhs.matrix[i * lhs.columns..(i + 1) * lhs.columns];
+            let column = &rhs.matrix[j * rhs.columns..(j + 1) * rhs.columns];
+            row.iter().zip(column).fold(T::zero(), |mut acc, (a, b)| {
+                acc += a.clone() * b.clone();
+                acc
+            })
+        };
+        // Fill in the product in the storage order of `self`.
+        let (rows, columns, matrix) = match self.alignment {
+            Alignment::RowAligned => (m, p, (0..m).flat_map(|i| (0..p).map(move |j| (i, j)))
+                .map(|(i, j)| dot(i, j))
+                .collect()),
+            Alignment::ColumnAligned => (p, m, (0..p).flat_map(|j| (0..m).map(move |i| (i, j)))
+                .map(|(i, j)| dot(i, j))
+                .collect())
+        };
+        Ok(Matrix { rows, columns, matrix, alignment: self.alignment.clone() })
     }
 }
 
-impl<'a, T, U> Mul<&'a Matrix<U>> for Matrix<T>
+/// The matrix product: an m x n matrix times an n x p one is the m x p matrix whose `(i, j)`th
+/// element is the sum of the products of the elements of row `i` of the left one and column `j`
+/// of the right one. The result has the alignment of the left hand side. Panics if the left
+/// hand side doesn't have as many columns as the right hand side has rows; see `try_mul()`.
+/// Only `Matrix`es can be multiplied: to multiply the coefficients of an `AugmentedMatrix`, split
+/// it up first.
+impl<T, U> Mul<&Matrix<U>> for &Matrix<T>
     where
-        T: AddAssign + Mul + MulAssign<T> + Clone + Zero,
-        U: Clone,
-        <T as Mul>::Output: Into<T>,
-        Matrix<T>: Mul<Matrix<U>>,
-        <Matrix<T> as Mul<Matrix<U>>>::Output: Into<Matrix<T>> {
+        T: AddAssign + Mul<T> + PartialEq + Clone + Zero + One,
+        U: Into<T> + Clone,
+        <T as Mul<T>>::Output: Into<T>, {
     type Output = Matrix<T>;
 
-    fn mul(self, rhs: &'a Matrix<U>) -> Self {
-        (self * rhs.clone()).into()
+    fn mul(self, rhs: &Matrix<U>) -> Matrix<T> {
+        match self.try_mul(rhs) {
+            Ok(product) => product,
+            Err(e) => panic!("{}", e)
+        }
     }
 }
 
 impl<'a, T, U> Mul<Matrix<U>> for &'a Matrix<T>
     where
-        T: AddAssign + Mul + MulAssign<T> + Clone + Zero,
-        U: Clone,
-        <T as Mul>::Output: Into<T>,
-        Matrix<T>: Mul<Matrix<U>>,
-        <Matrix<T> as Mul<Matrix<U>>>::Output: Into<Matrix<T>> {
+        T: AddAssign + Mul<T> + PartialEq + Clone + Zero + One,
+        U: Into<T> + Clone,
+        <T as Mul<T>>::Output: Into<T>, {
     type Output = Matrix<T>;
 
-    fn mul(self, rhs: Matrix<U>) -> Self::Output {
-        (self.clone() * rhs).into()
+    fn mul(self, rhs: Matrix<U>) -> Matrix<T> {
+        self * &rhs
     }
 }
 
-impl<'a, 'b, T, U> Mul<&'b Matrix<U>> for &'a Matrix<T>
+impl<'a, T, U> Mul<&'a Matrix<U>> for Matrix<T>
     where
-        T: AddAssign + Mul + MulAssign<T> + Clone + Zero,
-        U: Clone,
-        <T as Mul>::Output: Into<T>,
-        Matrix<T>: Mul<Matrix<U>>,
-        <Matrix<T> as Mul<Matrix<U>>>::Output: Into<Matrix<T>> {
+        T: AddAssign + Mul<T> + PartialEq + Clone + Zero + One,
+        U: Into<T> + Clone,
+        <T as Mul<T>>::Output: Into<T>, {
     type Output = Matrix<T>;
 
-    fn mul(self, rhs: &'b Matrix<U>) -> Self::Output {
-        (self.clone() * rhs.clone()).into()
+    fn mul(self, rhs: &'a Matrix<U>) -> Matrix<T> {
+        &self * rhs
     }
 }
 
-impl<T, U> Div<Matrix<U>> for Matrix<T>
+impl<T, U> Mul<Matrix<U>> for Matrix<T>
     where
-        Matrix<U>: Inverse + Clone,
-        Matrix<T>: Mul<Matrix<U>>,
-        <Matrix<T> as Mul<Matrix<U>>>::Output: Into<Matrix<T>> {
+        T: AddAssign + Mul<T> + PartialEq + Clone + Zero + One,
+        U: Into<T> + Clone,
+        <T as Mul<T>>::Output: Into<T>, {
     type Output = Matrix<T>;
 
-    fn div(self, rhs: Matrix<U>) -> Self {
-        mul_div_valid_operation_check(self.dimension(), rhs.dimension());
-        let mut inv = rhs.clone();
-        inv.inverse();
-        (self * inv).into()
+    fn mul(self, rhs: Matrix<U>) -> Matrix<T> {
+        &self * &rhs
     }
 }
 
-impl<'a, T, U> Div<&'a Matrix<U>> for Matrix<T>
+impl<T> Matrix<T>
+    where
+        T: AddAssign + Mul<T> + PartialEq + Clone + Zero + One,
+        <T as Mul<T>>::Output: Into<T>, {
+    /// Divides the matrix by `other`, i.e. multiplies it by the inverse of `other`, like `/`.
+    /// Returns a `FunctionError` if `other` isn't square or its number of rows isn't the number
+    /// of columns of `self`, and the `TransformError` from `try_inverse()` if `other` can't be
+    /// inverted.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, MatrixErrorKind};
+    /// let foo = matrix![
+    ///     1 2;
+    ///     3 4
+    /// ].convert::<Fraction>();
+    /// let bar = matrix![
+    ///     2 1;
+    ///     1 1
+    /// ].convert::<Fraction>();
+    /// assert_eq!((&foo * &bar).try_div(&bar).unwrap(), foo);
+    /// let singular = matrix![
+    ///     1 2;
+    ///     2 4
+    /// ].convert::<Fraction>();
+    /// assert_eq!(foo.try_div(&singular).unwrap_err().kind(), MatrixErrorKind::Transform);
+    /// ```
+    pub fn try_div<U: Into<T> + Clone>(&self, other: &Matrix<U>)
+        -> Result<Matrix<T>, MatrixError> where Matrix<U>: Inverse + Clone {
+        let (d1, d2) = (self.dimension(), other.dimension());
+        if d2.0 != d2.1 {
+            return Err(MatrixError::FunctionError(format!("Can't divide a {:?} matrix by a {:?} \
+            matrix: only square matrices can be inverted.", d1, d2)));
+        }
+        product_dimension_check(d1, d2)?;
+        let mut inverse = other.clone();
+        inverse.try_inverse()?;
+        self.try_mul(&inverse)
+    }
+}
+
+/// Division by a matrix: multiplication by its inverse, so `(A * B) / B == A`. The result has the
+/// alignment of the left hand side. Panics if the right hand side isn't square, doesn't have as
+/// many rows as the left hand side has columns, or can't be inverted; see `try_div()`.
+impl<T, U> Div<&Matrix<U>> for &Matrix<T>
     where
+        T: AddAssign + Mul<T> + PartialEq + Clone + Zero + One,
+        U: Into<T> + Clone,
         Matrix<U>: Inverse + Clone,
-        Matrix<T>: Mul<Matrix<U>>,
-        <Matrix<T> as Mul<Matrix<U>>>::Output: Into<Matrix<T>> {
+        <T as Mul<T>>::Output: Into<T>, {
     type Output = Matrix<T>;
 
-    fn div(self, rhs: &'a Matrix<U>) -> Matrix<T> {
-        mul_div_valid_operation_check(self.dimension(), rhs.dimension());
-        let mut inv = rhs.clone();
-        inv.inverse();
-        (self * inv).into()
+    fn div(self, rhs: &Matrix<U>) -> Matrix<T> {
+        match self.try_div(rhs) {
+            Ok(quotient) => quotient,
+            Err(e) => panic!("{}", e)
+        }
     }
 }
 
 impl<'a, T, U> Div<Matrix<U>> for &'a Matrix<T>
     where
-        T: Clone,
+        T: AddAssign + Mul<T> + PartialEq + Clone + Zero + One,
+        U: Into<T> + Clone,
         Matrix<U>: Inverse + Clone,
-        Matrix<T>: Mul<Matrix<U>>,
-        <Matrix<T> as Mul<Matrix<U>>>::Output: Into<Matrix<T>> {
+        <T as Mul<T>>::Output: Into<T>, {
     type Output = Matrix<T>;
 
     fn div(self, rhs: Matrix<U>) -> Matrix<T> {
-        mul_div_valid_operation_check(self.dimension(), rhs.dimension());
-        let mut inv = rhs.clone();
-        inv.inverse();
-        (self.clone() * inv).into()
+        self / &rhs
     }
 }
 
-impl<'a, 'b, T, U> Div<&'b Matrix<U>> for &'a Matrix<T>
+impl<'a, T, U> Div<&'a Matrix<U>> for Matrix<T>
     where
+        T: AddAssign + Mul<T> + PartialEq + Clone + Zero + One,
+        U: Into<T> + Clone,
         Matrix<U>: Inverse + Clone,
-        Matrix<T>: Mul<Matrix<U>> + Clone,
-        <Matrix<T> as Mul<Matrix<U>>>::Output: Into<Matrix<T>> {
+        <T as Mul<T>>::Output: Into<T>, {
     type Output = Matrix<T>;
 
-    fn div(self, rhs: &'b Matrix<U>) -> Matrix<T> {
-        mul_div_valid_operation_check(self.dimension(), rhs.dimension());
-        let mut inv = rhs.clone();
-        inv.inverse();
-        (self.clone() * inv).into()
-    }
-}
-
-macro_rules! matrix_operator_overload_assign_impl {
-    ($imp:ident, $method:ident, $tokens:tt) => {
-        impl<'a, T, U> $imp<&'a Matrix<U>> for Matrix<T> where
-            U: Clone,
-            Matrix<T>: $imp<Matrix<U>> {
-            fn $method(&mut self, rhs: &'a Matrix<U>) {
-                *self $tokens rhs.clone()
-            }
-        }
+    fn div(self, rhs: &'a Matrix<U>) -> Matrix<T> {
+        &self / rhs
     }
 }
 
-impl<T, U> AddAssign<Matrix<U>> for Matrix<T>
+impl<T, U> Div<Matrix<U>> for Matrix<T>
     where
-        T: AddAssign + Clone,
-        U: Into<T> + Clone, {
-    fn add_assign(&mut self, rhs: Matrix<U>) {
-        add_sub_valid_operation_check(self.dimension(), rhs.dimension());
-        if self.alignment == rhs.alignment {
-            for i in 0..self.rows {
-                for j in 0..self.columns {
-                    self[i][j] += rhs[i][j].clone().into();
-                }
-            }
-        } else {
-            for i in 0..self.rows {
-                for j in 0..self.columns {
-                    self[(i, j)] += rhs[(i, j)].clone().into();
-                }
-            }
-        }
+        T: AddAssign + Mul<T> + PartialEq + Clone + Zero + One,
+        U: Into<T> + Clone,
+        Matrix<U>: Inverse + Clone,
+        <T as Mul<T>>::Output: Into<T>, {
+    type Output = Matrix<T>;
+
+    fn div(self, rhs: Matrix<U>) -> Matrix<T> {
+        &self / &rhs
     }
 }
 
-matrix_operator_overload_assign_impl!{AddAssign, add_assign, +=}
-
-impl<T, U> SubAssign<Matrix<U>> for Matrix<T>
+/// Replaces the matrix with its product with `rhs` (see `Mul`).
+impl<'a, T, U> MulAssign<&'a Matrix<U>> for Matrix<T>
     where
-        T: SubAssign + From<U>,
-        U: SubAssign<T> + Clone + SubAssign<U>, {
-    fn sub_assign(&mut self, rhs: Matrix<U>) {
-        add_sub_valid_operation_check(self.dimension(), rhs.dimension());
-        if self.alignment == rhs.alignment {
-            for i in 0..self.rows {
-                for j in 0..self.columns {
-                    self[i][j] -= rhs[i][j].clone().into();
-                }
-            }
-        } else {
-            for i in 0..self.rows {
-                for j in 0..self.columns {
-                    self[(i, j)] -= rhs[(i, j)].clone().into();
-                }
-            }
-        }
+        T: AddAssign + Mul<T> + PartialEq + Clone + Zero + One,
+        U: Into<T> + Clone,
+        <T as Mul<T>>::Output: Into<T>, {
+    fn mul_assign(&mut self, rhs: &'a Matrix<U>) {
+        *self = &*self * rhs;
     }
 }
 
-matrix_operator_overload_assign_impl!{SubAssign, sub_assign, -=}
-
 impl<T, U> MulAssign<Matrix<U>> for Matrix<T>
     where
-        T: Add + AddAssign + Mul + MulAssign + Clone + Zero
-        + From<U> + From<<T as Mul<T>>::Output>,
-        U: Mul<T> + Mul + Clone + Mul<U>, {
+        T: AddAssign + Mul<T> + PartialEq + Clone + Zero + One,
+        U: Into<T> + Clone,
+        <T as Mul<T>>::Output: Into<T>, {
     fn mul_assign(&mut self, rhs: Matrix<U>) {
-        mul_div_valid_operation_check(self.dimension(), rhs.dimension());
-        if self.alignment != rhs.alignment {
-            let mut matr = Matrix::splat(&T::zero(), (self.rows, rhs.rows), self.alignment.clone());
-            for a in 0..self.rows {
-                for b in 0..rhs.rows {
-                    matr[(a, b)] += (self[a][b].clone() + rhs[b][a].clone().into()).into();
-                }
-            }
-            *self = matr;
-        } else {
-            let mut matr = Matrix::splat(&T::zero(), (self.rows, rhs.rows), self.alignment.clone());
-            for a in 0..self.rows {
-                for b in 0..rhs.rows {
-                    matr[(a, b)] += (self[(a, b)].clone() + rhs[(b, a)].clone().into()).into();
-                }
-            }
-            *self = matr;
-        }
+        *self *= &rhs;
     }
 }
 
-matrix_operator_overload_assign_impl!{MulAssign, mul_assign, *=}
+/// Replaces the matrix with its quotient by `rhs` (see `Div`).
+impl<'a, T, U> DivAssign<&'a Matrix<U>> for Matrix<T>
+    where
+        T: AddAssign + Mul<T> + PartialEq + Clone + Zero + One,
+        U: Into<T> + Clone,
+        Matrix<U>: Inverse + Clone,
+        <T as Mul<T>>::Output: Into<T>, {
+    fn div_assign(&mut self, rhs: &'a Matrix<U>) {
+        *self = &*self / rhs;
+    }
+}
 
 impl<T, U> DivAssign<Matrix<U>> for Matrix<T>
-    where Matrix<U>: Inverse + Clone, Matrix<T>: MulAssign<Matrix<U>>, {
+    where
+        T: AddAssign + Mul<T> + PartialEq + Clone + Zero + One,
+        U: Into<T> + Clone,
+        Matrix<U>: Inverse + Clone,
+        <T as Mul<T>>::Output: Into<T>, {
     fn div_assign(&mut self, rhs: Matrix<U>) {
-        mul_div_valid_operation_check(self.dimension(), rhs.dimension());
-        let mut inv = rhs.clone();
-        inv.inverse();
-        *self *= inv;
+        *self /= &rhs;
     }
-}
-
-matrix_operator_overload_assign_impl!{DivAssign, div_assign, /=}
\ No newline at end of file
+}
\ No newline at end of file
@@ -0,0 +1,99 @@
+//! Integer powers of square matrices, by binary exponentiation: `pow(n)` takes about `2 * log2(n)`
+//! matrix products rather than `n - 1`, which matters for `Fraction` matrices, where every product
+//! is exact (and slow) arithmetic. `pow_i()` also takes negative exponents, inverting the matrix
+//! first.
+//! # Example
+//! ```rust
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::matrices::base::{Matrix, Unit};
+//! let fibonacci = matrix![
+//!     1 1;
+//!     1 0
+//! ];
+//! assert_eq!(fibonacci.pow(10).unwrap(), matrix![
+//!     89 55;
+//!     55 34
+//! ]);
+//! assert_eq!(fibonacci.pow(0).unwrap(), Matrix::unit(2));
+//! assert!(matrix![1 2 3].pow(2).is_err());
+//! ```
+
+use std::ops::{AddAssign, Mul};
+
+use num::{One, Zero};
+
+use matrices::base::{Matrix, MatrixError, Unit};
+use matrices::transforms::Inverse;
+
+impl<T> Matrix<T>
+    where
+        T: AddAssign + Mul<T> + PartialEq + Clone + Zero + One,
+        <T as Mul<T>>::Output: Into<T>, {
+    /// Multiplies the matrix by itself `n` times. `pow(0)` is the unit matrix of the same
+    /// dimension, and the result has the alignment of `self`. Returns an error unless the matrix
+    /// is square.
+    pub fn pow(&self, n: u32) -> Result<Matrix<T>, MatrixError> {
+        let dimension = self.dimension();
+        if dimension.0 != dimension.1 {
+            return Err(MatrixError::FunctionError(format!("Only square matrices have powers, \
+            not {:?} ones.", dimension)));
+        }
+        // The product of the powers of two of `self` for the bits set in `n`
+        let mut res: Option<Matrix<T>> = None;
+        let mut square = self.clone();
+        let mut n = n;
+        while n > 0 {
+            // Once a power of two of `self` is the unit matrix, so are all the later ones, and
+            // multiplying by them wouldn't change `res`.
+            if square.is_unit() {
+                break;
+            }
+            if n & 1 == 1 {
+                res = Some(match res {
+                    Some(res) => &res * &square,
+                    None => square.clone()
+                });
+            }
+            n >>= 1;
+            if n > 0 {
+                square = &square * &square;
+            }
+        }
+        Ok(res.unwrap_or_else(|| {
+            let mut unit = Matrix::unit(dimension.0);
+            if self.is_column_aligned() {
+                unit.column_align();
+            }
+            unit
+        }))
+    }
+
+    /// Like `pow()`, but a negative `n` gives the `-n`th power of the inverse. Returns an error
+    /// unless the matrix is square, or if it's needed but `try_inverse()` can't find the inverse.
+    /// # Example
+    /// ```rust
+    /// # extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::fractions::base::Fraction;
+    /// # use fractions_and_matrices::matrices::base::{Matrix, Alignment::RowAligned};
+    /// let foo = Matrix::from_integer_slice((2, 2), &[2, 0, 0, 4], RowAligned).unwrap();
+    /// let bar = foo.pow_i(-2).unwrap();
+    /// assert_eq!(bar[(0, 0)], Fraction::new(1, 4));
+    /// assert_eq!(bar[(1, 1)], Fraction::new(1, 16));
+    /// assert!(Matrix::from_integer_slice((2, 2), &[1, 2, 2, 4], RowAligned).unwrap()
+    ///     .pow_i(-1)
+    ///     .is_err());
+    /// ```
+    pub fn pow_i(&self, n: i32) -> Result<Matrix<T>, MatrixError> where Matrix<T>: Inverse {
+        if n >= 0 {
+            return self.pow(n as u32);
+        }
+        let dimension = self.dimension();
+        if dimension.0 != dimension.1 {
+            return Err(MatrixError::FunctionError(format!("Only square matrices have powers, \
+            not {:?} ones.", dimension)));
+        }
+        let mut inverse = self.clone();
+        inverse.try_inverse()?;
+        inverse.pow(n.unsigned_abs())
+    }
+}
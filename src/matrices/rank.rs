@@ -0,0 +1,134 @@
+//! Ranks of matrices, found by fraction-free (Bareiss) elimination of a copy, so the matrix isn't
+//! changed. Every division in Bareiss elimination is exact, so this works for integers (signed or
+//! unsigned) as well as for fields such as `Fraction` and the floats. For an augmented matrix,
+//! comparing `rank()`
+//! (of the coefficient matrix) with `augmented_rank()` tells you whether the system has a solution:
+//! it does exactly when they're equal.
+//! # Example
+//! ```rust
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::matrices::base::{Matrix, AugmentedMatrix};
+//! let foo = matrix![
+//!     1 2 3;
+//!     2 4 6
+//! ];
+//! assert_eq!(foo.rank(), 1);
+//! let inconsistent = augmented_matrix![
+//!     1 1 => 1;
+//!     2 2 => 3
+//! ];
+//! assert_eq!(inconsistent.rank(), 1);
+//! assert_eq!(inconsistent.augmented_rank(), 2);
+//! ```
+
+use std::ops::{Sub, Mul, Div};
+
+use num::{One, Zero};
+
+use matrices::base::{Alignment, AugmentedMatrix, Matrix};
+
+// A value stored as a sign and a magnitude, so that the elimination below only ever subtracts a
+// smaller magnitude from a bigger one and works for unsigned elements too.
+#[derive(Clone)]
+struct SignMagnitude<T> {
+    negative: bool,
+    magnitude: T
+}
+
+impl<T> SignMagnitude<T>
+    where T: Clone + PartialOrd + Zero + Sub<Output = T> + Mul<Output = T> + Div<Output = T> {
+    fn new(magnitude: T) -> SignMagnitude<T> {
+        SignMagnitude { negative: false, magnitude }
+    }
+
+    fn times(&self, other: &SignMagnitude<T>) -> SignMagnitude<T> {
+        SignMagnitude {
+            negative: self.negative != other.negative,
+            magnitude: self.magnitude.clone() * other.magnitude.clone()
+        }
+    }
+
+    fn over(&self, other: &SignMagnitude<T>) -> SignMagnitude<T> {
+        SignMagnitude {
+            negative: self.negative != other.negative,
+            magnitude: self.magnitude.clone() / other.magnitude.clone()
+        }
+    }
+
+    fn minus(self, other: SignMagnitude<T>) -> SignMagnitude<T> {
+        if self.negative != other.negative {
+            SignMagnitude { negative: self.negative, magnitude: self.magnitude + other.magnitude }
+        } else if self.magnitude >= other.magnitude {
+            SignMagnitude { negative: self.negative, magnitude: self.magnitude - other.magnitude }
+        } else {
+            SignMagnitude { negative: !self.negative, magnitude: other.magnitude - self.magnitude }
+        }
+    }
+}
+
+// The rank of the first `width` elements of the first `lines` stored lines of a backing vec whose
+// lines are `stride` long. Rank doesn't change under transposition, so the stored lines can be
+// eliminated as rows whichever way the matrix is aligned.
+fn bareiss_rank<T>(matrix: &[T], stride: usize, lines: usize, width: usize) -> usize
+    where T: Clone + PartialOrd + Zero + One + Sub<Output = T> + Div<Output = T> {
+    if stride == 0 {
+        return 0;
+    }
+    let mut a = matrix.chunks(stride).take(lines)
+        .map(|line| line[..width].iter().cloned().map(SignMagnitude::new).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let mut previous = SignMagnitude::new(T::one());
+    let mut rank = 0;
+    for c in 0..width {
+        if rank == a.len() {
+            break;
+        }
+        let pivot = match (rank..a.len()).find(|&r| !a[r][c].magnitude.is_zero()) {
+            Some(pivot) => pivot,
+            None => continue
+        };
+        a.swap(rank, pivot);
+        let (done, rest) = a.split_at_mut(rank + 1);
+        let pivot_row = &done[rank];
+        for row in rest {
+            for j in c + 1..width {
+                row[j] = pivot_row[c].times(&row[j]).minus(row[c].times(&pivot_row[j]))
+                    .over(&previous);
+            }
+            row[c] = SignMagnitude::new(T::zero());
+        }
+        previous = pivot_row[c].clone();
+        rank += 1;
+    }
+    rank
+}
+
+impl<T> Matrix<T>
+    where T: Clone + PartialOrd + Zero + One + Sub<Output = T> + Div<Output = T> {
+    /// Gives the rank of the matrix: the number of linearly independent rows (or columns). Note
+    /// that for floating point matrices this is only as good as the rounding allows, since only
+    /// elements that are exactly zero count as zero.
+    pub fn rank(&self) -> usize {
+        bareiss_rank(&self.matrix, self.columns, self.rows, self.columns)
+    }
+}
+
+impl<T> AugmentedMatrix<T>
+    where T: Clone + PartialOrd + Zero + One + Sub<Output = T> + Div<Output = T> {
+    /// Gives the rank of the coefficient matrix, leaving out the solution column. See
+    /// `Matrix::rank()`.
+    pub fn rank(&self) -> usize {
+        match self.alignment {
+            Alignment::RowAligned => bareiss_rank(&self.matrix, self.columns, self.rows,
+                self.columns - 1),
+            Alignment::ColumnAligned => bareiss_rank(&self.matrix, self.columns, self.rows - 1,
+                self.columns)
+        }
+    }
+
+    /// Gives the rank of the whole augmented matrix, including the solution column. The system
+    /// is consistent exactly when this is equal to `rank()`.
+    pub fn augmented_rank(&self) -> usize {
+        bareiss_rank(&self.matrix, self.columns, self.rows, self.columns)
+    }
+}
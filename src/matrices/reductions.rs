@@ -0,0 +1,309 @@
+//! Reductions of the rows, the columns or all of the elements of a matrix: sums, means, products,
+//! minimums and maximums, and the squared Frobenius norm, as well as `fold_rows()`, `reduce_rows()`
+//! and their column counterparts for anything else. The norm is left squared so that it stays exact
+//! for `Fraction`s; take the square root if you need it. Apart from the folds, row and column
+//! reductions read whole stored lines at a time whatever the alignment. On augmented matrices these
+//! only cover the coefficient matrix: the solution column is left out.
+//! # Example
+//! ```rust
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::fractions::base::Fraction;
+//! # use fractions_and_matrices::matrices::base::{Matrix, AugmentedMatrix};
+//! let foo = matrix![
+//!     1 2 3;
+//!     4 5 6
+//! ];
+//! assert_eq!(foo.row_sums(), vec![6, 15]);
+//! assert_eq!(foo.column_sums(), vec![5, 7, 9]);
+//! assert_eq!(foo.sum(), 21);
+//! assert_eq!(foo.frobenius_norm_squared(), 91);
+//! assert_eq!(foo.product(), 720);
+//! assert_eq!(foo.min(), Some(1));
+//! assert_eq!(foo.row_max(), vec![3, 6]);
+//! assert_eq!(foo.column_min(), vec![1, 2, 3]);
+//! let means = foo.convert::<Fraction>().row_means();
+//! assert_eq!(means, vec![Fraction::from(2), Fraction::from(5)]);
+//! let bar = augmented_matrix![
+//!     1 2 => 10;
+//!     3 4 => 20
+//! ];
+//! assert_eq!(bar.row_sums(), vec![3, 7]);
+//! assert_eq!(bar.sum(), 10);
+//! assert_eq!(bar.max(), Some(4));
+//! // The number of odd elements in each row
+//! let odd = bar.fold_rows(Vec::new(), |mut acc, row| {
+//!     acc.push(row.iter().filter(|&&e| e % 2 == 1).count());
+//!     acc
+//! });
+//! assert_eq!(odd, vec![1, 1]);
+//! ```
+
+use std::ops::{Div, Mul};
+
+use num::{FromPrimitive, One, Zero};
+
+use matrices::base::{Alignment, AugmentedMatrix, Matrix};
+
+// The sums of the first `width` elements of each of the first `lines` stored lines, where stored
+// lines are `stride` elements long.
+fn line_sums<T: Zero + Clone>(matrix: &[T], stride: usize, lines: usize, width: usize) -> Vec<T> {
+    if stride == 0 {
+        return vec![T::zero(); lines];
+    }
+    matrix.chunks(stride)
+        .take(lines)
+        .map(|line| line[..width].iter().fold(T::zero(), |acc, e| acc + e.clone()))
+        .collect()
+}
+
+// The sums across the first `lines` stored lines of each of their first `width` positions. Adds
+// whole stored lines at a time so the backing vec is read in order.
+fn cross_sums<T: Zero + Clone>(matrix: &[T], stride: usize, lines: usize, width: usize) -> Vec<T> {
+    let mut sums = vec![T::zero(); width];
+    if stride == 0 {
+        return sums;
+    }
+    for line in matrix.chunks(stride).take(lines) {
+        for (sum, e) in sums.iter_mut().zip(line.iter()) {
+            *sum = sum.clone() + e.clone();
+        }
+    }
+    sums
+}
+
+// The reduction with `f` of the first `width` elements of each of the first `lines` stored lines,
+// where stored lines are `stride` elements long. Empty if the lines are.
+fn line_reduce<T: Clone, F: FnMut(T, &T) -> T>(matrix: &[T], stride: usize, lines: usize,
+    width: usize, mut f: F) -> Vec<T> {
+    if width == 0 {
+        return Vec::new();
+    }
+    matrix.chunks(stride)
+        .take(lines)
+        .map(|line| line[1..width].iter().fold(line[0].clone(), &mut f))
+        .collect()
+}
+
+// The reduction with `f` across the first `lines` stored lines of each of their first `width`
+// positions, reading the backing vec in order. Empty if there are no lines.
+fn cross_reduce<T: Clone, F: FnMut(T, &T) -> T>(matrix: &[T], stride: usize, lines: usize,
+    width: usize, mut f: F) -> Vec<T> {
+    if lines == 0 || width == 0 {
+        return Vec::new();
+    }
+    let mut reduced = matrix[..width].to_vec();
+    for line in matrix.chunks(stride).take(lines).skip(1) {
+        for (acc, e) in reduced.iter_mut().zip(line.iter()) {
+            *acc = f(acc.clone(), e);
+        }
+    }
+    reduced
+}
+
+// A number of elements as a `T`, to divide by. Panics if `T` can't hold it.
+fn count_as<T: FromPrimitive>(count: usize) -> T {
+    T::from_usize(count).unwrap_or_else(|| panic!("Can't divide by a count of {} elements: it \
+    doesn't fit in the element type.", count))
+}
+
+// `a` unless `b` is smaller, so ties keep the earlier element. Elements that can't be compared
+// with anything (not even themselves, like NaNs) are replaced by whatever comes next, and can't
+// replace anything themselves.
+fn lesser<T: PartialOrd + Clone>(a: T, b: &T) -> T {
+    if *b < a || a.partial_cmp(&a).is_none() { b.clone() } else { a }
+}
+
+// `a` unless `b` is larger. See `lesser`.
+fn greater<T: PartialOrd + Clone>(a: T, b: &T) -> T {
+    if *b > a || a.partial_cmp(&a).is_none() { b.clone() } else { a }
+}
+
+macro_rules! reduction_impls {
+    ($($target_type:ident: $solution_columns:expr),*) => ($(
+        impl<T> $target_type<T> {
+            // The number of stored lines to reduce and how many elements of each, leaving out the
+            // solution column of augmented matrices.
+            fn reduced_storage(&self) -> (usize, usize) {
+                match self.alignment {
+                    Alignment::RowAligned => (self.rows, self.columns - $solution_columns),
+                    Alignment::ColumnAligned => (self.rows - $solution_columns, self.columns)
+                }
+            }
+
+            fn reduced_elements(&self) -> impl Iterator<Item = &T> {
+                let (lines, width) = self.reduced_storage();
+                let stride = self.columns.max(1);
+                self.matrix.chunks(stride)
+                    .take(lines)
+                    .flat_map(move |line| line[..width].iter())
+            }
+
+            // Reduces each logical row (`rows`) or column with `f`.
+            fn reduce_lines<F: FnMut(T, &T) -> T>(&self, rows: bool, f: F) -> Vec<T>
+                where T: Clone {
+                let (lines, width) = self.reduced_storage();
+                if rows == self.is_row_aligned() {
+                    line_reduce(&self.matrix, self.columns, lines, width, f)
+                } else {
+                    cross_reduce(&self.matrix, self.columns, lines, width, f)
+                }
+            }
+        }
+
+        impl<T: Zero + Clone> $target_type<T> {
+            /// Gives the sum of each row, from top to bottom.
+            pub fn row_sums(&self) -> Vec<T> {
+                let (lines, width) = self.reduced_storage();
+                match self.alignment {
+                    Alignment::RowAligned => line_sums(&self.matrix, self.columns, lines, width),
+                    Alignment::ColumnAligned => cross_sums(&self.matrix, self.columns, lines, width)
+                }
+            }
+
+            /// Gives the sum of each column, from left to right.
+            pub fn column_sums(&self) -> Vec<T> {
+                let (lines, width) = self.reduced_storage();
+                match self.alignment {
+                    Alignment::RowAligned => cross_sums(&self.matrix, self.columns, lines, width),
+                    Alignment::ColumnAligned => line_sums(&self.matrix, self.columns, lines, width)
+                }
+            }
+
+            /// Gives the sum of all of the elements, or zero if there are none.
+            pub fn sum(&self) -> T {
+                self.reduced_elements().fold(T::zero(), |acc, e| acc + e.clone())
+            }
+
+            /// Gives the sum of the squares of all of the elements, i.e. the square of the
+            /// Frobenius norm.
+            pub fn frobenius_norm_squared(&self) -> T where T: Mul<Output = T> {
+                self.reduced_elements().fold(T::zero(), |acc, e| acc + e.clone() * e.clone())
+            }
+        }
+
+        impl<T: Zero + Clone + Div<Output = T> + FromPrimitive> $target_type<T> {
+            /// Gives the mean of each row, from top to bottom, dividing the sum by the number of
+            /// columns in `T` (so `Fraction` means are exact). Empty if the rows are.
+            pub fn row_means(&self) -> Vec<T> {
+                let columns = self.num_columns();
+                if columns == 0 {
+                    return Vec::new();
+                }
+                let count = count_as::<T>(columns);
+                self.row_sums().into_iter().map(|sum| sum / count.clone()).collect()
+            }
+
+            /// Gives the mean of each column, from left to right. See `row_means()`. Empty if the
+            /// columns are.
+            pub fn column_means(&self) -> Vec<T> {
+                let rows = self.num_rows();
+                if rows == 0 {
+                    return Vec::new();
+                }
+                let count = count_as::<T>(rows);
+                self.column_sums().into_iter().map(|sum| sum / count.clone()).collect()
+            }
+
+            /// Gives the mean of all of the elements, or `None` if there are none. See
+            /// `row_means()`.
+            pub fn mean(&self) -> Option<T> {
+                let (rows, columns) = (self.num_rows(), self.num_columns());
+                if rows == 0 || columns == 0 {
+                    return None;
+                }
+                Some(self.sum() / count_as(rows * columns))
+            }
+        }
+
+        impl<T: Clone> $target_type<T> {
+            /// Gives the product of all of the elements, or one if there are none.
+            pub fn product(&self) -> T where T: One {
+                self.reduced_elements().fold(T::one(), |acc, e| acc * e.clone())
+            }
+
+            /// Gives the smallest element, or `None` if there are none. Of equal elements, the
+            /// first in storage order is given. Elements that can't be compared (undefined
+            /// `Fraction`s or NaNs) are passed over unless there's nothing else.
+            pub fn min(&self) -> Option<T> where T: PartialOrd {
+                let mut elements = self.reduced_elements();
+                let first = elements.next()?.clone();
+                Some(elements.fold(first, lesser))
+            }
+
+            /// Gives the largest element, or `None` if there are none. See `min()`.
+            pub fn max(&self) -> Option<T> where T: PartialOrd {
+                let mut elements = self.reduced_elements();
+                let first = elements.next()?.clone();
+                Some(elements.fold(first, greater))
+            }
+
+            /// Gives the smallest element of each row, from top to bottom. See `min()`. Empty if
+            /// the rows are.
+            pub fn row_min(&self) -> Vec<T> where T: PartialOrd {
+                self.reduce_lines(true, lesser)
+            }
+
+            /// Gives the largest element of each row, from top to bottom. See `min()`. Empty if
+            /// the rows are.
+            pub fn row_max(&self) -> Vec<T> where T: PartialOrd {
+                self.reduce_lines(true, greater)
+            }
+
+            /// Gives the smallest element of each column, from left to right. See `min()`. Empty
+            /// if the columns are.
+            pub fn column_min(&self) -> Vec<T> where T: PartialOrd {
+                self.reduce_lines(false, lesser)
+            }
+
+            /// Gives the largest element of each column, from left to right. See `min()`. Empty
+            /// if the columns are.
+            pub fn column_max(&self) -> Vec<T> where T: PartialOrd {
+                self.reduce_lines(false, greater)
+            }
+
+            /// Folds the rows from top to bottom, passing each one to `f` as a slice. Rows of
+            /// column-aligned matrices aren't contiguous, so they're copied into a buffer first.
+            pub fn fold_rows<B, F: FnMut(B, &[T]) -> B>(&self, init: B, f: F) -> B {
+                self.fold_lines(true, init, f)
+            }
+
+            /// Folds the columns from left to right, passing each one to `f` as a slice. Columns
+            /// of row-aligned matrices aren't contiguous, so they're copied into a buffer first.
+            pub fn fold_columns<B, F: FnMut(B, &[T]) -> B>(&self, init: B, f: F) -> B {
+                self.fold_lines(false, init, f)
+            }
+
+            /// Reduces each row with `f`, from left to right, giving one value per row. Empty if
+            /// the rows are.
+            pub fn reduce_rows<F: FnMut(&T, &T) -> T>(&self, mut f: F) -> Vec<T> {
+                self.reduce_lines(true, |acc, e| f(&acc, e))
+            }
+
+            /// Reduces each column with `f`, from top to bottom, giving one value per column.
+            /// Empty if the columns are.
+            pub fn reduce_columns<F: FnMut(&T, &T) -> T>(&self, mut f: F) -> Vec<T> {
+                self.reduce_lines(false, |acc, e| f(&acc, e))
+            }
+
+            // Folds the logical rows (`rows`) or columns, copying them when they aren't stored
+            // lines.
+            fn fold_lines<B, F: FnMut(B, &[T]) -> B>(&self, rows: bool, init: B, mut f: F) -> B {
+                let (lines, width) = self.reduced_storage();
+                let stride = self.columns;
+                if rows == self.is_row_aligned() {
+                    return (0..lines).fold(init, |acc, l| {
+                        f(acc, &self.matrix[l * stride..l * stride + width])
+                    });
+                }
+                let mut buffer = Vec::with_capacity(lines);
+                (0..width).fold(init, |acc, i| {
+                    buffer.clear();
+                    buffer.extend((0..lines).map(|l| self.matrix[l * stride + i].clone()));
+                    f(acc, &buffer)
+                })
+            }
+        }
+    )*)
+}
+
+reduction_impls!{Matrix: 0, AugmentedMatrix: 1}
@@ -0,0 +1,109 @@
+//! Rotating a matrix by a quarter turn and mirroring it, e.g. for grids and image kernels. Flips
+//! only reorder the backing vec (reversing each stored line or the order of the lines) and can be
+//! done in place. Rotations are a transpose, which just reads the backing vec the other way round,
+//! followed by a flip, so the rotation of a row-aligned matrix is column-aligned and vice versa.
+//! # Example
+//! ```rust
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::matrices::base::Matrix;
+//! let foo = matrix![
+//!     1 2 3;
+//!     4 5 6
+//! ];
+//! assert_eq!(foo.rotate_cw(), matrix![
+//!     4 1;
+//!     5 2;
+//!     6 3
+//! ]);
+//! assert_eq!(foo.rotate_ccw(), matrix![
+//!     3 6;
+//!     2 5;
+//!     1 4
+//! ]);
+//! assert_eq!(foo.flip_horizontal(), matrix![
+//!     3 2 1;
+//!     6 5 4
+//! ]);
+//! assert_eq!(foo.flip_vertical(), matrix![
+//!     4 5 6;
+//!     1 2 3
+//! ]);
+//! ```
+
+use matrices::base::{Alignment, Matrix};
+
+// Reverses the order of the `stride`-element lines of `matrix`.
+fn reverse_lines<T>(matrix: &mut [T], stride: usize) {
+    if stride == 0 {
+        return;
+    }
+    let lines = matrix.len() / stride;
+    for i in 0..lines / 2 {
+        let (front, back) = matrix.split_at_mut((lines - 1 - i) * stride);
+        front[i * stride..(i + 1) * stride].swap_with_slice(&mut back[..stride]);
+    }
+}
+
+// Reverses every `stride`-element line of `matrix`.
+fn reverse_within_lines<T>(matrix: &mut [T], stride: usize) {
+    if stride == 0 {
+        return;
+    }
+    for line in matrix.chunks_mut(stride) {
+        line.reverse();
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Mirrors the matrix left to right, so the first column becomes the last.
+    pub fn flip_horizontal_in_place(&mut self) {
+        match self.alignment {
+            Alignment::RowAligned => reverse_within_lines(&mut self.matrix, self.columns),
+            Alignment::ColumnAligned => reverse_lines(&mut self.matrix, self.columns)
+        }
+    }
+
+    /// Mirrors the matrix top to bottom, so the first row becomes the last.
+    pub fn flip_vertical_in_place(&mut self) {
+        match self.alignment {
+            Alignment::RowAligned => reverse_lines(&mut self.matrix, self.columns),
+            Alignment::ColumnAligned => reverse_within_lines(&mut self.matrix, self.columns)
+        }
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Returns the matrix mirrored left to right (see `flip_horizontal_in_place()`), with the
+    /// same alignment.
+    pub fn flip_horizontal(&self) -> Matrix<T> {
+        let mut res = self.clone();
+        res.flip_horizontal_in_place();
+        res
+    }
+
+    /// Returns the matrix mirrored top to bottom (see `flip_vertical_in_place()`), with the same
+    /// alignment.
+    pub fn flip_vertical(&self) -> Matrix<T> {
+        let mut res = self.clone();
+        res.flip_vertical_in_place();
+        res
+    }
+
+    /// Returns the matrix rotated a quarter turn clockwise, so an m x n matrix becomes an n x m
+    /// one whose first row is the first column of this one, bottom to top. The result has the
+    /// other alignment.
+    pub fn rotate_cw(&self) -> Matrix<T> {
+        let mut res = self.transpose();
+        res.flip_horizontal_in_place();
+        res
+    }
+
+    /// Returns the matrix rotated a quarter turn counterclockwise, so an m x n matrix becomes an
+    /// n x m one whose first row is the last column of this one, top to bottom. The result has
+    /// the other alignment.
+    pub fn rotate_ccw(&self) -> Matrix<T> {
+        let mut res = self.transpose();
+        res.flip_vertical_in_place();
+        res
+    }
+}
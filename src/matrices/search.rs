@@ -0,0 +1,50 @@
+//! Looking for elements. The search goes in logical row-major order - `(0, 0)`, `(0, 1)`, ...,
+//! `(1, 0)`, ... - whatever the alignment, so the first match is the same for a matrix and its
+//! realigned copy. On augmented matrices each row's solution is checked after its coefficients,
+//! and is reported as being in column `num_columns()`.
+//! # Example
+//! ```rust
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::matrices::base::Matrix;
+//! let mut foo = matrix![
+//!     0 2 1;
+//!     3 0 4;
+//!     5 6 0
+//! ];
+//! foo.column_align();
+//! assert!(foo.contains(&6));
+//! assert_eq!(foo.position(&0), Some((0, 0)));
+//! // The first row with a nonzero element in the first column, to swap with the first row
+//! let pivot = foo.submatrix(0..3, 0..1).unwrap().find(|&e| e != 0).map(|(index, _)| index.0);
+//! assert_eq!(pivot, Some(1));
+//! ```
+
+use matrices::base::{AugmentedMatrix, Matrix};
+
+macro_rules! search_impls {
+    ($($target_type:ident),*) => ($(
+        impl<T> $target_type<T> {
+            /// Finds the first element (going row by row) for which `pred` returns true, and its
+            /// logical `(row, column)` index. `pred` isn't called on any element after it.
+            pub fn find<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Option<((usize, usize), &T)> {
+                self.indexed_iter().find(|&(_, e)| pred(e))
+            }
+        }
+
+        impl<T: PartialEq> $target_type<T> {
+            /// Checks whether any element is equal to `value`.
+            pub fn contains(&self, value: &T) -> bool {
+                // Any element will do, so there's no need to go in row-major order
+                self.matrix.contains(value)
+            }
+
+            /// Finds the logical `(row, column)` index of the first element (going row by row)
+            /// that's equal to `value`.
+            pub fn position(&self, value: &T) -> Option<(usize, usize)> {
+                self.find(|e| e == value).map(|(index, _)| index)
+            }
+        }
+    )*)
+}
+
+search_impls!{Matrix, AugmentedMatrix}
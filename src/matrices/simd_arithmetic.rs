@@ -0,0 +1,95 @@
+//! Element-wise arithmetic for `Matrix<f32>` and `Matrix<f64>` over fixed-width lanes. Only
+//! compiled with the `simd` feature. The loops work on `LANES`-sized chunks so that the compiler
+//! can vectorize them on stable; explicit intrinsics can replace the chunk loop later without
+//! changing the API.
+
+use std::ops::{Add, Sub, Mul};
+
+use matrices::base::{Matrix, MatrixError};
+
+const LANES: usize = 8;
+
+fn lanewise<T: Copy, F: Fn(T, T) -> T>(lhs: &mut [T], rhs: &[T], op: F) {
+    let mut lhs_chunks = lhs.chunks_exact_mut(LANES);
+    let mut rhs_chunks = rhs.chunks_exact(LANES);
+    for (l, r) in (&mut lhs_chunks).zip(&mut rhs_chunks) {
+        for i in 0..LANES {
+            l[i] = op(l[i], r[i]);
+        }
+    }
+    for (l, r) in lhs_chunks.into_remainder().iter_mut().zip(rhs_chunks.remainder()) {
+        *l = op(*l, *r);
+    }
+}
+
+// Both matrices have to be stored the same way for the lanes to line up; if they aren't, work on
+// a copy of `rhs` with `lhs`'s alignment.
+fn apply<T: Copy, F: Fn(T, T) -> T>(lhs: &mut Matrix<T>, rhs: &Matrix<T>, op: F)
+    -> Result<(), MatrixError> {
+    if lhs.dimension() != rhs.dimension() {
+        return Err(MatrixError::FunctionError(format!("The matrices do not have the same \
+        dimension ({:?} and {:?}).", lhs.dimension(), rhs.dimension())));
+    }
+    if lhs.get_alignment() == rhs.get_alignment() {
+        lanewise(&mut lhs.matrix, &rhs.matrix, op);
+    } else {
+        let mut rhs = rhs.clone();
+        if lhs.is_row_aligned() {
+            rhs.row_align();
+        } else {
+            rhs.column_align();
+        }
+        lanewise(&mut lhs.matrix, &rhs.matrix, op);
+    }
+    Ok(())
+}
+
+macro_rules! simd_elementwise_impls {
+    ($($t:ty)*) => ($(
+        impl Matrix<$t> {
+            /// Adds `rhs` to `self` element by element. Returns an error if the dimensions differ.
+            /// # Example
+            /// ```rust
+            /// # #[macro_use] extern crate fractions_and_matrices;
+            /// # use fractions_and_matrices::matrices::base::Matrix;
+            /// let mut foo: Matrix<f64> = matrix![1.0 2.0; 3.0 4.0];
+            /// foo.simd_add_assign(&matrix![0.5 0.5; 0.5 0.5]).unwrap();
+            /// assert_eq!(foo, matrix![1.5 2.5; 3.5 4.5]);
+            /// assert!(foo.simd_add_assign(&matrix![1.0 2.0]).is_err());
+            /// ```
+            pub fn simd_add_assign(&mut self, rhs: &Matrix<$t>) -> Result<(), MatrixError> {
+                apply(self, rhs, Add::add)
+            }
+
+            /// Subtracts `rhs` from `self` element by element. Returns an error if the dimensions
+            /// differ.
+            /// # Example
+            /// ```rust
+            /// # #[macro_use] extern crate fractions_and_matrices;
+            /// # use fractions_and_matrices::matrices::base::Matrix;
+            /// let mut foo: Matrix<f64> = matrix![1.0 2.0; 3.0 4.0];
+            /// foo.simd_sub_assign(&matrix![0.5 0.5; 0.5 0.5]).unwrap();
+            /// assert_eq!(foo, matrix![0.5 1.5; 2.5 3.5]);
+            /// ```
+            pub fn simd_sub_assign(&mut self, rhs: &Matrix<$t>) -> Result<(), MatrixError> {
+                apply(self, rhs, Sub::sub)
+            }
+
+            /// Multiplies `self` by `rhs` element by element (the Hadamard product). Returns an
+            /// error if the dimensions differ.
+            /// # Example
+            /// ```rust
+            /// # #[macro_use] extern crate fractions_and_matrices;
+            /// # use fractions_and_matrices::matrices::base::Matrix;
+            /// let mut foo: Matrix<f64> = matrix![1.0 2.0; 3.0 4.0];
+            /// foo.simd_hadamard_assign(&matrix![2.0 2.0; 0.5 0.5]).unwrap();
+            /// assert_eq!(foo, matrix![2.0 4.0; 1.5 2.0]);
+            /// ```
+            pub fn simd_hadamard_assign(&mut self, rhs: &Matrix<$t>) -> Result<(), MatrixError> {
+                apply(self, rhs, Mul::mul)
+            }
+        }
+    )*)
+}
+
+simd_elementwise_impls!{f32 f64}
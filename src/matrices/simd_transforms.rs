@@ -1,2 +1,5 @@
-#![cfg(target_feature = "nightly-ext")]
-// Preemptive stuff - will be available in future versions
\ No newline at end of file
+//! SIMD versions of the row operations and reductions in `matrices::transforms`. Only compiled
+//! with the `simd` feature; until dedicated implementations land this re-exports the scalar
+//! ones.
+
+pub use matrices::transforms::*;
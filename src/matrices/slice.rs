@@ -0,0 +1,404 @@
+//! Borrowed rectangular blocks of a matrix. Unlike `submatrix()` and `window!`, which copy, a
+//! [`MatrixSlice`] (or [`MatrixSliceMut`]) is just a reference to the backing vec of its matrix
+//! with the position, dimension and stride of the block, so taking one costs nothing however big
+//! the block is. Indices into a slice are relative to its top left corner.
+//! # Example
+//! ```rust
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::matrices::base::Matrix;
+//! let mut foo = matrix![
+//!     1 2 3;
+//!     4 5 6;
+//!     7 8 9
+//! ];
+//! let corner = foo.slice(1..3, 1..3);
+//! assert_eq!(corner.dimension(), (2, 2));
+//! assert_eq!(corner[(0, 0)], 5);
+//! assert_eq!(corner.iter().sum::<i32>(), 28);
+//! assert_eq!(corner.to_matrix(), matrix![
+//!     5 6;
+//!     8 9
+//! ]);
+//! foo.slice_mut(0..2, 0..1)[(1, 0)] = 40;
+//! assert_eq!(foo[(1, 0)], 40);
+//! ```
+//!
+//! A slice borrows its matrix, so the matrix can't be changed while the slice is in use:
+//! ```rust,compile_fail
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::matrices::base::Matrix;
+//! let mut foo = matrix![
+//!     1 2;
+//!     3 4
+//! ];
+//! let top = foo.slice(0..1, 0..2);
+//! foo[(0, 0)] = 10;
+//! assert_eq!(top[(0, 0)], 10);
+//! ```
+//!
+//! [`MatrixSlice`]: struct.MatrixSlice.html
+//! [`MatrixSliceMut`]: struct.MatrixSliceMut.html
+
+use std::ops::{Index, IndexMut, Range};
+use std::vec;
+
+use matrices::base::{Alignment, Matrix};
+use matrices::matrix_ref::MatrixRef;
+
+/// A borrowed block of a matrix, returned by `Matrix::slice()`, `slice_row()` and
+/// `slice_column()`. It can be indexed with `(row, column)` pairs and iterated over in row-major
+/// order like a matrix, sliced further, and copied into a matrix of its own with `to_matrix()`.
+/// It also implements `MatrixRef`, so the products and norms there work on blocks without copying
+/// them out first.
+/// # Example
+/// ```rust
+/// # #[macro_use] extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::matrices::base::Matrix;
+/// # use fractions_and_matrices::matrices::matrix_ref::MatrixRef;
+/// let foo = matrix![
+///     1 2 3 4;
+///     5 6 7 8
+/// ];
+/// let right = foo.slice(0..2, 2..4);
+/// assert_eq!(right.mul_vector(&[1, 1]).unwrap(), vec![7, 15]);
+/// let bottom_right = right.slice(1..2, 1..2);
+/// assert_eq!(bottom_right[(0, 0)], 8);
+/// assert_eq!(foo.slice_column(1).iter().cloned().collect::<Vec<i32>>(), vec![2, 6]);
+/// ```
+pub struct MatrixSlice<'a, T: 'a> {
+    data: &'a [T],
+    offset: usize,
+    dimension: (usize, usize),
+    stride: usize,
+    alignment: Alignment
+}
+
+/// A borrowed block of a matrix with mutable access to its elements, returned by
+/// `Matrix::slice_mut()`. Changes made through it are changes to the matrix.
+/// # Example
+/// ```rust
+/// # #[macro_use] extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::matrices::base::Matrix;
+/// let mut foo = matrix![
+///     1 2 3;
+///     4 5 6
+/// ];
+/// {
+///     let mut right = foo.slice_mut(0..2, 1..3);
+///     for e in right.iter_mut() {
+///         *e = 0;
+///     }
+///     right[(0, 1)] = 9;
+/// }
+/// assert_eq!(foo, matrix![
+///     1 0 9;
+///     4 0 0
+/// ]);
+/// ```
+pub struct MatrixSliceMut<'a, T: 'a> {
+    data: &'a mut [T],
+    offset: usize,
+    dimension: (usize, usize),
+    stride: usize,
+    alignment: Alignment
+}
+
+/// Iterator over references to the elements of a `MatrixSlice` in row-major order, returned by
+/// `iter()`.
+pub struct SliceIter<'a, T: 'a> {
+    slice: MatrixSlice<'a, T>,
+    front: usize,
+    back: usize
+}
+
+/// Iterator over mutable references to the elements of a `MatrixSliceMut` in row-major order,
+/// returned by `iter_mut()`.
+pub struct SliceIterMut<'a, T: 'a>(vec::IntoIter<&'a mut T>);
+
+// Where the element at `index` of a block starting at `offset` is in the backing vec.
+fn position(index: (usize, usize), offset: usize, stride: usize, alignment: &Alignment)
+    -> usize {
+    match *alignment {
+        Alignment::RowAligned => offset + index.0 * stride + index.1,
+        Alignment::ColumnAligned => offset + index.1 * stride + index.0
+    }
+}
+
+// The offset and dimension of the block at `rows` and `cols` of a block of dimension `dimension`
+// starting at `offset`. Panics if either range is empty or out of bounds.
+fn block(offset: usize, dimension: (usize, usize), stride: usize, alignment: &Alignment,
+    rows: Range<usize>, cols: Range<usize>) -> (usize, (usize, usize)) {
+    assert!(rows.start < rows.end && cols.start < cols.end && rows.end <= dimension.0
+        && cols.end <= dimension.1, "Can't slice rows {:?} and columns {:?} of a {:?} matrix: the \
+        ranges must be non-empty and in bounds.", rows, cols, dimension);
+    (position((rows.start, cols.start), offset, stride, alignment),
+        (rows.end - rows.start, cols.end - cols.start))
+}
+
+impl<T> Matrix<T> {
+    /// Borrows the block of the matrix at the rows in `rows` and the columns in `cols` without
+    /// copying it (see `submatrix()` for a copy). Panics if either range is empty or goes past
+    /// the end of the matrix.
+    pub fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixSlice<'_, T> {
+        let (offset, dimension) = block(0, self.dimension(), self.columns, &self.alignment,
+            rows, cols);
+        MatrixSlice {
+            data: &self.matrix,
+            offset,
+            dimension,
+            stride: self.columns,
+            alignment: self.alignment.clone()
+        }
+    }
+
+    /// Like `slice()`, but with mutable access to the elements of the block.
+    pub fn slice_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatrixSliceMut<'_, T> {
+        let (offset, dimension) = block(0, self.dimension(), self.columns, &self.alignment,
+            rows, cols);
+        MatrixSliceMut {
+            data: &mut self.matrix,
+            offset,
+            dimension,
+            stride: self.columns,
+            alignment: self.alignment.clone()
+        }
+    }
+
+    /// Borrows row `row` as a one-row `MatrixSlice`. Panics if it's out of bounds.
+    pub fn slice_row(&self, row: usize) -> MatrixSlice<'_, T> {
+        let columns = self.num_columns();
+        self.slice(row..row + 1, 0..columns)
+    }
+
+    /// Borrows column `column` as a one-column `MatrixSlice`. Panics if it's out of bounds.
+    pub fn slice_column(&self, column: usize) -> MatrixSlice<'_, T> {
+        let rows = self.num_rows();
+        self.slice(0..rows, column..column + 1)
+    }
+}
+
+impl<'a, T> Clone for MatrixSlice<'a, T> {
+    fn clone(&self) -> Self {
+        MatrixSlice { alignment: self.alignment.clone(), ..*self }
+    }
+}
+
+impl<'a, T> MatrixSlice<'a, T> {
+    /// Gets the dimension of the block as a `(rows, columns)` tuple.
+    pub fn dimension(&self) -> (usize, usize) {
+        self.dimension
+    }
+
+    /// Gets a reference to the element at `(row, column)` of the block, or `None` if it's out of
+    /// bounds. The reference borrows the matrix, not the slice.
+    pub fn get(&self, index: (usize, usize)) -> Option<&'a T> {
+        if index.0 < self.dimension.0 && index.1 < self.dimension.1 {
+            Some(&self.data[position(index, self.offset, self.stride, &self.alignment)])
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over references to the elements of the block in row-major order.
+    pub fn iter(&self) -> SliceIter<'a, T> {
+        SliceIter { slice: self.clone(), front: 0, back: self.dimension.0 * self.dimension.1 }
+    }
+
+    /// Borrows a block of this block, with `rows` and `cols` relative to it. The new slice borrows
+    /// the matrix, so it can outlive this one. Panics if either range is empty or out of bounds.
+    pub fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixSlice<'a, T> {
+        let (offset, dimension) = block(self.offset, self.dimension, self.stride,
+            &self.alignment, rows, cols);
+        MatrixSlice { offset, dimension, ..self.clone() }
+    }
+
+    /// Copies the block into a new matrix with the alignment of the one it's from.
+    pub fn to_matrix(&self) -> Matrix<T> where T: Clone {
+        to_matrix(self.data, self.offset, self.dimension, self.stride, &self.alignment)
+    }
+}
+
+impl<'a, T> MatrixSliceMut<'a, T> {
+    /// Gets the dimension of the block as a `(rows, columns)` tuple.
+    pub fn dimension(&self) -> (usize, usize) {
+        self.dimension
+    }
+
+    /// Borrows the block immutably.
+    pub fn as_slice(&self) -> MatrixSlice<'_, T> {
+        MatrixSlice {
+            data: self.data,
+            offset: self.offset,
+            dimension: self.dimension,
+            stride: self.stride,
+            alignment: self.alignment.clone()
+        }
+    }
+
+    /// Gets a reference to the element at `(row, column)` of the block, or `None` if it's out of
+    /// bounds.
+    pub fn get(&self, index: (usize, usize)) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    /// Gets a mutable reference to the element at `(row, column)` of the block, or `None` if it's
+    /// out of bounds.
+    pub fn get_mut(&mut self, index: (usize, usize)) -> Option<&mut T> {
+        if index.0 < self.dimension.0 && index.1 < self.dimension.1 {
+            Some(&mut self.data[position(index, self.offset, self.stride, &self.alignment)])
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over references to the elements of the block in row-major order.
+    pub fn iter(&self) -> SliceIter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Iterates over mutable references to the elements of the block in row-major order.
+    pub fn iter_mut(&mut self) -> SliceIterMut<'_, T> {
+        let (rows, columns) = self.dimension;
+        let mut elements = Vec::with_capacity(rows * columns);
+        // Each stored line of the block starts `stride` after the last
+        let lines = self.data[self.offset..].chunks_mut(self.stride);
+        match self.alignment {
+            Alignment::RowAligned => for line in lines.take(rows) {
+                elements.extend(line[..columns].iter_mut());
+            },
+            Alignment::ColumnAligned => {
+                let mut lines = lines.take(columns)
+                    .map(|line| line[..rows].iter_mut())
+                    .collect::<Vec<_>>();
+                for _ in 0..rows {
+                    elements.extend(lines.iter_mut().map(|line| line.next().unwrap()));
+                }
+            }
+        }
+        SliceIterMut(elements.into_iter())
+    }
+
+    /// Copies the block into a new matrix with the alignment of the one it's from.
+    pub fn to_matrix(&self) -> Matrix<T> where T: Clone {
+        self.as_slice().to_matrix()
+    }
+}
+
+// Copies the block out one stored line at a time.
+fn to_matrix<T: Clone>(data: &[T], offset: usize, dimension: (usize, usize), stride: usize,
+    alignment: &Alignment) -> Matrix<T> {
+    let (lines, within) = match *alignment {
+        Alignment::RowAligned => dimension,
+        Alignment::ColumnAligned => (dimension.1, dimension.0)
+    };
+    let mut matrix = Vec::with_capacity(lines * within);
+    for line in 0..lines {
+        let start = offset + line * stride;
+        matrix.extend_from_slice(&data[start..start + within]);
+    }
+    Matrix { rows: lines, columns: within, matrix, alignment: alignment.clone() }
+}
+
+impl<'a, T> Index<(usize, usize)> for MatrixSlice<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &T {
+        match self.get(index) {
+            Some(e) => e,
+            None => panic!("Index {:?} is out of bounds for a slice of dimension {:?}.", index,
+                self.dimension)
+        }
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for MatrixSliceMut<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &T {
+        match self.get(index) {
+            Some(e) => e,
+            None => panic!("Index {:?} is out of bounds for a slice of dimension {:?}.", index,
+                self.dimension)
+        }
+    }
+}
+
+impl<'a, T> IndexMut<(usize, usize)> for MatrixSliceMut<'a, T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        let dimension = self.dimension;
+        match self.get_mut(index) {
+            Some(e) => e,
+            None => panic!("Index {:?} is out of bounds for a slice of dimension {:?}.", index,
+                dimension)
+        }
+    }
+}
+
+impl<'a, T> MatrixRef<T> for MatrixSlice<'a, T> {
+    fn dimension(&self) -> (usize, usize) {
+        self.dimension
+    }
+
+    fn get(&self, index: (usize, usize)) -> Option<&T> {
+        MatrixSlice::get(self, index)
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[T]> {
+        if self.alignment == Alignment::RowAligned && row < self.dimension.0 {
+            let start = self.offset + row * self.stride;
+            Some(&self.data[start..start + self.dimension.1])
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> Iterator for SliceIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.front == self.back {
+            return None;
+        }
+        let columns = self.slice.dimension.1;
+        self.front += 1;
+        self.slice.get(((self.front - 1) / columns, (self.front - 1) % columns))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.back - self.front, Some(self.back - self.front))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for SliceIter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.front == self.back {
+            return None;
+        }
+        let columns = self.slice.dimension.1;
+        self.back -= 1;
+        self.slice.get((self.back / columns, self.back % columns))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for SliceIter<'a, T> {}
+
+impl<'a, T> Iterator for SliceIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for SliceIterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        self.0.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for SliceIterMut<'a, T> {}
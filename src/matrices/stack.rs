@@ -0,0 +1,139 @@
+//! Joining two matrices side by side (`hstack`) or one above the other (`vstack`). The result has
+//! the alignment of the left (or top) operand, and the other operand can have either alignment.
+//! # Example
+//! ```rust
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::matrices::base::{Matrix, Unit};
+//! // The starting point for inverting a matrix by row reduction
+//! let foo = matrix![
+//!     2 1;
+//!     1 1
+//! ];
+//! let bar = foo.hstack(&Matrix::unit(2)).unwrap();
+//! assert_eq!(bar, matrix![
+//!     2 1 1 0;
+//!     1 1 0 1
+//! ]);
+//! assert_eq!(foo.vstack(&foo).unwrap().dimension(), (4, 2));
+//! assert!(foo.vstack(&bar).is_err());
+//! ```
+
+use matrices::base::{Alignment, Matrix, MatrixError};
+
+impl<T: Clone> Matrix<T> {
+    /// Makes a new matrix with the columns of `other` after the columns of `self`. Returns an
+    /// error unless both have the same number of rows.
+    pub fn hstack(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.check_stack(other, true)?;
+        match self.alignment {
+            Alignment::ColumnAligned => {
+                let mut res = self.clone();
+                res.append_columns(other);
+                Ok(res)
+            },
+            Alignment::RowAligned => {
+                let mut matrix = Vec::with_capacity(self.matrix.len() + other.matrix.len());
+                for (a, b) in self.rows().zip(other.rows()) {
+                    matrix.extend(a.iter().cloned());
+                    matrix.extend(b.iter().cloned());
+                }
+                Ok(Matrix {
+                    rows: self.rows,
+                    columns: self.columns + other.num_columns(),
+                    matrix,
+                    alignment: Alignment::RowAligned
+                })
+            }
+        }
+    }
+
+    /// Makes a new matrix with the rows of `other` below the rows of `self`. Returns an error
+    /// unless both have the same number of columns.
+    pub fn vstack(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.check_stack(other, false)?;
+        match self.alignment {
+            Alignment::RowAligned => {
+                let mut res = self.clone();
+                res.append_rows(other);
+                Ok(res)
+            },
+            Alignment::ColumnAligned => {
+                let mut matrix = Vec::with_capacity(self.matrix.len() + other.matrix.len());
+                for (a, b) in self.columns().zip(other.columns()) {
+                    matrix.extend(a.iter().cloned());
+                    matrix.extend(b.iter().cloned());
+                }
+                Ok(Matrix {
+                    rows: self.rows,
+                    columns: self.columns + other.num_rows(),
+                    matrix,
+                    alignment: Alignment::ColumnAligned
+                })
+            }
+        }
+    }
+
+    /// Like `hstack()`, but reuses the backing vec of `self`. For column-aligned matrices the
+    /// columns of `other` are just appended to it; row-aligned ones are copied as by `hstack()`.
+    pub fn hstack_into(mut self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        match self.alignment {
+            Alignment::ColumnAligned => {
+                self.check_stack(other, true)?;
+                self.append_columns(other);
+                Ok(self)
+            },
+            Alignment::RowAligned => self.hstack(other)
+        }
+    }
+
+    /// Like `vstack()`, but reuses the backing vec of `self`. For row-aligned matrices the rows
+    /// of `other` are just appended to it; column-aligned ones are copied as by `vstack()`.
+    pub fn vstack_into(mut self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        match self.alignment {
+            Alignment::RowAligned => {
+                self.check_stack(other, false)?;
+                self.append_rows(other);
+                Ok(self)
+            },
+            Alignment::ColumnAligned => self.vstack(other)
+        }
+    }
+
+    // Appends the columns of `other` as new stored columns. Only for column-aligned matrices.
+    fn append_columns(&mut self, other: &Matrix<T>) {
+        self.matrix.reserve_exact(other.matrix.len());
+        if other.is_column_aligned() {
+            self.matrix.extend_from_slice(&other.matrix);
+        } else {
+            for column in other.columns() {
+                self.matrix.extend(column.iter().cloned());
+            }
+        }
+        self.rows += other.num_columns();
+    }
+
+    // Appends the rows of `other` as new stored rows. Only for row-aligned matrices.
+    fn append_rows(&mut self, other: &Matrix<T>) {
+        self.matrix.reserve_exact(other.matrix.len());
+        if other.is_row_aligned() {
+            self.matrix.extend_from_slice(&other.matrix);
+        } else {
+            self.matrix.extend(other.iter().cloned());
+        }
+        self.rows += other.num_rows();
+    }
+
+    // Errors unless the numbers of rows (`horizontal`) or columns match.
+    fn check_stack(&self, other: &Matrix<T>, horizontal: bool) -> Result<(), MatrixError> {
+        let (a, b) = (self.dimension(), other.dimension());
+        if horizontal && a.0 != b.0 {
+            return Err(MatrixError::FunctionError(format!("Can't hstack a {:?} matrix with a {:?} \
+            matrix: the numbers of rows differ.", a, b)));
+        }
+        if !horizontal && a.1 != b.1 {
+            return Err(MatrixError::FunctionError(format!("Can't vstack a {:?} matrix with a {:?} \
+            matrix: the numbers of columns differ.", a, b)));
+        }
+        Ok(())
+    }
+}
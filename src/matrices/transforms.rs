@@ -2,13 +2,15 @@
 
 use num::{One, Zero};
 
-use std::ops::{AddAssign, SubAssign, MulAssign, Neg, Div, DivAssign, Rem, Range};
+use std::ops::{AddAssign, Sub, SubAssign, Mul, MulAssign, Neg, Div, DivAssign, Rem, Range,
+    IndexMut};
 use std::cmp::{Eq, PartialEq, PartialOrd};
 use std::fmt::{Debug, Display};
 use std::mem::swap;
 use std::marker::Sized;
 
-use matrices::base::{Matrix, AugmentedMatrix, MatrixError, Unit};
+use matrices::base::{Matrix, AugmentedMatrix, MatrixError, Unit, is_echelon};
+use numeric_utils::normalize_slice_in_place;
 
 /// Implements the addition row operation. Always done in the form, for rows `n` and `m`,
 /// `Rn + Rm => Rn`. Row `n` is the `target` and row `m` is the `tool`.
@@ -22,8 +24,9 @@ pub trait RowOpSub {
     fn row_op_sub(&mut self, target: usize, tool: usize);
 }
 
-/// Implements the division row operation. Always done in the form, for row `n` and scalar
-/// `k`, `Rn / k => Rn`. Row 'n' is the `target` and `k` is the `tool`.
+/// Implements the multiplication row operation. Always done in the form, for row `n` and scalar
+/// `k`, `Rn * k => Rn`. Row 'n' is the `target` and `k` is the `tool`. The scalar doesn't need
+/// to be the element type, e.g. a `Matrix<Fraction>` row can be multiplied by an `i64`.
 pub trait RowOpMul<Scalar> {
     fn row_op_mul(&mut self, target: usize, tool: Scalar);
 }
@@ -34,16 +37,40 @@ pub trait RowOpDiv<Scalar> {
     fn row_op_div(&mut self, target: usize, tool: Scalar);
 }
 
+/// Implements the row swap operation. Always done in the form, for rows `n` and `m`,
+/// `Rn ↔ Rm`. The rows of an augmented matrix are swapped along with their solutions.
+pub trait RowOpSwap {
+    fn row_op_swap(&mut self, a: usize, b: usize);
+}
+
+/// Implements adding (or subtracting) a multiple of one row to another in a single pass. Always
+/// done in the form, for rows `n` and `m` and scalar `k`, `Rn + k * Rm => Rn` (or
+/// `Rn - k * Rm => Rn`). Row `n` is the `target` and row `m` is the `tool`, which is left as it
+/// was.
+pub trait RowOpAddMul<Scalar> {
+    fn row_op_add_mul(&mut self, target: usize, tool: usize, k: Scalar);
+    fn row_op_sub_mul(&mut self, target: usize, tool: usize, k: Scalar);
+}
+
 pub trait Gcd: Rem + PartialEq + Sized {}
 impl<T: Rem + PartialEq> Gcd for T {}
 
-fn gcd<T: Gcd + Zero + Clone>(a: T, b: T) -> T
-    where <T as Rem>::Output: Into<T> {
-    if b.is_zero() {
-        return a;
-    } else {
-        gcd(b.clone(), (a % b).into())
+// Normalizes the logical row `row` (including an augmented matrix's solution column) with
+// `normalize_slice_in_place`. Returns the divisor if one other than 1 was applied.
+fn normalize_row<M, T>(matrix: &mut M, row: usize, width: usize) -> Option<T>
+    where
+        M: IndexMut<(usize, usize), Output = T>,
+        T: SimplifyTraits + PartialOrd + Sub<Output = T> + Clone,
+        <T as Rem>::Output: Into<T> {
+    let mut values = (0..width).map(|c| matrix[(row, c)].clone()).collect::<Vec<T>>();
+    let divisor = normalize_slice_in_place(&mut values)?;
+    if divisor.is_one() {
+        return None;
     }
+    for (c, value) in values.into_iter().enumerate() {
+        matrix[(row, c)] = value;
+    }
+    Some(divisor)
 }
 
 /// This trait is used to simplify rows - in short, it tries to find the GCD of all the numbers in
@@ -71,12 +98,54 @@ pub trait SimplifyGetStepsDebug {
     fn simplify_matrix_get_steps_db(&mut self) -> Option<Vec<Option<String>>>;
 }
 
+/// Trait to solve the system of equations an augmented matrix represents. For square `Fraction`
+/// systems, `AugmentedMatrix::solve_exact()` (in `elimination`) also reports which equation ended
+/// up in which row and the largest denominator seen, and fails with the same errors.
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::fractions::base::Fraction;
+/// # use fractions_and_matrices::matrices::base::{AugmentedMatrix, Alignment::RowAligned};
+/// # use fractions_and_matrices::matrices::transforms::Solve;
+/// let f = |v: &[i64]| v.iter().map(|&x| Fraction::from(x)).collect::<Vec<Fraction>>();
+/// // 2y = 4, x + y = 3
+/// let foo = AugmentedMatrix::new_from_vec((2, 3), f(&[0, 2, 4, 1, 1, 3]), RowAligned).unwrap();
+/// assert_eq!(foo.solve().unwrap(), f(&[1, 2]));
+/// // x + y = 3, 2x + 2y = 7
+/// let bar = AugmentedMatrix::new_from_vec((2, 3), f(&[1, 1, 3, 2, 2, 7]), RowAligned).unwrap();
+/// assert!(bar.solve().is_err());
+/// ```
+pub trait Solve<T> {
+    /// Gets the unique solution of the system, one value per variable, without changing the
+    /// matrix. Returns an error if the system is inconsistent or doesn't have a unique solution.
+    fn solve(&self) -> Result<Vec<T>, MatrixError>;
+    /// Like `solve()`, but leaves the matrix in RREF form, even if there's no unique solution.
+    fn solve_in_place(&mut self) -> Result<Vec<T>, MatrixError>;
+}
+
+/// What `reduce_and_report` found while putting a(n augmented) matrix in RREF form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReductionReport {
+    /// The column of each row's leading one, top to bottom.
+    pub pivot_columns: Vec<usize>,
+    /// The columns without a leading one, i.e. the free variables of a system of equations.
+    pub free_columns: Vec<usize>,
+    /// The number of pivots.
+    pub rank: usize
+}
+
 /// Required traits for simplification
 pub trait SimplifyTraits: Div + DivAssign + Gcd + Zero + One + PartialEq {}
 impl<T: Div + DivAssign + Gcd + Zero + One + PartialEq> SimplifyTraits for T {}
 
 /// Trait to put a(n augmented) matrix in REF form and check whether a(n augmented) matrix is in
-/// REF form.
+/// REF form. Works for any shape: every non-zero row starts with a one strictly right of the
+/// leading one of the row above, and zero rows come last. Only the coefficients of an augmented
+/// matrix are considered.
+///
+/// Clearing the rows below a pivot leaves the pivot row exactly as it was, but making the leading
+/// one still divides the pivot row by its pivot. For integer elements that division truncates
+/// unless the pivot divides the whole row, so use `Fraction`s for exact results.
 pub trait REF {
     fn gaussian_elim(&mut self);
     fn is_row_reduced(&self) -> bool;
@@ -133,66 +202,236 @@ pub trait InverseDebug where Self: Sized {
     fn try_inverse_debug(&mut self) -> Result<Option<Vec<String>>, MatrixError>;
 }
 
+// A single row operation done by the elimination helpers below.
+enum RowStep<T> {
+    // `Ra ↔ Rb`
+    Swap(usize, usize),
+    // `Rn / k -> Rn`
+    Div(usize, T),
+    // `Rn - k * Rm -> Rn`
+    Sub(usize, T, usize)
+}
+
+impl<T: Div + Clone> RowStep<T> where <T as Div>::Output: Into<T> {
+    fn apply<M>(&self, matrix: &mut M) where M: RowOpAddMul<T> + RowOpDiv<T> + RowOpSwap {
+        match *self {
+            RowStep::Swap(a, b) => matrix.row_op_swap(a, b),
+            RowStep::Div(target, ref k) => matrix.row_op_div(target, k.clone()),
+            RowStep::Sub(target, ref k, tool) => matrix.row_op_sub_mul(target, tool, k.clone())
+        }
+    }
+}
+
+impl<T: Display> RowStep<T> {
+    fn display(&self) -> String {
+        match *self {
+            RowStep::Swap(a, b) => format!("R{} ↔ R{}", a, b),
+            RowStep::Div(target, ref k) => format!("R{} / ({}) -> R{0}", target, k),
+            RowStep::Sub(target, ref k, tool) => format!("R{} - ({}) * R{} -> R{0}", target, k,
+                tool)
+        }
+    }
+}
+
+impl<T: Debug> RowStep<T> {
+    fn debug(&self) -> String {
+        match *self {
+            RowStep::Swap(a, b) => format!("R{} ↔ R{}", a, b),
+            RowStep::Div(target, ref k) => format!("R{} / ({:?}) -> R{0}", target, k),
+            RowStep::Sub(target, ref k, tool) => format!("R{} - ({:?}) * R{} -> R{0}", target, k,
+                tool)
+        }
+    }
+}
+
+// Does `step` to `matrix` and to `companion` (if there is one), then passes it to `record`.
+fn do_step<M, T, F>(step: RowStep<T>, matrix: &mut M, companion: &mut Option<&mut M>,
+    record: &mut F)
+    where
+        M: RowOpAddMul<T> + RowOpDiv<T> + RowOpSwap,
+        T: Div + Clone,
+        <T as Div>::Output: Into<T>,
+        F: FnMut(RowStep<T>) {
+    step.apply(matrix);
+    if let Some(ref mut companion) = *companion {
+        step.apply(*companion);
+    }
+    record(step);
+}
+
+// Gaussian elimination with row swaps, leaving the first `columns` columns of `matrix` in row
+// echelon form. The pivot row moves down independently of the column: each column's pivot is the
+// first non-zero element at or below the next pivot row, swapped up into it if need be, and a
+// column without one is skipped. Returns the pivot columns, top to bottom. Every step is also done
+// to `companion`, for inverting.
+fn eliminate_below<M, T, F>(matrix: &mut M, mut companion: Option<&mut M>,
+    rows: usize, columns: usize, mut record: F) -> Vec<usize>
+    where
+        M: IndexMut<(usize, usize), Output = T> + RowOpAddMul<T> + RowOpDiv<T> + RowOpSwap,
+        T: Div + Zero + One + PartialEq + Clone,
+        <T as Div>::Output: Into<T>,
+        F: FnMut(RowStep<T>) {
+    let mut pivot_columns = Vec::new();
+    for c in 0..columns {
+        let row = pivot_columns.len();
+        if row == rows {
+            break;
+        }
+        let pivot_row = match (row..rows).find(|&r| !matrix[(r, c)].is_zero()) {
+            Some(r) => r,
+            None => continue
+        };
+        if pivot_row != row {
+            do_step(RowStep::Swap(row, pivot_row), matrix, &mut companion, &mut record);
+        }
+        let pivot = matrix[(row, c)].clone();
+        if !pivot.is_one() {
+            do_step(RowStep::Div(row, pivot), matrix, &mut companion, &mut record);
+        }
+        for r in row + 1..rows {
+            let k = matrix[(r, c)].clone();
+            if !k.is_zero() {
+                do_step(RowStep::Sub(r, k, row), matrix, &mut companion, &mut record);
+            }
+        }
+        pivot_columns.push(c);
+    }
+    pivot_columns
+}
+
+// Clears everything above the leading ones of a matrix that's had `eliminate_below` done to it,
+// given its pivot columns, working up from the last pivot. Every step is also done to
+// `companion`.
+fn eliminate_above<M, T, F>(matrix: &mut M, mut companion: Option<&mut M>,
+    pivot_columns: &[usize], mut record: F)
+    where
+        M: IndexMut<(usize, usize), Output = T> + RowOpAddMul<T> + RowOpDiv<T> + RowOpSwap,
+        T: Div + Zero + Clone,
+        <T as Div>::Output: Into<T>,
+        F: FnMut(RowStep<T>) {
+    for (row, &c) in pivot_columns.iter().enumerate().rev() {
+        for r in (0..row).rev() {
+            let k = matrix[(r, c)].clone();
+            if !k.is_zero() {
+                do_step(RowStep::Sub(r, k, row), matrix, &mut companion, &mut record);
+            }
+        }
+    }
+}
+
+// Replaces the `n`x`n` `matrix` with its inverse by reducing it to the identity while doing the
+// same row operations to an identity matrix, passing each one to `record`.
+fn invert<M, T, F>(matrix: &mut M, n: usize, mut record: F) -> Result<(), MatrixError>
+    where
+        M: IndexMut<(usize, usize), Output = T> + RowOpAddMul<T> + RowOpDiv<T> + RowOpSwap + Unit,
+        T: Div + Zero + One + PartialEq + Clone,
+        <T as Div>::Output: Into<T>,
+        F: FnMut(RowStep<T>) {
+    if !matrix.is_unit_dimension() {
+        return Err(MatrixError::InitError("Matrix does not have the same number of rows and \
+        columns - unable to make inverse.".to_string()));
+    }
+    let mut s = M::unit(n);
+    swap(&mut s, matrix);
+    let pivot_columns = eliminate_below(&mut s, Some(matrix), n, n, &mut record);
+    if pivot_columns.len() < n {
+        return Err(MatrixError::TransformError("Was unable to make an inverse - unable to put \
+        original matrix in REF form.".to_string()));
+    }
+    eliminate_above(&mut s, Some(matrix), &pivot_columns, &mut record);
+    if s.is_unit() {
+        Ok(())
+    } else {
+        Err(MatrixError::TransformError("Was unable to make an inverse - unable to put original \
+        matrix in RREF form.".to_string()))
+    }
+}
+
 macro_rules! transforms_impl {
     ($($target_type:ty: $name:ident),*) => ($(
         impl<T: AddAssign + Clone> RowOpAdd for $target_type {
             fn row_op_add(&mut self, target: usize, tool: usize) {
-                for b in 0..self.num_columns() {
-                    self[(target, b)] += self[(tool, b)].clone();
+                for b in 0..self.row_width() {
+                    let tool_val = self[(tool, b)].clone();
+                    self[(target, b)] += tool_val;
                 }
             }
         }
 
         impl<T: SubAssign + Clone> RowOpSub for $target_type {
             fn row_op_sub(&mut self, target: usize, tool: usize) {
-                for b in 0..self.num_columns() {
-                    self[(target, b)] -= self[(tool, b)].clone();
+                for b in 0..self.row_width() {
+                    let tool_val = self[(tool, b)].clone();
+                    self[(target, b)] -= tool_val;
                 }
             }
         }
 
-        impl<T: MulAssign + Clone> RowOpMul<T> for $target_type {
-            fn row_op_mul(&mut self, target: usize, tool: T) {
-                for b in 0..self.num_columns() {
+        impl<T: MulAssign<S>, S: Clone> RowOpMul<S> for $target_type {
+            fn row_op_mul(&mut self, target: usize, tool: S) {
+                for b in 0..self.row_width() {
                     self[(target, b)] *= tool.clone();
                 }
             }
         }
 
-        impl<T: DivAssign + Clone> RowOpDiv<T> for $target_type {
-            fn row_op_div(&mut self, target: usize, tool: T) {
-                for b in 0..self.num_columns() {
+        impl<T: DivAssign<S>, S: Clone> RowOpDiv<S> for $target_type {
+            fn row_op_div(&mut self, target: usize, tool: S) {
+                for b in 0..self.row_width() {
                     self[(target, b)] /= tool.clone();
                 }
             }
         }
 
-        impl<T: SimplifyTraits + Clone> Simplify for $target_type
-            where <T as Rem>::Output: Into<T> {
-            fn simplify_row(&mut self, row: usize) {
-                if self.num_columns() < 2 {
-                    return;
+        impl<T: AddAssign + SubAssign + Clone, S: Mul<T, Output = T> + Clone> RowOpAddMul<S>
+            for $target_type {
+            fn row_op_add_mul(&mut self, target: usize, tool: usize, k: S) {
+                for b in 0..self.row_width() {
+                    let tool_val = k.clone() * self[(tool, b)].clone();
+                    self[(target, b)] += tool_val;
                 }
-                let mut row_gcd = gcd(self[(row, 0)].clone(), self[(row, 1)].clone());
-                for i in 2..self.num_columns() {
-                    if self[(row, i)].is_zero() {
-                        continue;
-                    }
-                    row_gcd = gcd(row_gcd, self[(row, i)].clone());
-                    if row_gcd.is_one() {
-                        return;
-                    }
+            }
+
+            fn row_op_sub_mul(&mut self, target: usize, tool: usize, k: S) {
+                for b in 0..self.row_width() {
+                    let tool_val = k.clone() * self[(tool, b)].clone();
+                    self[(target, b)] -= tool_val;
                 }
-                if self.is_row_aligned() {
-                    for e in self[row].iter_mut() {
-                        *e /= row_gcd.clone();
-                    }
-                } else {
-                    for i in (row * self.num_columns())..((row + 1) * self.num_columns()) {
-                        self[(row, i)] /= row_gcd.clone();
-                    }
+            }
+        }
+
+        impl<T: Div + PartialEq + Zero + One + Clone> $target_type
+            where
+                $target_type: RowOpAddMul<T> + RowOpDiv<T> + RowOpSwap,
+                <T as Div>::Output: Into<T> {
+            /// Puts the matrix in RREF form like `gauss_jordan()` and reports which columns got
+            /// pivots. Only the coefficients of an augmented matrix are considered, so its free
+            /// columns are the free variables of the system.
+            pub fn reduce_and_report(&mut self) -> ReductionReport {
+                let (rows, columns) = (self.num_rows(), self.num_columns());
+                let pivot_columns = eliminate_below(self, None, rows, columns, |_| ());
+                eliminate_above(self, None, &pivot_columns, |_| ());
+                let free_columns = (0..columns).filter(|c| !pivot_columns.contains(c)).collect();
+                ReductionReport {
+                    rank: pivot_columns.len(),
+                    pivot_columns,
+                    free_columns
                 }
             }
+        }
+
+        impl<T> RowOpSwap for $target_type {
+            fn row_op_swap(&mut self, a: usize, b: usize) {
+                self.swap_rows(a, b);
+            }
+        }
+
+        impl<T: SimplifyTraits + PartialOrd + Sub<Output = T> + Clone> Simplify for $target_type
+            where <T as Rem>::Output: Into<T> {
+            fn simplify_row(&mut self, row: usize) {
+                let width = self.row_width();
+                normalize_row(self, row, width);
+            }
 
             fn simplify_rows(&mut self, rows: Range<usize>) {
                 for r in rows {
@@ -206,31 +445,12 @@ macro_rules! transforms_impl {
             }
         }
 
-        impl<T: SimplifyTraits + Display + Clone + Zero + One> SimplifyGetStepsDisplay for $target_type
+        impl<T: SimplifyTraits + PartialOrd + Sub<Output = T> + Display + Clone>
+            SimplifyGetStepsDisplay for $target_type
             where <T as Rem>::Output: Into<T> {
             fn simplify_row_get_steps_ds(&mut self, row: usize) -> Option<String> {
-                if self.num_columns() < 2 {
-                    return None;
-                }
-                let mut row_gcd = gcd(self[(row, 0)].clone(), self[(row, 1)].clone());
-                for i in 2..self.num_columns() {
-                    if self[(row, i)].is_zero() {
-                        continue;
-                    }
-                    row_gcd = gcd(row_gcd, self[(row, i)].clone());
-                    if row_gcd == T::one() {
-                        return None;
-                    }
-                }
-                if self.is_row_aligned() {
-                    for e in self[row].iter_mut() {
-                        *e /= row_gcd.clone();
-                    }
-                } else {
-                    for i in (row * self.num_columns())..((row + 1) * self.num_columns()) {
-                        self[(row, i)] /= row_gcd.clone();
-                    }
-                }
+                let width = self.row_width();
+                let row_gcd = normalize_row(self, row, width)?;
                 Some(format!("R{} / {} -> R{0}", row, row_gcd))
             }
 
@@ -252,31 +472,12 @@ macro_rules! transforms_impl {
             }
         }
 
-        impl<T: SimplifyTraits + Debug + Clone + Zero + One> SimplifyGetStepsDebug for $target_type
+        impl<T: SimplifyTraits + PartialOrd + Sub<Output = T> + Debug + Clone>
+            SimplifyGetStepsDebug for $target_type
             where <T as Rem>::Output: Into<T> {
             fn simplify_row_get_steps_db(&mut self, row: usize) -> Option<String> {
-                if self.num_columns() < 2 {
-                    return None;
-                }
-                let mut row_gcd = gcd(self[(row, 0)].clone(), self[(row, 1)].clone());
-                for i in 2..self.num_columns() {
-                    if self[(row, i)].is_zero() {
-                        continue;
-                    }
-                    row_gcd = gcd(row_gcd, self[(row, i)].clone());
-                    if row_gcd.is_one() {
-                        return None;
-                    }
-                }
-                if self.is_row_aligned() {
-                    for e in self[row].iter_mut() {
-                        *e /= row_gcd.clone();
-                    }
-                } else {
-                    for i in (row * self.num_columns())..((row + 1) * self.num_columns()) {
-                        self[(row, i)] /= row_gcd.clone();
-                    }
-                }
+                let width = self.row_width();
+                let row_gcd = normalize_row(self, row, width)?;
                 Some(format!("R{} / {:?} -> R{0}", row, row_gcd))
             }
 
@@ -300,69 +501,25 @@ macro_rules! transforms_impl {
 
         impl<T: Div + PartialOrd + PartialEq + Zero + One + Clone> REF for $target_type
             where
-                $target_type: RowOpAdd + RowOpSub + RowOpMul<T> + RowOpDiv<T>,
+                $target_type: RowOpAddMul<T> + RowOpDiv<T> + RowOpSwap,
                  <T as Div>::Output: Into<T> {
             fn gaussian_elim(&mut self) {
                 if self.is_row_reduced() {
                     return;
                 }
-                for r in 0..self.num_rows() {
-                    for c in 0..r + 1 {
-                        let amt1 = self[(r, c)].clone();
-                        if c < r { // If the value's under the major diagonal
-                            if amt1.is_zero() { // Continue if it's already what it should be
-                                continue;
-                            }
-                            // The value at (c, c) can be used to make (r, c) zero for REF - if
-                            // (c, c).is_zero(), just continue since doing more work on (r, c) right
-                            // now is pointless
-                            if self[(c, c)].is_zero() {
-                                continue;
-                            }
-                            let amt2 = (amt1 / self[(c, c)].clone()).into();
-                            (*self).row_op_mul(c, amt2.clone());
-                            (*self).row_op_sub(r, c);
-                            (*self).row_op_div(c, amt2);
-                        } else if c == r { // If the value's on the major diagonal
-                            if amt1.is_one() { // Continue if it's already what it should be
-                                continue;
-                            } else if !amt1.is_zero() { // If it's not zero...
-                                (*self).row_op_div(r, amt1); // ...divide by itself to make it one
-                            }
-                            if self[(r, c)].is_zero() { // If it is zero...
-                                // ...and the best tool is also zero, continue
-                                if self[(c, c)].is_zero() {
-                                    continue;
-                                }
-                                (*self).row_op_add(r, c); // Otherwise, add said tool to row 'r'
-                                if !self[(r, c)].is_one() { // If that tool somehow wasn't 1...
-                                    let src = self[(r, c)].clone();
-                                    (*self).row_op_div(r, src); // ...divide by itself
-                                }
-                            }
-                        }
-                    }
-                }
+                let (rows, columns) = (self.num_rows(), self.num_columns());
+                eliminate_below(self, None, rows, columns, |_| ());
             }
 
             fn is_row_reduced(&self) -> bool {
-                for a in 0..self.num_rows() {
-                    for b in 0..a {
-                        if !self[(a, b)].is_zero() {
-                            return false;
-                        }
-                    }
-                    if !self[(a, a)].is_one() {
-                        return false;
-                    }
-                }
-                true
+                is_echelon(&self.matrix, self.columns, &self.alignment, self.num_rows(),
+                    self.num_columns(), false)
             }
         }
 
         impl<T: Div + PartialOrd + PartialEq + Zero + One + Display + Clone> REFDisplay for $target_type
             where
-                $target_type: RowOpAdd + RowOpSub + RowOpMul<T> + RowOpDiv<T>,
+                $target_type: RowOpAddMul<T> + RowOpDiv<T> + RowOpSwap,
                  <T as Div>::Output: Into<T> {
             fn gaussian_elim_display(&mut self) -> Option<Vec<String>> {
                 if self.is_row_reduced() {
@@ -370,49 +527,15 @@ macro_rules! transforms_impl {
                 }
                 let mut steps = Vec::new();
                 steps.push("------- REF -------".to_string());
-                for r in 0..self.num_rows() {
-                    for c in 0..r + 1 {
-                        let amt1 = self[(r, c)].clone();
-                        if c < r {
-                            if amt1.is_zero() {
-                                continue;
-                            }
-                            if self[(c, c)].is_zero() {
-                                continue;
-                            }
-                            let amt2 = (amt1 / self[(c, c)].clone()).into();
-                            steps.push(format!("R{} - ({}) * R{} -> R{0}", r, amt2, c));
-                            (*self).row_op_mul(c, amt2.clone());
-                            (*self).row_op_sub(r, c);
-                            (*self).row_op_div(c, amt2);
-                        } else if c == r {
-                            if amt1.is_one() {
-                                continue;
-                            } else if !amt1.is_zero() {
-                                steps.push(format!("R{} / ({}) -> R{0}", r, amt1));
-                                (*self).row_op_div(r, amt1);
-                            } else if self[(r, c)].is_zero() {
-                                if self[(c, c)].is_zero() {
-                                    continue;
-                                }
-                                (*self).row_op_add(r, c);
-                                steps.push(format!("R{} + R{} -> R{0}", r, c));
-                                if !self[(r, c)].is_one() {
-                                    let src = self[(r, c)].clone();
-                                    steps.push(format!("R{} / ({}) -> R{0}", r, src));
-                                    (*self).row_op_div(r, src);
-                                }
-                            }
-                        }
-                    }
-                }
+                let (rows, columns) = (self.num_rows(), self.num_columns());
+                eliminate_below(self, None, rows, columns, |step| steps.push(step.display()));
                 Some(steps)
             }
         }
 
         impl<T: Div + PartialOrd + PartialEq + Zero + One + Debug + Clone> REFDebug for $target_type
             where
-                $target_type: RowOpAdd + RowOpSub + RowOpMul<T> + RowOpDiv<T>,
+                $target_type: RowOpAddMul<T> + RowOpDiv<T> + RowOpSwap,
                  <T as Div>::Output: Into<T> {
             fn gaussian_elim_debug(&mut self) -> Option<Vec<String>> {
                 if self.is_row_reduced() {
@@ -420,98 +543,37 @@ macro_rules! transforms_impl {
                 }
                 let mut steps = Vec::new();
                 steps.push("------- REF -------".to_string());
-                for r in 0..self.num_rows() {
-                    for c in 0..r + 1 {
-                        let amt1 = self[(r, c)].clone();
-                        if c < r {
-                            if amt1.is_zero() {
-                                continue;
-                            }
-                            if self[(c, c)].is_zero() {
-                                continue;
-                            }
-                            let amt2 = (amt1 / self[(c, c)].clone()).into();
-                            let step_no = steps.len();
-                            steps.push(format!("Step {}: R{} - ({:?}) * R{} -> R{0}", step_no, r,
-                                               amt2, c));
-                            (*self).row_op_mul(c, amt2.clone());
-                            (*self).row_op_sub(r, c);
-                            (*self).row_op_div(c, amt2);
-                        } else if c == r {
-                            if amt1.is_one() {
-                                continue;
-                            } else if !amt1.is_zero() {
-                                let step_no = steps.len();
-                                steps.push(format!("Step {}: R{} / ({:?}) -> R{0}", step_no, r,
-                                                   amt1));
-                                (*self).row_op_div(r, amt1);
-                            } else if self[(r, c)].is_zero() {
-                                if self[(c, c)].is_zero() {
-                                    continue;
-                                }
-                                (*self).row_op_add(r, c);
-                                let step_no = steps.len();
-                                steps.push(format!("Step {}: R{} + R{} -> R{0}", step_no, r, c));
-                                if !self[(r, c)].is_one() {
-                                    let src = self[(r, c)].clone();
-                                    let step_no = steps.len();
-                                    steps.push(format!("Step {}: R{} / ({:?}) -> R{0}", step_no,
-                                                       r, src));
-                                    (*self).row_op_div(r, src);
-                                }
-                            }
-                        }
-                    }
-                }
+                let (rows, columns) = (self.num_rows(), self.num_columns());
+                eliminate_below(self, None, rows, columns, |step| {
+                    let step_no = steps.len();
+                    steps.push(format!("Step {}: {}", step_no, step.debug()));
+                });
                 Some(steps)
             }
         }
 
         impl<T: Div + PartialEq + Zero + One + Clone> RREF for $target_type
             where
-                $target_type: REF + RowOpAdd + RowOpSub + RowOpMul<T> + RowOpDiv<T>,
+                $target_type: REF + RowOpAddMul<T> + RowOpDiv<T>,
                  <T as Div>::Output: Into<T> {
             fn gauss_jordan(&mut self) {
                 if self.is_gauss_jordan() {
                     return;
                 }
-                if !self.is_row_reduced() {
-                    (*self).gaussian_elim();
-                }
-                if !self.is_row_reduced() {
-                    return;
-                }
-                for c in (1..self.num_columns()).rev() {
-                    for r in (0..c).rev() {
-                        if self[(r, c)].is_zero() {
-                            continue;
-                        }
-                        let self_rc = self[(r, c)].clone();
-                        (*self).row_op_mul(c, self_rc.clone());
-                        (*self).row_op_sub(r, c);
-                        (*self).row_op_div(c, self_rc);
-                    }
-                }
+                let (rows, columns) = (self.num_rows(), self.num_columns());
+                let pivot_columns = eliminate_below(self, None, rows, columns, |_| ());
+                eliminate_above(self, None, &pivot_columns, |_| ());
             }
 
             fn is_gauss_jordan(&self) -> bool {
-                if !self.is_row_reduced() {
-                    return false;
-                }
-                for b in 1..self.num_rows() {
-                    for a in 0..b {
-                        if !self[(a, b)].is_zero() {
-                            return false;
-                        }
-                    }
-                }
-                true
+                is_echelon(&self.matrix, self.columns, &self.alignment, self.num_rows(),
+                    self.num_columns(), true)
             }
         }
 
         impl<T: Div + PartialEq + Zero + One + Display + Clone> RREFDisplay for $target_type
             where
-                $target_type: REF + REFDisplay + RowOpAdd + RowOpSub + RowOpMul<T> + RowOpDiv<T>,
+                $target_type: REF + REFDisplay + RowOpAddMul<T> + RowOpDiv<T>,
                  <T as Div>::Output: Into<T> {
             fn gauss_jordan_display(&mut self) -> Option<Vec<String>> {
                 if self.is_gauss_jordan() {
@@ -522,29 +584,18 @@ macro_rules! transforms_impl {
                 } else {
                     Vec::new()
                 };
-                if !self.is_row_reduced() && steps.len() == 0 {
-                    return None;
-                }
                 steps.push("------- RREF -------".to_string());
-                for c in (1..self.num_columns()).rev() {
-                    for r in (0..c).rev() {
-                        if self[(r, c)].is_zero() {
-                            continue;
-                        }
-                        let self_rc = self[(r, c)].clone();
-                        steps.push(format!("R{} - ({}) * R{} -> R{0}", r, self_rc, c));
-                        (*self).row_op_mul(c, self_rc.clone());
-                        (*self).row_op_sub(r, c);
-                        (*self).row_op_div(c, self_rc);
-                    }
-                }
+                let (rows, columns) = (self.num_rows(), self.num_columns());
+                // Already in REF, so this just finds the pivots
+                let pivot_columns = eliminate_below(self, None, rows, columns, |_| ());
+                eliminate_above(self, None, &pivot_columns, |step| steps.push(step.display()));
                 Some(steps)
             }
         }
 
         impl<T: Div + PartialEq + Zero + One + Debug + Clone> RREFDebug for $target_type
             where
-                $target_type: REF + REFDebug + RowOpAdd + RowOpSub + RowOpMul<T> + RowOpDiv<T>,
+                $target_type: REF + REFDebug + RowOpAddMul<T> + RowOpDiv<T>,
                  <T as Div>::Output: Into<T> {
             fn gauss_jordan_debug(&mut self) -> Option<Vec<String>> {
                 if self.is_gauss_jordan() {
@@ -555,474 +606,114 @@ macro_rules! transforms_impl {
                 } else {
                     Vec::new()
                 };
-                if !self.is_row_reduced() && steps.len() == 0 {
-                    return None;
-                }
                 steps.push("------- RREF -------".to_string());
-                for c in (1..self.num_columns()).rev() {
-                    for r in (0..c).rev() {
-                        if self[(r, c)].is_zero() {
-                            continue;
-                        }
-                        let self_rc = self[(r, c)].clone();
-                        let step_no = steps.len();
-                        steps.push(format!("Step {}: R{} - ({:?}) * R{} -> R{0}", step_no, r,
-                                           self_rc, c));
-                        (*self).row_op_mul(c, self_rc.clone());
-                        (*self).row_op_sub(r, c);
-                        (*self).row_op_div(c, self_rc);
-                    }
-                }
+                let (rows, columns) = (self.num_rows(), self.num_columns());
+                // Already in REF, so this just finds the pivots
+                let pivot_columns = eliminate_below(self, None, rows, columns, |_| ());
+                eliminate_above(self, None, &pivot_columns, |step| {
+                    let step_no = steps.len();
+                    steps.push(format!("Step {}: {}", step_no, step.debug()));
+                });
                 Some(steps)
             }
         }
 
         impl<T: Div + PartialOrd + PartialEq + Zero + One + Clone> Inverse for $target_type
             where
-                $target_type: REF + RowOpAdd + RowOpSub + RowOpMul<T> + RowOpDiv<T> + Unit,
+                $target_type: REF + RowOpAddMul<T> + RowOpDiv<T> + RowOpSwap + Unit,
                  <T as Div>::Output: Into<T> {
             fn inverse(&mut self) {
-                assert!(self.is_unit_dimension());
-                let mut s = $name::unit(self.rows);
-                swap(&mut s, self);
-                for r in 0..s.num_rows() {
-                    for c in 0..r + 1 {
-                        let amt1 = s[(r, c)].clone();
-                        if c < r {
-                            if amt1.is_zero() {
-                                continue;
-                            }
-                            if s[(c, c)].is_zero() {
-                                continue;
-                            }
-                            let amt2 = (amt1 / s[(c, c)].clone()).into();
-                            s.row_op_mul(c, amt2.clone());
-                            (*self).row_op_mul(c, amt2.clone());
-                            s.row_op_sub(r, c);
-                            (*self).row_op_sub(r, c);
-                            s.row_op_div(c, amt2.clone());
-                            (*self).row_op_div(c, amt2);
-                        } else if c == r {
-                            if amt1.is_one() {
-                                continue;
-                            } else if !amt1.is_zero() {
-                                s.row_op_div(r, amt1.clone());
-                                (*self).row_op_div(r, amt1);
-                            } else if amt1.is_zero() {
-                                if s[(c, c)].is_zero() {
-                                    continue;
-                                }
-                                s.row_op_add(r, c);
-                                (*self).row_op_add(r, c);
-                                if !s[(r, c)].is_one() {
-                                    let src = s[(r, c)].clone();
-                                    (*self).row_op_div(r, src.clone());
-                                    s.row_op_div(r, src);
-                                }
-                            }
-                        }
-                    }
-                }
-                assert!(s.is_row_reduced());
-                for c in (1..s.num_columns()).rev() {
-                    for r in (0..c).rev() {
-                        if s[(r, c)].is_zero() {
-                            continue;
-                        }
-                        let src = s[(r, c)].clone();
-                        s.row_op_mul(c, src.clone());
-                        (*self).row_op_mul(c, src.clone());
-                        s.row_op_sub(r, c);
-                        (*self).row_op_sub(r, c);
-                        s.row_op_div(c, src.clone());
-                        (*self).row_op_div(c, src);
-                    }
+                if let Err(e) = self.try_inverse() {
+                    panic!("{}", e);
                 }
-                assert!(s.is_unit());
             }
 
             fn try_inverse(&mut self) -> Result<(), MatrixError> {
-                if !(*self).is_unit_dimension() {
-                    return Err(MatrixError::InitError("Matrix does not have the same number of \
-                    rows and columns - unable to make inverse.".to_string()));
-                }
-                let mut s = $name::unit(self.rows);
-                swap(&mut s, self);
-                for r in 0..s.num_rows() {
-                    for c in 0..r + 1 {
-                        let amt1 = s[(r, c)].clone();
-                        if c < r {
-                            if amt1.is_zero() {
-                                continue;
-                            }
-                            if s[(c, c)].is_zero() {
-                                continue;
-                            }
-                            let amt2 = (amt1 / s[(c, c)].clone()).into();
-                            s.row_op_mul(c, amt2.clone());
-                            (*self).row_op_mul(c, amt2.clone());
-                            s.row_op_sub(r, c);
-                            (*self).row_op_sub(r, c);
-                            s.row_op_div(c, amt2.clone());
-                            (*self).row_op_div(c, amt2);
-                        } else if c == r {
-                            if amt1.is_one() {
-                                continue;
-                            } else if !amt1.is_zero() {
-                                s.row_op_div(r, amt1.clone());
-                                (*self).row_op_div(r, amt1);
-                            } else if amt1.is_zero() {
-                                if s[(c, c)].is_zero() {
-                                    continue;
-                                }
-                                s.row_op_add(r, c);
-                                (*self).row_op_add(r, c);
-                                if !s[(r, c)].is_one() {
-                                    let src = s[(r, c)].clone();
-                                    (*self).row_op_div(r, src.clone());
-                                    s.row_op_div(r, src);
-                                }
-                            }
-                        }
-                    }
-                }
-                if !self.is_row_reduced() {
-                    return Err(MatrixError::TransformError("Was unable to make an inverse - unable \
-                    to put original matrix in REF form.".to_string()));
-                }
-                for c in (1..s.num_columns()).rev() {
-                    for r in (0..c).rev() {
-                        if s[(r, c)].is_zero() {
-                            continue;
-                        }
-                        let src = s[(r, c)].clone();
-                        s.row_op_mul(c, src.clone());
-                        (*self).row_op_mul(c, src.clone());
-                        s.row_op_sub(r, c);
-                        (*self).row_op_sub(r, c);
-                        s.row_op_div(c, src.clone());
-                        (*self).row_op_div(c, src);
-                    }
-                }
-                if s.is_unit() {
-                    Ok(())
-                } else {
-                    Err(MatrixError::TransformError("Was unable to make an inverse - unable to put \
-                    original matrix in RREF form.".to_string()))
-                }
+                let n = self.num_rows();
+                invert(self, n, |_| ())
             }
         }
 
         impl<T> InverseDisplay for $target_type
             where
                 T: Div + PartialOrd + PartialEq + Display + Zero + One + Clone,
-                $target_type: REF + RowOpAdd + RowOpSub + RowOpMul<T> + RowOpDiv<T> + Unit,
+                $target_type: REF + RowOpAddMul<T> + RowOpDiv<T> + RowOpSwap + Unit,
                  <T as Div>::Output: Into<T> {
             fn inverse_display(&mut self) -> Option<Vec<String>> {
-                assert!(self.is_unit_dimension());
-                if (*self).is_unit() {
-                    return None;
-                }
-                let mut steps = Vec::new();
-                let mut s = $name::unit(self.rows);
-                swap(&mut s, self);
-                for r in 0..s.num_rows() {
-                    for c in 0..r + 1 {
-                        let amt1 = s[(r, c)].clone();
-                        if c < r {
-                            if amt1.is_zero() {
-                                continue;
-                            }
-                            if s[(c, c)].is_zero() {
-                                continue;
-                            }
-                            let amt2 = (amt1 / s[(c, c)].clone()).into();
-                            steps.push(format!("R{} - ({}) * R{} -> R{0}", r, amt2, c));
-                            s.row_op_mul(c, amt2.clone());
-                            (*self).row_op_mul(c, amt2.clone());
-                            s.row_op_sub(r, c);
-                            (*self).row_op_sub(r, c);
-                            s.row_op_div(c, amt2.clone());
-                            (*self).row_op_div(c, amt2);
-                        } else if c == r {
-                            if amt1.is_one() {
-                                continue;
-                            } else if !amt1.is_zero() {
-                                steps.push(format!("R{} / ({}) -> R{0}", r, amt1));
-                                s.row_op_div(r, amt1.clone());
-                                (*self).row_op_div(r, amt1);
-                            } else if amt1.is_zero() {
-                                if s[(c, c)].is_zero() {
-                                    continue;
-                                }
-                                s.row_op_add(r, c);
-                                (*self).row_op_add(r, c);
-                                steps.push(format!("R{} + R{} -> R{0}", r, c));
-                                if !s[(r, c)].is_one() {
-                                    let src = s[(r, c)].clone();
-                                    steps.push(format!("R{} / ({}) -> R{0}", r, src));
-                                    (*self).row_op_div(r, src.clone());
-                                    s.row_op_div(r, src);
-                                }
-                            }
-                        }
-                    }
-                }
-                for c in (1..s.num_columns()).rev() {
-                    for r in (0..c).rev() {
-                        if s[(r, c)].is_zero() {
-                            continue;
-                        }
-                        let src = s[(r, c)].clone();
-                        steps.push(format!("R{} - ({}) * R{} -> R{0}", r, src, c));
-                        s.row_op_mul(c, src.clone());
-                        (*self).row_op_mul(c, src.clone());
-                        s.row_op_sub(r, c);
-                        (*self).row_op_sub(r, c);
-                        s.row_op_div(c, src.clone());
-                        (*self).row_op_div(c, src);
-                    }
+                match self.try_inverse_display() {
+                    Ok(steps) => steps,
+                    Err(e) => panic!("{}", e)
                 }
-                assert!(s.is_unit());
-                Some(steps)
             }
 
             fn try_inverse_display(&mut self) -> Result<Option<Vec<String>>, MatrixError> {
-                if !(*self).is_unit_dimension() {
-                    return Err(MatrixError::InitError("Matrix does not have the same number of \
-                    rows and columns - unable to make inverse.".to_string()));
-                }
-                if (*self).is_unit() {
+                if self.is_unit_dimension() && self.is_unit() {
                     return Ok(None);
                 }
                 let mut steps = Vec::new();
-                let mut s = $name::unit(self.rows);
-                swap(&mut s, self);
-                for r in 0..s.num_rows() {
-                    for c in 0..r + 1 {
-                        let amt1 = s[(r, c)].clone();
-                        if c < r {
-                            if amt1.is_zero() {
-                                continue;
-                            }
-                            if s[(c, c)].is_zero() {
-                                continue;
-                            }
-                            let amt2 = (amt1 / s[(c, c)].clone()).into();
-                            steps.push(format!("R{} - ({}) * R{} -> R{0}", r, amt2, c));
-                            s.row_op_mul(c, amt2.clone());
-                            (*self).row_op_mul(c, amt2.clone());
-                            s.row_op_sub(r, c);
-                            (*self).row_op_sub(r, c);
-                            s.row_op_div(c, amt2.clone());
-                            (*self).row_op_div(c, amt2);
-                        } else if c == r {
-                            if amt1.is_one() {
-                                continue;
-                            } else if !amt1.is_zero() {
-                                steps.push(format!("R{} / ({}) -> R{0}", r, amt1));
-                                s.row_op_div(r, amt1.clone());
-                                (*self).row_op_div(r, amt1);
-                            } else if amt1.is_zero() {
-                                if s[(c, c)].is_zero() {
-                                    continue;
-                                }
-                                s.row_op_add(r, c);
-                                (*self).row_op_add(r, c);
-                                steps.push(format!("R{} + R{} -> R{0}", r, c));
-                                if !s[(r, c)].is_one() {
-                                    let src = s[(r, c)].clone();
-                                    steps.push(format!("R{} / ({}) -> R{0}", r, src));
-                                    (*self).row_op_div(r, src.clone());
-                                    s.row_op_div(r, src);
-                                }
-                            }
-                        }
-                    }
-                }
-                if !self.is_row_reduced() {
-                    return Err(MatrixError::TransformError("Was unable to make an inverse - unable \
-                    to put original matrix in REF form.".to_string()));
-                }
-                for c in (1..s.num_columns()).rev() {
-                    for r in (0..c).rev() {
-                        if s[(r, c)].is_zero() {
-                            continue;
-                        }
-                        let src = s[(r, c)].clone();
-                        steps.push(format!("R{} - ({}) * R{} -> R{0}", r, src, c));
-                        s.row_op_mul(c, src.clone());
-                        (*self).row_op_mul(c, src.clone());
-                        s.row_op_sub(r, c);
-                        (*self).row_op_sub(r, c);
-                        s.row_op_div(c, src.clone());
-                        (*self).row_op_div(c, src);
-                    }
-                }
-                if s.is_unit() {
-                    Ok(Some(steps))
-                } else {
-                    Err(MatrixError::TransformError("Was unable to make an inverse - unable to put \
-                    original matrix in RREF form.".to_string()))
-                }
+                let n = self.num_rows();
+                invert(self, n, |step| steps.push(step.display()))?;
+                Ok(Some(steps))
             }
         }
 
         impl<T> InverseDebug for $target_type
             where
                 T: Div + PartialOrd + PartialEq + Debug + Zero + One + Clone,
-                $target_type: REF + RowOpAdd + RowOpSub + RowOpMul<T> + RowOpDiv<T> + Unit,
+                $target_type: REF + RowOpAddMul<T> + RowOpDiv<T> + RowOpSwap + Unit,
                  <T as Div>::Output: Into<T> {
             fn inverse_debug(&mut self) -> Option<Vec<String>> {
-                assert!(self.is_unit_dimension());
-                if (*self).is_unit() {
-                    return None;
-                }
-                let mut steps = Vec::new();
-                let mut s = $name::unit(self.rows);
-                swap(&mut s, self);
-                for r in 0..s.num_rows() {
-                    for c in 0..r + 1 {
-                        let amt1 = s[(r, c)].clone();
-                        if c < r {
-                            if amt1.is_zero() {
-                                continue;
-                            }
-                            if s[(c, c)].is_zero() {
-                                continue;
-                            }
-                            let amt2 = (amt1 / s[(c, c)].clone()).into();
-                            steps.push(format!("R{} - ({:?}) * R{} -> R{0}", r, amt2, c));
-                            s.row_op_mul(c, amt2.clone());
-                            (*self).row_op_mul(c, amt2.clone());
-                            s.row_op_sub(r, c);
-                            (*self).row_op_sub(r, c);
-                            s.row_op_div(c, amt2.clone());
-                            (*self).row_op_div(c, amt2);
-                        } else if c == r {
-                            if amt1.is_one() {
-                                continue;
-                            } else if !amt1.is_zero() {
-                                steps.push(format!("R{} / ({:?}) -> R{0}", r, amt1));
-                                s.row_op_div(r, amt1.clone());
-                                (*self).row_op_div(r, amt1);
-                            } else if amt1.is_zero() {
-                                if s[(c, c)].is_zero() {
-                                    continue;
-                                }
-                                s.row_op_add(r, c);
-                                (*self).row_op_add(r, c);
-                                steps.push(format!("R{} + R{} -> R{0}", r, c));
-                                if !s[(r, c)].is_one() {
-                                    let src = s[(r, c)].clone();
-                                    steps.push(format!("R{} / ({:?}) -> R{0}", r, src));
-                                    (*self).row_op_div(r, src.clone());
-                                    s.row_op_div(r, src);
-                                }
-                            }
-                        }
-                    }
+                match self.try_inverse_debug() {
+                    Ok(steps) => steps,
+                    Err(e) => panic!("{}", e)
                 }
-                for c in (1..s.num_columns()).rev() {
-                    for r in (0..c).rev() {
-                        if s[(r, c)].is_zero() {
-                            continue;
-                        }
-                        let src = s[(r, c)].clone();
-                        steps.push(format!("R{} - ({:?}) * R{} -> R{0}", r, src, c));
-                        s.row_op_mul(c, src.clone());
-                        (*self).row_op_mul(c, src.clone());
-                        s.row_op_sub(r, c);
-                        (*self).row_op_sub(r, c);
-                        s.row_op_div(c, src.clone());
-                        (*self).row_op_div(c, src);
-                    }
-                }
-                assert!(s.is_unit());
-                Some(steps)
             }
 
             fn try_inverse_debug(&mut self) -> Result<Option<Vec<String>>, MatrixError> {
-                if !(*self).is_unit_dimension() {
-                    return Err(MatrixError::InitError("Matrix does not have the same number of \
-                    rows and columns - unable to make inverse.".to_string()));
-                }
-                if (*self).is_unit() {
+                if self.is_unit_dimension() && self.is_unit() {
                     return Ok(None);
                 }
                 let mut steps = Vec::new();
-                let mut s = $name::unit(self.rows);
-                swap(&mut s, self);
-                for r in 0..s.num_rows() {
-                    for c in 0..r + 1 {
-                        let amt1 = s[(r, c)].clone();
-                        if c < r {
-                            if amt1.is_zero() {
-                                continue;
-                            }
-                            if s[(c, c)].is_zero() {
-                                continue;
-                            }
-                            let amt2 = (amt1 / s[(c, c)].clone()).into();
-                            steps.push(format!("R{} - ({:?}) * R{} -> R{0}", r, amt2, c));
-                            s.row_op_mul(c, amt2.clone());
-                            (*self).row_op_mul(c, amt2.clone());
-                            s.row_op_sub(r, c);
-                            (*self).row_op_sub(r, c);
-                            s.row_op_div(c, amt2.clone());
-                            (*self).row_op_div(c, amt2);
-                        } else if c == r {
-                            if amt1.is_one() {
-                                continue;
-                            } else if !amt1.is_zero() {
-                                steps.push(format!("R{} / ({:?}) -> R{0}", r, amt1));
-                                s.row_op_div(r, amt1.clone());
-                                (*self).row_op_div(r, amt1);
-                            } else if amt1.is_zero() {
-                                if s[(c, c)].is_zero() {
-                                    continue;
-                                }
-                                s.row_op_add(r, c);
-                                (*self).row_op_add(r, c);
-                                steps.push(format!("R{} + R{} -> R{0}", r, c));
-                                if !s[(r, c)].is_one() {
-                                    let src = s[(r, c)].clone();
-                                    steps.push(format!("R{} / ({:?}) -> R{0}", r, src));
-                                    (*self).row_op_div(r, src.clone());
-                                    s.row_op_div(r, src);
-                                }
-                            }
-                        }
-                    }
-                }
-                if !self.is_row_reduced() {
-                    return Err(MatrixError::TransformError("Was unable to make an inverse - unable \
-                    to put original matrix in REF form.".to_string()));
-                }
-                for c in (1..s.num_columns()).rev() {
-                    for r in (0..c).rev() {
-                        if s[(r, c)].is_zero() {
-                            continue;
-                        }
-                        let src = s[(r, c)].clone();
-                        steps.push(format!("R{} - ({:?}) * R{} -> R{0}", r, src, c));
-                        s.row_op_mul(c, src.clone());
-                        (*self).row_op_mul(c, src.clone());
-                        s.row_op_sub(r, c);
-                        (*self).row_op_sub(r, c);
-                        s.row_op_div(c, src.clone());
-                        (*self).row_op_div(c, src);
-                    }
-                }
-                if s.is_unit() {
-                    Ok(Some(steps))
-                } else {
-                    Err(MatrixError::TransformError("Was unable to make an inverse - unable to put \
-                    original matrix in RREF form.".to_string()))
-                }
+                let n = self.num_rows();
+                invert(self, n, |step| steps.push(step.debug()))?;
+                Ok(Some(steps))
             }
         }
     )*)
 }
 
-transforms_impl!{Matrix<T>: Matrix, AugmentedMatrix<T>: AugmentedMatrix}
\ No newline at end of file
+transforms_impl!{Matrix<T>: Matrix, AugmentedMatrix<T>: AugmentedMatrix}
+
+impl<T: Div + PartialEq + Zero + One + Clone> Solve<T> for AugmentedMatrix<T>
+    where
+        AugmentedMatrix<T>: RowOpAddMul<T> + RowOpDiv<T> + RowOpSwap,
+        <T as Div>::Output: Into<T> {
+    fn solve(&self) -> Result<Vec<T>, MatrixError> {
+        self.clone().solve_in_place()
+    }
+
+    fn solve_in_place(&mut self) -> Result<Vec<T>, MatrixError> {
+        let report = self.reduce_and_report();
+        let columns = self.num_columns();
+        // Rows past the pivots have no coefficients left, so they read `0 = solution`
+        let inconsistent_row = (report.rank..self.num_rows())
+            .find(|&r| !self[(r, columns)].is_zero());
+        if inconsistent_row.is_some() || !report.free_columns.is_empty() {
+            return Err(no_unique_solution(inconsistent_row, &report.free_columns));
+        }
+        Ok((0..columns).map(|r| self[(r, columns)].clone()).collect())
+    }
+}
+
+// The error for a reduced system without a unique solution: inconsistent if `inconsistent_row`
+// (the first row past the pivots with a non-zero solution) is given, and otherwise
+// underdetermined, with `free_columns` the columns without a pivot.
+pub(crate) fn no_unique_solution(inconsistent_row: Option<usize>, free_columns: &[usize])
+    -> MatrixError {
+    match inconsistent_row {
+        Some(row) => MatrixError::TransformError(format!("The system is inconsistent - row {} \
+        reduces to 0 = c for some non-zero c.", row)),
+        None => MatrixError::TransformError(format!("The system is underdetermined - columns {:?} \
+        have no pivot, so it has infinitely many solutions.", free_columns))
+    }
+}
\ No newline at end of file
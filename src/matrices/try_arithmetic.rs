@@ -201,7 +201,7 @@ impl<T, U> TryMulMatrices<Matrix<U>> for Matrix<T>
 
     fn try_mul(self, other: Matrix<U>) -> Result<Matrix<T>, MatrixError> {
         try_mul_div_valid_operation_check(self.dimension(), other.dimension())?;
-        let mut matr = Matrix::splat(&T::zero(), (self.rows, other.rows), self.alignment.clone());
+        let mut matr = Matrix::zeros_aligned((self.rows, other.rows), self.alignment.clone());
         for a in 0..self.rows {
             for b in 0..other.rows {
                 matr[(a, b)] += (self[(a, b)].clone() * other[(b, a)].clone().into()).into();
@@ -219,7 +219,7 @@ impl<'a, T, U> TryMulMatrices<&'a Matrix<U>> for Matrix<T>
     type Output = Result<Matrix<T>, MatrixError>;
 
     fn try_mul(self, other: &'a Matrix<U>) -> Result<Matrix<T>, MatrixError> {
-        let mut matr = Matrix::splat(&T::zero(), (self.rows, other.rows), self.alignment.clone());
+        let mut matr = Matrix::zeros_aligned((self.rows, other.rows), self.alignment.clone());
         for a in 0..self.rows {
             for b in 0..other.rows {
                 matr[(a, b)] += (self[(a, b)].clone() * other[(b, a)].clone().into()).into();
@@ -237,7 +237,7 @@ impl<'a, T, U> TryMulMatrices<Matrix<U>> for &'a Matrix<T>
     type Output = Result<Matrix<T>, MatrixError>;
 
     fn try_mul(self, other: Matrix<U>) -> Result<Matrix<T>, MatrixError> {
-        let mut matr = Matrix::splat(&T::zero(), (self.rows, other.rows), self.alignment.clone());
+        let mut matr = Matrix::zeros_aligned((self.rows, other.rows), self.alignment.clone());
         for a in 0..self.rows {
             for b in 0..other.rows {
                 matr[(a, b)] += (self[(a, b)].clone() * other[(b, a)].clone().into()).into();
@@ -255,7 +255,7 @@ impl<'a, 'b, T, U> TryMulMatrices<&'b Matrix<U>> for &'a Matrix<T>
     type Output = Result<Matrix<T>, MatrixError>;
 
     fn try_mul(self, other: &'b Matrix<U>) -> Result<Matrix<T>, MatrixError> {
-        let mut matr = Matrix::splat(&T::zero(), (self.rows, other.rows), self.alignment.clone());
+        let mut matr = Matrix::zeros_aligned((self.rows, other.rows), self.alignment.clone());
         for a in 0..self.rows {
             for b in 0..other.rows {
                 matr[(a, b)] += (self[(a, b)].clone() * other[(b, a)].clone().into()).into();
@@ -411,7 +411,7 @@ impl<T, U> TryMulAssignMatrices<Matrix<U>> for Matrix<T>
         <Matrix<T> as TryMulMatrices<Matrix<U>>>::Output: Into<Result<Matrix<T>, MatrixError>> {
     fn try_mul_assign(&mut self, other: Matrix<U>) -> Result<(), MatrixError> {
         try_mul_div_valid_operation_check(self.dimension(), other.dimension())?;
-        let mut matr = Matrix::splat(&T::zero(), (self.rows, other.rows), self.alignment.clone());
+        let mut matr = Matrix::zeros_aligned((self.rows, other.rows), self.alignment.clone());
         swap(self, &mut matr);
         for a in 0..self.rows {
             for b in 0..other.rows {
@@ -431,7 +431,7 @@ impl<'a, T, U> TryMulAssignMatrices<&'a Matrix<U>> for Matrix<T>
         <Matrix<T> as TryMulMatrices<Matrix<U>>>::Output: Into<Result<Matrix<T>, MatrixError>> {
     fn try_mul_assign(&mut self, other: &'a Matrix<U>) -> Result<(), MatrixError> {
         try_mul_div_valid_operation_check(self.dimension(), other.dimension())?;
-        let mut matr = Matrix::splat(&T::zero(), (self.rows, other.rows), self.alignment.clone());
+        let mut matr = Matrix::zeros_aligned((self.rows, other.rows), self.alignment.clone());
         swap(self, &mut matr);
         for a in 0..self.rows {
             for b in 0..other.rows {
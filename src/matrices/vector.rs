@@ -0,0 +1,151 @@
+//! Treating matrices as vectors (dot and cross products), and multiplying them by vectors given as
+//! slices, so that a vector doesn't have to be wrapped up in a single-column matrix first.
+//! `residual()` checks how well a vector solves the system of an augmented matrix.
+//! # Example
+//! ```rust
+//! # #[macro_use] extern crate fractions_and_matrices;
+//! # use fractions_and_matrices::matrices::base::{Matrix, AugmentedMatrix};
+//! let foo = matrix![1 2 3];
+//! let mut bar = matrix![4 5 6];
+//! bar.column_align();
+//! assert_eq!(foo.dot(&bar).unwrap(), 32);
+//! let system = augmented_matrix![
+//!     1 1 => 3;
+//!     2 1 => 5
+//! ];
+//! assert_eq!(system.mul_vec(&[2, 1]).unwrap(), vec![3, 5]);
+//! assert_eq!(system.residual(&[2, 1]).unwrap(), vec![0, 0]);
+//! assert_eq!(system.residual(&[1, 1]).unwrap(), vec![-1, -2]);
+//! ```
+
+use std::ops::{Mul, Sub};
+
+use num::Zero;
+
+use matrices::base::{Alignment, AugmentedMatrix, Matrix, MatrixError};
+use matrices::matrix_ref::MatrixRef;
+
+impl<T: Clone + Zero + Mul<Output = T>> Matrix<T> {
+    /// Gives the dot product of two vectors, each of which can be a 1 x n or an n x 1 matrix
+    /// (with either alignment). Returns an error if either matrix has more than one row and more
+    /// than one column, or if their lengths differ.
+    pub fn dot(&self, other: &Matrix<T>) -> Result<T, MatrixError> {
+        let (d1, d2) = (self.dimension(), other.dimension());
+        if d1.0 != 1 && d1.1 != 1 || d2.0 != 1 && d2.1 != 1 {
+            return Err(MatrixError::FunctionError(format!("Can't take the dot product of a {:?} \
+            matrix and a {:?} matrix: both need to be a single row or column.", d1, d2)));
+        }
+        if self.matrix.len() != other.matrix.len() {
+            return Err(MatrixError::FunctionError(format!("Can't take the dot product of vectors \
+            of lengths {} and {}.", self.matrix.len(), other.matrix.len())));
+        }
+        // A single row or column is stored in order whatever the alignment
+        Ok(self.matrix.iter()
+            .zip(other.matrix.iter())
+            .fold(T::zero(), |acc, (a, b)| acc + a.clone() * b.clone()))
+    }
+
+    /// Multiplies the matrix by the column vector `v`. Returns an error if the length of `v`
+    /// isn't the number of columns. Same as `MatrixRef::mul_vector`, without needing the trait.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// let foo = matrix![
+    ///     1 2 3;
+    ///     4 5 6
+    /// ];
+    /// assert_eq!(foo.mul_vec(&[1, 0, 2]).unwrap(), vec![7, 16]);
+    /// assert!(foo.mul_vec(&[1, 2]).is_err());
+    /// ```
+    pub fn mul_vec(&self, v: &[T]) -> Result<Vec<T>, MatrixError> {
+        self.mul_vector(v)
+    }
+
+    /// Gives the cross product of two 3-vectors, which have to be both 1 x 3 or both 3 x 1
+    /// matrices (with either alignment). The result has the dimension and alignment of `self`.
+    /// Returns an error for any other dimensions.
+    /// # Example
+    /// ```rust
+    /// # #[macro_use] extern crate fractions_and_matrices;
+    /// # use fractions_and_matrices::matrices::base::Matrix;
+    /// let x = matrix![1 0 0];
+    /// let y = matrix![0 1 0];
+    /// assert_eq!(x.cross(&y).unwrap(), matrix![0 0 1]);
+    /// assert_eq!(y.cross(&x).unwrap()[(0, 2)], -1);
+    /// assert!(x.cross(&y.transpose()).is_err());
+    /// ```
+    pub fn cross(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: Sub<Output = T> {
+        let (d1, d2) = (self.dimension(), other.dimension());
+        if d1 != d2 || d1 != (1, 3) && d1 != (3, 1) {
+            return Err(MatrixError::FunctionError(format!("Can't take the cross product of a \
+            {:?} matrix and a {:?} matrix: both need to be 1x3 or both 3x1.", d1, d2)));
+        }
+        let (a, b) = (&self.matrix, &other.matrix);
+        let product = |i: usize, j: usize| a[i].clone() * b[j].clone()
+            - a[j].clone() * b[i].clone();
+        Ok(Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            matrix: vec![product(1, 2), product(2, 0), product(0, 1)],
+            alignment: self.alignment.clone()
+        })
+    }
+
+    /// Gives the scalar triple product `a · (b × c)`, the signed volume of the parallelepiped
+    /// spanned by the three vectors. `b` and `c` have to be as for `cross()`, and `a` can be
+    /// any 3-vector.
+    pub fn triple_product(a: &Matrix<T>, b: &Matrix<T>, c: &Matrix<T>) -> Result<T, MatrixError>
+        where T: Sub<Output = T> {
+        a.dot(&b.cross(c)?)
+    }
+}
+
+impl<T: Clone + Zero + Mul<Output = T>> AugmentedMatrix<T> {
+    /// Multiplies the coefficient matrix by the column vector `v`, leaving out the solution
+    /// column. Returns an error if the length of `v` isn't the number of coefficient columns.
+    pub fn mul_vec(&self, v: &[T]) -> Result<Vec<T>, MatrixError> {
+        Coefficients(self).mul_vector(v)
+    }
+
+    /// Gives `A * v - b`, where `A` is the coefficient matrix and `b` the solution column, so
+    /// it's all zeroes exactly when `v` solves the system. Returns an error if the length of `v`
+    /// isn't the number of coefficient columns.
+    pub fn residual(&self, v: &[T]) -> Result<Vec<T>, MatrixError> where T: Sub<Output = T> {
+        let solution = self.num_columns();
+        Ok(self.mul_vec(v)?
+            .into_iter()
+            .enumerate()
+            .map(|(r, e)| e - self[(r, solution)].clone())
+            .collect())
+    }
+}
+
+// The coefficient matrix of an augmented matrix, without the solution column, so that
+// `AugmentedMatrix::mul_vec` can use `MatrixRef::mul_vector`.
+struct Coefficients<'a, T: 'a>(&'a AugmentedMatrix<T>);
+
+impl<'a, T> MatrixRef<T> for Coefficients<'a, T> {
+    fn dimension(&self) -> (usize, usize) {
+        self.0.dimension()
+    }
+
+    fn get(&self, index: (usize, usize)) -> Option<&T> {
+        let (rows, columns) = self.0.dimension();
+        if index.0 < rows && index.1 < columns {
+            Some(&self.0[index])
+        } else {
+            None
+        }
+    }
+
+    fn row_slice(&self, row: usize) -> Option<&[T]> {
+        let (rows, columns) = self.0.dimension();
+        if self.0.alignment == Alignment::RowAligned && row < rows {
+            Some(&self.0[row][..columns])
+        } else {
+            None
+        }
+    }
+}
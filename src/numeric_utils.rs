@@ -0,0 +1,105 @@
+//! GCD and normalization helpers for slices of numbers. These are what `Simplify` uses to reduce
+//! matrix rows, exposed so that arbitrary vectors can be normalized the same way.
+
+use std::ops::{Rem, Sub};
+
+use fractions::base::{Fraction, get_gcd, get_lcm};
+use matrices::transforms::SimplifyTraits;
+
+pub(crate) fn gcd<T: SimplifyTraits + Clone>(a: T, b: T) -> T
+    where <T as Rem>::Output: Into<T> {
+    if b.is_zero() {
+        a
+    } else {
+        gcd(b.clone(), (a % b).into())
+    }
+}
+
+/// Finds the GCD of all the non-zero elements of a slice, made non-negative. Returns `None` if
+/// the slice is empty or all zeros.
+/// # Examples
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::numeric_utils::slice_gcd;
+/// assert_eq!(slice_gcd(&[12, -18, 0, 30]), Some(6));
+/// assert_eq!(slice_gcd(&[-7]), Some(7));
+/// assert_eq!(slice_gcd(&[0, 0]), None);
+/// ```
+pub fn slice_gcd<T>(s: &[T]) -> Option<T>
+    where
+        T: SimplifyTraits + PartialOrd + Sub<Output = T> + Clone,
+        <T as Rem>::Output: Into<T> {
+    let mut nonzero = s.iter().filter(|e| !e.is_zero()).cloned();
+    let first = nonzero.next()?;
+    let result = nonzero.fold(first, gcd);
+    if result < T::zero() {
+        Some(T::zero() - result)
+    } else {
+        Some(result)
+    }
+}
+
+/// Divides every element of a slice by the slice's GCD (see `slice_gcd`) and returns the divisor
+/// used. Returns `None`, leaving the slice alone, if it's empty or all zeros.
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::numeric_utils::normalize_slice_in_place;
+/// let mut foo = [-4, 0, 6, 10];
+/// assert_eq!(normalize_slice_in_place(&mut foo), Some(2));
+/// assert_eq!(foo, [-2, 0, 3, 5]);
+/// ```
+pub fn normalize_slice_in_place<T>(s: &mut [T]) -> Option<T>
+    where
+        T: SimplifyTraits + PartialOrd + Sub<Output = T> + Clone,
+        <T as Rem>::Output: Into<T> {
+    let divisor = slice_gcd(s)?;
+    if !divisor.is_one() {
+        for e in s.iter_mut() {
+            *e /= divisor.clone();
+        }
+    }
+    Some(divisor)
+}
+
+/// Scales a slice of `Fraction`s to the smallest integers with the same ratios, with the first
+/// non-zero element positive, and returns the divisor used (so `old[i] == new[i] * divisor`).
+/// Returns `None`, leaving the slice alone, if it's empty, all zeros, or contains an undefined
+/// fraction.
+/// # Example
+/// ```rust
+/// # extern crate fractions_and_matrices;
+/// # use fractions_and_matrices::fractions::base::Fraction;
+/// # use fractions_and_matrices::numeric_utils::normalize_fraction_slice;
+/// let mut foo = [Fraction::from(0), Fraction::new(-1, 2), Fraction::new(3, 4)];
+/// assert_eq!(normalize_fraction_slice(&mut foo), Some(Fraction::new(-1, 4)));
+/// assert_eq!(foo, [Fraction::from(0), Fraction::from(2), Fraction::from(-3)]);
+/// ```
+pub fn normalize_fraction_slice(s: &mut [Fraction]) -> Option<Fraction> {
+    if s.iter().any(|f| f.is_ud()) {
+        return None;
+    }
+    let nonzero = s.iter()
+        .map(|f| {
+            let mut f = *f;
+            f.simplify();
+            f
+        })
+        .filter(|f| f.num != 0)
+        .collect::<Vec<Fraction>>();
+    if nonzero.is_empty() {
+        return None;
+    }
+    let den_lcm = nonzero.iter().fold(1, |acc, f| get_lcm(acc, f.den));
+    let num_gcd = nonzero.iter()
+        .fold(0, |acc, f| get_gcd(acc, (f.num * (den_lcm / f.den)).unsigned_abs()));
+    let mut divisor = Fraction::new(num_gcd as i64, den_lcm);
+    if nonzero[0].num < 0 {
+        divisor.num = -divisor.num;
+    }
+    divisor.simplify();
+    for f in s.iter_mut() {
+        *f /= divisor;
+    }
+    Some(divisor)
+}